@@ -114,7 +114,11 @@ fn main() -> Result<()> {
                     },
                 },
                 close_proposal_on_execution_failure: false,
+                continue_on_execution_failure: false,
                 veto: None,
+                threshold_rules: vec![],
+                commit_reveal: None,
+                voting_period_range: None,
             })?,
             admin: Some(Admin::CoreModule {}),
             funds: vec![],
@@ -73,6 +73,7 @@ pub fn create_dao(
                 allow_revoting: false,
                 only_members_execute: true,
                 close_proposal_on_execution_failure: false,
+                continue_on_execution_failure: false,
                 pre_propose_info: PreProposeInfo::ModuleMayPropose {
                     info: ModuleInstantiateInfo {
                         code_id: chain.orc.contract_map.code_id("dao_pre_propose_single")?,
@@ -98,6 +99,9 @@ pub fn create_dao(
                     },
                 },
                 veto: None,
+                threshold_rules: vec![],
+                commit_reveal: None,
+                voting_period_range: None,
             })?,
             admin: Some(Admin::CoreModule {}),
             funds: vec![],
@@ -252,6 +256,9 @@ pub fn create_proposal(
                     description: "desc".to_string(),
                     msgs,
                     vote: None,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
                 },
             },
             key,
@@ -55,6 +55,12 @@ pub fn query_proposal_v1(
         votes: v1_votes_to_v2(proposal.votes),
         allow_revoting: proposal.allow_revoting,
         veto: None,
+        execute_after: None,
+        depends_on: vec![],
+        message_results: vec![],
+        threshold_rule: None,
+        commit_reveal: None,
+        no_with_veto_threshold: None,
     };
 
     (proposal_count, proposal)
@@ -1,3 +1,4 @@
+use cosmwasm_std::Uint128;
 use cw_utils::Expiration;
 use dao_voting::{
     status::Status,
@@ -50,5 +51,6 @@ pub(crate) fn v1_votes_to_v2(v1: voting_v1::Votes) -> Votes {
         yes: v1.yes,
         no: v1.no,
         abstain: v1.abstain,
+        no_with_veto: Uint128::zero(),
     }
 }
@@ -95,12 +95,18 @@ pub fn execute_propose(
             description,
             msgs,
             vote,
+            execute_after,
+            depends_on,
+            voting_period,
         } => ProposeMsg {
             title,
             description,
             msgs,
             proposer: Some(info.sender.to_string()),
             vote,
+            execute_after,
+            depends_on,
+            voting_period,
         },
     };
 
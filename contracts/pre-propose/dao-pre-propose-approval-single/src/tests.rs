@@ -88,7 +88,12 @@ fn get_default_proposal_module_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        continue_on_execution_failure: false,
         veto: None,
+        threshold_rules: vec![],
+        commit_reveal: None,
+        voting_period_range: None,
+        no_with_veto_threshold: None,
     }
 }
 
@@ -197,6 +202,9 @@ fn make_pre_proposal(app: &mut App, pre_propose: Addr, proposer: &str, funds: &[
                 description: "description".to_string(),
                 msgs: vec![],
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
             },
         },
         funds,
@@ -1160,6 +1168,7 @@ fn test_permissions() {
             &ExecuteMsg::ProposalCompletedHook {
                 proposal_id: 1,
                 new_status: Status::Closed,
+                no_with_veto: false,
             },
             &[],
         )
@@ -1180,6 +1189,9 @@ fn test_permissions() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
                 },
             },
             &[],
@@ -1332,6 +1344,9 @@ fn test_no_deposit_required_members_submission() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
                 },
             },
             &[],
@@ -1398,6 +1413,9 @@ fn test_anyone_denylist() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
                 },
             },
             &[],
@@ -1454,6 +1472,9 @@ fn test_specific_allowlist_denylist() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
                 },
             },
             &[],
@@ -1506,6 +1527,9 @@ fn test_specific_allowlist_denylist() {
                     description: "I am a member!!!".to_string(),
                     msgs: vec![],
                     vote: None,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
                 },
             },
             &[],
@@ -1542,6 +1566,9 @@ fn test_specific_allowlist_denylist() {
                     description: "I am a member!!!".to_string(),
                     msgs: vec![],
                     vote: None,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
                 },
             },
             &[],
@@ -1604,7 +1631,12 @@ fn test_instantiate_with_zero_native_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            continue_on_execution_failure: false,
             veto: None,
+            threshold_rules: vec![],
+            commit_reveal: None,
+            voting_period_range: None,
+            no_with_veto_threshold: None,
         }
     };
 
@@ -1673,7 +1705,12 @@ fn test_instantiate_with_zero_cw20_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            continue_on_execution_failure: false,
             veto: None,
+            threshold_rules: vec![],
+            commit_reveal: None,
+            voting_period_range: None,
+            no_with_veto_threshold: None,
         }
     };
 
@@ -94,6 +94,7 @@ pub fn execute(
         ExecuteMsg::ProposalCompletedHook {
             proposal_id,
             new_status,
+            ..
         } => execute_proposal_completed(deps, info, proposal_id, new_status),
         ExecuteMsg::Extension { msg } => match msg {
             ExecuteExt::ResetApprover {} => execute_reset_approver(deps, env, info),
@@ -115,7 +115,12 @@ fn get_proposal_module_approval_single_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        continue_on_execution_failure: false,
         veto: None,
+        threshold_rules: vec![],
+        commit_reveal: None,
+        voting_period_range: None,
+        no_with_veto_threshold: None,
     }
 }
 
@@ -148,7 +153,12 @@ fn get_proposal_module_approver_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        continue_on_execution_failure: false,
         veto: None,
+        threshold_rules: vec![],
+        commit_reveal: None,
+        voting_period_range: None,
+        no_with_veto_threshold: None,
     }
 }
 
@@ -321,6 +331,9 @@ fn make_pre_proposal(app: &mut App, pre_propose: Addr, proposer: &str, funds: &[
                 description: "description".to_string(),
                 msgs: vec![],
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
             },
         },
         funds,
@@ -1155,6 +1168,7 @@ fn test_permissions() {
             &ExecuteMsg::ProposalCompletedHook {
                 proposal_id: 1,
                 new_status: Status::Closed,
+                no_with_veto: false,
             },
             &[],
         )
@@ -1175,6 +1189,9 @@ fn test_permissions() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
                 },
             },
             &[],
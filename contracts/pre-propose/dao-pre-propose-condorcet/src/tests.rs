@@ -0,0 +1,267 @@
+use cosmwasm_std::{coins, from_json, to_json_binary, Addr, Decimal, Empty, Uint128};
+use cw2::ContractVersion;
+use cw20::Cw20Coin;
+use cw_denom::UncheckedDenom;
+use cw_multi_test::{App, BankSudo, Contract, ContractWrapper, Executor};
+use cw_utils::Duration;
+use dao_interface::state::{Admin, ModuleInstantiateInfo, ProposalModule};
+use dao_proposal_condorcet as cpc;
+use dao_testing::{
+    contracts::proposal_condorcet_contract, helpers::instantiate_with_cw4_groups_governance,
+};
+use dao_voting::{
+    deposit::{DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
+    pre_propose::{PreProposeInfo, PreProposeSubmissionPolicy, ProposalCreationPolicy},
+    threshold::PercentageThreshold,
+};
+
+use crate::contract::*;
+
+fn cw_pre_propose_condorcet_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(execute, instantiate, query);
+    Box::new(contract)
+}
+
+struct DefaultTestSetup {
+    core_addr: Addr,
+    condorcet: Addr,
+    pre_propose: Addr,
+}
+
+fn setup_default_test(app: &mut App, refund_policy: DepositRefundPolicy) -> DefaultTestSetup {
+    let condorcet_id = app.store_code(proposal_condorcet_contract());
+    let pre_propose_id = app.store_code(cw_pre_propose_condorcet_contract());
+
+    let proposal_module_instantiate = cpc::msg::InstantiateMsg {
+        quorum: PercentageThreshold::Percent(Decimal::percent(10)),
+        voting_period: Duration::Time(86400),
+        min_voting_period: None,
+        close_proposals_on_execution_failure: true,
+        allow_revoting: false,
+        cycle_resolution: None,
+        veto: None,
+        pre_propose_info: PreProposeInfo::ModuleMayPropose {
+            info: ModuleInstantiateInfo {
+                code_id: pre_propose_id,
+                msg: to_json_binary(&InstantiateMsg {
+                    deposit_info: Some(UncheckedDepositInfo {
+                        denom: DepositToken::Token {
+                            denom: UncheckedDenom::Native("ujuno".to_string()),
+                        },
+                        amount: Uint128::new(10),
+                        refund_policy,
+                    }),
+                    submission_policy: PreProposeSubmissionPolicy::Specific {
+                        dao_members: true,
+                        allowlist: None,
+                        denylist: None,
+                    },
+                    extension: Empty::default(),
+                })
+                .unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                funds: vec![],
+                label: "baby's first pre-propose module".to_string(),
+            },
+        },
+    };
+
+    let core_addr = instantiate_with_cw4_groups_governance(
+        app,
+        condorcet_id,
+        to_json_binary(&proposal_module_instantiate).unwrap(),
+        Some(vec![
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(9),
+            },
+            Cw20Coin {
+                address: "keze".to_string(),
+                amount: Uint128::new(8),
+            },
+        ]),
+    );
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &dao_interface::msg::QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(proposal_modules.len(), 1);
+    let condorcet = proposal_modules.into_iter().next().unwrap().address;
+    let proposal_creation_policy = app
+        .wrap()
+        .query_wasm_smart(
+            condorcet.clone(),
+            &cpc::msg::QueryMsg::ProposalCreationPolicy {},
+        )
+        .unwrap();
+
+    let pre_propose = match proposal_creation_policy {
+        ProposalCreationPolicy::Module { addr } => addr,
+        _ => panic!("expected a module for the proposal creation policy"),
+    };
+
+    DefaultTestSetup {
+        core_addr,
+        condorcet,
+        pre_propose,
+    }
+}
+
+fn make_proposal(app: &mut App, condorcet: &Addr, pre_propose: Addr, proposer: &str) -> u32 {
+    let id: u32 = app
+        .wrap()
+        .query_wasm_smart(condorcet, &cpc::msg::QueryMsg::NextProposalId {})
+        .unwrap();
+    app.sudo(cw_multi_test::SudoMsg::Bank(BankSudo::Mint {
+        to_address: proposer.to_string(),
+        amount: coins(10, "ujuno"),
+    }))
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(proposer),
+        pre_propose,
+        &ExecuteMsg::Propose {
+            msg: ProposeMessage::Propose {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                choices: vec![cpc::msg::Choice {
+                    title: "choice".to_string(),
+                    description: "choice description".to_string(),
+                    msgs: vec![],
+                }],
+            },
+        },
+        &coins(10, "ujuno"),
+    )
+    .unwrap();
+    // the deposit was taken.
+    assert_eq!(get_balance_native(app, proposer, "ujuno"), Uint128::zero());
+    id
+}
+
+fn get_balance_native(app: &App, who: &str, denom: &str) -> Uint128 {
+    app.wrap().query_balance(who, denom).unwrap().amount
+}
+
+fn execute_proposal_msg(app: &mut App, condorcet: Addr, sender: &str, msg: cpc::msg::ExecuteMsg) {
+    app.execute_contract(Addr::unchecked(sender), condorcet, &msg, &[])
+        .unwrap();
+}
+
+#[test]
+fn test_deposit_refunded_on_execution() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        condorcet,
+        pre_propose,
+    } = setup_default_test(&mut app, DepositRefundPolicy::OnlyPassed);
+
+    let proposal_id = make_proposal(&mut app, &condorcet, pre_propose, "ekez");
+
+    // ekez's voting power is larger than keze's, so ranking the only
+    // choice first passes the proposal.
+    execute_proposal_msg(
+        &mut app,
+        condorcet.clone(),
+        "ekez",
+        cpc::msg::ExecuteMsg::Vote {
+            proposal_id,
+            vote: vec![0, 1],
+        },
+    );
+    execute_proposal_msg(
+        &mut app,
+        condorcet,
+        "ekez",
+        cpc::msg::ExecuteMsg::Execute { proposal_id },
+    );
+
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::new(10));
+    assert_eq!(
+        get_balance_native(&app, core_addr.as_str(), "ujuno"),
+        Uint128::zero()
+    );
+}
+
+#[test]
+fn test_deposit_kept_on_close() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        condorcet,
+        pre_propose,
+    } = setup_default_test(&mut app, DepositRefundPolicy::OnlyPassed);
+
+    let proposal_id = make_proposal(&mut app, &condorcet, pre_propose, "ekez");
+
+    // nobody votes, so the proposal is rejected once it expires.
+    app.update_block(|b| b.time = b.time.plus_seconds(86400));
+    execute_proposal_msg(
+        &mut app,
+        condorcet,
+        "ekez",
+        cpc::msg::ExecuteMsg::Close { proposal_id },
+    );
+
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::zero());
+    assert_eq!(
+        get_balance_native(&app, core_addr.as_str(), "ujuno"),
+        Uint128::new(10)
+    );
+}
+
+#[test]
+fn test_deposit_refunded_on_close() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        condorcet,
+        pre_propose,
+    } = setup_default_test(&mut app, DepositRefundPolicy::Always);
+
+    let proposal_id = make_proposal(&mut app, &condorcet, pre_propose, "ekez");
+
+    app.update_block(|b| b.time = b.time.plus_seconds(86400));
+    execute_proposal_msg(
+        &mut app,
+        condorcet,
+        "ekez",
+        cpc::msg::ExecuteMsg::Close { proposal_id },
+    );
+
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::new(10));
+    assert_eq!(
+        get_balance_native(&app, core_addr.as_str(), "ujuno"),
+        Uint128::zero()
+    );
+}
+
+#[test]
+fn test_set_version() {
+    let mut app = App::default();
+    let DefaultTestSetup { pre_propose, .. } =
+        setup_default_test(&mut app, DepositRefundPolicy::Always);
+
+    let info: ContractVersion = from_json(
+        app.wrap()
+            .query_wasm_raw(pre_propose, "contract_info".as_bytes())
+            .unwrap()
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        ContractVersion {
+            contract: CONTRACT_NAME.to_string(),
+            version: CONTRACT_VERSION.to_string()
+        },
+        info
+    )
+}
@@ -3,6 +3,7 @@ use cosmwasm_schema::cw_serde;
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult};
 use cw2::set_contract_version;
+use cw_utils::{Duration, Expiration};
 
 use dao_pre_propose_base::{
     error::PreProposeError,
@@ -10,7 +11,7 @@ use dao_pre_propose_base::{
     state::PreProposeContract,
 };
 use dao_voting::{
-    multiple_choice::{MultipleChoiceAutoVote, MultipleChoiceOptions},
+    multiple_choice::{MultipleChoiceAutoVote, MultipleChoiceOptions, UncheckedBudget},
     proposal::MultipleChoiceProposeMsg as ProposeMsg,
 };
 
@@ -24,6 +25,12 @@ pub enum ProposeMessage {
         description: String,
         choices: MultipleChoiceOptions,
         vote: Option<MultipleChoiceAutoVote>,
+        execute_after: Option<Expiration>,
+        #[serde(default)]
+        depends_on: Vec<(String, u64)>,
+        voting_period: Option<Duration>,
+        #[serde(default)]
+        budget: Option<UncheckedBudget>,
     },
 }
 
@@ -73,6 +80,10 @@ pub fn execute(
                     description,
                     choices,
                     vote,
+                    execute_after,
+                    depends_on,
+                    voting_period,
+                    budget,
                 },
         } => ExecuteInternal::Propose {
             msg: ProposeMessageInternal::Propose(ProposeMsg {
@@ -81,6 +92,10 @@ pub fn execute(
                 description,
                 choices,
                 vote,
+                execute_after,
+                depends_on,
+                voting_period,
+                budget,
             }),
         },
         ExecuteMsg::Extension { msg } => ExecuteInternal::Extension { msg },
@@ -114,9 +129,11 @@ pub fn execute(
         ExecuteBase::ProposalCompletedHook {
             proposal_id,
             new_status,
+            no_with_veto,
         } => ExecuteInternal::ProposalCompletedHook {
             proposal_id,
             new_status,
+            no_with_veto,
         },
     };
 
@@ -89,6 +89,8 @@ fn get_default_proposal_module_instantiate(
         },
         close_proposal_on_execution_failure: false,
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     }
 }
 
@@ -206,15 +208,21 @@ fn make_proposal(
                             description: "multiple choice option 1".to_string(),
                             msgs: vec![],
                             title: "title".to_string(),
+                            funding: None,
                         },
                         MultipleChoiceOption {
                             description: "multiple choice option 2".to_string(),
                             msgs: vec![],
                             title: "title".to_string(),
+                            funding: None,
                         },
                     ],
                 },
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
+                budget: None,
             },
         },
         funds,
@@ -248,6 +256,7 @@ fn make_proposal(
                 vote_count: Uint128::zero(),
                 index: 0,
                 title: "title".to_string(),
+                funding: None,
             },
             CheckedMultipleChoiceOption {
                 description: "multiple choice option 2".to_string(),
@@ -256,6 +265,7 @@ fn make_proposal(
                 vote_count: Uint128::zero(),
                 index: 1,
                 title: "title".to_string(),
+                funding: None,
             },
             CheckedMultipleChoiceOption {
                 description: "None of the above".to_string(),
@@ -264,6 +274,7 @@ fn make_proposal(
                 vote_count: Uint128::zero(),
                 index: 2,
                 title: "None of the above".to_string(),
+                funding: None,
             },
         ]
     );
@@ -514,12 +525,20 @@ fn test_native_permutation(
         fn(&mut App, Addr, &str, u64) -> (),
     ) = match end_status {
         EndStatus::Passed => (
-            MultipleChoiceVote { option_id: 0 },
+            MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             Status::Passed,
             execute_proposal,
         ),
         EndStatus::Failed => (
-            MultipleChoiceVote { option_id: 2 },
+            MultipleChoiceVote {
+                option_id: 2,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             Status::Rejected,
             close_proposal,
         ),
@@ -592,12 +611,20 @@ fn test_cw20_permutation(
         fn(&mut App, Addr, &str, u64) -> (),
     ) = match end_status {
         EndStatus::Passed => (
-            MultipleChoiceVote { option_id: 0 },
+            MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             Status::Passed,
             execute_proposal,
         ),
         EndStatus::Failed => (
-            MultipleChoiceVote { option_id: 2 },
+            MultipleChoiceVote {
+                option_id: 2,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             Status::Rejected,
             close_proposal,
         ),
@@ -769,7 +796,11 @@ fn test_multiple_open_proposals() {
         proposal_single.clone(),
         "ekez",
         first_id,
-        MultipleChoiceVote { option_id: 0 },
+        MultipleChoiceVote {
+            option_id: 0,
+            additional_option_ids: vec![],
+            scores: vec![],
+        },
     );
     assert_eq!(Status::Passed, new_status);
 
@@ -789,7 +820,11 @@ fn test_multiple_open_proposals() {
         proposal_single.clone(),
         "ekez",
         second_id,
-        MultipleChoiceVote { option_id: 2 },
+        MultipleChoiceVote {
+            option_id: 2,
+            additional_option_ids: vec![],
+            scores: vec![],
+        },
     );
     assert_eq!(Status::Rejected, new_status);
 
@@ -867,6 +902,7 @@ fn test_permissions() {
             &ExecuteMsg::ProposalCompletedHook {
                 proposal_id: 1,
                 new_status: Status::Closed,
+                no_with_veto: false,
             },
             &[],
         )
@@ -890,9 +926,14 @@ fn test_permissions() {
                             description: "multiple choice option 1".to_string(),
                             msgs: vec![],
                             title: "title".to_string(),
+                            funding: None,
                         }],
                     },
                     vote: None,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
+                    budget: None,
                 },
             },
             &[],
@@ -940,7 +981,11 @@ fn test_propose_open_proposal_submission() {
         proposal_single,
         "ekez",
         id,
-        MultipleChoiceVote { option_id: 0 },
+        MultipleChoiceVote {
+            option_id: 0,
+            additional_option_ids: vec![],
+            scores: vec![],
+        },
     );
     assert_eq!(Status::Passed, new_status)
 }
@@ -970,7 +1015,11 @@ fn test_no_deposit_required_open_submission() {
         proposal_single,
         "ekez",
         id,
-        MultipleChoiceVote { option_id: 0 },
+        MultipleChoiceVote {
+            option_id: 0,
+            additional_option_ids: vec![],
+            scores: vec![],
+        },
     );
     assert_eq!(Status::Passed, new_status)
 }
@@ -1000,9 +1049,14 @@ fn test_no_deposit_required_members_submission() {
                             description: "multiple choice option 1".to_string(),
                             msgs: vec![],
                             title: "title".to_string(),
+                            funding: None,
                         }],
                     },
                     vote: None,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
+                    budget: None,
                 },
             },
             &[],
@@ -1021,7 +1075,11 @@ fn test_no_deposit_required_members_submission() {
         proposal_single,
         "ekez",
         id,
-        MultipleChoiceVote { option_id: 0 },
+        MultipleChoiceVote {
+            option_id: 0,
+            additional_option_ids: vec![],
+            scores: vec![],
+        },
     );
     assert_eq!(Status::Passed, new_status)
 }
@@ -1080,9 +1138,14 @@ fn test_anyone_denylist() {
                             description: "multiple choice option 1".to_string(),
                             msgs: vec![],
                             title: "title".to_string(),
+                            funding: None,
                         }],
                     },
                     vote: None,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
+                    budget: None,
                 },
             },
             &[],
@@ -1148,9 +1211,14 @@ fn test_specific_allowlist_denylist() {
                             description: "multiple choice option 1".to_string(),
                             msgs: vec![],
                             title: "title".to_string(),
+                            funding: None,
                         }],
                     },
                     vote: None,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
+                    budget: None,
                 },
             },
             &[],
@@ -1212,9 +1280,14 @@ fn test_specific_allowlist_denylist() {
                             description: "multiple choice option 1".to_string(),
                             msgs: vec![],
                             title: "title".to_string(),
+                            funding: None,
                         }],
                     },
                     vote: None,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
+                    budget: None,
                 },
             },
             &[],
@@ -1254,9 +1327,14 @@ fn test_specific_allowlist_denylist() {
                             description: "multiple choice option 1".to_string(),
                             msgs: vec![],
                             title: "title".to_string(),
+                            funding: None,
                         }],
                     },
                     vote: None,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
+                    budget: None,
                 },
             },
             &[],
@@ -1356,6 +1434,8 @@ fn test_instantiate_with_zero_native_deposit() {
             },
             close_proposal_on_execution_failure: false,
             veto: None,
+            voting_period_range: None,
+            tie_breaker: None,
         }
     };
 
@@ -1423,6 +1503,8 @@ fn test_instantiate_with_zero_cw20_deposit() {
             },
             close_proposal_on_execution_failure: false,
             veto: None,
+            voting_period_range: None,
+            tie_breaker: None,
         }
     };
 
@@ -1539,14 +1621,22 @@ fn test_update_config() {
         proposal_single.clone(),
         "ekez",
         id,
-        MultipleChoiceVote { option_id: 0 },
+        MultipleChoiceVote {
+            option_id: 0,
+            additional_option_ids: vec![],
+            scores: vec![],
+        },
     );
     vote(
         &mut app,
         proposal_single.clone(),
         "ekez",
         new_id,
-        MultipleChoiceVote { option_id: 0 },
+        MultipleChoiceVote {
+            option_id: 0,
+            additional_option_ids: vec![],
+            scores: vec![],
+        },
     );
     execute_proposal(&mut app, proposal_single.clone(), "ekez", id);
     execute_proposal(&mut app, proposal_single.clone(), "ekez", new_id);
@@ -2181,7 +2271,11 @@ fn test_withdraw() {
         proposal_single.clone(),
         "ekez",
         cw20_id,
-        MultipleChoiceVote { option_id: 0 },
+        MultipleChoiceVote {
+            option_id: 0,
+            additional_option_ids: vec![],
+            scores: vec![],
+        },
     );
     execute_proposal(&mut app, proposal_single.clone(), "ekez", cw20_id);
 
@@ -2203,7 +2297,11 @@ fn test_withdraw() {
         proposal_single.clone(),
         "ekez",
         native_id,
-        MultipleChoiceVote { option_id: 2 },
+        MultipleChoiceVote {
+            option_id: 2,
+            additional_option_ids: vec![],
+            scores: vec![],
+        },
     );
     close_proposal(&mut app, proposal_single.clone(), "ekez", native_id);
     withdraw(
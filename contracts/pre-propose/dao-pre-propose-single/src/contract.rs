@@ -1,18 +1,26 @@
-use cosmwasm_schema::cw_serde;
+use std::collections::BTreeMap;
+
+use cosmwasm_schema::{cw_serde, QueryResponses};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
+    to_json_binary, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response,
+    StdResult,
 };
 use cw2::set_contract_version;
+use cw_paginate_storage::paginate_map;
+use cw_utils::{Duration, Expiration};
 
 use dao_pre_propose_base::{
     error::PreProposeError,
     msg::{ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, QueryMsg as QueryBase},
     state::PreProposeContract,
+    templates::{Template, TemplateError, TemplateResponse},
 };
 use dao_voting::{proposal::SingleChoiceProposeMsg as ProposeMsg, voting::SingleChoiceAutoVote};
 
+use crate::state::TEMPLATES;
+
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-pre-propose-single";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -27,12 +35,47 @@ pub enum ProposeMessage {
         description: String,
         msgs: Vec<CosmosMsg<Empty>>,
         vote: Option<SingleChoiceAutoVote>,
+        execute_after: Option<Expiration>,
+        #[serde(default)]
+        depends_on: Vec<(String, u64)>,
+        voting_period: Option<Duration>,
+    },
+    /// Makes a proposal whose messages are rendered from the named
+    /// template with the given parameter values.
+    ProposeFromTemplate {
+        title: String,
+        description: String,
+        template: String,
+        params: BTreeMap<String, String>,
+    },
+}
+
+#[cw_serde]
+pub enum ExecuteExt {
+    /// Adds a template that proposals may be made from. Only callable
+    /// by the DAO.
+    AddTemplate { name: String, template: Template },
+    /// Removes a template. Only callable by the DAO.
+    RemoveTemplate { name: String },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryExt {
+    /// Gets a template by name.
+    #[returns(dao_pre_propose_base::templates::Template)]
+    Template { name: String },
+    /// Lists templates in order of name.
+    #[returns(Vec<dao_pre_propose_base::templates::TemplateResponse>)]
+    ListTemplates {
+        start_after: Option<String>,
+        limit: Option<u32>,
     },
 }
 
 pub type InstantiateMsg = InstantiateBase<Empty>;
-pub type ExecuteMsg = ExecuteBase<ProposeMessage, Empty>;
-pub type QueryMsg = QueryBase<Empty>;
+pub type ExecuteMsg = ExecuteBase<ProposeMessage, ExecuteExt>;
+pub type QueryMsg = QueryBase<QueryExt>;
 
 /// Internal version of the propose message that includes the
 /// `proposer` field. The module will fill this in based on the sender
@@ -42,7 +85,7 @@ enum ProposeMessageInternal {
     Propose(ProposeMsg),
 }
 
-type PrePropose = PreProposeContract<Empty, Empty, Empty, ProposeMessageInternal>;
+type PrePropose = PreProposeContract<Empty, ExecuteExt, QueryExt, ProposeMessageInternal>;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -67,7 +110,7 @@ pub fn execute(
     // message externally as that is to be set by this module. Here,
     // we transform an external message which omits that field into an
     // internal message which sets it.
-    type ExecuteInternal = ExecuteBase<ProposeMessageInternal, Empty>;
+    type ExecuteInternal = ExecuteBase<ProposeMessageInternal, ExecuteExt>;
     let internalized = match msg {
         ExecuteMsg::Propose {
             msg:
@@ -76,6 +119,9 @@ pub fn execute(
                     description,
                     msgs,
                     vote,
+                    execute_after,
+                    depends_on,
+                    voting_period,
                 },
         } => ExecuteInternal::Propose {
             msg: ProposeMessageInternal::Propose(ProposeMsg {
@@ -85,9 +131,49 @@ pub fn execute(
                 description,
                 msgs,
                 vote,
+                execute_after,
+                depends_on,
+                voting_period,
             }),
         },
-        ExecuteMsg::Extension { msg } => ExecuteInternal::Extension { msg },
+        ExecuteMsg::Propose {
+            msg:
+                ProposeMessage::ProposeFromTemplate {
+                    title,
+                    description,
+                    template,
+                    params,
+                },
+        } => {
+            let msgs = TEMPLATES
+                .may_load(deps.storage, template.clone())?
+                .ok_or(TemplateError::TemplateNotFound { name: template })?
+                .render(deps.api, &params)?;
+            ExecuteInternal::Propose {
+                msg: ProposeMessageInternal::Propose(ProposeMsg {
+                    proposer: Some(info.sender.to_string()),
+                    title,
+                    description,
+                    msgs,
+                    vote: None,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
+                }),
+            }
+        }
+        ExecuteMsg::Extension { msg } => {
+            let dao = PrePropose::default().dao.load(deps.storage)?;
+            if info.sender != dao {
+                return Err(PreProposeError::NotDao {});
+            }
+            return match msg {
+                ExecuteExt::AddTemplate { name, template } => {
+                    execute_add_template(deps, env, name, template)
+                }
+                ExecuteExt::RemoveTemplate { name } => execute_remove_template(deps, name),
+            };
+        }
         ExecuteMsg::Withdraw { denom } => ExecuteInternal::Withdraw { denom },
         ExecuteMsg::UpdateConfig {
             deposit_info,
@@ -118,16 +204,59 @@ pub fn execute(
         ExecuteMsg::ProposalCompletedHook {
             proposal_id,
             new_status,
+            no_with_veto,
         } => ExecuteInternal::ProposalCompletedHook {
             proposal_id,
             new_status,
+            no_with_veto,
         },
     };
 
     PrePropose::default().execute(deps, env, info, internalized)
 }
 
+pub fn execute_add_template(
+    deps: DepsMut,
+    env: Env,
+    name: String,
+    template: Template,
+) -> Result<Response, PreProposeError> {
+    if TEMPLATES.has(deps.storage, name.clone()) {
+        return Err(TemplateError::TemplateExists { name }.into());
+    }
+    template.validate(&env.contract.address)?;
+    TEMPLATES.save(deps.storage, name.clone(), &template)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "add_template")
+        .add_attribute("template", name))
+}
+
+pub fn execute_remove_template(deps: DepsMut, name: String) -> Result<Response, PreProposeError> {
+    if !TEMPLATES.has(deps.storage, name.clone()) {
+        return Err(TemplateError::TemplateNotFound { name }.into());
+    }
+    TEMPLATES.remove(deps.storage, name.clone());
+
+    Ok(Response::default()
+        .add_attribute("method", "remove_template")
+        .add_attribute("template", name))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
-    PrePropose::default().query(deps, env, msg)
+    match msg {
+        QueryMsg::QueryExtension { msg } => match msg {
+            QueryExt::Template { name } => to_json_binary(&TEMPLATES.load(deps.storage, name)?),
+            QueryExt::ListTemplates { start_after, limit } => {
+                let templates: Vec<TemplateResponse> =
+                    paginate_map(deps, &TEMPLATES, start_after, limit, Order::Ascending)?
+                        .into_iter()
+                        .map(|(name, template)| TemplateResponse { name, template })
+                        .collect();
+                to_json_binary(&templates)
+            }
+        },
+        _ => PrePropose::default().query(deps, env, msg),
+    }
 }
@@ -1,11 +1,12 @@
 #![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
 
 pub mod contract;
+pub mod state;
 
 #[cfg(test)]
 mod tests;
 
-pub use contract::{ExecuteMsg, InstantiateMsg, ProposeMessage, QueryMsg};
+pub use contract::{ExecuteExt, ExecuteMsg, InstantiateMsg, ProposeMessage, QueryExt, QueryMsg};
 
 // Exporting these means that contracts interacting with this one don't
 // need an explicit dependency on the base contract to read queries.
@@ -0,0 +1,6 @@
+use cw_storage_plus::Map;
+
+use dao_pre_propose_base::templates::Template;
+
+/// Templates that proposals may be made from, keyed by name.
+pub const TEMPLATES: Map<String, Template> = Map::new("templates");
@@ -1,4 +1,6 @@
-use cosmwasm_std::{coins, from_json, to_json_binary, Addr, Coin, Empty, Uint128};
+use cosmwasm_std::{
+    coins, from_json, to_json_binary, Addr, BankMsg, Coin, CosmosMsg, Empty, Uint128, WasmMsg,
+};
 use cw2::ContractVersion;
 use cw20::Cw20Coin;
 use cw_denom::UncheckedDenom;
@@ -6,7 +8,12 @@ use cw_multi_test::{App, BankSudo, Contract, ContractWrapper, Executor};
 use cw_utils::Duration;
 use dao_interface::state::ProposalModule;
 use dao_interface::state::{Admin, ModuleInstantiateInfo};
-use dao_pre_propose_base::{error::PreProposeError, msg::DepositInfoResponse, state::Config};
+use dao_pre_propose_base::{
+    error::PreProposeError,
+    msg::DepositInfoResponse,
+    state::Config,
+    templates::{ParamKind, Template, TemplateError, TemplateParam, TemplateResponse},
+};
 use dao_proposal_single as dps;
 use dao_testing::helpers::instantiate_with_cw4_groups_governance;
 use dao_voting::pre_propose::{PreProposeSubmissionPolicy, PreProposeSubmissionPolicyError};
@@ -86,7 +93,12 @@ fn get_default_proposal_module_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        continue_on_execution_failure: false,
         veto: None,
+        threshold_rules: vec![],
+        commit_reveal: None,
+        voting_period_range: None,
+        no_with_veto_threshold: None,
     }
 }
 
@@ -200,6 +212,9 @@ fn make_proposal(
                 description: "description".to_string(),
                 msgs: vec![],
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
             },
         },
         funds,
@@ -443,6 +458,55 @@ fn execute_proposal(app: &mut App, module: Addr, sender: &str, proposal_id: u64)
     .unwrap();
 }
 
+fn add_template(
+    app: &mut App,
+    sender: &Addr,
+    module: &Addr,
+    name: &str,
+    template: Template,
+) -> Result<(), PreProposeError> {
+    app.execute_contract(
+        sender.clone(),
+        module.clone(),
+        &ExecuteMsg::Extension {
+            msg: ExecuteExt::AddTemplate {
+                name: name.to_string(),
+                template,
+            },
+        },
+        &[],
+    )
+    .map(|_| ())
+    .map_err(|e| e.downcast().unwrap())
+}
+
+fn propose_from_template(
+    app: &mut App,
+    module: &Addr,
+    proposer: &str,
+    template: &str,
+    params: &[(&str, &str)],
+) -> Result<(), PreProposeError> {
+    app.execute_contract(
+        Addr::unchecked(proposer),
+        module.clone(),
+        &ExecuteMsg::Propose {
+            msg: ProposeMessage::ProposeFromTemplate {
+                title: "payout".to_string(),
+                description: "monthly contributor payout".to_string(),
+                template: template.to_string(),
+                params: params
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            },
+        },
+        &[],
+    )
+    .map(|_| ())
+    .map_err(|e| e.downcast().unwrap())
+}
+
 enum EndStatus {
     Passed,
     Failed,
@@ -833,6 +897,7 @@ fn test_permissions() {
             &ExecuteMsg::ProposalCompletedHook {
                 proposal_id: 1,
                 new_status: Status::Closed,
+                no_with_veto: false,
             },
             &[],
         )
@@ -853,6 +918,9 @@ fn test_permissions() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
                 },
             },
             &[],
@@ -945,6 +1013,9 @@ fn test_no_deposit_required_members_submission() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
                 },
             },
             &[],
@@ -1013,6 +1084,9 @@ fn test_anyone_denylist() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
                 },
             },
             &[],
@@ -1075,6 +1149,9 @@ fn test_specific_allowlist_denylist() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
                 },
             },
             &[],
@@ -1133,6 +1210,9 @@ fn test_specific_allowlist_denylist() {
                     description: "I am a member!!!".to_string(),
                     msgs: vec![],
                     vote: None,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
                 },
             },
             &[],
@@ -1169,6 +1249,9 @@ fn test_specific_allowlist_denylist() {
                     description: "I am a member!!!".to_string(),
                     msgs: vec![],
                     vote: None,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
                 },
             },
             &[],
@@ -1193,35 +1276,237 @@ fn test_specific_allowlist_denylist() {
 }
 
 #[test]
-fn test_execute_extension_does_nothing() {
+fn test_proposal_templates() {
     let mut app = App::default();
     let DefaultTestSetup {
-        core_addr: _,
-        proposal_single: _,
+        core_addr,
+        proposal_single,
         pre_propose,
     } = setup_default_test(
         &mut app, None, false, // no open proposal submission.
     );
 
-    let res = app
-        .execute_contract(
-            Addr::unchecked("ekez"),
-            pre_propose,
-            &ExecuteMsg::Extension {
-                msg: Empty::default(),
+    let payout = Template {
+        msgs: r#"[{"bank":{"send":{"to_address":"{{recipient}}","amount":[{"denom":"ujuno","amount":"{{amount}}"}]}}}]"#
+            .to_string(),
+        params: vec![
+            TemplateParam {
+                name: "recipient".to_string(),
+                kind: ParamKind::Address,
+            },
+            TemplateParam {
+                name: "amount".to_string(),
+                kind: ParamKind::Uint128,
+            },
+        ],
+    };
+
+    // Only the DAO may add templates.
+    let err = add_template(
+        &mut app,
+        &Addr::unchecked("ekez"),
+        &pre_propose,
+        "payout",
+        payout.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, PreProposeError::NotDao {});
+
+    add_template(&mut app, &core_addr, &pre_propose, "payout", payout.clone()).unwrap();
+
+    let err =
+        add_template(&mut app, &core_addr, &pre_propose, "payout", payout.clone()).unwrap_err();
+    assert_eq!(
+        err,
+        PreProposeError::Template(TemplateError::TemplateExists {
+            name: "payout".to_string()
+        })
+    );
+
+    // Templates are validated when they are added.
+    let mut unused = payout.clone();
+    unused.params.push(TemplateParam {
+        name: "memo".to_string(),
+        kind: ParamKind::String,
+    });
+    let err = add_template(&mut app, &core_addr, &pre_propose, "unused", unused).unwrap_err();
+    assert_eq!(
+        err,
+        PreProposeError::Template(TemplateError::UnusedParam {
+            name: "memo".to_string()
+        })
+    );
+
+    let template: Template = app
+        .wrap()
+        .query_wasm_smart(
+            &pre_propose,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::Template {
+                    name: "payout".to_string(),
+                },
             },
-            &[],
         )
         .unwrap();
+    assert_eq!(template, payout);
+    let templates: Vec<TemplateResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            &pre_propose,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::ListTemplates {
+                    start_after: None,
+                    limit: None,
+                },
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        templates,
+        vec![TemplateResponse {
+            name: "payout".to_string(),
+            template: payout,
+        }]
+    );
 
-    // There should be one event which is the invocation of the contract.
-    assert_eq!(res.events.len(), 1);
-    assert_eq!(res.events[0].ty, "execute".to_string());
-    assert_eq!(res.events[0].attributes.len(), 1);
+    propose_from_template(
+        &mut app,
+        &pre_propose,
+        "ekez",
+        "payout",
+        &[("recipient", "keze"), ("amount", "100")],
+    )
+    .unwrap();
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &proposal_single,
+            &dps::msg::QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+    assert_eq!(proposal.proposal.proposer, Addr::unchecked("ekez"));
     assert_eq!(
-        res.events[0].attributes[0].key,
-        "_contract_address".to_string()
+        proposal.proposal.msgs,
+        vec![BankMsg::Send {
+            to_address: "keze".to_string(),
+            amount: coins(100, "ujuno"),
+        }
+        .into()]
+    );
+
+    // The messages of contract calls, such as cw20 transfers, may be
+    // templated as JSON.
+    let cw20_payout = Template {
+        msgs: r#"[{"wasm":{"execute":{"contract_addr":"{{token}}","msg":{"transfer":{"recipient":"{{recipient}}","amount":"{{amount}}"}},"funds":[]}}}]"#
+            .to_string(),
+        params: vec![
+            TemplateParam {
+                name: "token".to_string(),
+                kind: ParamKind::Address,
+            },
+            TemplateParam {
+                name: "recipient".to_string(),
+                kind: ParamKind::Address,
+            },
+            TemplateParam {
+                name: "amount".to_string(),
+                kind: ParamKind::Uint128,
+            },
+        ],
+    };
+    add_template(
+        &mut app,
+        &core_addr,
+        &pre_propose,
+        "cw20_payout",
+        cw20_payout,
     )
+    .unwrap();
+    propose_from_template(
+        &mut app,
+        &pre_propose,
+        "ekez",
+        "cw20_payout",
+        &[("token", "cw20"), ("recipient", "keze"), ("amount", "100")],
+    )
+    .unwrap();
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &proposal_single,
+            &dps::msg::QueryMsg::Proposal { proposal_id: 2 },
+        )
+        .unwrap();
+    let CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr, msg, ..
+    }) = &proposal.proposal.msgs[0]
+    else {
+        panic!("expected a wasm execute message");
+    };
+    assert_eq!(contract_addr, "cw20");
+    assert_eq!(
+        from_json::<cw20::Cw20ExecuteMsg>(msg).unwrap(),
+        cw20::Cw20ExecuteMsg::Transfer {
+            recipient: "keze".to_string(),
+            amount: Uint128::new(100),
+        }
+    );
+
+    let err = propose_from_template(
+        &mut app,
+        &pre_propose,
+        "ekez",
+        "payout",
+        &[("recipient", "keze"), ("amount", "lots")],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        PreProposeError::Template(TemplateError::InvalidParam {
+            name: "amount".to_string(),
+            kind: ParamKind::Uint128,
+        })
+    );
+
+    // Proposals from templates follow the submission policy.
+    let err = propose_from_template(
+        &mut app,
+        &pre_propose,
+        "nonmember",
+        "payout",
+        &[("recipient", "keze"), ("amount", "100")],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        PreProposeError::SubmissionPolicy(PreProposeSubmissionPolicyError::Unauthorized {})
+    );
+
+    app.execute_contract(
+        core_addr,
+        pre_propose.clone(),
+        &ExecuteMsg::Extension {
+            msg: ExecuteExt::RemoveTemplate {
+                name: "payout".to_string(),
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    let err = propose_from_template(
+        &mut app,
+        &pre_propose,
+        "ekez",
+        "payout",
+        &[("recipient", "keze"), ("amount", "100")],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        PreProposeError::Template(TemplateError::TemplateNotFound {
+            name: "payout".to_string()
+        })
+    );
 }
 
 #[test]
@@ -1267,7 +1552,12 @@ fn test_instantiate_with_zero_native_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            continue_on_execution_failure: false,
             veto: None,
+            threshold_rules: vec![],
+            commit_reveal: None,
+            voting_period_range: None,
+            no_with_veto_threshold: None,
         }
     };
 
@@ -1334,7 +1624,12 @@ fn test_instantiate_with_zero_cw20_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            continue_on_execution_failure: false,
             veto: None,
+            threshold_rules: vec![],
+            commit_reveal: None,
+            voting_period_range: None,
+            no_with_veto_threshold: None,
         }
     };
 
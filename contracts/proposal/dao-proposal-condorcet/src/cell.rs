@@ -15,7 +15,7 @@ use cosmwasm_std::Uint128;
 /// ```
 #[cw_serde]
 #[derive(Copy)]
-pub(crate) enum Cell {
+pub enum Cell {
     Positive(Uint128),
     Zero,
     Negative(Uint128),
@@ -1,18 +1,51 @@
 use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Deps;
 use cw_utils::Duration;
 use dao_voting::{
     threshold::{validate_quorum, PercentageThreshold},
+    veto::VetoConfig,
     voting::validate_voting_period,
 };
 
 use crate::ContractError;
 
+/// The maximum number of choices, including "none of the above", that
+/// a proposal may have if a cycle resolution method is configured.
+/// Resolving a cycle costs more than checking for a Condorcet winner,
+/// so this bounds the gas used to do so.
+pub const MAX_CYCLE_RESOLUTION_CHOICES: u32 = 32;
+
+/// A method of choosing a winner when there is no Condorcet winner
+/// as the pairwise preferences between choices form a cycle.
+#[cw_serde]
+#[derive(Copy)]
+pub enum CycleResolution {
+    /// The Schulze method. The winner is the choice whose strongest
+    /// path of pairwise victories to every other choice is at least
+    /// as strong as the strongest path back.
+    Schulze,
+    /// Ranked pairs. Pairwise victories are locked in order of
+    /// descending margin, skipping those that would create a cycle,
+    /// and the winner is the choice that no locked victory is over.
+    /// Victories with equal margins are locked lowest index first.
+    RankedPairs,
+}
+
 #[cw_serde]
 pub struct UncheckedConfig {
     pub quorum: PercentageThreshold,
     pub voting_period: Duration,
     pub min_voting_period: Option<Duration>,
     pub close_proposals_on_execution_failure: bool,
+    /// If true, voters may change their ballots until proposals
+    /// expire, and proposals are only decided once they expire.
+    pub allow_revoting: bool,
+    /// How to choose the winner of proposals without a Condorcet
+    /// winner once they expire. If None, such proposals are rejected.
+    pub cycle_resolution: Option<CycleResolution>,
+    /// If set, passed proposals are timelocked until `timelock_duration`
+    /// after they expire, during which the vetoers may veto them.
+    pub veto: Option<VetoConfig>,
 }
 
 #[cw_serde]
@@ -21,16 +54,25 @@ pub(crate) struct Config {
     pub voting_period: Duration,
     pub min_voting_period: Option<Duration>,
     pub close_proposals_on_execution_failure: bool,
+    pub allow_revoting: bool,
+    pub cycle_resolution: Option<CycleResolution>,
+    pub veto: Option<VetoConfig>,
 }
 
 impl UncheckedConfig {
-    pub(crate) fn into_checked(self) -> Result<Config, ContractError> {
+    pub(crate) fn into_checked(self, deps: Deps) -> Result<Config, ContractError> {
         validate_quorum(&self.quorum)?;
         let (min_voting_period, voting_period) =
             validate_voting_period(self.min_voting_period, self.voting_period)?;
+        if let Some(veto) = &self.veto {
+            veto.validate(&deps, &voting_period)?;
+        }
         Ok(Config {
             quorum: self.quorum,
             close_proposals_on_execution_failure: self.close_proposals_on_execution_failure,
+            allow_revoting: self.allow_revoting,
+            cycle_resolution: self.cycle_resolution,
+            veto: self.veto,
             voting_period,
             min_voting_period,
         })
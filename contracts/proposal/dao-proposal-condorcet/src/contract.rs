@@ -1,20 +1,34 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult,
+    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Order, Reply, Response, StdResult,
+    Storage, SubMsg,
 };
 
 use cw2::set_contract_version;
-use dao_voting::reply::TaggedReplyId;
+use cw_hooks::Hooks;
+use cw_storage_plus::Bound;
+use cw_utils::parse_reply_instantiate_data;
+use dao_hooks::proposal::{
+    new_proposal_hooks, proposal_completed_hooks, proposal_status_changed_hooks,
+};
+use dao_hooks::vote::new_vote_hooks;
+use dao_voting::pre_propose::{PreProposeInfo, ProposalCreationPolicy};
+use dao_voting::proposal::DEFAULT_LIMIT;
+use dao_voting::reply::{failed_pre_propose_module_hook_id, TaggedReplyId};
+use dao_voting::veto::{VetoConfig, VetoError, VetoTallyResponse};
 use dao_voting::voting::{get_total_power, get_voting_power};
 
-use crate::config::UncheckedConfig;
+use crate::config::{UncheckedConfig, MAX_CYCLE_RESOLUTION_CHOICES};
 use crate::error::ContractError;
 use crate::msg::{Choice, ExecuteMsg, InstantiateMsg, QueryMsg};
-use crate::proposal::{Proposal, ProposalResponse, Status};
-use crate::state::{next_proposal_id, CONFIG, DAO, PROPOSAL, TALLY, VOTE};
-use crate::tally::Tally;
-use crate::vote::Vote;
+use crate::proposal::{Proposal, ProposalListResponse, ProposalResponse, Status};
+use crate::state::{
+    next_proposal_id, Ballot, CONFIG, CREATION_POLICY, DAO, EARLY_EXECUTE_VOTES, PROPOSAL,
+    PROPOSAL_HOOKS, TALLY, VETO_VOTES, VOTE, VOTE_HOOKS,
+};
+use crate::tally::{PairwiseMatrixResponse, Tally};
+use crate::vote::{Vote, VoteInfo, VoteListResponse, VoteResponse};
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-proposal-condorcet";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -28,10 +42,35 @@ pub fn instantiate(
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    let InstantiateMsg {
+        quorum,
+        voting_period,
+        min_voting_period,
+        close_proposals_on_execution_failure,
+        allow_revoting,
+        cycle_resolution,
+        veto,
+        pre_propose_info,
+    } = msg;
+    let config = UncheckedConfig {
+        quorum,
+        voting_period,
+        min_voting_period,
+        close_proposals_on_execution_failure,
+        allow_revoting,
+        cycle_resolution,
+        veto,
+    };
+    let config = config.into_checked(deps.as_ref())?;
+    let (initial_policy, pre_propose_messages) =
+        pre_propose_info.into_initial_policy_and_messages(info.sender.clone())?;
+
     DAO.save(deps.storage, &info.sender)?;
-    CONFIG.save(deps.storage, &msg.into_checked()?)?;
+    CONFIG.save(deps.storage, &config)?;
+    CREATION_POLICY.save(deps.storage, &initial_policy)?;
 
     Ok(Response::default()
+        .add_submessages(pre_propose_messages)
         .add_attribute("method", "instantiate")
         .add_attribute("creator", info.sender))
 }
@@ -52,22 +91,30 @@ pub fn instantiate(
 // execute: proposal_load + proposal_store + tally_load
 // vote:                                     tally_load + tally_store               + vote_load + vote_store
 //
+// hooks are not included here as their cost does not depend on the
+// number of candidates. for this same reason, status changed hooks
+// are only fired when a proposal's status is saved (on execution,
+// closing, and veto) as firing them on vote would require loading the
+// proposal.
+//
 // so we are good so long as:
 //
 // `vote_load + vote_store <= proposal_load + proposal_store + config_load`
 //
 // this is true so long as a vote is smaller than a proposal in
 // storage which is true because proposals store `choices =
-// Vec<Vec<CosmosMsg>>`, `choices.len() = vote.len()`, vote is a
+// Vec<Vec<CosmosMsg>>`, `choices.len() >= vote.len()`, vote is a
 // `Vec<u32>`, even an empty vec must contain it's length which is a
 // usize, so `sizeof(Vec<u32>) <= sizeof(Vec<usize>) <=
 // sizeof(Vec<Vec<CosmosMsg>) => sizeof(vote) <= sizeof(proposal)`.
+// votes are stored alongside the voter's power, a `Uint128`, as is
+// the proposal's total power.
 //
 // in terms of other costs:
 //
 // propose: query_voting_power + compute_winner [2]
-// execute: query_voting_power
-// vote:    query_voting_power + compute_winner
+// execute: query_voting_power + resolve_cycle [3]
+// vote:    query_voting_power + compute_winner [4]
 //
 // so we're good there as well.
 //
@@ -79,6 +126,15 @@ pub fn instantiate(
 //     be voted on or executed.
 // [2] Tally::new computes the winner over the new matrix so that this
 //     is the case.
+// [3] resolving a cycle is O(candidates^4) in the worst case, so it
+//     may cost more than computing the winner. proposals are limited
+//     to `MAX_CYCLE_RESOLUTION_CHOICES` choices when a cycle
+//     resolution method is configured so that this cost stays bounded.
+// [4] a revote removes the previous ballot from the matrix before
+//     adding the new one. this doubles the number of cells updated,
+//     each of which is far cheaper than loading or storing one, and
+//     the previous ballot is loaded in place of checking if one
+//     exists so storage costs are unchanged.
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
@@ -88,12 +144,33 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::Propose { choices } => execute_propose(deps, env, info, choices),
+        ExecuteMsg::Propose {
+            title,
+            description,
+            choices,
+            proposer,
+        } => execute_propose(deps, env, info, title, description, choices, proposer),
         ExecuteMsg::Vote { proposal_id, vote } => execute_vote(deps, env, info, proposal_id, vote),
         ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, info, proposal_id),
         ExecuteMsg::Close { proposal_id } => execute_close(deps, env, info, proposal_id),
+        ExecuteMsg::Veto { proposal_id } => execute_veto(deps, env, info, proposal_id),
 
         ExecuteMsg::SetConfig(config) => execute_set_config(deps, info, config),
+        ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
+            execute_update_proposal_creation_policy(deps, info, new_info)
+        }
+        ExecuteMsg::AddProposalHook { address } => {
+            execute_add_hook(deps, info, PROPOSAL_HOOKS, address, "add_proposal_hook")
+        }
+        ExecuteMsg::RemoveProposalHook { address } => {
+            execute_remove_hook(deps, info, PROPOSAL_HOOKS, address, "remove_proposal_hook")
+        }
+        ExecuteMsg::AddVoteHook { address } => {
+            execute_add_hook(deps, info, VOTE_HOOKS, address, "add_vote_hook")
+        }
+        ExecuteMsg::RemoveVoteHook { address } => {
+            execute_remove_hook(deps, info, VOTE_HOOKS, address, "remove_vote_hook")
+        }
     }
 }
 
@@ -101,14 +178,38 @@ fn execute_propose(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
+    title: String,
+    description: String,
     choices: Vec<Choice>,
+    proposer: Option<String>,
 ) -> Result<Response, ContractError> {
     let dao = DAO.load(deps.storage)?;
-    let sender_voting_power = get_voting_power(deps.as_ref(), info.sender.clone(), &dao, None)?;
-    if sender_voting_power.is_zero() {
-        return Err(ContractError::ZeroVotingPower {});
+    let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
+
+    // check that the sender is permitted to create proposals.
+    if !proposal_creation_policy.is_permitted(&info.sender) {
+        return Err(ContractError::Unauthorized {});
     }
 
+    // determine the appropriate proposer. if this is coming from our
+    // pre-propose module, it must be specified and the module is
+    // responsible for deciding who may propose. otherwise, the
+    // proposer is the sender who must have voting power.
+    let proposer = match (proposer, &proposal_creation_policy) {
+        (None, ProposalCreationPolicy::Anyone {}) => {
+            let sender_voting_power =
+                get_voting_power(deps.as_ref(), info.sender.clone(), &dao, None)?;
+            if sender_voting_power.is_zero() {
+                return Err(ContractError::ZeroVotingPower {});
+            }
+            info.sender
+        }
+        (Some(proposer), ProposalCreationPolicy::Module { .. }) => {
+            deps.api.addr_validate(&proposer)?
+        }
+        _ => return Err(ContractError::InvalidProposer {}),
+    };
+
     let config = CONFIG.load(deps.storage)?;
 
     let id = next_proposal_id(deps.storage)?;
@@ -118,23 +219,52 @@ fn execute_propose(
         return Err(ContractError::ZeroChoices {});
     }
 
-    let none_of_the_above = Choice { msgs: vec![] };
+    let none_of_the_above = Choice {
+        title: "None of the above".to_string(),
+        description: "None of the above".to_string(),
+        msgs: vec![],
+    };
     let mut choices = choices;
     choices.push(none_of_the_above);
 
+    // see the gas comment above `execute` [3].
+    if config.cycle_resolution.is_some() && choices.len() > MAX_CYCLE_RESOLUTION_CHOICES as usize {
+        return Err(ContractError::TooManyChoices {
+            max: MAX_CYCLE_RESOLUTION_CHOICES,
+        });
+    }
+
     let tally = Tally::new(
         choices.len() as u32,
         total_power,
         env.block.height,
         config.voting_period.after(&env.block),
+        config.allow_revoting,
     );
     TALLY.save(deps.storage, id, &tally)?;
 
-    let mut proposal = Proposal::new(&env.block, &config, info.sender, id, choices, total_power);
-    proposal.update_status(&env.block, &tally);
+    let mut proposal = Proposal::new(
+        &env.block,
+        &config,
+        proposer,
+        id,
+        title,
+        description,
+        choices,
+        total_power,
+    );
+    proposal.update_status(&env.block, &tally)?;
     PROPOSAL.save(deps.storage, id, &proposal)?;
 
+    let hooks = new_proposal_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        id as u64,
+        proposal.proposer.as_str(),
+    )?;
+
     Ok(Response::default()
+        .add_submessages(hooks)
         .add_attribute("method", "propose")
         .add_attribute("proposal_id", proposal.id.to_string())
         .add_attribute("proposer", proposal.proposer))
@@ -154,21 +284,44 @@ fn execute_vote(
         &DAO.load(deps.storage)?,
         Some(tally.start_height),
     )?;
+    let previous = VOTE.may_load(deps.storage, (proposal_id, info.sender.clone()))?;
     if sender_power.is_zero() {
         Err(ContractError::ZeroVotingPower {})
-    } else if VOTE.has(deps.storage, (proposal_id, info.sender.clone())) {
+    } else if previous.is_some() && !tally.allow_revoting {
         Err(ContractError::Voted {})
     } else if tally.expired(&env.block) {
         Err(ContractError::Expired {})
     } else {
         let vote = Vote::new(vote, tally.candidates())?;
-        VOTE.save(deps.storage, (proposal_id, info.sender.clone()), &vote)?;
-
         let mut tally = tally;
+        if let Some(previous) = previous {
+            if previous.vote == vote {
+                return Err(ContractError::AlreadyCast {});
+            }
+            tally.remove_vote(&previous.vote, previous.power);
+        }
+        VOTE.save(
+            deps.storage,
+            (proposal_id, info.sender.clone()),
+            &Ballot {
+                power: sender_power,
+                vote: vote.clone(),
+            },
+        )?;
+
+        let vote_hooks = new_vote_hooks(
+            VOTE_HOOKS,
+            deps.storage,
+            proposal_id as u64,
+            info.sender.to_string(),
+            vote.to_string(),
+        )?;
+
         tally.add_vote(vote, sender_power);
         TALLY.save(deps.storage, proposal_id, &tally)?;
 
         Ok(Response::default()
+            .add_submessages(vote_hooks)
             .add_attribute("method", "vote")
             .add_attribute("proposal_id", proposal_id.to_string())
             .add_attribute("voter", info.sender)
@@ -190,23 +343,68 @@ fn execute_execute(
         &dao,
         Some(tally.start_height),
     )?;
-    if sender_power.is_zero() {
-        return Err(ContractError::ZeroVotingPower {});
-    }
 
     let mut proposal = PROPOSAL.load(deps.storage, proposal_id)?;
-    if let Status::Passed { winner } = proposal.update_status(&env.block, &tally) {
-        let msgs = proposal.set_executed(dao, winner)?;
-        PROPOSAL.save(deps.storage, proposal_id, &proposal)?;
+    let old_status = proposal.last_status();
+    let winner = match proposal.update_status(&env.block, &tally)? {
+        Status::Passed { winner } => {
+            if sender_power.is_zero() {
+                return Err(ContractError::ZeroVotingPower {});
+            }
+            winner
+        }
+        // timelocked proposals may only be executed early by a vetoer
+        // if the veto config allows it.
+        Status::VetoTimelock { winner, .. } => {
+            let veto = proposal
+                .veto
+                .as_ref()
+                .ok_or(VetoError::NoVetoConfiguration {})?;
+            if veto.check_is_vetoer(&info).is_err() {
+                return Err(if sender_power.is_zero() {
+                    ContractError::ZeroVotingPower {}
+                } else {
+                    VetoError::Timelocked {}.into()
+                });
+            }
+            veto.check_early_execute_enabled()?;
+            // like a veto, early execution needs the approval of
+            // enough vetoers.
+            let approvals = veto.record_early_execute(
+                deps.storage,
+                &EARLY_EXECUTE_VOTES,
+                proposal_id,
+                &info.sender,
+            )?;
+            if !approvals.threshold_reached {
+                PROPOSAL.save(deps.storage, proposal_id, &proposal)?;
+                return Ok(Response::default()
+                    .add_attribute("method", "execute")
+                    .add_attribute("proposal_id", proposal_id.to_string())
+                    .add_attribute("executor", info.sender)
+                    .add_attribute("early_execute_approvals", approvals.count.to_string()));
+            }
+            winner
+        }
+        _ => {
+            if sender_power.is_zero() {
+                return Err(ContractError::ZeroVotingPower {});
+            }
+            return Err(ContractError::Unexecutable {});
+        }
+    };
 
-        Ok(Response::default()
-            .add_attribute("method", "execute")
-            .add_attribute("proposal_id", proposal_id.to_string())
-            .add_attribute("executor", info.sender)
-            .add_submessage(msgs))
-    } else {
-        Err(ContractError::Unexecutable {})
-    }
+    let msgs = proposal.set_executed(dao, winner)?;
+    PROPOSAL.save(deps.storage, proposal_id, &proposal)?;
+
+    let hooks = status_hooks(deps.storage, &proposal, old_status)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "execute")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("executor", info.sender)
+        .add_submessage(msgs)
+        .add_submessages(hooks))
 }
 
 fn execute_close(
@@ -217,11 +415,15 @@ fn execute_close(
 ) -> Result<Response, ContractError> {
     let tally = TALLY.load(deps.storage, proposal_id)?;
     let mut proposal = PROPOSAL.load(deps.storage, proposal_id)?;
-    if let Status::Rejected = proposal.update_status(&env.block, &tally) {
+    let old_status = proposal.last_status();
+    if let Status::Rejected = proposal.update_status(&env.block, &tally)? {
         proposal.set_closed();
         PROPOSAL.save(deps.storage, proposal_id, &proposal)?;
 
+        let hooks = status_hooks(deps.storage, &proposal, old_status)?;
+
         Ok(Response::default()
+            .add_submessages(hooks)
             .add_attribute("method", "close")
             .add_attribute("proposal_id", proposal_id.to_string())
             .add_attribute("closer", info.sender))
@@ -230,6 +432,83 @@ fn execute_close(
     }
 }
 
+fn execute_veto(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u32,
+) -> Result<Response, ContractError> {
+    let tally = TALLY.load(deps.storage, proposal_id)?;
+    let mut proposal = PROPOSAL.load(deps.storage, proposal_id)?;
+    let old_status = proposal.last_status();
+    let status = proposal.update_status(&env.block, &tally)?;
+
+    let veto = proposal
+        .veto
+        .clone()
+        .ok_or(VetoError::NoVetoConfiguration {})?;
+    veto.check_is_vetoer(&info)?;
+
+    match status {
+        Status::Open => veto.check_veto_before_passed_enabled()?,
+        Status::VetoTimelock { .. } => (),
+        // with a veto config, a passed proposal's timelock has
+        // expired.
+        Status::Passed { .. } => return Err(VetoError::TimelockExpired {}.into()),
+        _ => {
+            return Err(VetoError::InvalidProposalStatus {
+                status: status.to_string(),
+            }
+            .into())
+        }
+    }
+
+    // the proposal is only vetoed once enough vetoers have vetoed it.
+    let vetoes = veto.record_veto(deps.storage, &VETO_VOTES, proposal_id, &info.sender)?;
+
+    let response = Response::default()
+        .add_attribute("method", "veto")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("vetoer", info.sender)
+        .add_attribute("vetoes", vetoes.count.to_string());
+    if !vetoes.threshold_reached {
+        return Ok(response);
+    }
+
+    proposal.set_vetoed();
+    PROPOSAL.save(deps.storage, proposal_id, &proposal)?;
+
+    let hooks = status_hooks(deps.storage, &proposal, old_status)?;
+
+    Ok(response.add_submessages(hooks))
+}
+
+/// Prepares the hooks to be fired once a proposal that was last saved
+/// with `old_status` has been completed, those being proposal status
+/// changed hooks and the pre-propose module's completed hook which
+/// handles deposit refunds.
+fn status_hooks(
+    storage: &dyn Storage,
+    proposal: &Proposal,
+    old_status: Status,
+) -> Result<Vec<SubMsg>, ContractError> {
+    let new_status = proposal.last_status();
+    let mut hooks = proposal_status_changed_hooks(
+        PROPOSAL_HOOKS,
+        storage,
+        proposal.id as u64,
+        old_status.to_string(),
+        new_status.to_string(),
+    )?;
+    hooks.extend(proposal_completed_hooks(
+        CREATION_POLICY.load(storage)?,
+        proposal.id as u64,
+        new_status.into(),
+        false,
+    )?);
+    Ok(hooks)
+}
+
 fn execute_set_config(
     deps: DepsMut,
     info: MessageInfo,
@@ -238,31 +517,176 @@ fn execute_set_config(
     if info.sender != DAO.load(deps.storage)? {
         Err(ContractError::NotDao {})
     } else {
-        CONFIG.save(deps.storage, &config.into_checked()?)?;
+        let config = config.into_checked(deps.as_ref())?;
+        CONFIG.save(deps.storage, &config)?;
         Ok(Response::default()
             .add_attribute("method", "update_config")
             .add_attribute("updater", info.sender))
     }
 }
 
+fn execute_update_proposal_creation_policy(
+    deps: DepsMut,
+    info: MessageInfo,
+    new_info: PreProposeInfo,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        Err(ContractError::NotDao {})
+    } else {
+        let (initial_policy, messages) = new_info.into_initial_policy_and_messages(dao)?;
+        CREATION_POLICY.save(deps.storage, &initial_policy)?;
+        Ok(Response::default()
+            .add_submessages(messages)
+            .add_attribute("method", "update_proposal_creation_policy")
+            .add_attribute("updater", info.sender)
+            .add_attribute("new_policy", format!("{initial_policy:?}")))
+    }
+}
+
+fn execute_add_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    hooks: Hooks,
+    address: String,
+    method: &str,
+) -> Result<Response, ContractError> {
+    if info.sender != DAO.load(deps.storage)? {
+        Err(ContractError::NotDao {})
+    } else {
+        let address = deps.api.addr_validate(&address)?;
+        hooks.add_hook(deps.storage, address.clone())?;
+        Ok(Response::default()
+            .add_attribute("method", method)
+            .add_attribute("address", address))
+    }
+}
+
+fn execute_remove_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    hooks: Hooks,
+    address: String,
+    method: &str,
+) -> Result<Response, ContractError> {
+    if info.sender != DAO.load(deps.storage)? {
+        Err(ContractError::NotDao {})
+    } else {
+        let address = deps.api.addr_validate(&address)?;
+        hooks.remove_hook(deps.storage, address.clone())?;
+        Ok(Response::default()
+            .add_attribute("method", method)
+            .add_attribute("address", address))
+    }
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Proposal { id } => {
-            let mut proposal = PROPOSAL.load(deps.storage, id)?;
-            let tally = TALLY.load(deps.storage, id)?;
-            proposal.update_status(&env.block, &tally);
-            to_json_binary(&ProposalResponse { proposal, tally })
+            let proposal = PROPOSAL.load(deps.storage, id)?;
+            to_json_binary(&proposal_response(deps, &env, proposal)?)
+        }
+        QueryMsg::ProposalStats { proposal_id } => {
+            let proposal = PROPOSAL.load(deps.storage, proposal_id)?;
+            let tally = TALLY.load(deps.storage, proposal_id)?;
+            to_json_binary(&proposal.stats(&env.block, &tally)?)
         }
         QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::ListProposals { start_after, limit } => {
+            let min = start_after.map(Bound::exclusive);
+            query_list_proposals(deps, env, min, None, Order::Ascending, limit)
+        }
+        QueryMsg::ReverseProposals {
+            start_before,
+            limit,
+        } => {
+            let max = start_before.map(Bound::exclusive);
+            query_list_proposals(deps, env, None, max, Order::Descending, limit)
+        }
+        // proposals are never removed so the number of proposals
+        // created is one less than the next ID.
+        QueryMsg::ProposalCount {} => to_json_binary(&(next_proposal_id(deps.storage)? - 1)),
+        QueryMsg::GetVote { proposal_id, voter } => {
+            let voter = deps.api.addr_validate(&voter)?;
+            let vote = VOTE
+                .may_load(deps.storage, (proposal_id, voter.clone()))?
+                .map(|Ballot { power, vote }| VoteInfo { voter, vote, power });
+            to_json_binary(&VoteResponse { vote })
+        }
+        QueryMsg::ListVotes {
+            proposal_id,
+            start_after,
+            limit,
+        } => {
+            let start_after = start_after
+                .map(|addr| deps.api.addr_validate(&addr))
+                .transpose()?;
+            let votes = VOTE
+                .prefix(proposal_id)
+                .range(
+                    deps.storage,
+                    start_after.map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                )
+                .take(limit.unwrap_or(DEFAULT_LIMIT) as usize)
+                .map(|item| {
+                    let (voter, Ballot { power, vote }) = item?;
+                    Ok(VoteInfo { voter, vote, power })
+                })
+                .collect::<StdResult<Vec<_>>>()?;
+            to_json_binary(&VoteListResponse { votes })
+        }
+        QueryMsg::PairwiseMatrix { proposal_id } => {
+            let tally = TALLY.load(deps.storage, proposal_id)?;
+            to_json_binary(&PairwiseMatrixResponse {
+                margins: tally.margins(),
+            })
+        }
+        QueryMsg::VetoTally { proposal_id } => {
+            let proposal = PROPOSAL.load(deps.storage, proposal_id)?;
+            let vetoers = VETO_VOTES
+                .may_load(deps.storage, proposal_id)?
+                .unwrap_or_default();
+            to_json_binary(&VetoTallyResponse {
+                vetoers,
+                threshold: proposal.veto.as_ref().map(VetoConfig::threshold),
+            })
+        }
         QueryMsg::NextProposalId {} => to_json_binary(&next_proposal_id(deps.storage)?),
         QueryMsg::Dao {} => to_json_binary(&DAO.load(deps.storage)?),
+        QueryMsg::ProposalCreationPolicy {} => to_json_binary(&CREATION_POLICY.load(deps.storage)?),
+        QueryMsg::ProposalHooks {} => to_json_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
+        QueryMsg::VoteHooks {} => to_json_binary(&VOTE_HOOKS.query_hooks(deps)?),
         QueryMsg::Info {} => to_json_binary(&dao_interface::voting::InfoResponse {
             info: cw2::get_contract_version(deps.storage)?,
         }),
     }
 }
 
+fn proposal_response(deps: Deps, env: &Env, mut proposal: Proposal) -> StdResult<ProposalResponse> {
+    let tally = TALLY.load(deps.storage, proposal.id)?;
+    proposal.update_status(&env.block, &tally)?;
+    Ok(ProposalResponse { proposal, tally })
+}
+
+fn query_list_proposals(
+    deps: Deps,
+    env: Env,
+    min: Option<Bound<u32>>,
+    max: Option<Bound<u32>>,
+    order: Order,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let proposals = PROPOSAL
+        .range(deps.storage, min, max, order)
+        .take(limit.unwrap_or(DEFAULT_LIMIT) as usize)
+        .map(|item| proposal_response(deps, &env, item?.1))
+        .collect::<StdResult<Vec<_>>>()?;
+    to_json_binary(&ProposalListResponse { proposals })
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
     let repl = TaggedReplyId::new(msg.id)?;
@@ -276,6 +700,47 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
                 .add_attribute("proposal_execution_failed", proposal_id.to_string())
                 .add_attribute("error", msg.result.into_result().err().unwrap_or_default()))
         }
-        _ => unimplemented!("pre-propose and hooks not yet supported"),
+        TaggedReplyId::FailedProposalHook(idx) => {
+            let addr = PROPOSAL_HOOKS.remove_hook_by_index(deps.storage, idx)?;
+            Ok(Response::default().add_attribute("removed_proposal_hook", format!("{addr}:{idx}")))
+        }
+        TaggedReplyId::FailedVoteHook(idx) => {
+            let addr = VOTE_HOOKS.remove_hook_by_index(deps.storage, idx)?;
+            Ok(Response::default().add_attribute("removed_vote_hook", format!("{addr}:{idx}")))
+        }
+        TaggedReplyId::PreProposeModuleInstantiation => {
+            let res = parse_reply_instantiate_data(msg)?;
+            let module = deps.api.addr_validate(&res.contract_address)?;
+            CREATION_POLICY.save(
+                deps.storage,
+                &ProposalCreationPolicy::Module { addr: module },
+            )?;
+
+            let response = Response::default()
+                .add_attribute("update_pre_propose_module", res.contract_address);
+            Ok(match res.data {
+                Some(data) => response.set_data(data),
+                None => response,
+            })
+        }
+        TaggedReplyId::FailedPreProposeModuleHook => match CREATION_POLICY.load(deps.storage)? {
+            // receiving this reply without a pre-propose module
+            // installed should be unreachable.
+            ProposalCreationPolicy::Anyone {} => Err(ContractError::InvalidReplyId {
+                id: failed_pre_propose_module_hook_id(),
+            }),
+            // the pre-propose module errored while handling a
+            // proposal completed hook. remove it so that it can not
+            // lock proposals.
+            ProposalCreationPolicy::Module { addr } => {
+                CREATION_POLICY.save(deps.storage, &ProposalCreationPolicy::Anyone {})?;
+                Ok(Response::default().add_attribute("failed_prepropose_hook", addr))
+            }
+        },
+        // this module never executes proposal messages individually
+        // or records execution results.
+        TaggedReplyId::ProposalMessageExecution(..) | TaggedReplyId::ProposalExecutionResult(_) => {
+            Err(ContractError::InvalidReplyId { id: msg.id })
+        }
     }
 }
@@ -1,5 +1,9 @@
 use cosmwasm_std::StdError;
-use dao_voting::{error::VotingError, reply::error::TagError, threshold::ThresholdError};
+use cw_hooks::HookError;
+use cw_utils::ParseReplyError;
+use dao_voting::{
+    error::VotingError, reply::error::TagError, threshold::ThresholdError, veto::VetoError,
+};
 use thiserror::Error;
 
 use crate::vote::VoteError;
@@ -16,6 +20,12 @@ pub enum ContractError {
     Voting(#[from] VotingError),
     #[error(transparent)]
     Tag(#[from] TagError),
+    #[error(transparent)]
+    Hook(#[from] HookError),
+    #[error(transparent)]
+    ParseReply(#[from] ParseReplyError),
+    #[error(transparent)]
+    Veto(#[from] VetoError),
 
     #[error("non-zero voting power required to perform this action")]
     ZeroVotingPower {},
@@ -32,9 +42,24 @@ pub enum ContractError {
     #[error("already voted")]
     Voted {},
 
+    #[error("already cast this ballot")]
+    AlreadyCast {},
+
     #[error("only non-expired proposals may be voted on")]
     Expired {},
 
     #[error("must specify at least one choice for proposal")]
     ZeroChoices {},
+
+    #[error("proposals may have at most {max} choices, including none of the above, when a cycle resolution method is configured")]
+    TooManyChoices { max: u32 },
+
+    #[error("only the pre-propose module may create proposals")]
+    Unauthorized {},
+
+    #[error("proposer must be specified if, and only if, proposing from a pre-propose module")]
+    InvalidProposer {},
+
+    #[error("unexpected reply id ({id})")]
+    InvalidReplyId { id: u64 },
 }
@@ -1,4 +1,4 @@
-mod cell;
+pub mod cell;
 pub mod config;
 pub mod contract;
 mod error;
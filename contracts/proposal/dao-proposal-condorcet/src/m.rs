@@ -172,6 +172,134 @@ impl M {
             no_winnable_columns,
         }
     }
+
+    /// Returns M in its full matrix form, with `rows[x][y]` being the
+    /// margin by which x beats y.
+    pub fn rows(&self) -> Vec<Vec<Cell>> {
+        (0..self.n)
+            .map(|x| {
+                (0..self.n)
+                    .map(|y| if x == y { Cell::Zero } else { self.get((x, y)) })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Computes the voting power that would need to rank `col` first
+    /// for it to become a positive column.
+    pub fn power_to_win(&self, col: u32) -> Uint128 {
+        (0..self.n)
+            .filter(|row| *row != col)
+            .map(|row| match self.get((col, row)) {
+                Cell::Positive(_) => Uint128::zero(),
+                Cell::Zero => Uint128::one(),
+                Cell::Negative(v) => v.saturating_add(Uint128::one()),
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// The margin by which x beats y, or zero if x does not beat y.
+    fn victory(&self, (x, y): (u32, u32)) -> Uint128 {
+        match self.get((x, y)) {
+            Cell::Positive(p) => p,
+            Cell::Zero | Cell::Negative(_) => Uint128::zero(),
+        }
+    }
+
+    /// Computes the Schulze winner, being the column whose strongest
+    /// path to every other column is at least as strong as the
+    /// strongest path back. A path's strength is that of its smallest
+    /// victory. Returns None if there is more than one such column.
+    ///
+    /// Runs in O(N^3).
+    pub fn schulze_winner(&self) -> Option<u32> {
+        let n = self.n as usize;
+        // paths[x][y] is the strength of the strongest path from x to
+        // y. computed via floyd-warshall, starting from the direct
+        // victories.
+        let mut paths: Vec<Vec<Uint128>> = (0..self.n)
+            .map(|x| {
+                (0..self.n)
+                    .map(|y| {
+                        if x == y {
+                            Uint128::zero()
+                        } else {
+                            self.victory((x, y))
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        for i in 0..n {
+            for j in 0..n {
+                if i != j {
+                    for k in 0..n {
+                        if k != i && k != j {
+                            let through = std::cmp::min(paths[j][i], paths[i][k]);
+                            if through > paths[j][k] {
+                                paths[j][k] = through
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        let mut winners = (0..n).filter(|x| (0..n).all(|y| paths[*x][y] >= paths[y][*x]));
+        match (winners.next(), winners.next()) {
+            (Some(winner), None) => Some(winner as u32),
+            _ => None,
+        }
+    }
+
+    /// Computes the ranked pairs winner. Victories are locked in
+    /// order of descending margin, skipping those that would create a
+    /// cycle, and the winner is the column that no locked victory is
+    /// over. Victories with equal margins are locked in order of the
+    /// winning column and then the losing column, lowest index first,
+    /// so when they form a cycle the victory skipped is the last of
+    /// them in that order. Returns None if more than one column is
+    /// undefeated, which happens when columns are tied.
+    ///
+    /// Runs in O(N^4).
+    pub fn ranked_pairs_winner(&self) -> Option<u32> {
+        let n = self.n as usize;
+        let mut victories: Vec<(Uint128, usize, usize)> = (0..self.n)
+            .flat_map(|x| (0..self.n).map(move |y| (x, y)))
+            .filter(|(x, y)| x != y)
+            .map(|(x, y)| (self.victory((x, y)), x as usize, y as usize))
+            .filter(|(margin, _, _)| !margin.is_zero())
+            .collect();
+        victories.sort_by(|a, b| b.0.cmp(&a.0).then((a.1, a.2).cmp(&(b.1, b.2))));
+
+        let mut locked = vec![vec![false; n]; n];
+        for (_, x, y) in victories {
+            if !reaches(&locked, y, x) {
+                locked[x][y] = true
+            }
+        }
+        let mut winners = (0..n).filter(|y| (0..n).all(|x| !locked[x][*y]));
+        match (winners.next(), winners.next()) {
+            (Some(winner), None) => Some(winner as u32),
+            _ => None,
+        }
+    }
+}
+
+/// True if there is a path of locked victories from `from` to `to`.
+fn reaches(locked: &[Vec<bool>], from: usize, to: usize) -> bool {
+    let mut visited = vec![false; locked.len()];
+    let mut stack = vec![from];
+    while let Some(x) = stack.pop() {
+        if x == to {
+            return true;
+        }
+        if !visited[x] {
+            visited[x] = true;
+            stack.extend((0..locked.len()).filter(|y| locked[x][*y] && !visited[*y]));
+        }
+    }
+    false
 }
 
 #[cfg(test)]
@@ -384,4 +512,67 @@ mod test_lm {
             }
         }
     }
+
+    #[test]
+    fn test_cycle_resolution() {
+        let mut m = new_m(3);
+
+        // 0 beats 1 by 5, 1 beats 2 by 3, and 2 beats 0 by 1.
+        m.increment((0, 1), Uint128::new(5));
+        m.increment((1, 2), Uint128::new(3));
+        m.increment((2, 0), Uint128::new(1));
+        assert!(matches!(
+            m.stats(Uint128::zero()),
+            Stats::NoPositiveColumn { .. }
+        ));
+
+        // 0's path to 2 via 1 has strength 3, which is stronger than
+        // 2's direct victory over 0.
+        assert_eq!(m.schulze_winner(), Some(0));
+        // 2 beating 0 is the smallest victory so is not locked.
+        assert_eq!(m.ranked_pairs_winner(), Some(0));
+    }
+
+    #[test]
+    fn test_cycle_resolution_ties() {
+        let mut m = new_m(3);
+
+        m.increment((0, 1), Uint128::one());
+        m.increment((1, 2), Uint128::one());
+        m.increment((2, 0), Uint128::one());
+
+        // every path has the same strength.
+        assert_eq!(m.schulze_winner(), None);
+        // the victories have equal margins and form a cycle. 0 over 1
+        // and 1 over 2 are locked first, so 2 over 0 is skipped.
+        assert_eq!(m.ranked_pairs_winner(), Some(0));
+
+        // a tied cycle among the losers does not affect the winner.
+        let mut m = new_m(4);
+        m.increment((0, 1), Uint128::one());
+        m.increment((1, 2), Uint128::one());
+        m.increment((2, 0), Uint128::one());
+        for y in 0..3 {
+            m.increment((3, y), Uint128::new(2));
+        }
+        assert_eq!(m.schulze_winner(), Some(3));
+        assert_eq!(m.ranked_pairs_winner(), Some(3));
+
+        // no victories, so every column is undefeated.
+        let m = new_m(3);
+        assert_eq!(m.schulze_winner(), None);
+        assert_eq!(m.ranked_pairs_winner(), None);
+    }
+
+    #[test]
+    fn test_cycle_resolution_condorcet_winner() {
+        let mut m = new_m(4);
+        for y in 0..4 {
+            if y != 2 {
+                m.increment((2, y), Uint128::one())
+            }
+        }
+        assert_eq!(m.schulze_winner(), Some(2));
+        assert_eq!(m.ranked_pairs_winner(), Some(2));
+    }
 }
@@ -1,24 +1,114 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{CosmosMsg, Empty};
+use cw_utils::Duration;
 
 use dao_dao_macros::proposal_module_query;
+use dao_voting::{pre_propose::PreProposeInfo, threshold::PercentageThreshold, veto::VetoConfig};
 
-use crate::config::UncheckedConfig;
+use crate::config::{CycleResolution, UncheckedConfig};
 
-pub type InstantiateMsg = UncheckedConfig;
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub quorum: PercentageThreshold,
+    pub voting_period: Duration,
+    pub min_voting_period: Option<Duration>,
+    pub close_proposals_on_execution_failure: bool,
+    /// If true, voters may change their ballots until proposals
+    /// expire, and proposals are only decided once they expire.
+    pub allow_revoting: bool,
+    /// How to choose the winner of proposals without a Condorcet
+    /// winner once they expire. If None, such proposals are rejected.
+    pub cycle_resolution: Option<CycleResolution>,
+    /// If set, passed proposals are timelocked until `timelock_duration`
+    /// after they expire, during which the vetoers may veto them.
+    pub veto: Option<VetoConfig>,
+    /// Information about what addresses may create proposals.
+    pub pre_propose_info: PreProposeInfo,
+}
 
 #[cw_serde]
 pub struct Choice {
+    /// The title of the choice.
+    pub title: String,
+    /// A description of the choice.
+    pub description: String,
+    /// The messages to execute should the choice win.
     pub msgs: Vec<CosmosMsg<Empty>>,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
-    Propose { choices: Vec<Choice> },
-    Vote { proposal_id: u32, vote: Vec<u32> },
-    Execute { proposal_id: u32 },
-    Close { proposal_id: u32 },
+    Propose {
+        /// The title of the proposal.
+        title: String,
+        /// A description of the proposal.
+        description: String,
+        choices: Vec<Choice>,
+        /// The address creating the proposal. If no pre-propose
+        /// module is attached to this module this must always be None
+        /// as the proposer is the sender of the propose message. If a
+        /// pre-propose module is attached, this must be Some and will
+        /// set the proposer of the proposal it creates.
+        proposer: Option<String>,
+    },
+    /// Casts a ballot on a proposal. `vote` ranks choices from most to
+    /// least favored, and choices it does not rank are tied below those
+    /// it does. If revoting is allowed, casting a ballot again replaces
+    /// the voter's previous ballot.
+    Vote {
+        proposal_id: u32,
+        vote: Vec<u32>,
+    },
+    /// Executes a passed proposal. While a proposal is timelocked, it
+    /// may only be executed by the vetoers and only if `early_execute`
+    /// is set in its veto config. Each vetoer's execution counts as an
+    /// approval, and the proposal is executed once `veto_threshold`
+    /// vetoers have approved.
+    Execute {
+        proposal_id: u32,
+    },
+    Close {
+        proposal_id: u32,
+    },
+    /// Vetoes a proposal. Only callable by the vetoers in the
+    /// proposal's veto config, and the proposal is vetoed once
+    /// `veto_threshold` of them have done so. Timelocked proposals may
+    /// be vetoed, as may open ones if `veto_before_passed` is set.
+    Veto {
+        proposal_id: u32,
+    },
     SetConfig(UncheckedConfig),
+    /// Updates the proposal creation policy used for this
+    /// module. Only the DAO may call this method.
+    UpdatePreProposeInfo {
+        info: PreProposeInfo,
+    },
+    /// Adds an address as a consumer of proposal hooks. Consumers of
+    /// proposal hooks have hook messages executed on them whenever a
+    /// proposal is created, and whenever its status is saved, being
+    /// when it is executed, closed, or vetoed. Status changes caused
+    /// by votes or the passing of time are only reported once the
+    /// status is next saved. If a consumer contract errors when
+    /// handling a hook message it will be removed from the list of
+    /// consumers.
+    AddProposalHook {
+        address: String,
+    },
+    /// Removes a consumer of proposal hooks.
+    RemoveProposalHook {
+        address: String,
+    },
+    /// Adds an address as a consumer of vote hooks. Consumers of vote
+    /// hooks have hook messages executed on them whenever a vote
+    /// is cast. If a consumer contract errors when handling a hook
+    /// message it will be removed from the list of consumers.
+    AddVoteHook {
+        address: String,
+    },
+    /// Removes a consumer of vote hooks.
+    RemoveVoteHook {
+        address: String,
+    },
 }
 
 #[proposal_module_query]
@@ -27,6 +117,56 @@ pub enum ExecuteMsg {
 pub enum QueryMsg {
     #[returns(crate::proposal::ProposalResponse)]
     Proposal { id: u32 },
+    #[returns(::dao_voting::stats::ProposalStatsResponse)]
+    ProposalStats { proposal_id: u32 },
     #[returns(crate::config::Config)]
     Config {},
+    /// Lists proposals in order of ascending ID.
+    #[returns(crate::proposal::ProposalListResponse)]
+    ListProposals {
+        /// The proposal ID to start listing after.
+        start_after: Option<u32>,
+        /// The maximum number of proposals to return.
+        limit: Option<u64>,
+    },
+    /// Lists proposals in order of descending ID.
+    #[returns(crate::proposal::ProposalListResponse)]
+    ReverseProposals {
+        /// The proposal ID to start listing before.
+        start_before: Option<u32>,
+        /// The maximum number of proposals to return.
+        limit: Option<u64>,
+    },
+    /// Gets the number of proposals that have been created.
+    #[returns(::std::primitive::u32)]
+    ProposalCount {},
+    /// Gets the ballot cast by `voter` on a proposal, if any.
+    #[returns(crate::vote::VoteResponse)]
+    GetVote { proposal_id: u32, voter: String },
+    /// Lists the ballots cast on a proposal in order of ascending
+    /// voter address.
+    #[returns(crate::vote::VoteListResponse)]
+    ListVotes {
+        proposal_id: u32,
+        /// The voter to start listing after.
+        start_after: Option<String>,
+        /// The maximum number of ballots to return.
+        limit: Option<u64>,
+    },
+    /// Gets the pairwise margins between a proposal's choices, which
+    /// determine its outcome.
+    #[returns(crate::tally::PairwiseMatrixResponse)]
+    PairwiseMatrix { proposal_id: u32 },
+    /// Gets the vetoes cast on a proposal so far.
+    #[returns(::dao_voting::veto::VetoTallyResponse)]
+    VetoTally { proposal_id: u32 },
+    /// Gets the current proposal creation policy for this module.
+    #[returns(::dao_voting::pre_propose::ProposalCreationPolicy)]
+    ProposalCreationPolicy {},
+    /// Lists all of the consumers of proposal hooks for this module.
+    #[returns(::cw_hooks::HooksResponse)]
+    ProposalHooks {},
+    /// Lists all of the consumers of vote hooks for this module.
+    #[returns(::cw_hooks::HooksResponse)]
+    VoteHooks {},
 }
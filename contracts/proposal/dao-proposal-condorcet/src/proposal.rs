@@ -1,13 +1,18 @@
+use std::ops::Add;
+
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{to_json_binary, Addr, BlockInfo, StdResult, SubMsg, Uint128, WasmMsg};
 use cw_utils::Expiration;
 use dao_voting::{
-    reply::mask_proposal_execution_proposal_id, threshold::PercentageThreshold,
+    reply::mask_proposal_execution_proposal_id,
+    stats::{turnout, votes_needed, votes_needed_for_quorum, ProposalStatsResponse},
+    threshold::PercentageThreshold,
+    veto::VetoConfig,
     voting::does_vote_count_pass,
 };
 
 use crate::{
-    config::Config,
+    config::{Config, CycleResolution},
     msg::Choice,
     tally::{Tally, Winner},
 };
@@ -24,7 +29,20 @@ pub struct Proposal {
     pub close_on_execution_failure: bool,
     pub total_power: Uint128,
 
+    /// How to choose the winner if there is no Condorcet winner once
+    /// the proposal expires.
+    pub cycle_resolution: Option<CycleResolution>,
+    /// The cycle resolution method that chose the winner, if the
+    /// proposal passed without a Condorcet winner.
+    pub resolved_by: Option<CycleResolution>,
+
+    /// If set, the proposal is timelocked once it passes, during
+    /// which the vetoers may veto it.
+    pub veto: Option<VetoConfig>,
+
     pub id: u32,
+    pub title: String,
+    pub description: String,
     pub choices: Vec<Choice>,
 }
 
@@ -44,6 +62,41 @@ pub enum Status {
     Closed,
     /// The proposal's execution failed.
     ExecutionFailed,
+    /// The proposal has passed, but may be vetoed and may only be
+    /// executed early by the vetoers until `expiration`.
+    VetoTimelock { winner: u32, expiration: Expiration },
+    /// The proposal has been vetoed.
+    Vetoed,
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Open => write!(f, "open"),
+            Status::Rejected => write!(f, "rejected"),
+            Status::Passed { .. } => write!(f, "passed"),
+            Status::Executed => write!(f, "executed"),
+            Status::Closed => write!(f, "closed"),
+            Status::ExecutionFailed => write!(f, "execution_failed"),
+            Status::VetoTimelock { .. } => write!(f, "veto_timelock"),
+            Status::Vetoed => write!(f, "vetoed"),
+        }
+    }
+}
+
+impl From<Status> for dao_voting::status::Status {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Open => Self::Open,
+            Status::Rejected => Self::Rejected,
+            Status::Passed { .. } => Self::Passed,
+            Status::Executed => Self::Executed,
+            Status::Closed => Self::Closed,
+            Status::ExecutionFailed => Self::ExecutionFailed,
+            Status::VetoTimelock { expiration, .. } => Self::VetoTimelock { expiration },
+            Status::Vetoed => Self::Vetoed,
+        }
+    }
 }
 
 #[cw_serde]
@@ -52,67 +105,128 @@ pub struct ProposalResponse {
     pub tally: Tally,
 }
 
-fn status(block: &BlockInfo, proposal: &Proposal, tally: &Tally) -> Status {
+#[cw_serde]
+pub struct ProposalListResponse {
+    pub proposals: Vec<ProposalResponse>,
+}
+
+/// Computes the proposal's status, along with the cycle resolution
+/// method that chose its winner if it passed without a Condorcet
+/// winner.
+fn status(
+    block: &BlockInfo,
+    proposal: &Proposal,
+    tally: &Tally,
+) -> StdResult<(Status, Option<CycleResolution>)> {
     match proposal.last_status {
+        Status::Open => {
+            let (status, resolved_by) = open_status(block, proposal, tally);
+            match (status, &proposal.veto) {
+                // passed proposals with a veto config are timelocked
+                // until `timelock_duration` after they expire.
+                (Status::Passed { winner }, Some(veto)) => {
+                    let expiration = tally.expiration.add(veto.timelock_duration)?;
+                    if expiration.is_expired(block) {
+                        Ok((status, resolved_by))
+                    } else {
+                        Ok((Status::VetoTimelock { winner, expiration }, resolved_by))
+                    }
+                }
+                _ => Ok((status, resolved_by)),
+            }
+        }
+        Status::VetoTimelock { winner, expiration } if expiration.is_expired(block) => {
+            Ok((Status::Passed { winner }, proposal.resolved_by))
+        }
         Status::Rejected
         | Status::Passed { .. }
         | Status::Executed
         | Status::Closed
-        | Status::ExecutionFailed => proposal.last_status,
-        Status::Open => {
-            if proposal
-                .min_voting_period
-                .map_or(false, |min| !min.is_expired(block))
-            {
-                return Status::Open;
-            }
+        | Status::ExecutionFailed
+        | Status::VetoTimelock { .. }
+        | Status::Vetoed => Ok((proposal.last_status, proposal.resolved_by)),
+    }
+}
 
-            let winner = tally.winner;
-            let expired = tally.expiration.is_expired(block);
-            let quorum = does_vote_count_pass(
-                proposal.total_power - tally.power_outstanding,
-                proposal.total_power,
-                proposal.quorum,
-            );
-
-            if expired && !quorum {
-                Status::Rejected
-            } else {
-                match winner {
-                    Winner::Never => Status::Rejected,
-                    Winner::None => {
-                        if expired {
-                            Status::Rejected
-                        } else {
-                            Status::Open
-                        }
-                    }
-                    Winner::Some(winner) => {
-                        if expired && quorum {
-                            Status::Passed { winner }
-                        } else {
-                            Status::Open
-                        }
-                    }
-                    Winner::Undisputed(winner) => {
-                        if quorum {
-                            Status::Passed { winner }
-                        } else {
-                            Status::Open
-                        }
-                    }
+/// Computes the status of a proposal that was last saved while open,
+/// ignoring any veto timelock.
+fn open_status(
+    block: &BlockInfo,
+    proposal: &Proposal,
+    tally: &Tally,
+) -> (Status, Option<CycleResolution>) {
+    if proposal
+        .min_voting_period
+        .map_or(false, |min| !min.is_expired(block))
+    {
+        return (Status::Open, None);
+    }
+
+    let winner = tally.winner;
+    let expired = tally.expiration.is_expired(block);
+    let quorum = does_vote_count_pass(
+        proposal.total_power - tally.power_outstanding,
+        proposal.total_power,
+        proposal.quorum,
+    );
+
+    if expired && !quorum {
+        (Status::Rejected, None)
+    } else if tally.allow_revoting && !expired {
+        // any outcome may be changed by a revote.
+        (Status::Open, None)
+    } else {
+        let status = match (winner, proposal.cycle_resolution) {
+            // with a cycle resolution method, proposals
+            // without a Condorcet winner are decided once
+            // they expire, as any vote may change the
+            // method's winner.
+            (Winner::Never | Winner::None, Some(method)) => {
+                if expired {
+                    return match tally.resolve_cycle(method) {
+                        Some(winner) => (Status::Passed { winner }, Some(method)),
+                        None => (Status::Rejected, None),
+                    };
+                } else {
+                    Status::Open
                 }
             }
-        }
+            (Winner::Never, None) => Status::Rejected,
+            (Winner::None, None) => {
+                if expired {
+                    Status::Rejected
+                } else {
+                    Status::Open
+                }
+            }
+            (Winner::Some(winner), _) => {
+                if expired && quorum {
+                    Status::Passed { winner }
+                } else {
+                    Status::Open
+                }
+            }
+            (Winner::Undisputed(winner), _) => {
+                if quorum {
+                    Status::Passed { winner }
+                } else {
+                    Status::Open
+                }
+            }
+        };
+        (status, None)
     }
 }
 
 impl Proposal {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         block: &BlockInfo,
         config: &Config,
         proposer: Addr,
         id: u32,
+        title: String,
+        description: String,
         choices: Vec<Choice>,
         total_power: Uint128,
     ) -> Self {
@@ -122,25 +236,97 @@ impl Proposal {
             min_voting_period: config.min_voting_period.map(|m| m.after(block)),
             quorum: config.quorum,
             close_on_execution_failure: config.close_proposals_on_execution_failure,
+            cycle_resolution: config.cycle_resolution,
+            resolved_by: None,
+            veto: config.veto.clone(),
 
             id,
             proposer,
+            title,
+            description,
             choices,
             total_power,
         }
     }
 
-    pub(crate) fn update_status(&mut self, block: &BlockInfo, tally: &Tally) -> Status {
-        self.last_status = status(block, self, tally);
-        self.last_status
+    pub(crate) fn update_status(&mut self, block: &BlockInfo, tally: &Tally) -> StdResult<Status> {
+        (self.last_status, self.resolved_by) = status(block, self, tally)?;
+        Ok(self.last_status)
     }
 
-    pub fn status(&self, block: &BlockInfo, tally: &Tally) -> Status {
-        status(block, self, tally)
+    pub fn status(&self, block: &BlockInfo, tally: &Tally) -> StdResult<Status> {
+        Ok(status(block, self, tally)?.0)
     }
 
-    // To test that status is updated before responding to queries.
-    #[cfg(test)]
+    /// Computes participation statistics for the proposal. Votes to
+    /// pass it rank the candidate closest to winning first, and votes
+    /// to reject it rank the current winner last. If there is no
+    /// Condorcet winner but a cycle resolution method would choose
+    /// one, votes to pass it need only meet quorum, and votes to
+    /// reject it are not computed as any vote may change the method's
+    /// winner.
+    pub fn stats(&self, block: &BlockInfo, tally: &Tally) -> StdResult<ProposalStatsResponse> {
+        // a proposal is held open until its minimum voting period
+        // ends, though its outcome may already be decided.
+        let status = match self.status(block, tally)? {
+            Status::Open => Proposal {
+                min_voting_period: None,
+                ..self.clone()
+            }
+            .status(block, tally)?,
+            status => status,
+        };
+        let outcome_locked = status != Status::Open;
+        let cast = self.total_power - tally.power_outstanding;
+        let outstanding = if outcome_locked {
+            Uint128::zero()
+        } else {
+            tally.power_outstanding
+        };
+        let quorum_met = |votes| does_vote_count_pass(votes, self.total_power, self.quorum);
+
+        let (votes_needed_to_pass, votes_needed_to_reject) = match status {
+            Status::Open => {
+                let power_to_win = tally.power_to_win();
+                let winning_margin = tally.winning_margin();
+                let resolved = winning_margin.is_none()
+                    && self
+                        .cycle_resolution
+                        .map_or(false, |method| tally.resolve_cycle(method).is_some());
+                let to_pass = votes_needed(outstanding, |votes| {
+                    (resolved || votes >= power_to_win) && quorum_met(cast + votes)
+                });
+                let to_reject = match winning_margin {
+                    Some(margin) if quorum_met(cast) => {
+                        votes_needed(outstanding, |votes| votes >= margin)
+                    }
+                    _ if resolved => None,
+                    _ => Some(Uint128::zero()),
+                };
+                (to_pass, to_reject)
+            }
+            Status::Passed { .. }
+            | Status::VetoTimelock { .. }
+            | Status::Executed
+            | Status::ExecutionFailed => (Some(Uint128::zero()), None),
+            Status::Rejected | Status::Closed | Status::Vetoed => (None, Some(Uint128::zero())),
+        };
+
+        Ok(ProposalStatsResponse {
+            turnout: turnout(cast, self.total_power),
+            votes_needed_to_pass,
+            votes_needed_to_reject,
+            votes_needed_for_quorum: votes_needed_for_quorum(
+                cast,
+                outstanding,
+                self.total_power,
+                self.quorum,
+            ),
+            outcome_locked,
+        })
+    }
+
+    /// The proposal's status as of the last time it was saved.
     pub fn last_status(&self) -> Status {
         self.last_status
     }
@@ -151,10 +337,22 @@ impl Proposal {
         self.last_status = Status::Closed;
     }
 
+    pub(crate) fn set_vetoed(&mut self) {
+        debug_assert!(matches!(
+            self.last_status,
+            Status::Open | Status::VetoTimelock { .. }
+        ));
+
+        self.last_status = Status::Vetoed;
+    }
+
     /// Sets the proposal's status to executed and returns a
     /// submessage to be executed.
     pub(crate) fn set_executed(&mut self, dao: Addr, winner: u32) -> StdResult<SubMsg> {
-        debug_assert_eq!(self.last_status, Status::Passed { winner });
+        debug_assert!(matches!(
+            self.last_status,
+            Status::Passed { winner: w } | Status::VetoTimelock { winner: w, .. } if w == winner
+        ));
 
         self.last_status = Status::Executed;
 
@@ -1,14 +1,35 @@
-use cosmwasm_std::{Addr, StdResult, Storage};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, StdResult, Storage, Uint128};
+use cw_hooks::Hooks;
 use cw_storage_plus::{Item, Map};
+use dao_voting::pre_propose::ProposalCreationPolicy;
 
 use crate::{config::Config, proposal::Proposal, tally::Tally, vote::Vote};
 
 pub(crate) const DAO: Item<Addr> = Item::new("dao");
 pub(crate) const CONFIG: Item<Config> = Item::new("config");
+pub(crate) const CREATION_POLICY: Item<ProposalCreationPolicy> = Item::new("creation_policy");
 
 pub(crate) const TALLY: Map<u32, Tally> = Map::new("tallys");
 pub(crate) const PROPOSAL: Map<u32, Proposal> = Map::new("proposals");
-pub(crate) const VOTE: Map<(u32, Addr), Vote> = Map::new("votes");
+pub(crate) const VOTE: Map<(u32, Addr), Ballot> = Map::new("votes");
+/// The vetoers who have vetoed each proposal so far.
+pub(crate) const VETO_VOTES: Map<u32, Vec<Addr>> = Map::new("veto_votes");
+/// The vetoers who have approved executing each timelocked proposal
+/// early so far.
+pub(crate) const EARLY_EXECUTE_VOTES: Map<u32, Vec<Addr>> = Map::new("early_execute_votes");
+
+pub(crate) const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
+pub(crate) const VOTE_HOOKS: Hooks = Hooks::new("vote_hooks");
+
+/// A ranked ballot cast on a proposal.
+#[cw_serde]
+pub(crate) struct Ballot {
+    /// The voting power of the voter.
+    pub power: Uint128,
+    /// The voter's ranking of the proposal's choices.
+    pub vote: Vote,
+}
 
 pub(crate) fn next_proposal_id(storage: &dyn Storage) -> StdResult<u32> {
     PROPOSAL
@@ -3,6 +3,8 @@ use cosmwasm_std::{BlockInfo, Uint128};
 use cw_utils::Expiration;
 
 use crate::{
+    cell::Cell,
+    config::CycleResolution,
     m::{Stats, M},
     vote::Vote,
 };
@@ -30,6 +32,9 @@ pub struct Tally {
     pub power_outstanding: Uint128,
     /// The current winner. Always up to date and updated on vote.
     pub winner: Winner,
+    /// If true, voters may change their votes while the tally is not
+    /// expired.
+    pub allow_revoting: bool,
 }
 
 #[cw_serde]
@@ -41,12 +46,23 @@ pub enum Winner {
     Undisputed(u32),
 }
 
+#[cw_serde]
+pub struct PairwiseMatrixResponse {
+    /// `margins[x][y]` is the voting power that ranked choice x above
+    /// choice y minus the voting power that ranked y above x. A
+    /// choice beats every other choice, and is thus the Condorcet
+    /// winner, if every value in its row besides `margins[x][x]` is
+    /// positive.
+    pub margins: Vec<Vec<Cell>>,
+}
+
 impl Tally {
     pub fn new(
         candidates: u32,
         total_power: Uint128,
         start_height: u64,
         expiration: Expiration,
+        allow_revoting: bool,
     ) -> Self {
         let mut tally = Self {
             m: M::new(candidates),
@@ -54,6 +70,7 @@ impl Tally {
             winner: Winner::None,
             start_height,
             expiration,
+            allow_revoting,
         };
         // compute even though this will always be Winner::None so
         // that creating a tally has the same compute cost of adding a
@@ -74,26 +91,100 @@ impl Tally {
     /// Records a vote in the tally. The tally must not be expired.
     ///
     ///  - `vote` a list of candidates sorted in order from most to
-    ///    least favored
+    ///    least favored. candidates not in the list are tied below
+    ///    those that are.
     ///  - `power` the voting power of the voter
     ///
     /// Invariants:
     ///
-    /// - Voter has not already voted.
+    /// - Voter has not already voted, or their previous vote has
+    ///   been removed.
     /// - Tally is not expired.
     pub fn add_vote(&mut self, vote: Vote, power: Uint128) {
-        for (index, preference) in vote.iter().enumerate() {
-            // an interesting property of the symetry of M is that in
-            // recording all the defeats, we also record all of the
-            // victories.
-            for defeat in 0..index {
-                self.m.decrement((*preference, vote[defeat]), power)
-            }
+        // an interesting property of the symetry of M is that in
+        // recording all the defeats, we also record all of the
+        // victories.
+        for (winner, loser) in self.defeats(&vote) {
+            self.m.decrement((loser, winner), power)
         }
         self.power_outstanding -= power;
         self.winner = self.winner();
     }
 
+    /// Removes a vote previously recorded with `add_vote` from the
+    /// tally. The tally must not be expired.
+    ///
+    /// Invariants:
+    ///
+    /// - `vote` and `power` are those of a vote that was added.
+    /// - Tally is not expired.
+    pub fn remove_vote(&mut self, vote: &Vote, power: Uint128) {
+        // decrementing (winner, loser) is the same as incrementing
+        // (loser, winner), which undoes the defeats recorded by
+        // `add_vote`.
+        for (winner, loser) in self.defeats(vote) {
+            self.m.decrement((winner, loser), power)
+        }
+        self.power_outstanding += power;
+        self.winner = self.winner();
+    }
+
+    /// The pairs of candidates `(winner, loser)` where `vote` ranks
+    /// winner above loser. Every ranked candidate is above the
+    /// candidates ranked after it and all unranked candidates, and
+    /// unranked candidates are tied with each other.
+    fn defeats(&self, vote: &Vote) -> Vec<(u32, u32)> {
+        let mut ranked = vec![false; self.candidates() as usize];
+        for candidate in vote.iter() {
+            ranked[*candidate as usize] = true;
+        }
+        let unranked: Vec<u32> = (0..self.candidates())
+            .filter(|candidate| !ranked[*candidate as usize])
+            .collect();
+
+        let mut defeats = vec![];
+        for (index, winner) in vote.iter().enumerate() {
+            for loser in vote.iter().skip(index + 1).chain(unranked.iter()) {
+                defeats.push((*winner, *loser))
+            }
+        }
+        defeats
+    }
+
+    /// The voting power that would need to rank the candidate
+    /// closest to winning first for it to become the Condorcet
+    /// winner.
+    pub fn power_to_win(&self) -> Uint128 {
+        (0..self.candidates())
+            .map(|col| self.m.power_to_win(col))
+            .min()
+            .unwrap_or_default()
+    }
+
+    /// The smallest margin by which the current Condorcet winner, if
+    /// any, beats another candidate.
+    pub fn winning_margin(&self) -> Option<Uint128> {
+        match self.m.stats(self.power_outstanding) {
+            Stats::PositiveColumn { min_margin, .. } => Some(min_margin),
+            Stats::NoPositiveColumn { .. } => None,
+        }
+    }
+
+    /// The pairwise margins between candidates, see
+    /// `PairwiseMatrixResponse`.
+    pub fn margins(&self) -> Vec<Vec<Cell>> {
+        self.m.rows()
+    }
+
+    /// The winner chosen by `method` from the pairwise margins, or
+    /// None if it does not choose exactly one candidate.
+    pub fn resolve_cycle(&self, method: CycleResolution) -> Option<u32> {
+        match method {
+            CycleResolution::Schulze => self.m.schulze_winner(),
+            CycleResolution::RankedPairs => self.m.ranked_pairs_winner(),
+        }
+    }
+
     fn winner(&self) -> Winner {
         match self.m.stats(self.power_outstanding) {
             Stats::PositiveColumn { col, min_margin } => {
@@ -0,0 +1,242 @@
+use dao_proposal_hook_counter::msg::QueryMsg as CounterQuery;
+use dao_voting::pre_propose::{PreProposeInfo, ProposalCreationPolicy};
+
+use crate::{msg::ExecuteMsg, proposal::Status, ContractError};
+
+use super::{
+    is_error,
+    suite::{into_choices, unimportant_message, SuiteBuilder},
+};
+
+#[test]
+fn test_proposal_and_vote_hooks() {
+    let mut suite = SuiteBuilder::default().build();
+    let counter = suite.instantiate_hook_counter(false);
+
+    suite
+        .execute_msg(
+            suite.core.clone(),
+            &ExecuteMsg::AddProposalHook {
+                address: counter.to_string(),
+            },
+        )
+        .unwrap();
+    suite
+        .execute_msg(
+            suite.core.clone(),
+            &ExecuteMsg::AddVoteHook {
+                address: counter.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        suite.query_proposal_hooks().hooks,
+        vec![counter.to_string()]
+    );
+    assert_eq!(suite.query_vote_hooks().hooks, vec![counter.to_string()]);
+
+    let id = suite
+        .propose(suite.sender(), vec![vec![unimportant_message()]])
+        .unwrap();
+    assert_eq!(
+        suite.query_hook_count(&counter, CounterQuery::ProposalCounter {}),
+        1
+    );
+
+    suite.vote(suite.sender(), id, vec![0, 1]).unwrap();
+    assert_eq!(
+        suite.query_hook_count(&counter, CounterQuery::VoteCounter {}),
+        1
+    );
+    // status changed hooks are fired once the status is saved.
+    assert_eq!(
+        suite.query_hook_count(&counter, CounterQuery::StatusChangedCounter {}),
+        0
+    );
+
+    suite.a_day_passes();
+    suite.execute(suite.sender(), id).unwrap();
+    assert_eq!(
+        suite.query_hook_count(&counter, CounterQuery::StatusChangedCounter {}),
+        1
+    );
+
+    suite
+        .execute_msg(
+            suite.core.clone(),
+            &ExecuteMsg::RemoveProposalHook {
+                address: counter.to_string(),
+            },
+        )
+        .unwrap();
+    suite
+        .execute_msg(
+            suite.core.clone(),
+            &ExecuteMsg::RemoveVoteHook {
+                address: counter.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(suite.query_proposal_hooks().hooks.is_empty());
+    assert!(suite.query_vote_hooks().hooks.is_empty());
+}
+
+#[test]
+fn test_hooks_only_dao() {
+    let mut suite = SuiteBuilder::default().build();
+    let counter = suite.instantiate_hook_counter(false);
+
+    let err = suite.execute_msg(
+        suite.sender(),
+        &ExecuteMsg::AddProposalHook {
+            address: counter.to_string(),
+        },
+    );
+    is_error!(err, &ContractError::NotDao {}.to_string());
+    let err = suite.execute_msg(
+        suite.sender(),
+        &ExecuteMsg::AddVoteHook {
+            address: counter.to_string(),
+        },
+    );
+    is_error!(err, &ContractError::NotDao {}.to_string());
+
+    suite
+        .execute_msg(
+            suite.core.clone(),
+            &ExecuteMsg::AddVoteHook {
+                address: counter.to_string(),
+            },
+        )
+        .unwrap();
+    let err = suite.execute_msg(
+        suite.sender(),
+        &ExecuteMsg::RemoveVoteHook {
+            address: counter.to_string(),
+        },
+    );
+    is_error!(err, &ContractError::NotDao {}.to_string());
+}
+
+#[test]
+fn test_failing_hooks_removed() {
+    let mut suite = SuiteBuilder::default().build();
+    let counter = suite.instantiate_hook_counter(true);
+
+    suite
+        .execute_msg(
+            suite.core.clone(),
+            &ExecuteMsg::AddProposalHook {
+                address: counter.to_string(),
+            },
+        )
+        .unwrap();
+    suite
+        .execute_msg(
+            suite.core.clone(),
+            &ExecuteMsg::AddVoteHook {
+                address: counter.to_string(),
+            },
+        )
+        .unwrap();
+
+    let id = suite
+        .propose(suite.sender(), vec![vec![unimportant_message()]])
+        .unwrap();
+    assert!(suite.query_proposal_hooks().hooks.is_empty());
+
+    suite.vote(suite.sender(), id, vec![0, 1]).unwrap();
+    assert!(suite.query_vote_hooks().hooks.is_empty());
+}
+
+#[test]
+fn test_pre_propose_module() {
+    let mut suite = SuiteBuilder::default().with_pre_propose().build();
+    let module = suite.pre_propose_module();
+
+    // only the pre-propose module may create proposals.
+    let err = suite.propose(suite.sender(), vec![vec![unimportant_message()]]);
+    is_error!(err, &ContractError::Unauthorized {}.to_string());
+
+    // and it must specify a proposer.
+    let err = suite.execute_msg(
+        module,
+        &ExecuteMsg::Propose {
+            title: "title".to_string(),
+            description: "description".to_string(),
+            choices: into_choices(vec![vec![unimportant_message()]]),
+            proposer: None,
+        },
+    );
+    is_error!(err, &ContractError::InvalidProposer {}.to_string());
+
+    // non-members may not propose under the module's submission
+    // policy.
+    suite
+        .pre_propose("somebody", vec![vec![unimportant_message()]])
+        .unwrap_err();
+
+    let id = suite
+        .pre_propose(suite.sender(), vec![vec![unimportant_message()]])
+        .unwrap();
+    assert_eq!(suite.query_proposal(id).proposal.proposer, suite.sender());
+
+    // executing the proposal fires the module's completed hook, which
+    // would remove the module were it to fail.
+    suite.vote(suite.sender(), id, vec![0, 1]).unwrap();
+    suite.a_day_passes();
+    suite.execute(suite.sender(), id).unwrap();
+    let (_, status) = suite.query_winner_and_status(id);
+    assert_eq!(status, Status::Executed);
+    assert!(matches!(
+        suite.query_creation_policy(),
+        ProposalCreationPolicy::Module { .. }
+    ));
+}
+
+#[test]
+fn test_proposer_without_pre_propose() {
+    let mut suite = SuiteBuilder::default().build();
+
+    let err = suite.execute_msg(
+        suite.sender(),
+        &ExecuteMsg::Propose {
+            title: "title".to_string(),
+            description: "description".to_string(),
+            choices: into_choices(vec![vec![unimportant_message()]]),
+            proposer: Some("somebody".to_string()),
+        },
+    );
+    is_error!(err, &ContractError::InvalidProposer {}.to_string());
+}
+
+#[test]
+fn test_update_pre_propose_info() {
+    let mut suite = SuiteBuilder::default().with_pre_propose().build();
+
+    let err = suite.execute_msg(
+        suite.sender(),
+        &ExecuteMsg::UpdatePreProposeInfo {
+            info: PreProposeInfo::AnyoneMayPropose {},
+        },
+    );
+    is_error!(err, &ContractError::NotDao {}.to_string());
+
+    suite
+        .execute_msg(
+            suite.core.clone(),
+            &ExecuteMsg::UpdatePreProposeInfo {
+                info: PreProposeInfo::AnyoneMayPropose {},
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        suite.query_creation_policy(),
+        ProposalCreationPolicy::Anyone {}
+    );
+
+    let id = suite
+        .propose(suite.sender(), vec![vec![unimportant_message()]])
+        .unwrap();
+    assert_eq!(suite.query_proposal(id).proposal.proposer, suite.sender());
+}
@@ -1,4 +1,4 @@
-use cosmwasm_std::Decimal;
+use cosmwasm_std::{testing::mock_dependencies, Decimal};
 use cw_utils::Duration;
 use dao_voting::threshold::PercentageThreshold;
 
@@ -13,7 +13,8 @@ fn test_instantiation() {
     let suite = SuiteBuilder::default().build();
     let config = suite.query_config();
 
-    assert_eq!(config, default_config.into_checked().unwrap())
+    let deps = mock_dependencies();
+    assert_eq!(config, default_config.into_checked(deps.as_ref()).unwrap())
 }
 
 #[test]
@@ -24,6 +25,9 @@ fn test_instantiate_conflicting_proposal_durations() {
         voting_period: Duration::Height(10),
         min_voting_period: Some(Duration::Height(11)),
         close_proposals_on_execution_failure: true,
+        allow_revoting: false,
+        cycle_resolution: None,
+        veto: None,
     })
     .build();
 }
@@ -38,6 +42,9 @@ fn test_instantiate_conflicting_duration_types() {
         voting_period: Duration::Height(10),
         min_voting_period: Some(Duration::Time(9)),
         close_proposals_on_execution_failure: true,
+        allow_revoting: false,
+        cycle_resolution: None,
+        veto: None,
     })
     .build();
 }
@@ -49,6 +56,9 @@ fn test_instantiate_open_til_expiry() {
         voting_period: Duration::Height(10),
         min_voting_period: Some(Duration::Height(10)),
         close_proposals_on_execution_failure: true,
+        allow_revoting: false,
+        cycle_resolution: None,
+        veto: None,
     })
     .build();
     SuiteBuilder::with_config(UncheckedConfig {
@@ -56,6 +66,9 @@ fn test_instantiate_open_til_expiry() {
         voting_period: Duration::Time(10),
         min_voting_period: Some(Duration::Time(10)),
         close_proposals_on_execution_failure: true,
+        allow_revoting: false,
+        cycle_resolution: None,
+        veto: None,
     })
     .build();
 }
@@ -1,8 +1,10 @@
-use cosmwasm_std::{to_json_binary, WasmMsg};
+use cosmwasm_std::{to_json_binary, Decimal, Uint128, WasmMsg};
 use cw_utils::Duration;
+use dao_voting::stats::ProposalStatsResponse;
 
 use crate::{
-    config::UncheckedConfig,
+    cell::Cell,
+    config::{CycleResolution, UncheckedConfig, MAX_CYCLE_RESOLUTION_CHOICES},
     msg::ExecuteMsg,
     proposal::{ProposalResponse, Status},
     tally::Winner,
@@ -55,9 +57,13 @@ fn test_make_proposal() {
     let ProposalResponse { proposal, tally } = suite.query_proposal(id);
 
     assert_eq!(proposal.id, id);
+    assert_eq!(proposal.title, "title");
+    assert_eq!(proposal.description, "description");
     assert_eq!(proposal.choices.len(), 2);
+    assert_eq!(proposal.choices[0].title, "choice 0");
     assert_eq!(proposal.choices[0].msgs[0], unimportant_message());
     assert_eq!(proposal.choices[1].msgs, vec![]); // none-of-the-above added to the end.
+    assert_eq!(proposal.choices[1].title, "None of the above");
 
     assert_eq!(tally.candidates(), 2);
     assert_eq!(tally.winner, Winner::None);
@@ -134,6 +140,154 @@ fn test_proposal_passes_after_expiry() {
     assert_eq!(status, Status::Passed { winner: 0 });
 }
 
+#[test]
+fn test_proposal_stats() {
+    let mut suite = SuiteBuilder::default()
+        .with_voters(&[("blue", 10), ("violet", 30), ("gold", 60)])
+        .with_proposal(2)
+        .build();
+
+    let stats = suite.query_proposal_stats(1);
+    assert_eq!(
+        stats,
+        ProposalStatsResponse {
+            turnout: Decimal::zero(),
+            votes_needed_to_pass: Some(Uint128::new(15)),
+            votes_needed_to_reject: Some(Uint128::zero()),
+            votes_needed_for_quorum: Some(Uint128::new(15)),
+            outcome_locked: false,
+        }
+    );
+
+    // candidate 1 leads, but quorum has not been met.
+    suite.vote("blue", 1, vec![1, 0, 2]).unwrap();
+    let stats = suite.query_proposal_stats(1);
+    assert_eq!(stats.turnout, Decimal::percent(10));
+    assert_eq!(stats.votes_needed_to_pass, Some(Uint128::new(5)));
+    assert_eq!(stats.votes_needed_to_reject, Some(Uint128::zero()));
+    assert_eq!(stats.votes_needed_for_quorum, Some(Uint128::new(5)));
+
+    // candidate 0 leads candidate 1 by 20.
+    suite.vote("violet", 1, vec![0, 1, 2]).unwrap();
+    let stats = suite.query_proposal_stats(1);
+    assert_eq!(stats.turnout, Decimal::percent(40));
+    assert_eq!(stats.votes_needed_to_pass, Some(Uint128::zero()));
+    assert_eq!(stats.votes_needed_to_reject, Some(Uint128::new(20)));
+    assert_eq!(stats.votes_needed_for_quorum, Some(Uint128::zero()));
+
+    // everyone has voted. the minimum voting period keeps the
+    // proposal open, though its outcome is decided.
+    suite.vote("gold", 1, vec![2, 1, 0]).unwrap();
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::Open);
+    let stats = suite.query_proposal_stats(1);
+    assert_eq!(stats.votes_needed_to_pass, Some(Uint128::zero()));
+    assert_eq!(stats.votes_needed_to_reject, None);
+    assert!(stats.outcome_locked);
+
+    suite.a_day_passes();
+    let stats = suite.query_proposal_stats(1);
+    assert_eq!(
+        stats,
+        ProposalStatsResponse {
+            turnout: Decimal::one(),
+            votes_needed_to_pass: Some(Uint128::zero()),
+            votes_needed_to_reject: None,
+            votes_needed_for_quorum: Some(Uint128::zero()),
+            outcome_locked: true,
+        }
+    );
+}
+
+// a cycle where 0 beats 1 by 35, 1 beats 2 by 25, and 2 beats 0 by
+// 15 is resolved once the proposal expires.
+fn cycle_resolution(method: CycleResolution) {
+    let mut suite = SuiteBuilder::default()
+        .with_voters(&[("blue", 30), ("violet", 20), ("gold", 25)])
+        .with_proposal(2);
+    suite.instantiate.cycle_resolution = Some(method);
+    let mut suite = suite.build();
+
+    suite.vote("blue", 1, vec![0, 1, 2]).unwrap();
+    suite.vote("violet", 1, vec![1, 2, 0]).unwrap();
+    suite.vote("gold", 1, vec![2, 0, 1]).unwrap();
+
+    suite.a_day_passes();
+
+    // without a cycle resolution method, this would be rejected.
+    let (winner, status) = suite.query_winner_and_status(1);
+    assert_eq!(winner, Winner::Never);
+    assert_eq!(status, Status::Open);
+
+    let stats = suite.query_proposal_stats(1);
+    assert_eq!(stats.votes_needed_to_pass, Some(Uint128::zero()));
+    assert_eq!(stats.votes_needed_to_reject, None);
+    assert!(!stats.outcome_locked);
+
+    suite.a_week_passes();
+
+    let ProposalResponse { proposal, .. } = suite.query_proposal(1);
+    assert_eq!(proposal.last_status(), Status::Passed { winner: 0 });
+    assert_eq!(proposal.resolved_by, Some(method));
+
+    suite.execute("blue", 1).unwrap();
+
+    let ProposalResponse { proposal, .. } = suite.query_proposal(1);
+    assert_eq!(proposal.last_status(), Status::ExecutionFailed);
+    assert_eq!(proposal.resolved_by, Some(method));
+}
+
+#[test]
+fn test_cycle_resolution_schulze() {
+    cycle_resolution(CycleResolution::Schulze)
+}
+
+#[test]
+fn test_cycle_resolution_ranked_pairs() {
+    cycle_resolution(CycleResolution::RankedPairs)
+}
+
+#[test]
+fn test_condorcet_winner_not_resolved() {
+    let mut suite = SuiteBuilder::default().with_proposal(2);
+    suite.instantiate.cycle_resolution = Some(CycleResolution::Schulze);
+    let mut suite = suite.build();
+
+    suite.vote(suite.sender(), 1, vec![1, 0, 2]).unwrap();
+    suite.a_day_passes();
+
+    let ProposalResponse { proposal, .. } = suite.query_proposal(1);
+    assert_eq!(proposal.last_status(), Status::Passed { winner: 1 });
+    assert_eq!(proposal.resolved_by, None);
+}
+
+#[test]
+fn test_cycle_resolution_max_choices() {
+    let mut suite = SuiteBuilder::default();
+    suite.instantiate.cycle_resolution = Some(CycleResolution::RankedPairs);
+    let mut suite = suite.build();
+
+    // with none of the above, this is one too many choices.
+    let err = suite.propose(
+        suite.sender(),
+        vec![vec![]; MAX_CYCLE_RESOLUTION_CHOICES as usize],
+    );
+    is_error!(
+        err,
+        &ContractError::TooManyChoices {
+            max: MAX_CYCLE_RESOLUTION_CHOICES
+        }
+        .to_string()
+    );
+
+    suite
+        .propose(
+            suite.sender(),
+            vec![vec![]; MAX_CYCLE_RESOLUTION_CHOICES as usize - 1],
+        )
+        .unwrap();
+}
+
 #[test]
 fn test_no_vote_after_expiry() {
     let mut suite = SuiteBuilder::default().with_proposal(1).build();
@@ -154,6 +308,51 @@ fn test_no_revoting() {
     is_error!(err, &ContractError::Voted {}.to_string());
 }
 
+#[test]
+fn test_revoting() {
+    let mut suite = SuiteBuilder::default()
+        .with_voters(&[("blue", 10), ("violet", 30), ("gold", 60)])
+        .with_proposal(2);
+    suite.instantiate.allow_revoting = true;
+    let mut suite = suite.build();
+
+    // unranked choices tie below ranked ones.
+    suite.vote("gold", 1, vec![1]).unwrap();
+    suite.a_day_passes();
+
+    // revoting may change any outcome, so the proposal remains open
+    // until it expires.
+    let (winner, status) = suite.query_winner_and_status(1);
+    assert_eq!(winner, Winner::Undisputed(1));
+    assert_eq!(status, Status::Open);
+
+    let err = suite.vote("gold", 1, vec![1]);
+    is_error!(err, &ContractError::AlreadyCast {}.to_string());
+
+    // the previous ballot is removed from the tally.
+    suite.vote("gold", 1, vec![0, 2]).unwrap();
+    let margins = suite.query_pairwise_matrix(1);
+    assert_eq!(margins[0][1], Cell::Positive(Uint128::new(60)));
+    assert_eq!(margins[0][2], Cell::Positive(Uint128::new(60)));
+    assert_eq!(margins[2][1], Cell::Positive(Uint128::new(60)));
+
+    let vote = suite.query_vote(1, "gold").unwrap();
+    assert_eq!(vote.power, Uint128::new(60));
+    assert_eq!(vote.vote.to_string(), "0,2");
+
+    let ProposalResponse { tally, .. } = suite.query_proposal(1);
+    assert_eq!(tally.power_outstanding, Uint128::new(40));
+
+    suite.a_week_passes();
+
+    let (winner, status) = suite.query_winner_and_status(1);
+    assert_eq!(winner, Winner::Undisputed(0));
+    assert_eq!(status, Status::Passed { winner: 0 });
+
+    let err = suite.vote("gold", 1, vec![1]);
+    is_error!(err, &ContractError::Expired {}.to_string());
+}
+
 #[test]
 fn test_no_vote_zero_power() {
     let mut suite = SuiteBuilder::default().with_proposal(1).build();
@@ -176,6 +375,9 @@ fn test_proposal_set_config() {
                     voting_period: config.voting_period,
                     min_voting_period: None,
                     close_proposals_on_execution_failure: false,
+                    allow_revoting: false,
+                    cycle_resolution: None,
+                    veto: None,
                 }))
                 .unwrap(),
                 funds: vec![],
@@ -199,6 +401,9 @@ fn test_proposal_set_config() {
                     voting_period: config.voting_period,
                     min_voting_period: Some(Duration::Height(10)),
                     close_proposals_on_execution_failure: false,
+                    allow_revoting: false,
+                    cycle_resolution: None,
+                    veto: None,
                 }))
                 .unwrap(),
                 funds: vec![],
@@ -236,3 +441,45 @@ fn test_execution_fail_handling() {
     // no state changes get committed.
     suite.execute(suite.sender(), 1).unwrap_err();
 }
+
+// proposals in other modules that depend on a condorcet proposal read
+// its status with dao-voting's copy of `Status`, which must be able to
+// read every status.
+#[test]
+fn test_status_readable_by_dependents() {
+    use cosmwasm_std::from_json;
+    use cw_utils::Expiration;
+    use dao_voting::{proposal::CondorcetStatus, status::Status as DependencyStatus};
+
+    let expiration = Expiration::AtHeight(10);
+    for status in [
+        Status::Open,
+        Status::Rejected,
+        Status::Passed { winner: 1 },
+        Status::Executed,
+        Status::Closed,
+        Status::ExecutionFailed,
+        Status::VetoTimelock {
+            winner: 1,
+            expiration,
+        },
+        Status::Vetoed,
+    ] {
+        // adding a status fails to compile here, so that it is
+        // added to the list above and to dao-voting's copy.
+        let expected = match status {
+            Status::Open => DependencyStatus::Open,
+            Status::Rejected => DependencyStatus::Rejected,
+            Status::Passed { .. } => DependencyStatus::Passed,
+            Status::Executed => DependencyStatus::Executed,
+            Status::Closed => DependencyStatus::Closed,
+            Status::ExecutionFailed => DependencyStatus::ExecutionFailed,
+            Status::VetoTimelock { expiration, .. } => {
+                DependencyStatus::VetoTimelock { expiration }
+            }
+            Status::Vetoed => DependencyStatus::Vetoed,
+        };
+        let read: CondorcetStatus = from_json(to_json_binary(&status).unwrap()).unwrap();
+        assert_eq!(DependencyStatus::from(read), expected);
+    }
+}
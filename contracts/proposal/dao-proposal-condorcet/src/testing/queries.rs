@@ -0,0 +1,91 @@
+use cosmwasm_std::{Addr, Uint128};
+
+use crate::{cell::Cell, vote::Vote};
+
+use super::suite::{unimportant_message, SuiteBuilder};
+
+#[test]
+fn test_list_proposals() {
+    let mut suite = SuiteBuilder::default().build();
+    assert_eq!(suite.query_proposal_count(), 0);
+    assert!(suite.query_list_proposals(None, None).is_empty());
+
+    for _ in 0..5 {
+        suite
+            .propose(suite.sender(), vec![vec![unimportant_message()]])
+            .unwrap();
+    }
+    assert_eq!(suite.query_proposal_count(), 5);
+
+    let ids = |proposals: Vec<crate::proposal::ProposalResponse>| -> Vec<u32> {
+        proposals.into_iter().map(|p| p.proposal.id).collect()
+    };
+    assert_eq!(
+        ids(suite.query_list_proposals(None, None)),
+        vec![1, 2, 3, 4, 5]
+    );
+    assert_eq!(
+        ids(suite.query_list_proposals(Some(2), Some(2))),
+        vec![3, 4]
+    );
+    assert_eq!(
+        ids(suite.query_reverse_proposals(None, None)),
+        vec![5, 4, 3, 2, 1]
+    );
+    assert_eq!(
+        ids(suite.query_reverse_proposals(Some(4), Some(2))),
+        vec![3, 2]
+    );
+}
+
+#[test]
+fn test_list_votes() {
+    let mut suite = SuiteBuilder::default()
+        .with_voters(&[("blue", 3), ("violet", 2), ("magenta", 1)])
+        .with_proposal(2)
+        .build();
+
+    assert_eq!(suite.query_vote(1, "blue"), None);
+
+    suite.vote("violet", 1, vec![1, 0, 2]).unwrap();
+    suite.vote("blue", 1, vec![0, 2, 1]).unwrap();
+
+    let blue = suite.query_vote(1, "blue").unwrap();
+    assert_eq!(blue.voter, Addr::unchecked("blue"));
+    assert_eq!(blue.vote, Vote::new(vec![0, 2, 1], 3).unwrap());
+    assert_eq!(blue.power, Uint128::new(3));
+
+    let votes = suite.query_list_votes(1, None, None);
+    assert_eq!(
+        votes.iter().map(|v| v.voter.as_str()).collect::<Vec<_>>(),
+        vec!["blue", "violet"]
+    );
+    let votes = suite.query_list_votes(1, Some("blue".to_string()), None);
+    assert_eq!(votes.len(), 1);
+    assert_eq!(votes[0].voter, Addr::unchecked("violet"));
+    assert_eq!(votes[0].power, Uint128::new(2));
+}
+
+#[test]
+fn test_pairwise_matrix() {
+    let mut suite = SuiteBuilder::default()
+        .with_voters(&[("blue", 3), ("violet", 2), ("magenta", 1)])
+        .with_proposal(2)
+        .build();
+
+    assert_eq!(suite.query_pairwise_matrix(1), vec![vec![Cell::Zero; 3]; 3]);
+
+    suite.vote("blue", 1, vec![0, 1, 2]).unwrap();
+    suite.vote("violet", 1, vec![1, 0, 2]).unwrap();
+
+    let p = |n| Cell::Positive(Uint128::new(n));
+    let n = |n| Cell::Negative(Uint128::new(n));
+    assert_eq!(
+        suite.query_pairwise_matrix(1),
+        vec![
+            vec![Cell::Zero, p(1), p(5)],
+            vec![n(1), Cell::Zero, p(5)],
+            vec![n(5), n(5), Cell::Zero],
+        ]
+    );
+}
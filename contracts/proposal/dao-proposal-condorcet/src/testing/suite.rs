@@ -1,22 +1,31 @@
-use cosmwasm_std::{coins, to_json_binary, Addr, BankMsg, CosmosMsg, Decimal};
-use cw_multi_test::{next_block, App, Executor};
+use cosmwasm_std::{coins, to_json_binary, Addr, BankMsg, CosmosMsg, Decimal, Empty};
+use cw_hooks::HooksResponse;
+use cw_multi_test::{next_block, App, Contract, ContractWrapper, Executor};
 use cw_utils::Duration;
 use dao_interface::{
     state::{Admin, ModuleInstantiateInfo},
     voting::InfoResponse,
 };
 use dao_testing::contracts::{
-    cw4_group_contract, dao_dao_contract, dao_voting_cw4_contract, proposal_condorcet_contract,
+    cw4_group_contract, dao_dao_contract, dao_voting_cw4_contract, pre_propose_condorcet_contract,
+    proposal_condorcet_contract,
+};
+use dao_voting::{
+    pre_propose::{PreProposeInfo, PreProposeSubmissionPolicy, ProposalCreationPolicy},
+    stats::ProposalStatsResponse,
+    threshold::PercentageThreshold,
+    veto::VetoTallyResponse,
 };
-use dao_voting::threshold::PercentageThreshold;
 use dao_voting_cw4::msg::GroupContract;
 
 use crate::{
+    cell::Cell,
     config::{Config, UncheckedConfig},
     contract::{CONTRACT_NAME, CONTRACT_VERSION},
     msg::{Choice, ExecuteMsg, InstantiateMsg, QueryMsg},
-    proposal::{ProposalResponse, Status},
-    tally::Winner,
+    proposal::{ProposalListResponse, ProposalResponse, Status},
+    tally::{PairwiseMatrixResponse, Winner},
+    vote::{VoteInfo, VoteListResponse, VoteResponse},
 };
 
 pub(crate) struct Suite {
@@ -27,9 +36,10 @@ pub(crate) struct Suite {
 }
 
 pub(crate) struct SuiteBuilder {
-    pub instantiate: InstantiateMsg,
+    pub instantiate: UncheckedConfig,
     with_proposal: Option<u32>,
     with_voters: Vec<(String, u64)>,
+    with_pre_propose: bool,
 }
 
 impl Default for SuiteBuilder {
@@ -40,9 +50,13 @@ impl Default for SuiteBuilder {
                 voting_period: Duration::Time(60 * 60 * 24 * 7),
                 min_voting_period: Some(Duration::Time(60 * 60 * 24)),
                 close_proposals_on_execution_failure: true,
+                allow_revoting: false,
+                cycle_resolution: None,
+                veto: None,
             },
             with_proposal: None,
             with_voters: vec![("sender".to_string(), 10)],
+            with_pre_propose: false,
         }
     }
 }
@@ -65,6 +79,13 @@ impl SuiteBuilder {
         self
     }
 
+    /// Installs a `dao-pre-propose-condorcet` module which allows DAO
+    /// members to propose without a deposit.
+    pub fn with_pre_propose(mut self) -> Self {
+        self.with_pre_propose = true;
+        self
+    }
+
     pub fn build(self) -> Suite {
         let initial_members: Vec<_> = self
             .with_voters
@@ -78,6 +99,49 @@ impl SuiteBuilder {
         let core_id = app.store_code(dao_dao_contract());
         let cw4_id = app.store_code(cw4_group_contract());
         let cw4_voting_id = app.store_code(dao_voting_cw4_contract());
+        let pre_propose_id = app.store_code(pre_propose_condorcet_contract());
+
+        let pre_propose_info = if self.with_pre_propose {
+            PreProposeInfo::ModuleMayPropose {
+                info: ModuleInstantiateInfo {
+                    code_id: pre_propose_id,
+                    msg: to_json_binary(&dao_pre_propose_condorcet::InstantiateMsg {
+                        deposit_info: None,
+                        submission_policy: PreProposeSubmissionPolicy::Specific {
+                            dao_members: true,
+                            allowlist: None,
+                            denylist: None,
+                        },
+                        extension: Empty::default(),
+                    })
+                    .unwrap(),
+                    admin: Some(Admin::CoreModule {}),
+                    funds: vec![],
+                    label: "pre-propose module".to_string(),
+                },
+            }
+        } else {
+            PreProposeInfo::AnyoneMayPropose {}
+        };
+        let UncheckedConfig {
+            quorum,
+            voting_period,
+            min_voting_period,
+            close_proposals_on_execution_failure,
+            allow_revoting,
+            cycle_resolution,
+            veto,
+        } = self.instantiate;
+        let instantiate = InstantiateMsg {
+            quorum,
+            voting_period,
+            min_voting_period,
+            close_proposals_on_execution_failure,
+            allow_revoting,
+            cycle_resolution,
+            veto,
+            pre_propose_info,
+        };
 
         let core_instantiate = dao_interface::msg::InstantiateMsg {
             admin: None,
@@ -101,7 +165,7 @@ impl SuiteBuilder {
             },
             proposal_modules_instantiate_info: vec![ModuleInstantiateInfo {
                 code_id: condorcet_id,
-                msg: to_json_binary(&self.instantiate).unwrap(),
+                msg: to_json_binary(&instantiate).unwrap(),
                 admin: Some(Admin::CoreModule {}),
                 funds: vec![],
                 label: "condorcet module".to_string(),
@@ -144,14 +208,14 @@ impl SuiteBuilder {
         assert_eq!(next_id, 1);
 
         if let Some(candidates) = self.with_proposal {
-            suite
-                .propose(
-                    &suite.sender(),
-                    (0..candidates)
-                        .map(|_| vec![unimportant_message()])
-                        .collect(),
-                )
-                .unwrap();
+            let choices = (0..candidates)
+                .map(|_| vec![unimportant_message()])
+                .collect();
+            if self.with_pre_propose {
+                suite.pre_propose(suite.sender(), choices).unwrap();
+            } else {
+                suite.propose(suite.sender(), choices).unwrap();
+            }
             let next_id = suite.query_next_proposal_id();
             assert_eq!(next_id, 2);
         }
@@ -189,6 +253,13 @@ impl Suite {
     pub fn sender(&self) -> Addr {
         self.sender.clone()
     }
+
+    pub fn pre_propose_module(&self) -> Addr {
+        match self.query_creation_policy() {
+            ProposalCreationPolicy::Module { addr } => addr,
+            ProposalCreationPolicy::Anyone {} => panic!("no pre-propose module installed"),
+        }
+    }
 }
 
 // query
@@ -207,11 +278,112 @@ impl Suite {
             .unwrap()
     }
 
+    pub fn query_proposal_stats(&self, proposal_id: u32) -> ProposalStatsResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.condorcet, &QueryMsg::ProposalStats { proposal_id })
+            .unwrap()
+    }
+
+    pub fn query_list_proposals(
+        &self,
+        start_after: Option<u32>,
+        limit: Option<u64>,
+    ) -> Vec<ProposalResponse> {
+        let res: ProposalListResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.condorcet,
+                &QueryMsg::ListProposals { start_after, limit },
+            )
+            .unwrap();
+        res.proposals
+    }
+
+    pub fn query_reverse_proposals(
+        &self,
+        start_before: Option<u32>,
+        limit: Option<u64>,
+    ) -> Vec<ProposalResponse> {
+        let res: ProposalListResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.condorcet,
+                &QueryMsg::ReverseProposals {
+                    start_before,
+                    limit,
+                },
+            )
+            .unwrap();
+        res.proposals
+    }
+
+    pub fn query_proposal_count(&self) -> u32 {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.condorcet, &QueryMsg::ProposalCount {})
+            .unwrap()
+    }
+
+    pub fn query_vote<S: Into<String>>(&self, proposal_id: u32, voter: S) -> Option<VoteInfo> {
+        let res: VoteResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.condorcet,
+                &QueryMsg::GetVote {
+                    proposal_id,
+                    voter: voter.into(),
+                },
+            )
+            .unwrap();
+        res.vote
+    }
+
+    pub fn query_list_votes(
+        &self,
+        proposal_id: u32,
+        start_after: Option<String>,
+        limit: Option<u64>,
+    ) -> Vec<VoteInfo> {
+        let res: VoteListResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.condorcet,
+                &QueryMsg::ListVotes {
+                    proposal_id,
+                    start_after,
+                    limit,
+                },
+            )
+            .unwrap();
+        res.votes
+    }
+
+    pub fn query_pairwise_matrix(&self, proposal_id: u32) -> Vec<Vec<Cell>> {
+        let res: PairwiseMatrixResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(&self.condorcet, &QueryMsg::PairwiseMatrix { proposal_id })
+            .unwrap();
+        res.margins
+    }
+
     pub fn query_winner_and_status(&self, id: u32) -> (Winner, Status) {
         let q = self.query_proposal(id);
         (q.tally.winner, q.proposal.last_status())
     }
 
+    pub fn query_veto_tally(&self, proposal_id: u32) -> VetoTallyResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.condorcet, &QueryMsg::VetoTally { proposal_id })
+            .unwrap()
+    }
+
     pub fn query_next_proposal_id(&self) -> u32 {
         self.app
             .wrap()
@@ -226,6 +398,27 @@ impl Suite {
             .unwrap()
     }
 
+    pub fn query_creation_policy(&self) -> ProposalCreationPolicy {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.condorcet, &QueryMsg::ProposalCreationPolicy {})
+            .unwrap()
+    }
+
+    pub fn query_proposal_hooks(&self) -> HooksResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.condorcet, &QueryMsg::ProposalHooks {})
+            .unwrap()
+    }
+
+    pub fn query_vote_hooks(&self) -> HooksResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.condorcet, &QueryMsg::VoteHooks {})
+            .unwrap()
+    }
+
     pub fn query_info(&self) -> InfoResponse {
         self.app
             .wrap()
@@ -246,13 +439,74 @@ impl Suite {
             Addr::unchecked(sender),
             self.condorcet.clone(),
             &ExecuteMsg::Propose {
-                choices: choices.into_iter().map(|msgs| Choice { msgs }).collect(),
+                title: "title".to_string(),
+                description: "description".to_string(),
+                choices: into_choices(choices),
+                proposer: None,
             },
             &[],
         )?;
         Ok(id)
     }
 
+    /// Creates a proposal via the installed pre-propose module.
+    pub fn pre_propose<S: Into<String>>(
+        &mut self,
+        sender: S,
+        choices: Vec<Vec<CosmosMsg>>,
+    ) -> anyhow::Result<u32> {
+        let id = self.query_next_proposal_id();
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.pre_propose_module(),
+            &dao_pre_propose_condorcet::ExecuteMsg::Propose {
+                msg: dao_pre_propose_condorcet::ProposeMessage::Propose {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    choices: into_choices(choices),
+                },
+            },
+            &[],
+        )?;
+        Ok(id)
+    }
+
+    pub fn execute_msg<S: Into<String>>(
+        &mut self,
+        sender: S,
+        msg: &ExecuteMsg,
+    ) -> anyhow::Result<()> {
+        self.app
+            .execute_contract(Addr::unchecked(sender), self.condorcet.clone(), msg, &[])
+            .map(|_| ())
+    }
+
+    /// Instantiates a hook counter contract, returning its address.
+    pub fn instantiate_hook_counter(&mut self, should_error: bool) -> Addr {
+        let code_id = self.app.store_code(hook_counter_contract());
+        self.app
+            .instantiate_contract(
+                code_id,
+                self.sender(),
+                &dao_proposal_hook_counter::msg::InstantiateMsg { should_error },
+                &[],
+                "hook counter",
+                None,
+            )
+            .unwrap()
+    }
+
+    /// Queries a hook counter contract for one of its counts.
+    pub fn query_hook_count(
+        &self,
+        counter: &Addr,
+        query: dao_proposal_hook_counter::msg::QueryMsg,
+    ) -> u64 {
+        let count: dao_proposal_hook_counter::msg::CountResponse =
+            self.app.wrap().query_wasm_smart(counter, &query).unwrap();
+        count.count
+    }
+
     pub fn vote<S: Into<String>>(
         &mut self,
         sender: S,
@@ -280,6 +534,17 @@ impl Suite {
             .map(|_| ())
     }
 
+    pub fn veto<S: Into<String>>(&mut self, sender: S, proposal_id: u32) -> anyhow::Result<()> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender),
+                self.condorcet.clone(),
+                &ExecuteMsg::Veto { proposal_id },
+                &[],
+            )
+            .map(|_| ())
+    }
+
     pub fn close<S: Into<String>>(&mut self, sender: S, proposal_id: u32) -> anyhow::Result<()> {
         self.app
             .execute_contract(
@@ -292,6 +557,29 @@ impl Suite {
     }
 }
 
+/// Makes choices with the messages in `choices`, titled by their
+/// index.
+pub fn into_choices(choices: Vec<Vec<CosmosMsg>>) -> Vec<Choice> {
+    choices
+        .into_iter()
+        .enumerate()
+        .map(|(i, msgs)| Choice {
+            title: format!("choice {i}"),
+            description: format!("description {i}"),
+            msgs,
+        })
+        .collect()
+}
+
+fn hook_counter_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_proposal_hook_counter::contract::execute,
+        dao_proposal_hook_counter::contract::instantiate,
+        dao_proposal_hook_counter::contract::query,
+    );
+    Box::new(contract)
+}
+
 pub fn unimportant_message() -> CosmosMsg {
     BankMsg::Send {
         to_address: "someone".to_string(),
@@ -2,6 +2,7 @@ use cosmwasm_std::Uint128;
 use cw_utils::Expiration;
 
 use crate::{
+    cell::Cell,
     tally::{Tally, Winner},
     vote::Vote,
 };
@@ -9,7 +10,7 @@ use crate::{
 #[test]
 fn test_pair_election() {
     let candidates = 2;
-    let mut tally = Tally::new(candidates, Uint128::new(3), 0, Expiration::Never {});
+    let mut tally = Tally::new(candidates, Uint128::new(3), 0, Expiration::Never {}, false);
 
     tally.add_vote(Vote::new(vec![0, 1], candidates).unwrap(), Uint128::one());
     tally.add_vote(Vote::new(vec![1, 0], candidates).unwrap(), Uint128::one());
@@ -21,7 +22,7 @@ fn test_pair_election() {
 #[test]
 fn test_triplet_election() {
     let candidates = 3;
-    let mut tally = Tally::new(candidates, Uint128::new(3), 0, Expiration::Never {});
+    let mut tally = Tally::new(candidates, Uint128::new(3), 0, Expiration::Never {}, false);
 
     tally.add_vote(
         Vote::new(vec![0, 1, 2], candidates).unwrap(),
@@ -45,7 +46,7 @@ fn test_triplet_election() {
 #[test]
 fn test_condorcet_paradox() {
     let candidates = 3;
-    let mut tally = Tally::new(candidates, Uint128::new(6), 0, Expiration::Never {});
+    let mut tally = Tally::new(candidates, Uint128::new(6), 0, Expiration::Never {}, false);
 
     tally.add_vote(
         Vote::new(vec![0, 2, 1], candidates).unwrap(),
@@ -96,7 +97,7 @@ fn test_condorcet_paradox() {
 #[test]
 fn test_tally_overflow() {
     let candidates = 6;
-    let mut tally = Tally::new(candidates, Uint128::MAX, 0, Expiration::Never {});
+    let mut tally = Tally::new(candidates, Uint128::MAX, 0, Expiration::Never {}, false);
 
     tally.add_vote(
         Vote::new(vec![1, 2, 3, 4, 5, 0], candidates).unwrap(),
@@ -117,7 +118,7 @@ fn test_tally_overflow() {
 #[test]
 fn test_winner_none() {
     let candidates = 6;
-    let mut tally = Tally::new(candidates, Uint128::new(9), 0, Expiration::Never {});
+    let mut tally = Tally::new(candidates, Uint128::new(9), 0, Expiration::Never {}, false);
 
     tally.add_vote(
         Vote::new(vec![1, 2, 3, 4, 5, 0], candidates).unwrap(),
@@ -151,3 +152,53 @@ fn test_winner_none() {
     // -2 -2  2  2  4  \
     assert_eq!(tally.winner, Winner::None)
 }
+
+#[test]
+fn test_partial_ranking() {
+    let candidates = 4;
+    let mut tally = Tally::new(candidates, Uint128::new(3), 0, Expiration::Never {}, false);
+
+    // 2 beats every other candidate, and the rest are tied.
+    tally.add_vote(Vote::new(vec![2], candidates).unwrap(), Uint128::one());
+
+    let margins = tally.margins();
+    for other in [0, 1, 3] {
+        assert_eq!(margins[2][other], Cell::Positive(Uint128::one()));
+        assert_eq!(margins[other][2], Cell::Negative(Uint128::one()));
+    }
+    assert_eq!(margins[0][1], Cell::Zero);
+    assert_eq!(margins[1][3], Cell::Zero);
+    assert_eq!(tally.winner, Winner::Some(2));
+
+    // 3 beats 1, and both beat 0 and 2.
+    tally.add_vote(Vote::new(vec![3, 1], candidates).unwrap(), Uint128::new(2));
+
+    let margins = tally.margins();
+    assert_eq!(margins[3][1], Cell::Positive(Uint128::new(2)));
+    assert_eq!(margins[1][0], Cell::Positive(Uint128::new(2)));
+    assert_eq!(margins[3][2], Cell::Positive(Uint128::one()));
+    assert_eq!(tally.winner, Winner::Undisputed(3));
+}
+
+#[test]
+fn test_remove_vote() {
+    let candidates = 3;
+    let mut tally = Tally::new(candidates, Uint128::new(3), 0, Expiration::Never {}, false);
+    let empty = tally.margins();
+
+    let vote = Vote::new(vec![1, 0], candidates).unwrap();
+    tally.add_vote(vote.clone(), Uint128::new(2));
+    assert_eq!(tally.winner, Winner::Undisputed(1));
+    assert_eq!(tally.power_outstanding, Uint128::one());
+
+    tally.remove_vote(&vote, Uint128::new(2));
+    assert_eq!(tally.margins(), empty);
+    assert_eq!(tally.winner, Winner::None);
+    assert_eq!(tally.power_outstanding, Uint128::new(3));
+
+    tally.add_vote(
+        Vote::new(vec![0, 2, 1], candidates).unwrap(),
+        Uint128::new(2),
+    );
+    assert_eq!(tally.winner, Winner::Undisputed(0));
+}
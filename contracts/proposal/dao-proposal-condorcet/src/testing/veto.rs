@@ -0,0 +1,193 @@
+use cosmwasm_std::{Addr, Uint128};
+use cw_utils::Duration;
+use dao_voting::veto::{VetoConfig, VetoError};
+
+use crate::{proposal::Status, ContractError};
+
+use super::{is_error, suite::SuiteBuilder};
+
+fn veto_config(early_execute: bool, veto_before_passed: bool) -> VetoConfig {
+    VetoConfig {
+        timelock_duration: Duration::Time(60 * 60 * 24),
+        vetoer: "oversight".to_string(),
+        early_execute,
+        veto_before_passed,
+        additional_vetoers: vec![],
+        veto_threshold: None,
+    }
+}
+
+#[test]
+fn test_veto_timelock() {
+    let mut suite = SuiteBuilder::default().with_proposal(1);
+    suite.instantiate.veto = Some(veto_config(false, false));
+    let mut suite = suite.build();
+
+    suite.vote(suite.sender(), 1, vec![0, 1]).unwrap();
+
+    // the proposal may not be vetoed before it passes.
+    let err = suite.veto("oversight", 1);
+    is_error!(err, &VetoError::NoVetoBeforePassed {}.to_string());
+
+    suite.a_day_passes();
+
+    let (_, status) = suite.query_winner_and_status(1);
+    assert!(matches!(status, Status::VetoTimelock { winner: 0, .. }));
+
+    let err = suite.execute(suite.sender(), 1);
+    is_error!(err, &VetoError::Timelocked {}.to_string());
+    let err = suite.execute("oversight", 1);
+    is_error!(err, &VetoError::NoEarlyExecute {}.to_string());
+    let err = suite.veto(suite.sender(), 1);
+    is_error!(err, &VetoError::Unauthorized {}.to_string());
+
+    suite.veto("oversight", 1).unwrap();
+
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::Vetoed);
+
+    let err = suite.execute(suite.sender(), 1);
+    is_error!(err, &ContractError::Unexecutable {}.to_string());
+    let err = suite.veto("oversight", 1);
+    is_error!(
+        err,
+        &VetoError::InvalidProposalStatus {
+            status: "vetoed".to_string()
+        }
+        .to_string()
+    );
+}
+
+#[test]
+fn test_veto_timelock_expires() {
+    let mut suite = SuiteBuilder::default().with_proposal(1);
+    suite.instantiate.veto = Some(veto_config(false, false));
+    let mut suite = suite.build();
+
+    suite.vote(suite.sender(), 1, vec![0, 1]).unwrap();
+
+    // the timelock lasts until a day after the proposal expires.
+    suite.a_week_passes();
+    let (_, status) = suite.query_winner_and_status(1);
+    assert!(matches!(status, Status::VetoTimelock { winner: 0, .. }));
+
+    suite.a_day_passes();
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::Passed { winner: 0 });
+
+    let err = suite.veto("oversight", 1);
+    is_error!(err, &VetoError::TimelockExpired {}.to_string());
+
+    suite.execute(suite.sender(), 1).unwrap();
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::ExecutionFailed);
+}
+
+#[test]
+fn test_early_execute() {
+    let mut suite = SuiteBuilder::default().with_proposal(1);
+    suite.instantiate.veto = Some(veto_config(true, false));
+    let mut suite = suite.build();
+
+    suite.vote(suite.sender(), 1, vec![0, 1]).unwrap();
+    suite.a_day_passes();
+
+    let err = suite.execute(suite.sender(), 1);
+    is_error!(err, &VetoError::Timelocked {}.to_string());
+    let err = suite.execute("someone", 1);
+    is_error!(err, &ContractError::ZeroVotingPower {}.to_string());
+
+    // the vetoer need not have voting power to execute early.
+    suite.execute("oversight", 1).unwrap();
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::ExecutionFailed);
+}
+
+#[test]
+fn test_veto_council_early_execute() {
+    let mut suite = SuiteBuilder::default().with_proposal(1);
+    suite.instantiate.veto = Some(VetoConfig {
+        additional_vetoers: vec!["council".to_string()],
+        veto_threshold: Some(2),
+        ..veto_config(true, false)
+    });
+    let mut suite = suite.build();
+
+    suite.vote(suite.sender(), 1, vec![0, 1]).unwrap();
+    suite.a_day_passes();
+
+    // a single vetoer may not execute early when the threshold is
+    // higher.
+    suite.execute("oversight", 1).unwrap();
+    let (_, status) = suite.query_winner_and_status(1);
+    assert!(matches!(status, Status::VetoTimelock { .. }));
+
+    let err = suite.execute("oversight", 1);
+    is_error!(err, &VetoError::AlreadyApprovedEarlyExecute {}.to_string());
+
+    // approving early execution is not a veto.
+    assert!(suite.query_veto_tally(1).vetoers.is_empty());
+
+    suite.execute("council", 1).unwrap();
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::ExecutionFailed);
+}
+
+#[test]
+fn test_veto_council_before_passed() {
+    let mut suite = SuiteBuilder::default().with_proposal(1);
+    suite.instantiate.veto = Some(VetoConfig {
+        additional_vetoers: vec!["council".to_string()],
+        veto_threshold: Some(2),
+        ..veto_config(false, true)
+    });
+    let mut suite = suite.build();
+
+    suite.veto("oversight", 1).unwrap();
+
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::Open);
+    let tally = suite.query_veto_tally(1);
+    assert_eq!(tally.vetoers, vec![Addr::unchecked("oversight")]);
+    assert_eq!(tally.threshold, Some(2));
+
+    let err = suite.veto("oversight", 1);
+    is_error!(err, &VetoError::AlreadyVetoed {}.to_string());
+
+    suite.veto("council", 1).unwrap();
+
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::Vetoed);
+
+    // votes may still be cast, but do not change the outcome.
+    suite.vote(suite.sender(), 1, vec![0, 1]).unwrap();
+    suite.a_day_passes();
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::Vetoed);
+    assert_eq!(
+        suite.query_proposal(1).tally.power_outstanding,
+        Uint128::zero()
+    );
+}
+
+#[test]
+fn test_no_veto_config() {
+    let mut suite = SuiteBuilder::default().with_proposal(1).build();
+
+    let err = suite.veto("oversight", 1);
+    is_error!(err, &VetoError::NoVetoConfiguration {}.to_string());
+    assert_eq!(suite.query_veto_tally(1).threshold, None);
+}
+
+#[test]
+#[should_panic(
+    expected = "The veto timelock duration must have the same units as the max_voting_period of the proposal (height or time)."
+)]
+fn test_instantiate_timelock_unit_mismatch() {
+    let mut suite = SuiteBuilder::default();
+    suite.instantiate.veto = Some(VetoConfig {
+        timelock_duration: Duration::Height(10),
+        ..veto_config(false, false)
+    });
+    suite.build();
+}
@@ -1,14 +1,20 @@
 use std::ops::Index;
 
 use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
 use thiserror::Error;
 
+/// A ranking of candidates from most to least favored. Candidates
+/// that are not ranked are tied below those that are.
 #[cw_serde]
 pub struct Vote(Vec<u32>);
 
 impl Vote {
     pub(crate) fn new(vote: Vec<u32>, candidates: u32) -> Result<Self, VoteError> {
-        if vote.len() != candidates as usize {
+        if vote.is_empty() {
+            return Err(VoteError::Empty {});
+        }
+        if vote.len() > candidates as usize {
             return Err(VoteError::LenMissmatch {
                 got: vote.len() as u32,
                 expected: candidates,
@@ -32,6 +38,13 @@ impl Vote {
     }
 }
 
+impl std::fmt::Display for Vote {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ranking: Vec<String> = self.0.iter().map(|c| c.to_string()).collect();
+        write!(f, "{}", ranking.join(","))
+    }
+}
+
 impl Index<usize> for Vote {
     type Output = u32;
 
@@ -40,6 +53,29 @@ impl Index<usize> for Vote {
     }
 }
 
+/// Information about a ballot cast on a proposal.
+#[cw_serde]
+pub struct VoteInfo {
+    /// The address that cast the ballot.
+    pub voter: Addr,
+    /// The voter's ranking of the proposal's choices, from most to
+    /// least favored. Choices that are not ranked are tied below
+    /// those that are.
+    pub vote: Vote,
+    /// The voting power behind the ballot.
+    pub power: Uint128,
+}
+
+#[cw_serde]
+pub struct VoteResponse {
+    pub vote: Option<VoteInfo>,
+}
+
+#[cw_serde]
+pub struct VoteListResponse {
+    pub votes: Vec<VoteInfo>,
+}
+
 #[derive(Error, Debug, PartialEq)]
 pub enum VoteError {
     #[error("candidate ({candidate}) appears in ballot more than once")]
@@ -48,8 +84,11 @@ pub enum VoteError {
     #[error("no such candidate ({candidate})")]
     InvalidCandidate { candidate: u32 },
 
-    #[error("ballot has wrong number of candidates. got ({got}) expected ({expected})")]
+    #[error("ballot ranks too many candidates. got ({got}) expected at most ({expected})")]
     LenMissmatch { got: u32, expected: u32 },
+
+    #[error("ballot must rank at least one candidate")]
+    Empty {},
 }
 
 #[cfg(test)]
@@ -72,12 +111,16 @@ mod tests {
         assert_eq!(
             Vote::new(vec![1, 1, 2, 2], 4).unwrap_err(),
             VoteError::DuplicateCandidate { candidate: 1 }
-        )
+        );
+        assert_eq!(Vote::new(vec![], 2).unwrap_err(), VoteError::Empty {})
     }
 
     #[test]
     fn test_vote_construction() {
         let vote = Vote::new(vec![0, 1, 2], 3).unwrap();
-        assert_eq!(vote.0, vec![0, 1, 2])
+        assert_eq!(vote.0, vec![0, 1, 2]);
+
+        let vote = Vote::new(vec![2], 3).unwrap();
+        assert_eq!(vote.0, vec![2])
     }
 }
@@ -1,8 +1,8 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Addr, Attribute, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply,
-    Response, StdResult, Storage, SubMsg, WasmMsg,
+    to_json_binary, Addr, Attribute, Binary, BlockInfo, Deps, DepsMut, Empty, Env, MessageInfo,
+    Order, Reply, Response, StdResult, Storage, SubMsg, Uint128, WasmMsg,
 };
 
 use cw2::set_contract_version;
@@ -15,24 +15,40 @@ use dao_hooks::proposal::{
 use dao_hooks::vote::new_vote_hooks;
 use dao_interface::voting::IsActiveResponse;
 use dao_voting::{
-    multiple_choice::{MultipleChoiceVote, MultipleChoiceVotes, VotingStrategy},
+    multiple_choice::{
+        CheckedBudget, CheckedMultipleChoiceOption, MultipleChoiceOptionType, MultipleChoiceVote,
+        MultipleChoiceVotes, TieBreaker, UncheckedBudget, VotingStrategy,
+    },
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
-    proposal::{MultipleChoiceProposeMsg as ProposeMsg, DEFAULT_LIMIT, MAX_PROPOSAL_SIZE},
+    proposal::{
+        dependency_status, is_closable, query_proposal_status, validate_depends_on,
+        DependencyStatus, MultipleChoiceProposeMsg as ProposeMsg, DEFAULT_LIMIT, MAX_PROPOSAL_SIZE,
+    },
     reply::{
         failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id, TaggedReplyId,
     },
+    signed_vote::SignedVotePayload,
     status::Status,
-    veto::{VetoConfig, VetoError},
-    voting::{get_total_power, get_voting_power, validate_voting_period},
+    veto::{VetoConfig, VetoError, VetoTallyResponse},
+    voting::{
+        get_total_power, get_voting_power, validate_execute_after, validate_voting_period,
+        VotingPeriodRange,
+    },
 };
 
 use crate::{msg::MigrateMsg, state::CREATION_POLICY};
 use crate::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     proposal::{MultipleChoiceProposal, VoteResult},
-    query::{ProposalListResponse, ProposalResponse, VoteInfo, VoteListResponse, VoteResponse},
+    query::{
+        ProposalListResponse, ProposalResponse, RunoffRoundsResponse, VoteInfo, VoteListResponse,
+        VoteResponse, VoterVoteInfo, VoterVoteListResponse,
+    },
+    runoff::{add_ranking, remove_ranking, tally_runoff, MAX_RANKINGS_PER_TALLY},
     state::{
-        Ballot, Config, BALLOTS, CONFIG, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS, VOTE_HOOKS,
+        proposals, Ballot, Config, BALLOTS, BALLOT_INDEX_CURSOR, CONFIG, EARLY_EXECUTE_VOTES,
+        EXPIRES_AT_HEIGHT, EXPIRES_AT_TIME, PROPOSAL_COUNT, PROPOSAL_HOOKS, PROPOSAL_INDEX_CURSOR,
+        RUNOFF_ROUNDS, TIE_RUNOFFS, VETO_VOTES, VOTER_PROPOSALS, VOTE_HOOKS, VOTE_NONCES,
     },
     ContractError,
 };
@@ -64,6 +80,9 @@ pub fn instantiate(
     if let Some(veto_config) = &msg.veto {
         veto_config.validate(&deps.as_ref(), &max_voting_period)?;
     };
+    if let Some(range) = &msg.voting_period_range {
+        range.validate(min_voting_period, max_voting_period)?;
+    }
 
     let config = Config {
         voting_strategy: msg.voting_strategy,
@@ -74,6 +93,8 @@ pub fn instantiate(
         dao,
         close_proposal_on_execution_failure: msg.close_proposal_on_execution_failure,
         veto: msg.veto,
+        voting_period_range: msg.voting_period_range,
+        tie_breaker: msg.tie_breaker.unwrap_or_default(),
     };
 
     // Initialize proposal count to zero so that queries return zero
@@ -102,9 +123,16 @@ pub fn execute(
             vote,
             rationale,
         } => execute_vote(deps, env, info.sender, proposal_id, vote, rationale),
+        ExecuteMsg::VoteWithSignature {
+            voter,
+            payload,
+            pubkey,
+            signature,
+        } => execute_vote_with_signature(deps, env, info, voter, payload, pubkey, signature),
         ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, info, proposal_id),
         ExecuteMsg::Veto { proposal_id } => execute_veto(deps, env, info, proposal_id),
         ExecuteMsg::Close { proposal_id } => execute_close(deps, env, info, proposal_id),
+        ExecuteMsg::TallyRunoff { proposal_id } => execute_tally_runoff(deps, env, proposal_id),
         ExecuteMsg::UpdateConfig {
             voting_strategy,
             min_voting_period,
@@ -114,6 +142,8 @@ pub fn execute(
             dao,
             close_proposal_on_execution_failure,
             veto,
+            voting_period_range,
+            tie_breaker,
         } => execute_update_config(
             deps,
             info,
@@ -125,10 +155,14 @@ pub fn execute(
             dao,
             close_proposal_on_execution_failure,
             veto,
+            voting_period_range,
+            tie_breaker,
         ),
         ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
             execute_update_proposal_creation_policy(deps, info, new_info)
         }
+        ExecuteMsg::IndexProposals { limit } => execute_index_proposals(deps, limit),
+        ExecuteMsg::IndexBallots { limit } => execute_index_ballots(deps, limit),
         ExecuteMsg::AddProposalHook { address } => {
             execute_add_proposal_hook(deps, env, info, address)
         }
@@ -156,6 +190,10 @@ pub fn execute_propose(
         choices,
         proposer,
         vote,
+        execute_after,
+        depends_on,
+        voting_period,
+        budget,
     }: ProposeMsg,
 ) -> Result<Response<Empty>, ContractError> {
     let config = CONFIG.load(deps.storage)?;
@@ -197,8 +235,36 @@ pub fn execute_propose(
 
     // Validate options.
     let checked_multiple_choice_options = choices.into_checked()?.options;
+    let budget = validate_budget(
+        deps.as_ref(),
+        &config.voting_strategy,
+        budget,
+        &checked_multiple_choice_options,
+    )?;
+
+    let execute_after = validate_execute_after(execute_after, &config.max_voting_period)?;
+    let depends_on = validate_depends_on(deps.api, depends_on, |module, proposal_id| {
+        query_dependency_status(deps.as_ref(), &env, module, proposal_id)
+    })?;
 
-    let expiration = config.max_voting_period.after(&env.block);
+    // Proposers may choose a voting period within the configured
+    // range. It may not be shorter than the minimum voting period,
+    // and must have the same units as the veto timelock.
+    let voting_period = match voting_period {
+        Some(voting_period) => {
+            let voting_period = config
+                .voting_period_range
+                .ok_or(ContractError::VotingPeriodNotAllowed {})?
+                .check(voting_period)?;
+            validate_voting_period(config.min_voting_period, voting_period)?;
+            if let Some(veto_config) = &config.veto {
+                veto_config.validate(&deps.as_ref(), &voting_period)?;
+            }
+            voting_period
+        }
+        None => config.max_voting_period,
+    };
+    let expiration = voting_period.after(&env.block);
     let total_power = get_total_power(deps.as_ref(), &config.dao, None)?;
 
     let proposal = {
@@ -217,12 +283,23 @@ pub fn execute_propose(
             allow_revoting: config.allow_revoting,
             choices: checked_multiple_choice_options,
             veto: config.veto,
+            execute_after,
+            depends_on,
+            runoff: None,
+            tie_breaker: config.tie_breaker,
+            weights_changed_at: vec![],
+            budget,
         };
         // Update the proposal's status. Addresses case where proposal
         // expires on the same block as it is created.
         proposal.update_status(&env.block)?;
         proposal
     };
+
+    // Save the statuses of the proposals that expired first so that
+    // the status index does not drift as they expire.
+    let status_changed_hooks = sync_proposal_statuses(deps.storage, &env.block)?;
+
     let id = advance_proposal_id(deps.storage)?;
 
     // Limit the size of proposals.
@@ -247,7 +324,7 @@ pub fn execute_propose(
         });
     }
 
-    PROPOSALS.save(deps.storage, id, &proposal)?;
+    proposals().save(deps.storage, id, &proposal)?;
 
     let hooks = new_proposal_hooks(PROPOSAL_HOOKS, deps.storage, id, proposer.as_str())?;
 
@@ -260,7 +337,7 @@ pub fn execute_propose(
             env,
             proposer.clone(),
             id,
-            vote.vote,
+            vote.vote.clone(),
             vote.rationale.clone(),
         )?;
         (
@@ -281,6 +358,7 @@ pub fn execute_propose(
     };
 
     Ok(Response::default()
+        .add_submessages(status_changed_hooks)
         .add_submessages(hooks)
         .add_submessages(vote_hooks)
         .add_attribute("action", "propose")
@@ -296,11 +374,12 @@ pub fn execute_veto(
     info: MessageInfo,
     proposal_id: u64,
 ) -> Result<Response, ContractError> {
-    let mut prop = PROPOSALS
+    let mut prop = proposals()
         .may_load(deps.storage, proposal_id)?
         .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
 
     // ensure status is up to date
+    tally_runoff_if_ready(deps.storage, &env.block, proposal_id, &mut prop)?;
     prop.update_status(&env.block)?;
     let old_status = prop.status;
 
@@ -338,9 +417,23 @@ pub fn execute_veto(
         }
     }
 
+    // Record this vetoer's veto. The proposal is only vetoed once
+    // enough vetoers have vetoed it.
+    let vetoes = veto_config.record_veto(deps.storage, &VETO_VOTES, proposal_id, &info.sender)?;
+    if !vetoes.threshold_reached {
+        // Save the updated status, which may have changed from the
+        // update above.
+        proposals().save(deps.storage, proposal_id, &prop)?;
+        return Ok(Response::new()
+            .add_attribute("action", "veto")
+            .add_attribute("proposal_id", proposal_id.to_string())
+            .add_attribute("vetoes", vetoes.count.to_string())
+            .add_attribute("status", prop.status.to_string()));
+    }
+
     // Update proposal status to vetoed
     prop.status = Status::Vetoed;
-    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    proposals().save(deps.storage, proposal_id, &prop)?;
 
     // Add proposal status change hooks
     let proposal_status_changed_hooks = proposal_status_changed_hooks(
@@ -352,9 +445,8 @@ pub fn execute_veto(
     )?;
 
     // Add prepropose / deposit module hook which will handle deposit refunds.
-    let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
     let proposal_completed_hooks =
-        proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status)?;
+        creation_policy_completed_hooks(deps.storage, proposal_id, prop.status)?;
 
     Ok(Response::new()
         .add_attribute("action", "veto")
@@ -363,6 +455,28 @@ pub fn execute_veto(
         .add_submessages(proposal_completed_hooks))
 }
 
+pub fn execute_vote_with_signature(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    voter: String,
+    payload: SignedVotePayload<MultipleChoiceVote>,
+    pubkey: Binary,
+    signature: Binary,
+) -> Result<Response<Empty>, ContractError> {
+    let voter = deps.api.addr_validate(&voter)?;
+    let nonce = VOTE_NONCES
+        .may_load(deps.storage, &voter)?
+        .unwrap_or_default();
+    payload.verify(deps.api, &env, &voter, nonce, &pubkey, &signature)?;
+    VOTE_NONCES.save(deps.storage, &voter, &(nonce + 1))?;
+
+    Ok(
+        execute_vote(deps, env, voter, payload.proposal_id, payload.vote, None)?
+            .add_attribute("relayer", info.sender),
+    )
+}
+
 pub fn execute_vote(
     deps: DepsMut,
     env: Env,
@@ -372,12 +486,12 @@ pub fn execute_vote(
     rationale: Option<String>,
 ) -> Result<Response<Empty>, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    let mut prop = PROPOSALS
+    let mut prop = proposals()
         .may_load(deps.storage, proposal_id)?
         .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
 
     // Check that this is a valid vote.
-    if vote.option_id as usize >= prop.choices.len() {
+    if !prop.is_valid_vote(&vote) {
         return Err(ContractError::InvalidVote {});
     }
 
@@ -402,6 +516,7 @@ pub fn execute_vote(
         return Err(ContractError::NotRegistered {});
     }
 
+    let mut previous_ballot = None;
     BALLOTS.update(deps.storage, (proposal_id, &sender), |bal| match bal {
         Some(current_ballot) => {
             if prop.allow_revoting {
@@ -412,11 +527,11 @@ pub fn execute_vote(
                     Err(ContractError::AlreadyCast {})
                 } else {
                     // Remove the old vote if this is a re-vote.
-                    prop.votes
-                        .remove_vote(current_ballot.vote, current_ballot.power)?;
+                    prop.remove_vote(&current_ballot.vote, current_ballot.power)?;
+                    previous_ballot = Some(current_ballot);
                     Ok(Ballot {
                         power: vote_power,
-                        vote,
+                        vote: vote.clone(),
                         rationale: rationale.clone(),
                     })
                 }
@@ -425,17 +540,33 @@ pub fn execute_vote(
             }
         }
         None => Ok(Ballot {
-            vote,
+            vote: vote.clone(),
             power: vote_power,
             rationale: rationale.clone(),
         }),
     })?;
+    VOTER_PROPOSALS.save(deps.storage, (&sender, proposal_id), &Empty {})?;
+
+    // Ranked choice votes are also tallied by their full ranking for
+    // the proposal's runoff.
+    if let VotingStrategy::RankedChoice { .. } = prop.voting_strategy {
+        if let Some(previous_ballot) = previous_ballot {
+            remove_ranking(
+                deps.storage,
+                proposal_id,
+                &previous_ballot.vote,
+                previous_ballot.power,
+            )?;
+        }
+        add_ranking(deps.storage, proposal_id, &vote, vote_power)?;
+    }
 
     let old_status = prop.status;
 
-    prop.votes.add_vote(vote, vote_power)?;
+    prop.add_vote(&vote, vote_power)?;
+    tally_runoff_if_ready(deps.storage, &env.block, proposal_id, &mut prop)?;
     prop.update_status(&env.block)?;
-    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    proposals().save(deps.storage, proposal_id, &prop)?;
     let new_status = prop.status;
     let change_hooks = proposal_status_changed_hooks(
         PROPOSAL_HOOKS,
@@ -471,7 +602,7 @@ pub fn execute_execute(
     info: MessageInfo,
     proposal_id: u64,
 ) -> Result<Response, ContractError> {
-    let mut prop = PROPOSALS
+    let mut prop = proposals()
         .may_load(deps.storage, proposal_id)?
         .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
 
@@ -495,6 +626,7 @@ pub fn execute_execute(
     // as it passed during its voting period. Allow it to be
     // executed in timelock state if early_execute is enabled
     // and the sender is the vetoer.
+    tally_runoff_if_ready(deps.storage, &env.block, proposal_id, &mut prop)?;
     prop.update_status(&env.block)?;
     let old_status = prop.status;
     match &prop.status {
@@ -510,9 +642,9 @@ pub fn execute_execute(
                 .as_ref()
                 .ok_or(VetoError::NoVetoConfiguration {})?;
 
-            // check that the sender is the vetoer
-            if veto_config.vetoer != info.sender {
-                // if the sender can normally execute, but is not the vetoer,
+            // check that the sender is a vetoer
+            if veto_config.check_is_vetoer(&info).is_err() {
+                // if the sender can normally execute, but is not a vetoer,
                 // return timelocked error. otherwise return unauthorized.
                 if sender_can_execute {
                     return Err(ContractError::VetoError(VetoError::Timelocked {}));
@@ -523,104 +655,443 @@ pub fn execute_execute(
 
             // if veto timelocked, only allow execution if early_execute enabled
             veto_config.check_early_execute_enabled()?;
+
+            // Like a veto, early execution needs the approval of
+            // enough vetoers.
+            let approvals = veto_config.record_early_execute(
+                deps.storage,
+                &EARLY_EXECUTE_VOTES,
+                proposal_id,
+                &info.sender,
+            )?;
+            if !approvals.threshold_reached {
+                proposals().save(deps.storage, proposal_id, &prop)?;
+                return Ok(Response::new()
+                    .add_attribute("action", "execute")
+                    .add_attribute("sender", info.sender)
+                    .add_attribute("proposal_id", proposal_id.to_string())
+                    .add_attribute("early_execute_approvals", approvals.count.to_string())
+                    .add_attribute("status", prop.status.to_string()));
+            }
         }
         _ => {
             return Err(ContractError::NotPassed {});
         }
     }
 
+    // Regardless of any veto timelock, the proposal may not be
+    // executed before its earliest execution time.
+    if let Some(execute_after) = prop.execute_after {
+        if !execute_after.is_expired(&env.block) {
+            return Err(ContractError::ExecutionLocked { execute_after });
+        }
+    }
+
+    match dependency_status(&prop.depends_on, |module, proposal_id| {
+        query_dependency_status(deps.as_ref(), &env, module, proposal_id)
+    })? {
+        DependencyStatus::Executed => (),
+        DependencyStatus::Pending {
+            module,
+            proposal_id: dependency_id,
+        } => {
+            return Err(ContractError::DependencyNotExecuted {
+                module: module.into_string(),
+                proposal_id: dependency_id,
+            })
+        }
+        // This proposal can never be executed, so close it instead.
+        DependencyStatus::Failed {
+            module,
+            proposal_id: dependency_id,
+        } => {
+            return Ok(close_proposal(deps.storage, proposal_id, prop)?
+                .add_attribute("action", "close")
+                .add_attribute("sender", info.sender)
+                .add_attribute("proposal_id", proposal_id.to_string())
+                .add_attribute("failed_dependency", format!("{module}:{dependency_id}")))
+        }
+    }
+
     prop.status = Status::Executed;
 
-    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
-
-    let vote_result = prop.calculate_vote_result()?;
-    match vote_result {
-        VoteResult::Tie => Err(ContractError::Tie {}), // We don't anticipate this case as the proposal would not be in passed state, checked above.
-        VoteResult::SingleWinner(winning_choice) => {
-            let response = if !winning_choice.msgs.is_empty() {
-                let execute_message = WasmMsg::Execute {
-                    contract_addr: config.dao.to_string(),
-                    msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook {
-                        msgs: winning_choice.msgs,
-                    })?,
-                    funds: vec![],
-                };
-                match config.close_proposal_on_execution_failure {
-                    true => {
-                        let masked_proposal_id = mask_proposal_execution_proposal_id(proposal_id);
-                        Response::default().add_submessage(SubMsg::reply_on_error(
-                            execute_message,
-                            masked_proposal_id,
-                        ))
-                    }
-                    false => Response::default().add_message(execute_message),
-                }
-            } else {
+    proposals().save(deps.storage, proposal_id, &prop)?;
+
+    let msgs = match &prop.budget {
+        // Budget proposals transfer each funded option its share of
+        // the budget. What is not allocated stays in the treasury.
+        Some(budget) => prop
+            .budget_allocations()
+            .into_iter()
+            .filter_map(|(choice, amount)| {
+                choice.funding.as_ref().map(|funding| {
+                    // Recipients are validated when proposals are
+                    // created.
+                    budget
+                        .denom
+                        .get_transfer_to_message(&Addr::unchecked(&funding.recipient), amount)
+                })
+            })
+            .collect::<StdResult<Vec<_>>>()?,
+        None => match prop.calculate_vote_result()? {
+            VoteResult::Tie => return Err(ContractError::Tie {}), // We don't anticipate this case as the proposal would not be in passed state, checked above.
+            VoteResult::SingleWinner(winning_choice) => winning_choice.msgs,
+            // Under the approval strategy, the messages of every winning
+            // choice are executed in order of their vote weight.
+            VoteResult::Winners(winning_choices) => winning_choices
+                .into_iter()
+                .flat_map(|choice| choice.msgs)
+                .collect(),
+        },
+    };
+    let response = if !msgs.is_empty() {
+        let execute_message = WasmMsg::Execute {
+            contract_addr: config.dao.to_string(),
+            msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook { msgs })?,
+            funds: vec![],
+        };
+        match config.close_proposal_on_execution_failure {
+            true => {
+                let masked_proposal_id = mask_proposal_execution_proposal_id(proposal_id);
                 Response::default()
-            };
+                    .add_submessage(SubMsg::reply_on_error(execute_message, masked_proposal_id))
+            }
+            false => Response::default().add_message(execute_message),
+        }
+    } else {
+        Response::default()
+    };
 
-            let proposal_status_changed_hooks = proposal_status_changed_hooks(
-                PROPOSAL_HOOKS,
-                deps.storage,
-                proposal_id,
-                old_status.to_string(),
-                prop.status.to_string(),
-            )?;
+    let proposal_status_changed_hooks = proposal_status_changed_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        proposal_id,
+        old_status.to_string(),
+        prop.status.to_string(),
+    )?;
+
+    // Add prepropose / deposit module hook which will handle deposit refunds.
+    let proposal_completed_hooks =
+        creation_policy_completed_hooks(deps.storage, proposal_id, prop.status)?;
 
-            // Add prepropose / deposit module hook which will handle deposit refunds.
-            let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
-            let proposal_completed_hooks =
-                proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status)?;
+    Ok(response
+        .add_submessages(proposal_status_changed_hooks)
+        .add_submessages(proposal_completed_hooks)
+        .add_attribute("action", "execute")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("dao", config.dao))
+}
 
-            Ok(response
-                .add_submessages(proposal_status_changed_hooks)
-                .add_submessages(proposal_completed_hooks)
-                .add_attribute("action", "execute")
-                .add_attribute("sender", info.sender)
-                .add_attribute("proposal_id", proposal_id.to_string())
-                .add_attribute("dao", config.dao))
-        }
+pub fn execute_tally_runoff(
+    deps: DepsMut,
+    env: Env,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let mut prop = proposals()
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+    if !prop.is_runoff_ready(&env.block) {
+        return Err(ContractError::RunoffNotReady { id: proposal_id });
     }
+
+    let old_status = prop.status;
+    tally_runoff_if_ready(deps.storage, &env.block, proposal_id, &mut prop)?;
+    prop.update_status(&env.block)?;
+    proposals().save(deps.storage, proposal_id, &prop)?;
+
+    let proposal_status_changed_hooks = proposal_status_changed_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        proposal_id,
+        old_status.to_string(),
+        prop.status.to_string(),
+    )?;
+
+    Ok(Response::default()
+        .add_submessages(proposal_status_changed_hooks)
+        .add_attribute("action", "tally_runoff")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("complete", prop.runoff.is_some().to_string())
+        .add_attribute("status", prop.status.to_string()))
 }
 
 pub fn execute_close(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     proposal_id: u64,
 ) -> Result<Response<Empty>, ContractError> {
-    let mut prop = PROPOSALS.load(deps.storage, proposal_id)?;
+    let mut prop = proposals().load(deps.storage, proposal_id)?;
 
+    tally_runoff_if_ready(deps.storage, &env.block, proposal_id, &mut prop)?;
     prop.update_status(&env.block)?;
-    if prop.status != Status::Rejected {
+    // Proposals that have not completed may also be closed if one of
+    // their dependencies has failed.
+    if !is_closable(&prop.status, || {
+        dependency_status(&prop.depends_on, |module, proposal_id| {
+            query_dependency_status(deps.as_ref(), &env, module, proposal_id)
+        })
+    })? {
         return Err(ContractError::WrongCloseStatus {});
     }
 
+    // Tied proposals may be decided by a runoff between the tied
+    // options, which is created once they are closed.
+    let runoff_options = if prop.status == Status::Rejected {
+        prop.tie_runoff_options()?
+    } else {
+        vec![]
+    };
+    let runoff = if runoff_options.len() > 1 {
+        Some(create_tie_runoff(
+            deps.branch(),
+            &env,
+            proposal_id,
+            &prop,
+            runoff_options,
+        )?)
+    } else {
+        None
+    };
+
+    let response = close_proposal(deps.storage, proposal_id, prop)?
+        .add_attribute("action", "close")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string());
+    Ok(match runoff {
+        Some((runoff_id, hooks)) => response
+            .add_submessages(hooks)
+            .add_attribute("runoff_proposal_id", runoff_id.to_string()),
+        None => response,
+    })
+}
+
+/// Checks that a proposal has a budget and that each of its options
+/// requests funding if, and only if, it uses the `Budget` voting
+/// strategy. Options funded from a budget may not have messages.
+fn validate_budget(
+    deps: Deps,
+    voting_strategy: &VotingStrategy,
+    budget: Option<UncheckedBudget>,
+    options: &[CheckedMultipleChoiceOption],
+) -> Result<Option<CheckedBudget>, ContractError> {
+    if !matches!(voting_strategy, VotingStrategy::Budget { .. }) {
+        if budget.is_some() || options.iter().any(|option| option.funding.is_some()) {
+            return Err(ContractError::UnexpectedBudget {});
+        }
+        return Ok(None);
+    }
+    let budget = match budget {
+        Some(budget) if !budget.amount.is_zero() => budget.into_checked(deps)?,
+        _ => return Err(ContractError::InvalidBudget {}),
+    };
+    for option in options
+        .iter()
+        .filter(|option| option.option_type == MultipleChoiceOptionType::Standard)
+    {
+        match &option.funding {
+            Some(funding) if !funding.amount.is_zero() && option.msgs.is_empty() => {
+                deps.api.addr_validate(&funding.recipient)?;
+            }
+            _ => return Err(ContractError::InvalidFundingRequest {}),
+        }
+    }
+    Ok(Some(budget))
+}
+
+/// Creates a runoff proposal between `options` of a tied proposal,
+/// returning its ID and new proposal hooks. The runoff is voted on as
+/// a new proposal with the module's current voting periods, and ties
+/// in it are not broken so that runoffs do not repeat.
+fn create_tie_runoff(
+    deps: DepsMut,
+    env: &Env,
+    proposal_id: u64,
+    prop: &MultipleChoiceProposal,
+    mut options: Vec<CheckedMultipleChoiceOption>,
+) -> Result<(u64, Vec<SubMsg>), ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    options.sort_by_key(|option| option.index);
+    let choices: Vec<CheckedMultipleChoiceOption> = options
+        .into_iter()
+        .chain(
+            prop.choices
+                .iter()
+                .filter(|choice| choice.option_type == MultipleChoiceOptionType::None)
+                .cloned(),
+        )
+        .enumerate()
+        .map(|(index, choice)| CheckedMultipleChoiceOption {
+            index: index as u32,
+            vote_count: Uint128::zero(),
+            ..choice
+        })
+        .collect();
+
+    let total_power = get_total_power(deps.as_ref(), &config.dao, None)?;
+    let mut runoff = MultipleChoiceProposal {
+        title: format!("Runoff: {}", prop.title),
+        description: prop.description.clone(),
+        proposer: prop.proposer.clone(),
+        start_height: env.block.height,
+        min_voting_period: config.min_voting_period.map(|min| min.after(&env.block)),
+        expiration: config.max_voting_period.after(&env.block),
+        voting_strategy: prop.voting_strategy.clone(),
+        total_power,
+        status: Status::Open,
+        votes: MultipleChoiceVotes::zero(choices.len()),
+        allow_revoting: config.allow_revoting,
+        choices,
+        veto: config.veto,
+        execute_after: prop.execute_after,
+        depends_on: prop.depends_on.clone(),
+        runoff: None,
+        tie_breaker: TieBreaker::Fail {},
+        weights_changed_at: vec![],
+        budget: prop.budget.clone(),
+    };
+    runoff.update_status(&env.block)?;
+
+    let id = advance_proposal_id(deps.storage)?;
+    proposals().save(deps.storage, id, &runoff)?;
+    TIE_RUNOFFS.save(deps.storage, id, &proposal_id)?;
+    let hooks = new_proposal_hooks(PROPOSAL_HOOKS, deps.storage, id, runoff.proposer.as_str())?;
+    Ok((id, hooks))
+}
+
+/// Continues tallying the runoff of a ranked choice proposal once
+/// voting on it has ended. Ranked choice proposals are decided by
+/// their runoff, so this must happen before their status is updated.
+fn tally_runoff_if_ready(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    proposal_id: u64,
+    prop: &mut MultipleChoiceProposal,
+) -> StdResult<()> {
+    if prop.is_runoff_ready(block) {
+        prop.runoff = tally_runoff(
+            storage,
+            proposal_id,
+            prop.choices.len(),
+            MAX_RANKINGS_PER_TALLY,
+        )?;
+    }
+    Ok(())
+}
+
+/// The maximum number of proposals expiring at a height, and at a
+/// time, whose status is saved when a proposal is created.
+const MAX_STATUS_SYNCS: usize = 5;
+
+/// Saves the current status of the proposals whose stored status
+/// expired first. Statuses change lazily as time passes, so otherwise
+/// the status index would only catch up when a proposal is next voted
+/// on, executed, or closed. Returns the status changed hooks for the
+/// proposals whose status was saved.
+fn sync_proposal_statuses(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+) -> Result<Vec<SubMsg>, ContractError> {
+    let mut hooks = vec![];
+    for (units, now) in [
+        (EXPIRES_AT_HEIGHT, block.height),
+        (EXPIRES_AT_TIME, block.time.nanos()),
+    ] {
+        let expired = proposals()
+            .idx
+            .status_expiration
+            .sub_prefix(units)
+            .range(
+                storage,
+                None,
+                Some(Bound::inclusive((now, u64::MAX))),
+                Order::Ascending,
+            )
+            .take(MAX_STATUS_SYNCS)
+            .collect::<StdResult<Vec<(u64, MultipleChoiceProposal)>>>()?;
+        for (id, mut prop) in expired {
+            let old_status = prop.status;
+            prop.update_status(block)?;
+            if prop.status != old_status {
+                proposals().save(storage, id, &prop)?;
+                hooks.extend(proposal_status_changed_hooks(
+                    PROPOSAL_HOOKS,
+                    storage,
+                    id,
+                    old_status.to_string(),
+                    prop.status.to_string(),
+                )?);
+            }
+        }
+    }
+    Ok(hooks)
+}
+
+/// Closes a proposal, notifying proposal hooks and the proposal
+/// creation policy module of the change so that deposits may be
+/// refunded.
+fn close_proposal(
+    storage: &mut dyn Storage,
+    proposal_id: u64,
+    mut prop: MultipleChoiceProposal,
+) -> Result<Response<Empty>, ContractError> {
     let old_status = prop.status;
 
     prop.status = Status::Closed;
 
-    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    proposals().save(storage, proposal_id, &prop)?;
 
     let proposal_status_changed_hooks = proposal_status_changed_hooks(
         PROPOSAL_HOOKS,
-        deps.storage,
+        storage,
         proposal_id,
         old_status.to_string(),
         prop.status.to_string(),
     )?;
 
     // Add prepropose / deposit module hook which will handle deposit refunds.
-    let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
     let proposal_completed_hooks =
-        proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status)?;
+        creation_policy_completed_hooks(storage, proposal_id, prop.status)?;
 
     Ok(Response::default()
         .add_submessages(proposal_status_changed_hooks)
-        .add_submessages(proposal_completed_hooks)
-        .add_attribute("action", "close")
-        .add_attribute("sender", info.sender)
-        .add_attribute("proposal_id", proposal_id.to_string()))
+        .add_submessages(proposal_completed_hooks))
+}
+
+/// Builds the hook telling the proposal creation policy's module that
+/// a proposal has completed so that it may refund the proposal's
+/// deposit. Tie runoffs are created by this module rather than
+/// submitted through the creation policy, so it is not told of them.
+fn creation_policy_completed_hooks(
+    storage: &dyn Storage,
+    proposal_id: u64,
+    status: Status,
+) -> StdResult<Vec<SubMsg>> {
+    if TIE_RUNOFFS.has(storage, proposal_id) {
+        return Ok(vec![]);
+    }
+    let proposal_creation_policy = CREATION_POLICY.load(storage)?;
+    proposal_completed_hooks(proposal_creation_policy, proposal_id, status, false)
+}
+
+/// Gets the current status of a proposal that another proposal
+/// depends on. Proposals in this module are loaded from storage
+/// directly instead of being queried.
+fn query_dependency_status(
+    deps: Deps,
+    env: &Env,
+    module: &Addr,
+    proposal_id: u64,
+) -> StdResult<Status> {
+    if *module == env.contract.address {
+        proposals()
+            .load(deps.storage, proposal_id)?
+            .current_status(&env.block)
+    } else {
+        query_proposal_status(&deps.querier, module, proposal_id)
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -635,6 +1106,8 @@ pub fn execute_update_config(
     dao: String,
     close_proposal_on_execution_failure: bool,
     veto: Option<VetoConfig>,
+    voting_period_range: Option<VotingPeriodRange>,
+    tie_breaker: Option<TieBreaker>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -654,6 +1127,9 @@ pub fn execute_update_config(
     if let Some(veto_config) = &veto {
         veto_config.validate(&deps.as_ref(), &max_voting_period)?;
     };
+    if let Some(range) = &voting_period_range {
+        range.validate(min_voting_period, max_voting_period)?;
+    }
 
     CONFIG.save(
         deps.storage,
@@ -666,6 +1142,8 @@ pub fn execute_update_config(
             dao,
             close_proposal_on_execution_failure,
             veto,
+            voting_period_range,
+            tie_breaker: tie_breaker.unwrap_or(config.tie_breaker),
         },
     )?;
 
@@ -694,6 +1172,108 @@ pub fn execute_update_proposal_creation_policy(
         .add_attribute("new_policy", format!("{initial_policy:?}")))
 }
 
+pub fn execute_index_proposals(
+    deps: DepsMut,
+    limit: Option<u64>,
+) -> Result<Response, ContractError> {
+    let cursor = PROPOSAL_INDEX_CURSOR
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NothingToIndex {})?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+
+    let props = proposals()
+        .range(
+            deps.storage,
+            Some(Bound::exclusive(cursor)),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .collect::<StdResult<Vec<_>>>()?;
+    // Saving a proposal again adds it to the indexes.
+    for (id, prop) in &props {
+        proposals().save(deps.storage, *id, prop)?;
+    }
+
+    let complete = (props.len() as u64) < limit;
+    match props.last() {
+        Some((id, _)) if !complete => PROPOSAL_INDEX_CURSOR.save(deps.storage, id)?,
+        _ => PROPOSAL_INDEX_CURSOR.remove(deps.storage),
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "index_proposals")
+        .add_attribute("indexed", props.len().to_string())
+        .add_attribute("complete", complete.to_string()))
+}
+
+pub fn execute_index_ballots(deps: DepsMut, limit: Option<u64>) -> Result<Response, ContractError> {
+    let cursor = BALLOT_INDEX_CURSOR
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NothingToIndex {})?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+
+    let keys = BALLOTS
+        .keys(
+            deps.storage,
+            cursor
+                .as_ref()
+                .map(|(proposal_id, voter)| Bound::exclusive((*proposal_id, voter))),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (proposal_id, voter) in &keys {
+        VOTER_PROPOSALS.save(deps.storage, (voter, *proposal_id), &Empty {})?;
+    }
+
+    let complete = (keys.len() as u64) < limit;
+    match keys.last() {
+        Some(key) if !complete => BALLOT_INDEX_CURSOR.save(deps.storage, &Some(key.clone()))?,
+        _ => BALLOT_INDEX_CURSOR.remove(deps.storage),
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "index_ballots")
+        .add_attribute("indexed", keys.len().to_string())
+        .add_attribute("complete", complete.to_string()))
+}
+
+/// Starts indexing the proposals and ballots saved before their
+/// indexes were added, which is continued in batches with
+/// `IndexProposals` and `IndexBallots`. Indexing that is underway is
+/// not restarted, and indexing is not started for indexes that are
+/// already populated or that have nothing to index.
+fn start_indexing(storage: &mut dyn Storage) -> StdResult<()> {
+    let has_proposals = proposals()
+        .keys_raw(storage, None, None, Order::Ascending)
+        .next()
+        .is_some();
+    let proposals_indexed = proposals()
+        .idx
+        .proposer
+        .keys_raw(storage, None, None, Order::Ascending)
+        .next()
+        .is_some();
+    if has_proposals && !proposals_indexed && PROPOSAL_INDEX_CURSOR.may_load(storage)?.is_none() {
+        PROPOSAL_INDEX_CURSOR.save(storage, &0)?;
+    }
+
+    let has_ballots = BALLOTS
+        .keys_raw(storage, None, None, Order::Ascending)
+        .next()
+        .is_some();
+    let ballots_indexed = VOTER_PROPOSALS
+        .keys_raw(storage, None, None, Order::Ascending)
+        .next()
+        .is_some();
+    if has_ballots && !ballots_indexed && BALLOT_INDEX_CURSOR.may_load(storage)?.is_none() {
+        BALLOT_INDEX_CURSOR.save(storage, &None)?;
+    }
+    Ok(())
+}
+
 pub fn execute_update_rationale(
     deps: DepsMut,
     info: MessageInfo,
@@ -856,14 +1436,33 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             start_after,
             limit,
         } => query_list_votes(deps, proposal_id, start_after, limit),
+        QueryMsg::ListVotesByVoter {
+            voter,
+            start_after,
+            limit,
+        } => query_list_votes_by_voter(deps, voter, start_after, limit),
         QueryMsg::Info {} => query_info(deps),
         QueryMsg::ReverseProposals {
             start_before,
             limit,
         } => query_reverse_proposals(deps, env, start_before, limit),
+        QueryMsg::ListProposalsByStatus {
+            status,
+            start_after,
+            limit,
+        } => query_list_proposals_by_status(deps, env, status, start_after, limit),
+        QueryMsg::ListProposalsByProposer {
+            proposer,
+            start_after,
+            limit,
+        } => query_list_proposals_by_proposer(deps, env, proposer, start_after, limit),
         QueryMsg::ProposalCreationPolicy {} => query_creation_policy(deps),
         QueryMsg::ProposalHooks {} => to_json_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
         QueryMsg::VoteHooks {} => to_json_binary(&VOTE_HOOKS.query_hooks(deps)?),
+        QueryMsg::VetoTally { proposal_id } => query_veto_tally(deps, proposal_id),
+        QueryMsg::VoteNonce { voter } => query_vote_nonce(deps, voter),
+        QueryMsg::ProposalStats { proposal_id } => query_proposal_stats(deps, env, proposal_id),
+        QueryMsg::RunoffRounds { proposal_id } => query_runoff_rounds(deps, proposal_id),
         QueryMsg::Dao {} => query_dao(deps),
     }
 }
@@ -879,10 +1478,22 @@ pub fn query_dao(deps: Deps) -> StdResult<Binary> {
 }
 
 pub fn query_proposal(deps: Deps, env: Env, id: u64) -> StdResult<Binary> {
-    let proposal = PROPOSALS.load(deps.storage, id)?;
+    let proposal = proposals().load(deps.storage, id)?;
     to_json_binary(&proposal.into_response(&env.block, id)?)
 }
 
+pub fn query_proposal_stats(deps: Deps, env: Env, id: u64) -> StdResult<Binary> {
+    let proposal = proposals().load(deps.storage, id)?;
+    to_json_binary(&proposal.stats(&env.block)?)
+}
+
+pub fn query_runoff_rounds(deps: Deps, id: u64) -> StdResult<Binary> {
+    let rounds = RUNOFF_ROUNDS
+        .may_load(deps.storage, id)?
+        .unwrap_or_default();
+    to_json_binary(&RunoffRoundsResponse { rounds })
+}
+
 pub fn query_creation_policy(deps: Deps) -> StdResult<Binary> {
     let policy = CREATION_POLICY.load(deps.storage)?;
     to_json_binary(&policy)
@@ -896,7 +1507,7 @@ pub fn query_list_proposals(
 ) -> StdResult<Binary> {
     let min = start_after.map(Bound::exclusive);
     let limit = limit.unwrap_or(DEFAULT_LIMIT);
-    let props: Vec<ProposalResponse> = PROPOSALS
+    let props: Vec<ProposalResponse> = proposals()
         .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
         .take(limit as usize)
         .collect::<Result<Vec<(u64, MultipleChoiceProposal)>, _>>()?
@@ -915,7 +1526,7 @@ pub fn query_reverse_proposals(
 ) -> StdResult<Binary> {
     let limit = limit.unwrap_or(DEFAULT_LIMIT);
     let max = start_before.map(Bound::exclusive);
-    let props: Vec<ProposalResponse> = PROPOSALS
+    let props: Vec<ProposalResponse> = proposals()
         .range(deps.storage, None, max, cosmwasm_std::Order::Descending)
         .take(limit as usize)
         .collect::<Result<Vec<(u64, MultipleChoiceProposal)>, _>>()?
@@ -930,6 +1541,81 @@ pub fn query_next_proposal_id(deps: Deps) -> StdResult<Binary> {
     to_json_binary(&next_proposal_id(deps.storage)?)
 }
 
+pub fn query_list_proposals_by_status(
+    deps: Deps,
+    env: Env,
+    status: Status,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT) as usize;
+    // Proposals with this status may be stored with any status that
+    // lazily becomes it, so each of those statuses is scanned and
+    // entries whose current status differs are skipped. The first
+    // `limit` matching proposals stored with each status contain the
+    // first `limit` matching proposals overall.
+    //
+    // The skipped entries are proposals whose stored status has
+    // expired. The scan is not bounded, but as each new proposal
+    // saves the status of the proposals that expired first, only
+    // proposals that expired since proposals were last created are
+    // usually skipped.
+    let mut props = vec![];
+    for key in status.stored_keys() {
+        let matching = proposals()
+            .idx
+            .status
+            .prefix(key.to_string())
+            .range(
+                deps.storage,
+                start_after.map(Bound::exclusive),
+                None,
+                cosmwasm_std::Order::Ascending,
+            )
+            .filter(|item| match item {
+                Ok((_, proposal)) => proposal
+                    .current_status(&env.block)
+                    .map_or(true, |current| current.key() == status.key()),
+                Err(_) => true,
+            })
+            .take(limit)
+            .collect::<StdResult<Vec<(u64, MultipleChoiceProposal)>>>()?;
+        props.extend(matching);
+    }
+    props.sort_by_key(|(id, _)| *id);
+    let props = props
+        .into_iter()
+        .take(limit)
+        .map(|(id, proposal)| proposal.into_response(&env.block, id))
+        .collect::<StdResult<Vec<ProposalResponse>>>()?;
+
+    to_json_binary(&ProposalListResponse { proposals: props })
+}
+
+pub fn query_list_proposals_by_proposer(
+    deps: Deps,
+    env: Env,
+    proposer: String,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let proposer = deps.api.addr_validate(&proposer)?;
+    let min = start_after.map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let props: Vec<ProposalResponse> = proposals()
+        .idx
+        .proposer
+        .prefix(proposer)
+        .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
+        .take(limit as usize)
+        .collect::<Result<Vec<(u64, MultipleChoiceProposal)>, _>>()?
+        .into_iter()
+        .map(|(id, proposal)| proposal.into_response(&env.block, id))
+        .collect::<StdResult<Vec<ProposalResponse>>>()?;
+
+    to_json_binary(&ProposalListResponse { proposals: props })
+}
+
 pub fn query_proposal_count(deps: Deps) -> StdResult<Binary> {
     let proposal_count = PROPOSAL_COUNT.load(deps.storage)?;
     to_json_binary(&proposal_count)
@@ -977,6 +1663,55 @@ pub fn query_list_votes(
     to_json_binary(&VoteListResponse { votes })
 }
 
+pub fn query_list_votes_by_voter(
+    deps: Deps,
+    voter: String,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let voter = deps.api.addr_validate(&voter)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let min = start_after.map(Bound::exclusive);
+
+    let votes = VOTER_PROPOSALS
+        .prefix(&voter)
+        .keys(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|proposal_id| {
+            let proposal_id = proposal_id?;
+            let ballot = BALLOTS.load(deps.storage, (proposal_id, &voter))?;
+            Ok(VoterVoteInfo {
+                proposal_id,
+                vote: ballot.vote,
+                power: ballot.power,
+                rationale: ballot.rationale,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&VoterVoteListResponse { votes })
+}
+
+pub fn query_vote_nonce(deps: Deps, voter: String) -> StdResult<Binary> {
+    let voter = deps.api.addr_validate(&voter)?;
+    to_json_binary(
+        &VOTE_NONCES
+            .may_load(deps.storage, &voter)?
+            .unwrap_or_default(),
+    )
+}
+
+pub fn query_veto_tally(deps: Deps, proposal_id: u64) -> StdResult<Binary> {
+    let prop = proposals().load(deps.storage, proposal_id)?;
+    let vetoers = VETO_VOTES
+        .may_load(deps.storage, proposal_id)?
+        .unwrap_or_default();
+    to_json_binary(&VetoTallyResponse {
+        vetoers,
+        threshold: prop.veto.as_ref().map(VetoConfig::threshold),
+    })
+}
+
 pub fn query_info(deps: Deps) -> StdResult<Binary> {
     let info = cw2::get_contract_version(deps.storage)?;
     to_json_binary(&dao_interface::voting::InfoResponse { info })
@@ -987,7 +1722,7 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
     let repl = TaggedReplyId::new(msg.id)?;
     match repl {
         TaggedReplyId::FailedProposalExecution(proposal_id) => {
-            PROPOSALS.update(deps.storage, proposal_id, |prop| match prop {
+            proposals().update(deps.storage, proposal_id, |prop| match prop {
                 Some(mut prop) => {
                     prop.status = Status::ExecutionFailed;
                     Ok(prop)
@@ -1044,11 +1779,17 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
             };
             Ok(Response::new().add_attribute("failed_prepropose_hook", format!("{addr}")))
         }
+        // This module never executes proposal messages individually
+        // or records execution results.
+        TaggedReplyId::ProposalMessageExecution(..) | TaggedReplyId::ProposalExecutionResult(_) => {
+            Err(ContractError::InvalidReplyID { id: msg.id })
+        }
     }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    start_indexing(deps.storage)?;
     Ok(Response::default())
 }
@@ -1,7 +1,11 @@
 use cosmwasm_std::StdError;
+use cw_denom::DenomError;
 use cw_hooks::HookError;
-use cw_utils::ParseReplyError;
-use dao_voting::{reply::error::TagError, threshold::ThresholdError, veto::VetoError};
+use cw_utils::{Expiration, ParseReplyError};
+use dao_voting::{
+    reply::error::TagError, signed_vote::SignedVoteError, threshold::ThresholdError,
+    veto::VetoError,
+};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -18,9 +22,18 @@ pub enum ContractError {
     #[error(transparent)]
     VetoError(#[from] VetoError),
 
+    #[error(transparent)]
+    SignedVoteError(#[from] SignedVoteError),
+
+    #[error(transparent)]
+    DenomError(#[from] DenomError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
+    #[error("Nothing is waiting to be indexed.")]
+    NothingToIndex {},
+
     #[error("{0}")]
     ThresholdError(#[from] ThresholdError),
 
@@ -30,9 +43,15 @@ pub enum ContractError {
     #[error("Suggested proposal expiration is larger than the maximum proposal duration")]
     InvalidExpiration {},
 
+    #[error("This module does not allow proposers to choose a voting period")]
+    VotingPeriodNotAllowed {},
+
     #[error("No such proposal ({id})")]
     NoSuchProposal { id: u64 },
 
+    #[error("Proposal ({id}) does not have a runoff ready to be tallied.")]
+    RunoffNotReady { id: u64 },
+
     #[error("Proposal is ({size}) bytes, must be <= ({max}) bytes")]
     ProposalTooLarge { size: u64, max: u64 },
 
@@ -54,6 +73,12 @@ pub enum ContractError {
     #[error("Proposal must be in 'passed' state to be executed.")]
     NotPassed {},
 
+    #[error("Proposal may not be executed until ({execute_after}).")]
+    ExecutionLocked { execute_after: Expiration },
+
+    #[error("Proposal ({proposal_id}) in module ({module}) must be executed first.")]
+    DependencyNotExecuted { module: String, proposal_id: u64 },
+
     #[error("Proposal is in a tie: two or more options have the same number of votes.")]
     Tie {},
 
@@ -78,6 +103,17 @@ pub enum ContractError {
     #[error("Invalid vote selected.")]
     InvalidVote {},
 
+    #[error("Proposals using the budget voting strategy must have a non-zero budget.")]
+    InvalidBudget {},
+
+    #[error("Every option of a budget proposal must request a non-zero amount of funding and have no messages.")]
+    InvalidFundingRequest {},
+
+    #[error(
+        "Only proposals using the budget voting strategy may have a budget or request funding."
+    )]
+    UnexpectedBudget {},
+
     #[error("Must have voting power to propose.")]
     MustHaveVotingPower {},
 
@@ -5,6 +5,7 @@ mod error;
 pub mod msg;
 pub mod proposal;
 pub mod query;
+pub mod runoff;
 pub mod state;
 pub use crate::error::ContractError;
 
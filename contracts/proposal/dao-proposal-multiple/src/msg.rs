@@ -1,11 +1,15 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Binary;
 use cw_utils::Duration;
 use dao_dao_macros::proposal_module_query;
 use dao_voting::{
-    multiple_choice::{MultipleChoiceVote, VotingStrategy},
+    multiple_choice::{MultipleChoiceVote, TieBreaker, VotingStrategy},
     pre_propose::PreProposeInfo,
     proposal::MultipleChoiceProposeMsg,
+    signed_vote::SignedVotePayload,
+    status::Status,
     veto::VetoConfig,
+    voting::VotingPeriodRange,
 };
 
 #[cw_serde]
@@ -42,9 +46,18 @@ pub struct InstantiateMsg {
     /// Optional veto configuration for proposal execution.
     /// If set, proposals can only be executed after the timelock
     /// delay expiration.
-    /// During this period an oversight account (`veto.vetoer`) can
-    /// veto the proposal.
+    /// During this period an oversight account (`veto.vetoer`), or
+    /// a council of them, can veto the proposal.
     pub veto: Option<VetoConfig>,
+    /// If set, proposers may choose a voting period within this range
+    /// when creating a proposal. Otherwise, proposals are open for
+    /// `max_voting_period`.
+    #[serde(default)]
+    pub voting_period_range: Option<VotingPeriodRange>,
+    /// How proposals whose leading options are tied are decided.
+    /// Defaults to rejecting them.
+    #[serde(default)]
+    pub tie_breaker: Option<TieBreaker>,
 }
 
 #[cw_serde]
@@ -63,6 +76,21 @@ pub enum ExecuteMsg {
         /// the vote.
         rationale: Option<String>,
     },
+    /// Casts a vote signed by `voter` on their behalf, allowing
+    /// voters without gas tokens to have their votes relayed by
+    /// anyone. The payload must use the voter's current nonce, which
+    /// may be queried with `VoteNonce`.
+    VoteWithSignature {
+        /// The address of the voter that signed the vote.
+        voter: String,
+        /// The signed vote.
+        payload: SignedVotePayload<MultipleChoiceVote>,
+        /// The voter's compressed secp256k1 public key.
+        pubkey: Binary,
+        /// The secp256k1 signature of the SHA-256 hash of the
+        /// payload's ADR-036 sign doc. See `SignedVotePayload::sign_doc`.
+        signature: Binary,
+    },
     /// Causes the messages associated with a passed proposal to be
     /// executed by the DAO.
     Execute {
@@ -81,6 +109,14 @@ pub enum ExecuteMsg {
         /// The ID of the proposal to close.
         proposal_id: u64,
     },
+    /// Continues tallying the instant-runoff of a ranked choice
+    /// proposal whose voting has ended. Runoffs are tallied in
+    /// batches, so large ones may need to be continued several times
+    /// before the proposal is decided.
+    TallyRunoff {
+        /// The ID of the proposal to tally.
+        proposal_id: u64,
+    },
     /// Updates the governance module's config.
     UpdateConfig {
         /// The new proposal voting strategy. This will only apply
@@ -119,6 +155,15 @@ pub enum ExecuteMsg {
         /// Optional time delay on proposal execution, during which the
         /// proposal may be vetoed.
         veto: Option<VetoConfig>,
+        /// If set, proposers may choose a voting period within this
+        /// range when creating a proposal.
+        #[serde(default)]
+        voting_period_range: Option<VotingPeriodRange>,
+        /// How proposals whose leading options are tied are
+        /// decided. Applies to future proposals. If not set, the
+        /// current tie breaker is kept.
+        #[serde(default)]
+        tie_breaker: Option<TieBreaker>,
     },
     /// Updates the sender's rationale for their vote on the specified
     /// proposal. Errors if no vote vote has been cast.
@@ -131,6 +176,20 @@ pub enum ExecuteMsg {
     UpdatePreProposeInfo {
         info: PreProposeInfo,
     },
+    /// Populates the proposal indexes for up to `limit` proposals
+    /// created before the indexes were added. Callable by anyone after
+    /// migrating from a version without the indexes, until every
+    /// proposal has been indexed.
+    IndexProposals {
+        limit: Option<u64>,
+    },
+    /// Populates the index of ballots by voter for up to `limit`
+    /// ballots cast before it was added. Callable by anyone after
+    /// migrating from a version without the index, until every ballot
+    /// has been indexed.
+    IndexBallots {
+        limit: Option<u64>,
+    },
     AddProposalHook {
         address: String,
     },
@@ -168,6 +227,29 @@ pub enum QueryMsg {
         start_before: Option<u64>,
         limit: Option<u64>,
     },
+    /// Lists the proposals whose current status is `status` in
+    /// ascending order of proposal ID. The data of `VetoTimelock`
+    /// statuses is ignored when matching.
+    ///
+    /// Proposals are indexed by their stored status, which changes
+    /// lazily as voting and timelocks end and is saved when proposals
+    /// are created. Proposals whose status changed since proposals
+    /// were last created are scanned and skipped, so this query may
+    /// read more proposals than `limit`.
+    #[returns(crate::query::ProposalListResponse)]
+    ListProposalsByStatus {
+        status: Status,
+        start_after: Option<u64>,
+        limit: Option<u64>,
+    },
+    /// Lists the proposals created by `proposer` in ascending order
+    /// of proposal ID.
+    #[returns(crate::query::ProposalListResponse)]
+    ListProposalsByProposer {
+        proposer: String,
+        start_after: Option<u64>,
+        limit: Option<u64>,
+    },
     /// Returns a voters position on a proposal.
     #[returns(crate::query::VoteResponse)]
     GetVote { proposal_id: u64, voter: String },
@@ -178,6 +260,14 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u64>,
     },
+    /// Lists the votes cast by a voter in ascending order of proposal
+    /// ID.
+    #[returns(crate::query::VoterVoteListResponse)]
+    ListVotesByVoter {
+        voter: String,
+        start_after: Option<u64>,
+        limit: Option<u64>,
+    },
     /// Returns the number of proposals that have been created in this module.
     #[returns(::std::primitive::u64)]
     ProposalCount {},
@@ -190,6 +280,21 @@ pub enum QueryMsg {
     /// Lists all of the consumers of vote hooks for this module.
     #[returns(::cw_hooks::HooksResponse)]
     VoteHooks {},
+    /// Gets the vetoes cast on a proposal so far.
+    #[returns(::dao_voting::veto::VetoTallyResponse)]
+    VetoTally { proposal_id: u64 },
+    /// Gets the nonce that the next signed vote from `voter` must
+    /// use.
+    #[returns(::std::primitive::u64)]
+    VoteNonce { voter: String },
+    /// Gets participation statistics for a proposal, such as its
+    /// turnout and the votes needed to pass or reject it.
+    #[returns(::dao_voting::stats::ProposalStatsResponse)]
+    ProposalStats { proposal_id: u64 },
+    /// Gets the rounds of a ranked choice proposal's instant-runoff,
+    /// which is tallied once voting has ended.
+    #[returns(crate::query::RunoffRoundsResponse)]
+    RunoffRounds { proposal_id: u64 },
 }
 
 #[cw_serde]
@@ -1,18 +1,20 @@
 use std::ops::Add;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, BlockInfo, StdError, StdResult, Uint128};
+use cosmwasm_std::{Addr, BlockInfo, Decimal, StdError, StdResult, Uint128};
 use cw_utils::Expiration;
 use dao_voting::{
     multiple_choice::{
-        CheckedMultipleChoiceOption, MultipleChoiceOptionType, MultipleChoiceVotes, VotingStrategy,
+        CheckedBudget, CheckedMultipleChoiceOption, MultipleChoiceOptionType, MultipleChoiceVote,
+        MultipleChoiceVotes, TieBreaker, VotingStrategy,
     },
+    stats::{turnout, votes_needed, votes_needed_for_quorum, ProposalStatsResponse},
     status::Status,
     veto::VetoConfig,
     voting::does_vote_count_pass,
 };
 
-use crate::query::ProposalResponse;
+use crate::{query::ProposalResponse, runoff::RunoffResult};
 
 #[cw_serde]
 pub struct MultipleChoiceProposal {
@@ -51,13 +53,55 @@ pub struct MultipleChoiceProposal {
     /// Optional veto configuration. If set to `None`, veto option
     /// is disabled. Otherwise contains the configuration for veto flow.
     pub veto: Option<VetoConfig>,
+    /// An optional time before which this proposal may not be
+    /// executed, even if it has passed. This is independent of any
+    /// veto timelock.
+    #[serde(default)]
+    pub execute_after: Option<Expiration>,
+    /// Proposals that must be executed before this one may be, given
+    /// as (proposal module address, proposal ID) pairs.
+    #[serde(default)]
+    pub depends_on: Vec<(Addr, u64)>,
+    /// The result of the proposal's instant-runoff if it uses the
+    /// `RankedChoice` voting strategy and has been tallied. Ranked
+    /// choice proposals are tallied once voting has ended, or once
+    /// all voting power has voted if revoting is disabled.
+    #[serde(default)]
+    pub runoff: Option<RunoffResult>,
+    /// How the proposal is decided if its leading options are tied.
+    #[serde(default)]
+    pub tie_breaker: TieBreaker,
+    /// For each option, the number of vote weight changes that had
+    /// been made when its vote weight last changed. Only tracked for
+    /// the `EarliestToReach` tie breaker.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub weights_changed_at: Vec<u64>,
+    /// The funds split between the proposal's options if it uses the
+    /// `Budget` voting strategy.
+    #[serde(default)]
+    pub budget: Option<CheckedBudget>,
 }
 
 pub enum VoteResult {
     SingleWinner(CheckedMultipleChoiceOption),
+    /// The winners of an approval vote, ordered by vote weight, or
+    /// the funded options of a budget proposal.
+    Winners(Vec<CheckedMultipleChoiceOption>),
     Tie,
 }
 
+/// The result of a vote before ties are broken.
+enum Tally {
+    Decided(VoteResult),
+    /// The `tied` options, ordered by index, are tied for the last
+    /// `seats` winning places after the `secured` winners.
+    Tied {
+        secured: Vec<CheckedMultipleChoiceOption>,
+        tied: Vec<CheckedMultipleChoiceOption>,
+        seats: usize,
+    },
+}
+
 impl MultipleChoiceProposal {
     /// Consumes the proposal and returns a version which may be used
     /// in a query response. The difference being that proposal
@@ -112,6 +156,18 @@ impl MultipleChoiceProposal {
         Ok(())
     }
 
+    /// The expiration after which this proposal's stored status is
+    /// sure to lazily change, or `Never` if it will not. Open
+    /// proposals change once voting has ended, and timelocked
+    /// proposals once their timelock has ended.
+    pub fn status_expiration(&self) -> Expiration {
+        match self.status {
+            Status::Open => self.expiration,
+            Status::VetoTimelock { expiration } => expiration,
+            _ => Expiration::Never {},
+        }
+    }
+
     /// Returns true iff this proposal is sure to pass (even before
     /// expiration if no future sequence of possible votes can cause
     /// it to fail). Passing in the case of multiple choice proposals
@@ -134,6 +190,11 @@ impl MultipleChoiceProposal {
                 return Ok(false);
             }
         }
+        // Ranked choice proposals can not pass until their runoff has
+        // been tallied.
+        if self.is_awaiting_runoff() {
+            return Ok(false);
+        }
 
         // Proposal can only pass if quorum has been met.
         if does_vote_count_pass(
@@ -145,6 +206,15 @@ impl MultipleChoiceProposal {
             match vote_result {
                 // Proposal is not passed if there is a tie.
                 VoteResult::Tie => return Ok(false),
+                VoteResult::Winners(winning_choices) => {
+                    let winners_final = match self.voting_strategy {
+                        // Any outstanding vote may change how the
+                        // budget is allocated.
+                        VotingStrategy::Budget { .. } => self.votes.total() == self.total_power,
+                        _ => self.are_approval_winners_final(&winning_choices),
+                    };
+                    return Ok(self.expiration.is_expired(block) || winners_final);
+                }
                 VoteResult::SingleWinner(winning_choice) => {
                     // Proposal is not passed if winning choice is None.
                     if winning_choice.option_type != MultipleChoiceOptionType::None {
@@ -169,6 +239,16 @@ impl MultipleChoiceProposal {
         if self.allow_revoting && !self.expiration.is_expired(block) {
             return Ok(false);
         }
+        // Until their runoff has been tallied, ranked choice proposals
+        // may only be rejected for not meeting quorum.
+        if self.is_awaiting_runoff() {
+            return Ok(self.expiration.is_expired(block)
+                && !does_vote_count_pass(
+                    self.votes.total(),
+                    self.total_power,
+                    self.voting_strategy.get_quorum(),
+                ));
+        }
 
         let vote_result = self.calculate_vote_result()?;
         match vote_result {
@@ -179,6 +259,14 @@ impl MultipleChoiceProposal {
                     self.expiration.is_expired(block) || self.total_power == self.votes.total();
                 Ok(rejected)
             }
+            // Proposal is rejected if it has winners but expired
+            // without meeting quorum.
+            VoteResult::Winners(_) => Ok(self.expiration.is_expired(block)
+                && !does_vote_count_pass(
+                    self.votes.total(),
+                    self.total_power,
+                    self.voting_strategy.get_quorum(),
+                )),
             VoteResult::SingleWinner(winning_choice) => {
                 match (
                     does_vote_count_pass(
@@ -212,8 +300,275 @@ impl MultipleChoiceProposal {
         }
     }
 
-    /// Find the option with the highest vote weight, and note if there is a tie.
+    /// Computes participation statistics for this proposal. The
+    /// votes needed to pass it are votes for the leading option that
+    /// is not "None of the above", and the votes needed to reject it
+    /// are votes for "None of the above". Both assume that voting
+    /// ends afterwards, and are `None` for ranked choice proposals
+    /// awaiting their runoff as they depend on how votes are ranked.
+    pub fn stats(&self, block: &BlockInfo) -> StdResult<ProposalStatsResponse> {
+        let status = self.current_status(block)?;
+        // A proposal is held open until its minimum voting period
+        // ends, though its outcome may already be decided.
+        let decided_status = match status {
+            Status::Open => MultipleChoiceProposal {
+                min_voting_period: None,
+                ..self.clone()
+            }
+            .current_status(block)?,
+            status => status,
+        };
+        let outcome_locked = decided_status != Status::Open;
+        let cast = self.votes.total();
+        let outstanding = if outcome_locked {
+            Uint128::zero()
+        } else {
+            self.total_power.saturating_sub(cast)
+        };
+        let passed = matches!(
+            decided_status,
+            Status::Passed
+                | Status::VetoTimelock { .. }
+                | Status::Executed
+                | Status::PartiallyExecuted
+                | Status::ExecutionFailed
+        );
+
+        let (votes_needed_to_pass, votes_needed_to_reject) = if outcome_locked {
+            (passed.then(Uint128::zero), (!passed).then(Uint128::zero))
+        } else if self.is_awaiting_runoff() {
+            (None, None)
+        } else {
+            let ended_with = |option: Option<&CheckedMultipleChoiceOption>, power: Uint128| {
+                let mut ended = MultipleChoiceProposal {
+                    expiration: Expiration::AtHeight(block.height),
+                    min_voting_period: None,
+                    ..self.clone()
+                };
+                if let Some(option) = option {
+                    // Scored votes support an option with the
+                    // highest score.
+                    let scores = match self.voting_strategy {
+                        VotingStrategy::Score { max_score, .. } => vec![max_score],
+                        _ => vec![],
+                    };
+                    let vote = MultipleChoiceVote {
+                        option_id: option.index,
+                        additional_option_ids: vec![],
+                        scores,
+                    };
+                    if ended.votes.add_vote(&vote, power).is_err() {
+                        return false;
+                    }
+                }
+                matches!(ended.is_passed(block), Ok(true))
+            };
+            // The first of the standard options with the most votes.
+            let leading = self
+                .choices
+                .iter()
+                .filter(|choice| choice.option_type == MultipleChoiceOptionType::Standard)
+                .min_by_key(|choice| {
+                    (
+                        std::cmp::Reverse(self.votes.vote_weights[choice.index as usize]),
+                        choice.index,
+                    )
+                });
+            let none = self
+                .choices
+                .iter()
+                .find(|choice| choice.option_type == MultipleChoiceOptionType::None);
+            (
+                votes_needed(outstanding, |power| ended_with(leading, power)),
+                votes_needed(outstanding, |power| !ended_with(none, power)),
+            )
+        };
+
+        Ok(ProposalStatsResponse {
+            turnout: turnout(cast, self.total_power),
+            votes_needed_to_pass,
+            votes_needed_to_reject,
+            votes_needed_for_quorum: votes_needed_for_quorum(
+                cast,
+                outstanding,
+                self.total_power,
+                self.voting_strategy.get_quorum(),
+            ),
+            outcome_locked,
+        })
+    }
+
+    /// Adds `vote` to the proposal's vote tally. Ranked choice votes
+    /// are tallied by their first preference, their full rankings
+    /// being saved separately for the runoff.
+    pub fn add_vote(&mut self, vote: &MultipleChoiceVote, power: Uint128) -> StdResult<()> {
+        let counted = self.counted_vote(vote);
+        self.votes.add_vote(&counted, power)?;
+        self.record_weights_changed(&counted, power);
+        Ok(())
+    }
+
+    /// Removes `vote` from the proposal's vote tally.
+    pub fn remove_vote(&mut self, vote: &MultipleChoiceVote, power: Uint128) -> StdResult<()> {
+        let counted = self.counted_vote(vote);
+        self.votes.remove_vote(&counted, power)?;
+        self.record_weights_changed(&counted, power);
+        Ok(())
+    }
+
+    /// Notes that the vote weights of the options `vote` scores have
+    /// changed, so that ties may be broken in favor of the option
+    /// that reached the tied weight first.
+    fn record_weights_changed(&mut self, vote: &MultipleChoiceVote, power: Uint128) {
+        if !matches!(self.tie_breaker, TieBreaker::EarliestToReach {}) || power.is_zero() {
+            return;
+        }
+        if self.weights_changed_at.is_empty() {
+            self.weights_changed_at = vec![0; self.choices.len()];
+        }
+        let change = self
+            .weights_changed_at
+            .iter()
+            .max()
+            .copied()
+            .unwrap_or_default()
+            + 1;
+        for (option_id, score) in vote.scored_option_ids() {
+            if score > 0 {
+                self.weights_changed_at[option_id as usize] = change;
+            }
+        }
+    }
+
+    fn counted_vote(&self, vote: &MultipleChoiceVote) -> MultipleChoiceVote {
+        match self.voting_strategy {
+            VotingStrategy::RankedChoice { .. } => MultipleChoiceVote {
+                option_id: vote.option_id,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
+            _ => vote.clone(),
+        }
+    }
+
+    /// Returns true if `vote` selects options of this proposal in a
+    /// way its voting strategy allows.
+    pub fn is_valid_vote(&self, vote: &MultipleChoiceVote) -> bool {
+        let option_ids: Vec<u32> = vote.option_ids().collect();
+        if option_ids
+            .iter()
+            .any(|id| *id as usize >= self.choices.len())
+        {
+            return false;
+        }
+        let distinct = option_ids
+            .iter()
+            .enumerate()
+            .all(|(i, id)| !option_ids[..i].contains(id));
+        // Only scored votes may give scores.
+        if !vote.scores.is_empty() && !matches!(self.voting_strategy, VotingStrategy::Score { .. })
+        {
+            return false;
+        }
+        match self.voting_strategy {
+            VotingStrategy::SingleChoice { .. } | VotingStrategy::Budget { .. } => {
+                option_ids.len() == 1
+            }
+            VotingStrategy::Approval { max_selections, .. } => {
+                // "None of the above" may not be selected alongside
+                // other options.
+                let selects_none = option_ids.iter().any(|id| {
+                    self.choices[*id as usize].option_type == MultipleChoiceOptionType::None
+                });
+                distinct
+                    && option_ids.len() <= max_selections as usize
+                    && (option_ids.len() == 1 || !selects_none)
+            }
+            // Ranked choice votes may rank any of the options, so long
+            // as they rank each once.
+            VotingStrategy::RankedChoice { .. } => distinct,
+            // Scored votes give each option they select one score.
+            VotingStrategy::Score { max_score, .. } => {
+                distinct
+                    && vote.scores.len() == option_ids.len()
+                    && vote.scores.iter().all(|score| *score <= max_score)
+            }
+        }
+    }
+
+    /// Find the option with the highest vote weight, breaking any tie
+    /// with the proposal's tie breaker.
     pub fn calculate_vote_result(&self) -> StdResult<VoteResult> {
+        let (secured, mut tied, seats) = match self.tally()? {
+            Tally::Decided(result) => return Ok(result),
+            Tally::Tied {
+                secured,
+                tied,
+                seats,
+            } => (secured, tied, seats),
+        };
+        // Ties with "None of the above" are never broken.
+        if tied
+            .iter()
+            .any(|choice| choice.option_type == MultipleChoiceOptionType::None)
+        {
+            return Ok(VoteResult::Tie);
+        }
+        match self.tie_breaker {
+            TieBreaker::Fail {} | TieBreaker::Runoff {} => return Ok(VoteResult::Tie),
+            TieBreaker::LowestIndex {} => (),
+            TieBreaker::EarliestToReach {} => tied.sort_by_key(|choice| {
+                (
+                    self.weights_changed_at
+                        .get(choice.index as usize)
+                        .copied()
+                        .unwrap_or_default(),
+                    choice.index,
+                )
+            }),
+        }
+        match self.voting_strategy {
+            VotingStrategy::Approval { .. } => Ok(VoteResult::Winners(
+                secured
+                    .into_iter()
+                    .chain(tied.into_iter().take(seats))
+                    .collect(),
+            )),
+            _ => Ok(VoteResult::SingleWinner(tied.remove(0))),
+        }
+    }
+
+    /// Returns the options a runoff proposal should be held between:
+    /// if the proposal's tie breaker is `Runoff`, quorum was met, and
+    /// options other than "None of the above" were tied, the tied
+    /// options and any approval winners ahead of them. Otherwise,
+    /// none.
+    pub fn tie_runoff_options(&self) -> StdResult<Vec<CheckedMultipleChoiceOption>> {
+        if !matches!(self.tie_breaker, TieBreaker::Runoff {})
+            || !does_vote_count_pass(
+                self.votes.total(),
+                self.total_power,
+                self.voting_strategy.get_quorum(),
+            )
+            || self.is_awaiting_runoff()
+        {
+            return Ok(vec![]);
+        }
+        match self.tally()? {
+            Tally::Tied { secured, tied, .. }
+                if tied
+                    .iter()
+                    .all(|choice| choice.option_type == MultipleChoiceOptionType::Standard) =>
+            {
+                Ok(secured.into_iter().chain(tied).collect())
+            }
+            _ => Ok(vec![]),
+        }
+    }
+
+    /// Finds the option with the highest vote weight, noting the tied
+    /// options if there is a tie.
+    fn tally(&self) -> StdResult<Tally> {
         match self.voting_strategy {
             VotingStrategy::SingleChoice { quorum: _ } => {
                 // We expect to have at least 3 vote weights
@@ -228,14 +583,21 @@ impl MultipleChoiceProposal {
 
                     // If more than one choice has the highest number of votes, we have a tie.
                     if top_choices.len() > 1 {
-                        return Ok(VoteResult::Tie);
+                        return Ok(Tally::Tied {
+                            secured: vec![],
+                            tied: top_choices
+                                .iter()
+                                .map(|(index, _)| self.choices[*index].clone())
+                                .collect(),
+                            seats: 1,
+                        });
                     }
 
                     match top_choices.first() {
                         Some(winning_choice) => {
-                            return Ok(VoteResult::SingleWinner(
+                            return Ok(Tally::Decided(VoteResult::SingleWinner(
                                 self.choices[winning_choice.0].clone(),
-                            ));
+                            )));
                         }
                         None => {
                             return Err(StdError::generic_err("no votes found"));
@@ -244,7 +606,179 @@ impl MultipleChoiceProposal {
                 }
                 Err(StdError::not_found("max vote weight"))
             }
+            VotingStrategy::Approval { max_selections, .. } => {
+                let none_option = self.none_option()?;
+                let none_weight = self.votes.vote_weights[none_option.index as usize];
+                // Options must receive more votes than "None of the
+                // above" to win.
+                let mut contenders: Vec<&CheckedMultipleChoiceOption> = self
+                    .choices
+                    .iter()
+                    .filter(|choice| {
+                        choice.option_type == MultipleChoiceOptionType::Standard
+                            && self.votes.vote_weights[choice.index as usize] > none_weight
+                    })
+                    .collect();
+                contenders.sort_by_key(|choice| {
+                    (
+                        std::cmp::Reverse(self.votes.vote_weights[choice.index as usize]),
+                        choice.index,
+                    )
+                });
+
+                let k = max_selections as usize;
+                if contenders.is_empty() {
+                    return Ok(Tally::Decided(VoteResult::SingleWinner(
+                        none_option.clone(),
+                    )));
+                }
+                // If the last winner is tied with the first option
+                // that did not win, we have a tie.
+                if contenders.len() > k {
+                    let last_weight = self.votes.vote_weights[contenders[k - 1].index as usize];
+                    if last_weight == self.votes.vote_weights[contenders[k].index as usize] {
+                        let (secured, tied): (Vec<_>, Vec<_>) =
+                            contenders.into_iter().cloned().partition(|choice| {
+                                self.votes.vote_weights[choice.index as usize] > last_weight
+                            });
+                        let tied: Vec<_> = tied
+                            .into_iter()
+                            .filter(|choice| {
+                                self.votes.vote_weights[choice.index as usize] == last_weight
+                            })
+                            .collect();
+                        return Ok(Tally::Tied {
+                            seats: k - secured.len(),
+                            secured,
+                            tied,
+                        });
+                    }
+                }
+                Ok(Tally::Decided(VoteResult::Winners(
+                    contenders.into_iter().take(k).cloned().collect(),
+                )))
+            }
+            VotingStrategy::RankedChoice { .. } => match &self.runoff {
+                Some(RunoffResult::Winner { option_id }) => Ok(Tally::Decided(
+                    VoteResult::SingleWinner(self.choices[*option_id as usize].clone()),
+                )),
+                Some(RunoffResult::Tie { option_ids }) => Ok(Tally::Tied {
+                    secured: vec![],
+                    tied: option_ids
+                        .iter()
+                        .map(|id| self.choices[*id as usize].clone())
+                        .collect(),
+                    seats: 1,
+                }),
+                None => Err(StdError::generic_err(
+                    "ranked choice votes have not been tallied",
+                )),
+            },
+            VotingStrategy::Score { quorum, .. } => {
+                // Options must be scored by enough voting power to meet
+                // quorum to win.
+                let eligible: Vec<&CheckedMultipleChoiceOption> = self
+                    .choices
+                    .iter()
+                    .filter(|choice| {
+                        does_vote_count_pass(
+                            self.votes.participation(choice.index),
+                            self.total_power,
+                            quorum,
+                        )
+                    })
+                    .collect();
+                let best = match eligible
+                    .iter()
+                    .copied()
+                    .max_by(|a, b| self.compare_average_scores(a, b))
+                {
+                    Some(best) => best,
+                    None => {
+                        return Ok(Tally::Decided(VoteResult::SingleWinner(
+                            self.none_option()?.clone(),
+                        )))
+                    }
+                };
+                let tied: Vec<CheckedMultipleChoiceOption> = eligible
+                    .iter()
+                    .filter(|choice| self.compare_average_scores(choice, best).is_eq())
+                    .map(|choice| (*choice).clone())
+                    .collect();
+                if tied.len() > 1 {
+                    return Ok(Tally::Tied {
+                        secured: vec![],
+                        tied,
+                        seats: 1,
+                    });
+                }
+                Ok(Tally::Decided(VoteResult::SingleWinner(best.clone())))
+            }
+            // Every funded option wins. If none are, the proposal is
+            // rejected.
+            VotingStrategy::Budget { .. } => {
+                let funded: Vec<CheckedMultipleChoiceOption> = self
+                    .budget_allocations()
+                    .into_iter()
+                    .map(|(choice, _)| choice.clone())
+                    .collect();
+                if funded.is_empty() {
+                    return Ok(Tally::Decided(VoteResult::SingleWinner(
+                        self.none_option()?.clone(),
+                    )));
+                }
+                Ok(Tally::Decided(VoteResult::Winners(funded)))
+            }
+        }
+    }
+
+    /// Splits a budget proposal's budget between its options in
+    /// proportion to the votes they received, returning the options
+    /// that are funded and their funding. Options are funded no more
+    /// than they requested, and are not funded if they received less
+    /// than the minimum share of the votes.
+    pub fn budget_allocations(&self) -> Vec<(&CheckedMultipleChoiceOption, Uint128)> {
+        let (budget, min_share) = match (&self.budget, &self.voting_strategy) {
+            (Some(budget), VotingStrategy::Budget { min_share, .. }) => (budget, *min_share),
+            _ => return vec![],
+        };
+        let total = self.votes.total();
+        if total.is_zero() {
+            return vec![];
         }
+        self.choices
+            .iter()
+            .filter_map(|choice| {
+                let funding = choice.funding.as_ref()?;
+                let votes = self.votes.vote_weights[choice.index as usize];
+                if votes.is_zero() || Decimal::from_ratio(votes, total) < min_share {
+                    return None;
+                }
+                let amount = budget
+                    .amount
+                    .multiply_ratio(votes, total)
+                    .min(funding.amount);
+                (!amount.is_zero()).then_some((choice, amount))
+            })
+            .collect()
+    }
+
+    /// Compares the average scores of two options, being the sum of
+    /// their scores weighted by voting power over the voting power
+    /// that scored them.
+    fn compare_average_scores(
+        &self,
+        a: &CheckedMultipleChoiceOption,
+        b: &CheckedMultipleChoiceOption,
+    ) -> std::cmp::Ordering {
+        let score = |choice: &CheckedMultipleChoiceOption| {
+            (
+                self.votes.vote_weights[choice.index as usize],
+                self.votes.participation(choice.index),
+            )
+        };
+        let ((a_sum, a_power), (b_sum, b_power)) = (score(a), score(b));
+        a_sum.full_mul(b_power).cmp(&b_sum.full_mul(a_power))
     }
 
     /// Ensure that with the remaining vote power, the choice with the second highest votes
@@ -253,16 +787,39 @@ impl MultipleChoiceProposal {
         &self,
         winning_choice: &CheckedMultipleChoiceOption,
     ) -> StdResult<bool> {
+        // Approval votes only have a single winner when "None of the
+        // above" wins, in which case there are no winners.
+        if let VotingStrategy::Approval { .. } = self.voting_strategy {
+            return Ok(self.are_approval_winners_final(&[]));
+        }
+        // Runoffs are only tallied once no more votes may be cast.
+        if let VotingStrategy::RankedChoice { .. } = self.voting_strategy {
+            return Ok(true);
+        }
+        // Any outstanding vote may change which option has the highest
+        // average score, or whether any option is funded.
+        if let VotingStrategy::Score { .. } | VotingStrategy::Budget { .. } = self.voting_strategy {
+            return Ok(self.votes.total() == self.total_power);
+        }
         let winning_choice_power = self.votes.vote_weights[winning_choice.index as usize];
+        let remaining_vote_power = self.total_power - self.votes.total();
+        // Once all voting power has voted, the winner is final even if
+        // it won a tie.
+        if remaining_vote_power.is_zero() {
+            return Ok(true);
+        }
+        // The second choice may be tied with the winning choice if the
+        // tie was broken.
         if let Some(second_choice_power) = self
             .votes
             .vote_weights
             .iter()
-            .filter(|&x| x < &winning_choice_power)
+            .enumerate()
+            .filter(|(index, _)| *index != winning_choice.index as usize)
+            .map(|(_, weight)| weight)
             .max_by(|&a, &b| a.cmp(b))
         {
             // Check if the remaining vote power can be used to overtake the current winning choice.
-            let remaining_vote_power = self.total_power - self.votes.total();
             match winning_choice.option_type {
                 MultipleChoiceOptionType::Standard => {
                     if winning_choice_power > *second_choice_power + remaining_vote_power {
@@ -283,6 +840,60 @@ impl MultipleChoiceProposal {
         }
         Ok(false)
     }
+
+    /// Ensure that with the remaining vote power, no option that did
+    /// not win an approval vote can overtake a winner, and that no
+    /// option can overtake "None of the above" to fill an empty seat.
+    fn are_approval_winners_final(&self, winners: &[CheckedMultipleChoiceOption]) -> bool {
+        let max_selections = match self.voting_strategy {
+            VotingStrategy::Approval { max_selections, .. } => max_selections as usize,
+            _ => return false,
+        };
+        let none_weight = match self.none_option() {
+            Ok(none_option) => self.votes.vote_weights[none_option.index as usize],
+            Err(_) => return false,
+        };
+        let remaining_vote_power = self.total_power - self.votes.total();
+        if remaining_vote_power.is_zero() {
+            return true;
+        }
+        let weight =
+            |choice: &CheckedMultipleChoiceOption| self.votes.vote_weights[choice.index as usize];
+
+        self.choices
+            .iter()
+            .filter(|choice| !winners.iter().any(|winner| winner.index == choice.index))
+            .all(|choice| {
+                let max_weight = weight(choice) + remaining_vote_power;
+                let overtakes_winner = winners.iter().any(|winner| weight(winner) <= max_weight);
+                let fills_seat = choice.option_type == MultipleChoiceOptionType::Standard
+                    && winners.len() < max_selections
+                    && max_weight > none_weight;
+                !overtakes_winner && !fills_seat
+            })
+    }
+
+    /// Returns true if this is a ranked choice proposal whose runoff
+    /// has not been tallied.
+    pub fn is_awaiting_runoff(&self) -> bool {
+        matches!(self.voting_strategy, VotingStrategy::RankedChoice { .. }) && self.runoff.is_none()
+    }
+
+    /// Returns true if this is a ranked choice proposal whose runoff
+    /// may be tallied, which is once voting has ended or, if revoting
+    /// is disabled, once all voting power has voted.
+    pub fn is_runoff_ready(&self, block: &BlockInfo) -> bool {
+        self.is_awaiting_runoff()
+            && (self.expiration.is_expired(block)
+                || (!self.allow_revoting && self.votes.total() == self.total_power))
+    }
+
+    fn none_option(&self) -> StdResult<&CheckedMultipleChoiceOption> {
+        self.choices
+            .iter()
+            .find(|choice| choice.option_type == MultipleChoiceOptionType::None)
+            .ok_or_else(|| StdError::not_found("none of the above option"))
+    }
 }
 
 #[cfg(test)]
@@ -290,7 +901,9 @@ mod tests {
     use super::*;
 
     use cosmwasm_std::testing::mock_env;
-    use dao_voting::multiple_choice::{MultipleChoiceOption, MultipleChoiceOptions};
+    use dao_voting::multiple_choice::{
+        FundingRequest, MultipleChoiceOption, MultipleChoiceOptions,
+    };
 
     fn create_proposal(
         block: &BlockInfo,
@@ -306,11 +919,13 @@ mod tests {
                 description: "multiple choice option 1".to_string(),
                 msgs: vec![],
                 title: "title".to_string(),
+                funding: None,
             },
             MultipleChoiceOption {
                 description: "multiple choice option 2".to_string(),
                 msgs: vec![],
                 title: "title".to_string(),
+                funding: None,
             },
         ];
 
@@ -336,6 +951,12 @@ mod tests {
             allow_revoting,
             min_voting_period: None,
             veto: None,
+            execute_after: None,
+            depends_on: vec![],
+            runoff: None,
+            tie_breaker: TieBreaker::Fail {},
+            weights_changed_at: vec![],
+            budget: None,
         }
     }
 
@@ -348,6 +969,8 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(1), Uint128::new(0), Uint128::new(0)],
+            power_cast: None,
+            participation: vec![],
         };
 
         let prop = create_proposal(
@@ -365,6 +988,8 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(0), Uint128::new(0), Uint128::new(1)],
+            power_cast: None,
+            participation: vec![],
         };
         let prop = create_proposal(
             &env.block,
@@ -381,6 +1006,8 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(1), Uint128::new(0), Uint128::new(0)],
+            power_cast: None,
+            participation: vec![],
         };
         let prop = create_proposal(
             &env.block,
@@ -397,6 +1024,8 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(1), Uint128::new(0), Uint128::new(0)],
+            power_cast: None,
+            participation: vec![],
         };
         let prop = create_proposal(
             &env.block,
@@ -413,6 +1042,8 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(50), Uint128::new(50), Uint128::new(0)],
+            power_cast: None,
+            participation: vec![],
         };
         let prop = create_proposal(
             &env.block,
@@ -429,6 +1060,8 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(50), Uint128::new(50), Uint128::new(0)],
+            power_cast: None,
+            participation: vec![],
         };
         let prop = create_proposal(
             &env.block,
@@ -455,6 +1088,8 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(1), Uint128::new(0), Uint128::new(0)],
+            power_cast: None,
+            participation: vec![],
         };
 
         let prop = create_proposal(
@@ -472,6 +1107,8 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(0), Uint128::new(0), Uint128::new(1)],
+            power_cast: None,
+            participation: vec![],
         };
         let prop = create_proposal(
             &env.block,
@@ -488,6 +1125,8 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(1), Uint128::new(0), Uint128::new(0)],
+            power_cast: None,
+            participation: vec![],
         };
         let prop = create_proposal(
             &env.block,
@@ -504,6 +1143,8 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(1), Uint128::new(0), Uint128::new(0)],
+            power_cast: None,
+            participation: vec![],
         };
         let prop = create_proposal(
             &env.block,
@@ -520,6 +1161,8 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(50), Uint128::new(50), Uint128::new(0)],
+            power_cast: None,
+            participation: vec![],
         };
         let prop = create_proposal(
             &env.block,
@@ -536,6 +1179,8 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(50), Uint128::new(50), Uint128::new(0)],
+            power_cast: None,
+            participation: vec![],
         };
         let prop = create_proposal(
             &env.block,
@@ -561,6 +1206,8 @@ mod tests {
         };
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(0), Uint128::new(50), Uint128::new(500)],
+            power_cast: None,
+            participation: vec![],
         };
         let prop = create_proposal(
             &env.block,
@@ -586,6 +1233,8 @@ mod tests {
         };
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(10), Uint128::new(0), Uint128::new(0)],
+            power_cast: None,
+            participation: vec![],
         };
         let prop = create_proposal(
             &env.block,
@@ -609,6 +1258,8 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(999999), Uint128::new(0), Uint128::new(0)],
+            power_cast: None,
+            participation: vec![],
         };
         let prop = create_proposal(
             &env.block,
@@ -632,6 +1283,8 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(9888889), Uint128::new(0), Uint128::new(0)],
+            power_cast: None,
+            participation: vec![],
         };
         let prop = create_proposal(
             &env.block,
@@ -657,6 +1310,8 @@ mod tests {
         };
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(7), Uint128::new(0), Uint128::new(6)],
+            power_cast: None,
+            participation: vec![],
         };
         let prop = create_proposal(
             &env.block,
@@ -694,6 +1349,8 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(7), Uint128::new(0), Uint128::new(0)],
+            power_cast: None,
+            participation: vec![],
         };
         let prop = create_proposal(
             &env.block,
@@ -732,6 +1389,8 @@ mod tests {
         };
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(6), Uint128::new(0), Uint128::new(0)],
+            power_cast: None,
+            participation: vec![],
         };
 
         let prop = create_proposal(
@@ -767,6 +1426,8 @@ mod tests {
         };
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(5), Uint128::new(5), Uint128::new(0)],
+            power_cast: None,
+            participation: vec![],
         };
 
         let prop = create_proposal(
@@ -809,6 +1470,8 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(81), Uint128::new(0), Uint128::new(0)],
+            power_cast: None,
+            participation: vec![],
         };
 
         let prop = create_proposal(
@@ -847,6 +1510,8 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(90), Uint128::new(0), Uint128::new(0)],
+            power_cast: None,
+            participation: vec![],
         };
 
         let prop = create_proposal(
@@ -862,6 +1527,8 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(50), Uint128::new(0), Uint128::new(0)],
+            power_cast: None,
+            participation: vec![],
         };
 
         let prop = create_proposal(
@@ -875,4 +1542,225 @@ mod tests {
         // No quorum reached & proposal has expired => rejection
         assert!(prop.is_rejected(&env.block).unwrap());
     }
+
+    #[test]
+    fn test_approval_vote_result() {
+        let env = mock_env();
+        let voting_strategy = VotingStrategy::Approval {
+            quorum: dao_voting::threshold::PercentageThreshold::Majority {},
+            max_selections: 2,
+        };
+
+        // Only options with more votes than none of the above win.
+        let votes = MultipleChoiceVotes {
+            vote_weights: vec![Uint128::new(5), Uint128::new(3), Uint128::new(4)],
+            power_cast: Some(Uint128::new(8)),
+            participation: vec![],
+        };
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy.clone(),
+            votes,
+            Uint128::new(8),
+            false,
+            false,
+        );
+        match prop.calculate_vote_result().unwrap() {
+            VoteResult::Winners(winners) => {
+                assert_eq!(winners.len(), 1);
+                assert_eq!(winners[0].index, 0);
+            }
+            _ => panic!("expected winners"),
+        }
+        // All voting power has voted, should be passed.
+        assert!(prop.is_passed(&env.block).unwrap());
+
+        // Outstanding voting power could still make option 2 a
+        // winner.
+        let votes = MultipleChoiceVotes {
+            vote_weights: vec![Uint128::new(5), Uint128::new(3), Uint128::new(4)],
+            power_cast: Some(Uint128::new(8)),
+            participation: vec![],
+        };
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy.clone(),
+            votes,
+            Uint128::new(10),
+            false,
+            false,
+        );
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(!prop.is_rejected(&env.block).unwrap());
+
+        // The last winner is tied with an option that did not win.
+        let voting_strategy = VotingStrategy::Approval {
+            quorum: dao_voting::threshold::PercentageThreshold::Majority {},
+            max_selections: 1,
+        };
+        let votes = MultipleChoiceVotes {
+            vote_weights: vec![Uint128::new(5), Uint128::new(5), Uint128::new(0)],
+            power_cast: Some(Uint128::new(6)),
+            participation: vec![],
+        };
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy,
+            votes,
+            Uint128::new(10),
+            true,
+            false,
+        );
+        assert!(matches!(
+            prop.calculate_vote_result().unwrap(),
+            VoteResult::Tie
+        ));
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(prop.is_rejected(&env.block).unwrap());
+    }
+
+    #[test]
+    fn test_tie_breakers() {
+        let env = mock_env();
+        let voting_strategy = VotingStrategy::SingleChoice {
+            quorum: dao_voting::threshold::PercentageThreshold::Majority {},
+        };
+        let tied = |tie_breaker: TieBreaker, total_power: u128, is_expired: bool| {
+            let mut prop = create_proposal(
+                &env.block,
+                voting_strategy.clone(),
+                MultipleChoiceVotes::zero(3),
+                Uint128::new(total_power),
+                is_expired,
+                false,
+            );
+            prop.tie_breaker = tie_breaker;
+            // Option 1 reaches five votes before option 0 does.
+            let vote = |option_id| MultipleChoiceVote {
+                option_id,
+                additional_option_ids: vec![],
+                scores: vec![],
+            };
+            prop.add_vote(&vote(1), Uint128::new(5)).unwrap();
+            prop.add_vote(&vote(0), Uint128::new(2)).unwrap();
+            prop.add_vote(&vote(0), Uint128::new(3)).unwrap();
+            prop
+        };
+        let winner = |prop: &MultipleChoiceProposal| match prop.calculate_vote_result().unwrap() {
+            VoteResult::SingleWinner(winner) => Some(winner.index),
+            _ => None,
+        };
+
+        let prop = tied(TieBreaker::Fail {}, 10, true);
+        assert_eq!(winner(&prop), None);
+        assert!(prop.is_rejected(&env.block).unwrap());
+        assert!(prop.tie_runoff_options().unwrap().is_empty());
+        assert!(prop.weights_changed_at.is_empty());
+
+        let prop = tied(TieBreaker::LowestIndex {}, 10, true);
+        assert_eq!(winner(&prop), Some(0));
+        assert!(prop.is_passed(&env.block).unwrap());
+
+        let prop = tied(TieBreaker::EarliestToReach {}, 10, true);
+        assert_eq!(prop.weights_changed_at, vec![3, 1, 0]);
+        assert_eq!(winner(&prop), Some(1));
+        assert!(prop.is_passed(&env.block).unwrap());
+
+        let prop = tied(TieBreaker::Runoff {}, 10, true);
+        assert_eq!(winner(&prop), None);
+        assert!(prop.is_rejected(&env.block).unwrap());
+        let options: Vec<u32> = prop
+            .tie_runoff_options()
+            .unwrap()
+            .iter()
+            .map(|option| option.index)
+            .collect();
+        assert_eq!(options, vec![0, 1]);
+
+        // Outstanding voting power could still break the tie, so the
+        // proposal has not passed.
+        let prop = tied(TieBreaker::LowestIndex {}, 11, false);
+        assert_eq!(winner(&prop), Some(0));
+        assert!(!prop.is_passed(&env.block).unwrap());
+        // Once all voting power has voted, the tie broken winner has
+        // passed.
+        let prop = tied(TieBreaker::LowestIndex {}, 10, false);
+        assert!(prop.is_passed(&env.block).unwrap());
+
+        // Ties with none of the above are not broken.
+        let mut prop = tied(TieBreaker::LowestIndex {}, 15, true);
+        prop.add_vote(
+            &MultipleChoiceVote {
+                option_id: 2,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
+            Uint128::new(5),
+        )
+        .unwrap();
+        assert_eq!(winner(&prop), None);
+        assert!(prop.is_rejected(&env.block).unwrap());
+    }
+
+    #[test]
+    fn test_budget_allocations() {
+        let env = mock_env();
+        let budget_proposal = |min_share: u64, vote_weights: [u128; 3]| {
+            let votes = MultipleChoiceVotes {
+                vote_weights: vote_weights.into_iter().map(Uint128::new).collect(),
+                power_cast: None,
+                participation: vec![],
+            };
+            let mut prop = create_proposal(
+                &env.block,
+                VotingStrategy::Budget {
+                    quorum: dao_voting::threshold::PercentageThreshold::Majority {},
+                    min_share: Decimal::percent(min_share),
+                },
+                votes,
+                Uint128::new(100),
+                true,
+                false,
+            );
+            prop.budget = Some(CheckedBudget {
+                denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
+                amount: Uint128::new(100),
+            });
+            for (choice, requested) in prop.choices.iter_mut().zip([60, 100]) {
+                choice.funding = Some(FundingRequest {
+                    recipient: format!("recipient{}", choice.index),
+                    amount: Uint128::new(requested),
+                });
+            }
+            prop
+        };
+        let allocations = |prop: &MultipleChoiceProposal| -> Vec<(u32, u128)> {
+            prop.budget_allocations()
+                .into_iter()
+                .map(|(choice, amount)| (choice.index, amount.u128()))
+                .collect()
+        };
+
+        // Votes for none of the above are not allocated.
+        let prop = budget_proposal(25, [50, 30, 20]);
+        assert_eq!(allocations(&prop), vec![(0, 50), (1, 30)]);
+        assert!(prop.is_passed(&env.block).unwrap());
+
+        // Options below the minimum share are not funded.
+        let prop = budget_proposal(40, [50, 30, 20]);
+        assert_eq!(allocations(&prop), vec![(0, 50)]);
+
+        // Options are funded no more than they requested.
+        let prop = budget_proposal(25, [70, 30, 0]);
+        assert_eq!(allocations(&prop), vec![(0, 60), (1, 30)]);
+
+        // Proposals that fund no options are rejected.
+        let prop = budget_proposal(25, [10, 10, 80]);
+        assert!(allocations(&prop).is_empty());
+        assert!(matches!(
+            prop.calculate_vote_result().unwrap(),
+            VoteResult::SingleWinner(choice) if choice.option_type == MultipleChoiceOptionType::None
+        ));
+        assert!(prop.is_rejected(&env.block).unwrap());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{proposal::MultipleChoiceProposal, state::Config};
+use crate::{proposal::MultipleChoiceProposal, runoff::RunoffRound, state::Config};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint128};
 
@@ -39,6 +39,25 @@ pub struct VoteListResponse {
     pub votes: Vec<VoteInfo>,
 }
 
+/// Information about a vote cast by a voter on a proposal.
+#[cw_serde]
+pub struct VoterVoteInfo {
+    /// The proposal that was voted on.
+    pub proposal_id: u64,
+    /// Position on the vote.
+    pub vote: MultipleChoiceVote,
+    /// The voting power behind the vote.
+    pub power: Uint128,
+    /// The rationale behind the vote.
+    pub rationale: Option<String>,
+}
+
+/// A list of votes returned by `ListVotesByVoter`.
+#[cw_serde]
+pub struct VoterVoteListResponse {
+    pub votes: Vec<VoterVoteInfo>,
+}
+
 #[cw_serde]
 pub struct VoterResponse {
     pub weight: Option<Uint128>,
@@ -48,3 +67,10 @@ pub struct VoterResponse {
 pub struct ConfigResponse {
     pub config: Config,
 }
+
+/// The rounds of a ranked choice proposal's instant-runoff. Empty
+/// until the runoff has been tallied.
+#[cw_serde]
+pub struct RunoffRoundsResponse {
+    pub rounds: Vec<RunoffRound>,
+}
@@ -0,0 +1,373 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Order, StdResult, Storage, Uint128};
+use cw_storage_plus::Bound;
+use dao_voting::multiple_choice::MultipleChoiceVote;
+
+use crate::state::{RANKINGS, RUNOFF_ROUNDS, RUNOFF_TALLIES};
+
+/// The maximum number of rankings counted toward a runoff in one
+/// transaction. Runoffs with more distinct rankings, or that need
+/// more rounds, are tallied over several transactions.
+pub const MAX_RANKINGS_PER_TALLY: usize = 100;
+
+/// The outcome of a ranked choice proposal's instant-runoff.
+#[cw_serde]
+pub enum RunoffResult {
+    /// The option that won a majority of the remaining votes.
+    Winner { option_id: u32 },
+    /// Every remaining option was tied.
+    Tie {
+        /// The tied options.
+        #[serde(default)]
+        option_ids: Vec<u32>,
+    },
+}
+
+/// A round of an instant-runoff.
+#[cw_serde]
+pub struct RunoffRound {
+    /// The voting power counted toward each option this round,
+    /// indexed by option ID. Eliminated options count zero.
+    pub vote_weights: Vec<Uint128>,
+    /// The voting power of ballots whose ranked options have all
+    /// been eliminated.
+    pub exhausted: Uint128,
+    /// The options with the fewest votes, which were eliminated at
+    /// the end of this round. Empty in the final round.
+    pub eliminated: Vec<u32>,
+}
+
+/// An instant-runoff that is being tallied.
+#[cw_serde]
+pub struct RunoffTally {
+    /// Whether each option, indexed by option ID, has not been
+    /// eliminated.
+    pub active: Vec<bool>,
+    /// The rounds that have been completed.
+    pub rounds: Vec<RunoffRound>,
+    /// The voting power counted toward each option so far this round.
+    pub vote_weights: Vec<Uint128>,
+    /// The exhausted voting power counted so far this round.
+    pub exhausted: Uint128,
+    /// The key of the last ranking counted this round, if any.
+    pub last_counted: Option<Vec<u8>>,
+}
+
+impl RunoffTally {
+    pub fn new(num_options: usize) -> Self {
+        Self {
+            active: vec![true; num_options],
+            rounds: vec![],
+            vote_weights: vec![Uint128::zero(); num_options],
+            exhausted: Uint128::zero(),
+            last_counted: None,
+        }
+    }
+
+    /// Counts `weight` toward the most preferred option of `ranking`
+    /// that has not been eliminated.
+    fn count(&mut self, ranking: &[u32], weight: Uint128) {
+        match ranking.iter().find(|id| self.active[**id as usize]) {
+            Some(id) => self.vote_weights[*id as usize] += weight,
+            None => self.exhausted += weight,
+        }
+    }
+
+    /// Ends the current round once every ranking has been counted
+    /// toward it. An option wins once it has a majority of the votes,
+    /// otherwise the options with the fewest votes are eliminated. If
+    /// every remaining option has the fewest votes, the result is a
+    /// tie. Returns the result once the runoff is over.
+    fn end_round(&mut self) -> Option<RunoffResult> {
+        let num_options = self.active.len();
+        let vote_weights =
+            std::mem::replace(&mut self.vote_weights, vec![Uint128::zero(); num_options]);
+        let exhausted = std::mem::take(&mut self.exhausted);
+        self.last_counted = None;
+
+        let counted: Uint128 = vote_weights.iter().sum();
+        let remaining: Vec<usize> = (0..num_options).filter(|i| self.active[*i]).collect();
+
+        let winner = match remaining.as_slice() {
+            [only] => Some(*only),
+            _ => remaining
+                .iter()
+                .copied()
+                .find(|i| vote_weights[*i] > counted - vote_weights[*i]),
+        };
+        if let Some(winner) = winner {
+            self.rounds.push(RunoffRound {
+                vote_weights,
+                exhausted,
+                eliminated: vec![],
+            });
+            return Some(RunoffResult::Winner {
+                option_id: winner as u32,
+            });
+        }
+
+        let fewest = remaining
+            .iter()
+            .map(|i| vote_weights[*i])
+            .min()
+            .unwrap_or_default();
+        let eliminated: Vec<u32> = remaining
+            .iter()
+            .filter(|i| vote_weights[**i] == fewest)
+            .map(|i| *i as u32)
+            .collect();
+        if eliminated.len() == remaining.len() {
+            self.rounds.push(RunoffRound {
+                vote_weights,
+                exhausted,
+                eliminated: vec![],
+            });
+            return Some(RunoffResult::Tie {
+                option_ids: eliminated,
+            });
+        }
+        for id in &eliminated {
+            self.active[*id as usize] = false;
+        }
+        self.rounds.push(RunoffRound {
+            vote_weights,
+            exhausted,
+            eliminated,
+        });
+        None
+    }
+}
+
+/// Encodes a ranked choice vote as a storage key. Option IDs are less
+/// than `MAX_NUM_CHOICES` plus one, so each fits in a byte.
+fn ranking_key(vote: &MultipleChoiceVote) -> Vec<u8> {
+    vote.option_ids().map(|id| id as u8).collect()
+}
+
+/// Adds `power` to the voting power that ranked the options of
+/// `proposal_id` as `vote` does.
+pub fn add_ranking(
+    storage: &mut dyn Storage,
+    proposal_id: u64,
+    vote: &MultipleChoiceVote,
+    power: Uint128,
+) -> StdResult<()> {
+    RANKINGS.update(storage, (proposal_id, ranking_key(vote)), |weight| {
+        weight
+            .unwrap_or_default()
+            .checked_add(power)
+            .map_err(Into::into)
+    })?;
+    Ok(())
+}
+
+/// Removes `power` from the voting power that ranked the options of
+/// `proposal_id` as `vote` does.
+pub fn remove_ranking(
+    storage: &mut dyn Storage,
+    proposal_id: u64,
+    vote: &MultipleChoiceVote,
+    power: Uint128,
+) -> StdResult<()> {
+    let key = (proposal_id, ranking_key(vote));
+    let weight = RANKINGS
+        .may_load(storage, key.clone())?
+        .unwrap_or_default()
+        .checked_sub(power)?;
+    if weight.is_zero() {
+        RANKINGS.remove(storage, key);
+    } else {
+        RANKINGS.save(storage, key, &weight)?;
+    }
+    Ok(())
+}
+
+/// Continues the instant-runoff for `proposal_id` over the rankings
+/// that have been cast, counting at most `limit` rankings. There is
+/// one ranking per distinct ballot, and at most one round per option.
+/// Returns the result and saves the runoff's rounds once it is over,
+/// otherwise saves its progress.
+pub fn tally_runoff(
+    storage: &mut dyn Storage,
+    proposal_id: u64,
+    num_options: usize,
+    limit: usize,
+) -> StdResult<Option<RunoffResult>> {
+    let mut tally = RUNOFF_TALLIES
+        .may_load(storage, proposal_id)?
+        .unwrap_or_else(|| RunoffTally::new(num_options));
+    let mut budget = limit;
+    loop {
+        let rankings = RANKINGS
+            .prefix(proposal_id)
+            .range(
+                storage,
+                tally.last_counted.clone().map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .take(budget)
+            .collect::<StdResult<Vec<(Vec<u8>, Uint128)>>>()?;
+        let round_counted = rankings.len() < budget;
+        budget -= rankings.len();
+        for (key, weight) in rankings {
+            let ranking: Vec<u32> = key.iter().copied().map(u32::from).collect();
+            tally.count(&ranking, weight);
+            tally.last_counted = Some(key);
+        }
+
+        if !round_counted {
+            RUNOFF_TALLIES.save(storage, proposal_id, &tally)?;
+            return Ok(None);
+        }
+        if let Some(result) = tally.end_round() {
+            RUNOFF_TALLIES.remove(storage, proposal_id);
+            RUNOFF_ROUNDS.save(storage, proposal_id, &tally.rounds)?;
+            return Ok(Some(result));
+        }
+    }
+}
+
+/// Tallies `rankings`, pairs of option IDs in order of preference and
+/// the voting power that ranked them so, by instant-runoff. Each
+/// round, every ranking counts toward its most preferred option that
+/// has not been eliminated. See `RunoffTally::end_round` for how
+/// rounds end.
+pub fn instant_runoff(
+    num_options: usize,
+    rankings: &[(Vec<u32>, Uint128)],
+) -> (RunoffResult, Vec<RunoffRound>) {
+    let mut tally = RunoffTally::new(num_options);
+    loop {
+        for (ranking, weight) in rankings {
+            tally.count(ranking, *weight);
+        }
+        if let Some(result) = tally.end_round() {
+            return (result, tally.rounds);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockStorage;
+
+    use super::*;
+
+    fn rankings(rankings: &[(&[u32], u128)]) -> Vec<(Vec<u32>, Uint128)> {
+        rankings
+            .iter()
+            .map(|(ranking, weight)| (ranking.to_vec(), Uint128::new(*weight)))
+            .collect()
+    }
+
+    fn weights(weights: &[u128]) -> Vec<Uint128> {
+        weights.iter().copied().map(Uint128::new).collect()
+    }
+
+    #[test]
+    fn test_majority_in_first_round() {
+        let (result, rounds) = instant_runoff(3, &rankings(&[(&[0], 6), (&[1, 0], 4)]));
+        assert_eq!(result, RunoffResult::Winner { option_id: 0 });
+        assert_eq!(
+            rounds,
+            vec![RunoffRound {
+                vote_weights: weights(&[6, 4, 0]),
+                exhausted: Uint128::zero(),
+                eliminated: vec![],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_votes_transfer() {
+        // Option 0 leads on first preferences, but option 1 wins
+        // once option 2 is eliminated.
+        let (result, rounds) =
+            instant_runoff(4, &rankings(&[(&[0], 40), (&[1, 0], 35), (&[2, 1], 25)]));
+        assert_eq!(result, RunoffResult::Winner { option_id: 1 });
+        assert_eq!(
+            rounds,
+            vec![
+                RunoffRound {
+                    vote_weights: weights(&[40, 35, 25, 0]),
+                    exhausted: Uint128::zero(),
+                    eliminated: vec![3],
+                },
+                RunoffRound {
+                    vote_weights: weights(&[40, 35, 25, 0]),
+                    exhausted: Uint128::zero(),
+                    eliminated: vec![2],
+                },
+                RunoffRound {
+                    vote_weights: weights(&[40, 60, 0, 0]),
+                    exhausted: Uint128::zero(),
+                    eliminated: vec![],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_exhausted_ballots() {
+        let (result, rounds) = instant_runoff(
+            3,
+            &rankings(&[(&[0], 5), (&[1], 4), (&[2], 1), (&[2, 1], 2)]),
+        );
+        assert_eq!(result, RunoffResult::Winner { option_id: 1 });
+        assert_eq!(
+            rounds.last().unwrap(),
+            &RunoffRound {
+                vote_weights: weights(&[5, 6, 0]),
+                exhausted: Uint128::one(),
+                eliminated: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn test_tie() {
+        let (result, rounds) = instant_runoff(3, &rankings(&[(&[0], 5), (&[1], 5)]));
+        assert_eq!(
+            result,
+            RunoffResult::Tie {
+                option_ids: vec![0, 1]
+            }
+        );
+        assert_eq!(rounds.len(), 2);
+
+        let (result, rounds) = instant_runoff(3, &[]);
+        assert_eq!(
+            result,
+            RunoffResult::Tie {
+                option_ids: vec![0, 1, 2]
+            }
+        );
+        assert_eq!(rounds.len(), 1);
+    }
+
+    #[test]
+    fn test_tally_in_batches() {
+        let mut storage = MockStorage::new();
+        let cast = rankings(&[(&[0], 40), (&[1, 0], 35), (&[2, 1], 25)]);
+        for (ranking, weight) in &cast {
+            let key = ranking.iter().map(|id| *id as u8).collect();
+            RANKINGS.save(&mut storage, (1, key), weight).unwrap();
+        }
+
+        // Three rounds over three rankings, counting two at a time.
+        let mut batches = 0;
+        let result = loop {
+            batches += 1;
+            if let Some(result) = tally_runoff(&mut storage, 1, 4, 2).unwrap() {
+                break result;
+            }
+        };
+        assert_eq!(batches, 5);
+
+        let (expected, rounds) = instant_runoff(4, &cast);
+        assert_eq!(result, expected);
+        assert_eq!(RUNOFF_ROUNDS.load(&storage, 1).unwrap(), rounds);
+        assert!(!RUNOFF_TALLIES.has(&storage, 1));
+    }
+}
@@ -1,13 +1,17 @@
-use crate::proposal::MultipleChoiceProposal;
+use crate::{
+    proposal::MultipleChoiceProposal,
+    runoff::{RunoffRound, RunoffTally},
+};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Empty, Uint128};
 use cw_hooks::Hooks;
-use cw_storage_plus::{Item, Map};
-use cw_utils::Duration;
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
+use cw_utils::{Duration, Expiration};
 use dao_voting::{
-    multiple_choice::{MultipleChoiceVote, VotingStrategy},
+    multiple_choice::{MultipleChoiceVote, TieBreaker, VotingStrategy},
     pre_propose::ProposalCreationPolicy,
     veto::VetoConfig,
+    voting::VotingPeriodRange,
 };
 
 /// The proposal module's configuration.
@@ -47,6 +51,15 @@ pub struct Config {
     /// Optional veto configuration. If set to `None`, veto option
     /// is disabled. Otherwise contains the configuration for veto flow.
     pub veto: Option<VetoConfig>,
+    /// If set, proposers may choose a voting period within this range
+    /// when creating a proposal. Otherwise, proposals are open for
+    /// `max_voting_period`.
+    #[serde(default)]
+    pub voting_period_range: Option<VotingPeriodRange>,
+    /// How proposals whose leading options are tied are decided.
+    /// Proposals record the tie breaker they were created with.
+    #[serde(default)]
+    pub tie_breaker: TieBreaker,
 }
 
 // Each ballot stores a chosen vote and corresponding voting power and rationale.
@@ -63,8 +76,39 @@ pub struct Ballot {
 /// The current top level config for the module.
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
-pub const PROPOSALS: Map<u64, MultipleChoiceProposal> = Map::new("proposals");
 pub const BALLOTS: Map<(u64, &Addr), Ballot> = Map::new("ballots");
+/// The proposals each address has voted on, keyed by voter and
+/// proposal ID. A reverse index of `BALLOTS`.
+pub const VOTER_PROPOSALS: Map<(&Addr, u64), Empty> = Map::new("voter_proposals");
+/// The nonce the next signed vote from each voter must use.
+pub const VOTE_NONCES: Map<&Addr, u64> = Map::new("vote_nonces");
+/// The vetoers who have vetoed a proposal, keyed by proposal ID. A
+/// proposal is vetoed once the veto threshold is reached.
+pub const VETO_VOTES: Map<u64, Vec<Addr>> = Map::new("veto_votes");
+/// The vetoers who have approved executing a timelocked proposal
+/// early, keyed by proposal ID. A proposal may be executed early once
+/// the veto threshold is reached.
+pub const EARLY_EXECUTE_VOTES: Map<u64, Vec<Addr>> = Map::new("early_execute_votes");
+/// The voting power behind each distinct ranking cast on a ranked
+/// choice proposal, keyed by proposal ID and the ranked option IDs.
+pub const RANKINGS: Map<(u64, Vec<u8>), Uint128> = Map::new("rankings");
+/// The rounds of each ranked choice proposal's instant-runoff, saved
+/// once it has been tallied.
+pub const RUNOFF_ROUNDS: Map<u64, Vec<RunoffRound>> = Map::new("runoff_rounds");
+/// The progress of ranked choice proposals' instant-runoffs that are
+/// being tallied over several transactions.
+pub const RUNOFF_TALLIES: Map<u64, RunoffTally> = Map::new("runoff_tallies");
+/// The ID of the tied proposal each tie runoff was created to decide,
+/// keyed by the runoff's ID.
+pub const TIE_RUNOFFS: Map<u64, u64> = Map::new("tie_runoffs");
+/// The key of the last ballot added to `VOTER_PROPOSALS` after
+/// migrating from a version without it, or `None` if none have been
+/// added yet. Removed once every ballot has been indexed.
+pub const BALLOT_INDEX_CURSOR: Item<Option<(u64, Addr)>> = Item::new("ballot_index_cursor");
+/// The ID of the last proposal saved again to populate the proposal
+/// indexes after migrating from a version without them. Removed once
+/// every proposal has been indexed.
+pub const PROPOSAL_INDEX_CURSOR: Item<u64> = Item::new("proposal_index_cursor");
 /// Consumers of proposal state change hooks.
 pub const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
 /// Consumers of vote hooks.
@@ -72,3 +116,60 @@ pub const VOTE_HOOKS: Hooks = Hooks::new("vote_hooks");
 /// The address of the pre-propose module associated with this
 /// proposal module (if any).
 pub const CREATION_POLICY: Item<ProposalCreationPolicy> = Item::new("creation_policy");
+
+pub struct ProposalIndexes<'a> {
+    pub status: MultiIndex<'a, String, MultipleChoiceProposal, u64>,
+    pub proposer: MultiIndex<'a, Addr, MultipleChoiceProposal, u64>,
+    pub status_expiration: MultiIndex<'a, (u8, u64), MultipleChoiceProposal, u64>,
+}
+
+impl<'a> IndexList<MultipleChoiceProposal> for ProposalIndexes<'a> {
+    fn get_indexes(
+        &'_ self,
+    ) -> Box<dyn Iterator<Item = &'_ dyn Index<MultipleChoiceProposal>> + '_> {
+        let v: Vec<&dyn Index<MultipleChoiceProposal>> =
+            vec![&self.status, &self.proposer, &self.status_expiration];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Prefixes the keys of `ProposalIndexes::status_expiration` with
+/// the units of each expiration.
+pub const EXPIRES_AT_HEIGHT: u8 = 0;
+pub const EXPIRES_AT_TIME: u8 = 1;
+pub const EXPIRES_NEVER: u8 = 2;
+
+/// The key of an expiration in `ProposalIndexes::status_expiration`.
+/// Keys sort by units and then by when they expire.
+pub fn expiration_key(expiration: Expiration) -> (u8, u64) {
+    match expiration {
+        Expiration::AtHeight(height) => (EXPIRES_AT_HEIGHT, height),
+        Expiration::AtTime(time) => (EXPIRES_AT_TIME, time.nanos()),
+        Expiration::Never {} => (EXPIRES_NEVER, 0),
+    }
+}
+
+/// The module's proposals, indexed by their stored status, by
+/// proposer, and by when their stored status will lazily change.
+/// Statuses are updated lazily, so the status index may lag behind a
+/// proposal's current status. See `Status::stored_keys`.
+pub fn proposals<'a>() -> IndexedMap<'a, u64, MultipleChoiceProposal, ProposalIndexes<'a>> {
+    let indexes = ProposalIndexes {
+        status: MultiIndex::new(
+            |_pk: &[u8], p: &MultipleChoiceProposal| p.status.key().to_string(),
+            "proposals",
+            "proposals__status",
+        ),
+        proposer: MultiIndex::new(
+            |_pk: &[u8], p: &MultipleChoiceProposal| p.proposer.clone(),
+            "proposals",
+            "proposals__proposer",
+        ),
+        status_expiration: MultiIndex::new(
+            |_pk: &[u8], p: &MultipleChoiceProposal| expiration_key(p.status_expiration()),
+            "proposals",
+            "proposals__status_expiration",
+        ),
+    };
+    IndexedMap::new("proposals", indexes)
+}
@@ -43,11 +43,13 @@ fn setup_test(_messages: Vec<CosmosMsg>) -> CommonTest {
             title: "title 1".to_string(),
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            funding: None,
         },
         MultipleChoiceOption {
             title: "title 2".to_string(),
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            funding: None,
         },
     ];
 
@@ -112,7 +114,11 @@ fn test_execute_proposal_rejected_closed() {
     assert_eq!(prop.proposal.status, Status::Open);
 
     // Vote on both options to reject the proposal
-    let vote = MultipleChoiceVote { option_id: 0 };
+    let vote = MultipleChoiceVote {
+        option_id: 0,
+        additional_option_ids: vec![],
+        scores: vec![],
+    };
     app.execute_contract(
         Addr::unchecked(CREATOR_ADDR),
         proposal_module.clone(),
@@ -125,7 +131,11 @@ fn test_execute_proposal_rejected_closed() {
     )
     .unwrap();
 
-    let vote = MultipleChoiceVote { option_id: 1 };
+    let vote = MultipleChoiceVote {
+        option_id: 1,
+        additional_option_ids: vec![],
+        scores: vec![],
+    };
     app.execute_contract(
         Addr::unchecked(ALTERNATIVE_ADDR),
         proposal_module.clone(),
@@ -198,13 +208,17 @@ fn test_execute_proposal_more_than_once() {
     app.update_block(next_block);
 
     // get the proposal to pass
-    let vote = MultipleChoiceVote { option_id: 0 };
+    let vote = MultipleChoiceVote {
+        option_id: 0,
+        additional_option_ids: vec![],
+        scores: vec![],
+    };
     app.execute_contract(
         Addr::unchecked(CREATOR_ADDR),
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id,
-            vote,
+            vote: vote.clone(),
             rationale: None,
         },
         &[],
@@ -283,6 +297,8 @@ pub fn test_allow_voting_after_proposal_execution_pre_expiration_cw20() {
         ),
         close_proposal_on_execution_failure: true,
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     let core_addr = instantiate_with_multiple_staked_balances_governance(
@@ -322,11 +338,13 @@ pub fn test_allow_voting_after_proposal_execution_pre_expiration_cw20() {
                 funds: vec![],
             }
             .into()],
+            funding: None,
         },
         MultipleChoiceOption {
             title: "title 2".to_string(),
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            funding: None,
         },
     ];
 
@@ -340,7 +358,11 @@ pub fn test_allow_voting_after_proposal_execution_pre_expiration_cw20() {
 
     app.update_block(next_block);
 
-    let vote = MultipleChoiceVote { option_id: 0 };
+    let vote = MultipleChoiceVote {
+        option_id: 0,
+        additional_option_ids: vec![],
+        scores: vec![],
+    };
 
     // someone votes enough to pass the proposal
     app.execute_contract(
@@ -365,7 +387,11 @@ pub fn test_allow_voting_after_proposal_execution_pre_expiration_cw20() {
 
     // someone wakes up and casts their vote to express their
     // opinion (not affecting the result of proposal)
-    let vote = MultipleChoiceVote { option_id: 1 };
+    let vote = MultipleChoiceVote {
+        option_id: 1,
+        additional_option_ids: vec![],
+        scores: vec![],
+    };
     app.execute_contract(
         Addr::unchecked(ALTERNATIVE_ADDR),
         proposal_module.clone(),
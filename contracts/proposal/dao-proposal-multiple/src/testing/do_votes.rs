@@ -131,6 +131,8 @@ where
         close_proposal_on_execution_failure: true,
         pre_propose_info,
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     let governance_addr = setup_governance(&mut app, instantiate, Some(initial_balances));
@@ -195,11 +197,13 @@ where
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
 
@@ -214,6 +218,10 @@ where
                 description: "This is a simple text proposal".to_string(),
                 choices: mc_options,
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
+                budget: None,
             },
         },
         &funds,
@@ -234,7 +242,7 @@ where
             govmod.clone(),
             &ExecuteMsg::Vote {
                 proposal_id: 1,
-                vote: position,
+                vote: position.clone(),
                 rationale: None,
             },
             &[],
@@ -334,7 +342,11 @@ where
     do_test_votes(
         vec![TestMultipleChoiceVote {
             voter: "bluenote".to_string(),
-            position: MultipleChoiceVote { option_id: 0 },
+            position: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -350,7 +362,11 @@ where
     do_test_votes(
         vec![TestMultipleChoiceVote {
             voter: "bluenote".to_string(),
-            position: MultipleChoiceVote { option_id: 2 },
+            position: MultipleChoiceVote {
+                option_id: 2,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -371,7 +387,11 @@ where
     do_test_votes(
         vec![TestMultipleChoiceVote {
             voter: "bluenote".to_string(),
-            position: MultipleChoiceVote { option_id: 10 },
+            position: MultipleChoiceVote {
+                option_id: 10,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::No,
         }],
@@ -391,7 +411,11 @@ where
     do_votes(
         vec![TestMultipleChoiceVote {
             voter: "bluenote".to_string(),
-            position: MultipleChoiceVote { option_id: 0 },
+            position: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             weight: Uint128::new(u128::MAX),
             should_execute: ShouldExecute::Yes,
         }],
@@ -407,13 +431,21 @@ where
         vec![
             TestMultipleChoiceVote {
                 voter: "bluenote".to_string(),
-                position: MultipleChoiceVote { option_id: 0 },
+                position: MultipleChoiceVote {
+                    option_id: 0,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
                 weight: Uint128::new(1),
                 should_execute: ShouldExecute::Yes,
             },
             TestMultipleChoiceVote {
                 voter: "bob".to_string(),
-                position: MultipleChoiceVote { option_id: 1 },
+                position: MultipleChoiceVote {
+                    option_id: 1,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
                 weight: Uint128::new(u128::MAX - 1),
                 should_execute: ShouldExecute::Yes,
             },
@@ -435,13 +467,21 @@ where
         vec![
             TestMultipleChoiceVote {
                 voter: "bluenote".to_string(),
-                position: MultipleChoiceVote { option_id: 0 },
+                position: MultipleChoiceVote {
+                    option_id: 0,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
                 weight: Uint128::new(1),
                 should_execute: ShouldExecute::Yes,
             },
             TestMultipleChoiceVote {
                 voter: "bob".to_string(),
-                position: MultipleChoiceVote { option_id: 1 },
+                position: MultipleChoiceVote {
+                    option_id: 1,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
                 weight: Uint128::new(1),
                 should_execute: ShouldExecute::Yes,
             },
@@ -462,7 +502,11 @@ where
     do_votes(
         vec![TestMultipleChoiceVote {
             voter: "bluenote".to_string(),
-            position: MultipleChoiceVote { option_id: 2 }, // the last index is none of the above
+            position: MultipleChoiceVote {
+                option_id: 2,
+                additional_option_ids: vec![],
+                scores: vec![],
+            }, // the last index is none of the above
             weight: Uint128::new(u64::MAX.into()),
             should_execute: ShouldExecute::Yes,
         }],
@@ -478,7 +522,11 @@ where
         do_votes(
             vec![TestMultipleChoiceVote {
                 voter: "bluenote".to_string(),
-                position: MultipleChoiceVote { option_id: 2 },
+                position: MultipleChoiceVote {
+                    option_id: 2,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
                 weight: Uint128::new(u64::MAX.into()),
                 should_execute: ShouldExecute::Yes,
             }],
@@ -503,7 +551,11 @@ where
     do_votes(
         vec![TestMultipleChoiceVote {
             voter: "bluenote".to_string(),
-            position: MultipleChoiceVote { option_id: 0 },
+            position: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             weight: Uint128::new(1),
             should_execute: ShouldExecute::Yes,
         }],
@@ -518,7 +570,11 @@ where
     do_votes(
         vec![TestMultipleChoiceVote {
             voter: "bluenote".to_string(),
-            position: MultipleChoiceVote { option_id: 0 },
+            position: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -535,7 +591,11 @@ where
     do_votes(
         vec![TestMultipleChoiceVote {
             voter: "bluenote".to_string(),
-            position: MultipleChoiceVote { option_id: 1 },
+            position: MultipleChoiceVote {
+                option_id: 1,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             weight: Uint128::new(9999999),
             should_execute: ShouldExecute::Yes,
         }],
@@ -551,7 +611,11 @@ where
     do_votes(
         vec![TestMultipleChoiceVote {
             voter: "bluenote".to_string(),
-            position: MultipleChoiceVote { option_id: 2 },
+            position: MultipleChoiceVote {
+                option_id: 2,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             weight: Uint128::new(1),
             should_execute: ShouldExecute::Yes,
         }],
@@ -572,13 +636,21 @@ where
         vec![
             TestMultipleChoiceVote {
                 voter: "bluenote".to_string(),
-                position: MultipleChoiceVote { option_id: 1 },
+                position: MultipleChoiceVote {
+                    option_id: 1,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
                 weight: Uint128::new(2),
                 should_execute: ShouldExecute::Yes,
             },
             TestMultipleChoiceVote {
                 voter: "bluenote".to_string(),
-                position: MultipleChoiceVote { option_id: 1 },
+                position: MultipleChoiceVote {
+                    option_id: 1,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
                 weight: Uint128::new(2),
                 should_execute: ShouldExecute::No,
             },
@@ -608,13 +680,21 @@ where
         vec![
             TestMultipleChoiceVote {
                 voter: "bluenote".to_string(),
-                position: MultipleChoiceVote { option_id: 0 },
+                position: MultipleChoiceVote {
+                    option_id: 0,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
                 weight: Uint128::new(10),
                 should_execute: ShouldExecute::Yes,
             },
             TestMultipleChoiceVote {
                 voter: "blue".to_string(),
-                position: MultipleChoiceVote { option_id: 0 },
+                position: MultipleChoiceVote {
+                    option_id: 0,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
                 weight: Uint128::new(10),
                 should_execute: ShouldExecute::Yes,
             },
@@ -632,13 +712,21 @@ where
         vec![
             TestMultipleChoiceVote {
                 voter: "bluenote".to_string(),
-                position: MultipleChoiceVote { option_id: 0 },
+                position: MultipleChoiceVote {
+                    option_id: 0,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
                 weight: Uint128::new(10),
                 should_execute: ShouldExecute::Yes,
             },
             TestMultipleChoiceVote {
                 voter: "blue".to_string(),
-                position: MultipleChoiceVote { option_id: 0 },
+                position: MultipleChoiceVote {
+                    option_id: 0,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
                 weight: Uint128::new(10),
                 should_execute: ShouldExecute::Yes,
             },
@@ -659,7 +747,11 @@ where
     do_votes(
         vec![TestMultipleChoiceVote {
             voter: "bluenote".to_string(),
-            position: MultipleChoiceVote { option_id: 0 },
+            position: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             weight: Uint128::new(60),
             should_execute: ShouldExecute::Yes,
         }],
@@ -675,7 +767,11 @@ where
     do_votes(
         vec![TestMultipleChoiceVote {
             voter: "bluenote".to_string(),
-            position: MultipleChoiceVote { option_id: 2 },
+            position: MultipleChoiceVote {
+                option_id: 2,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             weight: Uint128::new(60),
             should_execute: ShouldExecute::Yes,
         }],
@@ -718,7 +814,11 @@ where
             .enumerate()
             .map(|(idx, weight)| TestMultipleChoiceVote {
                 voter: format!("zero_{idx}"),
-                position: MultipleChoiceVote { option_id: 0 },
+                position: MultipleChoiceVote {
+                    option_id: 0,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
                 weight: Uint128::new(weight as u128),
                 should_execute: ShouldExecute::Meh,
             });
@@ -727,7 +827,11 @@ where
             .enumerate()
             .map(|(idx, weight)| TestMultipleChoiceVote {
                 voter: format!("one_{idx}"),
-                position: MultipleChoiceVote { option_id: 1 },
+                position: MultipleChoiceVote {
+                    option_id: 1,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
                 weight: Uint128::new(weight as u128),
                 should_execute: ShouldExecute::Meh,
             });
@@ -737,7 +841,11 @@ where
             .enumerate()
             .map(|(idx, weight)| TestMultipleChoiceVote {
                 voter: format!("none_{idx}"),
-                position: MultipleChoiceVote { option_id: 2 },
+                position: MultipleChoiceVote {
+                    option_id: 2,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
                 weight: Uint128::new(weight as u128),
                 should_execute: ShouldExecute::Meh,
             });
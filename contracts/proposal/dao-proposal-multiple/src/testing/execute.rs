@@ -77,6 +77,10 @@ pub fn make_proposal(
                     choices,
                     proposer: None,
                     vote,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
+                    budget: None,
                 }),
                 &[],
             )
@@ -91,6 +95,10 @@ pub fn make_proposal(
                         description: "description".to_string(),
                         choices,
                         vote,
+                        execute_after: None,
+                        depends_on: vec![],
+                        voting_period: None,
+                        budget: None,
                     },
                 },
                 &funds,
@@ -82,6 +82,8 @@ pub fn _get_default_token_dao_proposal_module_instantiate(app: &mut App) -> Inst
         ),
         close_proposal_on_execution_failure: true,
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     }
 }
 
@@ -99,6 +101,8 @@ fn _get_default_non_token_dao_proposal_module_instantiate(app: &mut App) -> Inst
         pre_propose_info: get_pre_propose_info(app, None, false),
         close_proposal_on_execution_failure: true,
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     }
 }
 
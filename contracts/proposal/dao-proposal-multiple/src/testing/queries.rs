@@ -3,11 +3,13 @@ use cw_hooks::HooksResponse;
 use cw_multi_test::App;
 use dao_interface::state::{ProposalModule, ProposalModuleStatus};
 use dao_pre_propose_multiple as cppm;
-use dao_voting::pre_propose::ProposalCreationPolicy;
+use dao_voting::{
+    pre_propose::ProposalCreationPolicy, stats::ProposalStatsResponse, status::Status,
+};
 
 use crate::{
     msg::QueryMsg,
-    query::{ProposalListResponse, ProposalResponse},
+    query::{ProposalListResponse, ProposalResponse, RunoffRoundsResponse, VoterVoteListResponse},
     state::Config,
 };
 
@@ -86,6 +88,63 @@ pub fn query_list_proposals(
         .unwrap()
 }
 
+pub fn query_list_proposals_by_status(
+    app: &App,
+    proposal_multiple: &Addr,
+    status: Status,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> ProposalListResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_multiple,
+            &QueryMsg::ListProposalsByStatus {
+                status,
+                start_after,
+                limit,
+            },
+        )
+        .unwrap()
+}
+
+pub fn query_list_proposals_by_proposer(
+    app: &App,
+    proposal_multiple: &Addr,
+    proposer: &str,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> ProposalListResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_multiple,
+            &QueryMsg::ListProposalsByProposer {
+                proposer: proposer.to_string(),
+                start_after,
+                limit,
+            },
+        )
+        .unwrap()
+}
+
+pub fn query_list_votes_by_voter(
+    app: &App,
+    proposal_multiple: &Addr,
+    voter: &str,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> VoterVoteListResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_multiple,
+            &QueryMsg::ListVotesByVoter {
+                voter: voter.to_string(),
+                start_after,
+                limit,
+            },
+        )
+        .unwrap()
+}
+
 pub fn query_proposal_hooks(app: &App, proposal_multiple: &Addr) -> HooksResponse {
     app.wrap()
         .query_wasm_smart(proposal_multiple, &QueryMsg::ProposalHooks {})
@@ -175,3 +234,32 @@ pub fn query_proposal(app: &App, proposal_multiple: &Addr, id: u64) -> ProposalR
         .query_wasm_smart(proposal_multiple, &QueryMsg::Proposal { proposal_id: id })
         .unwrap()
 }
+
+pub fn query_proposal_stats(app: &App, proposal_multiple: &Addr, id: u64) -> ProposalStatsResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_multiple,
+            &QueryMsg::ProposalStats { proposal_id: id },
+        )
+        .unwrap()
+}
+
+pub fn query_vote_nonce(app: &App, proposal_multiple: &Addr, voter: &str) -> u64 {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_multiple,
+            &QueryMsg::VoteNonce {
+                voter: voter.to_string(),
+            },
+        )
+        .unwrap()
+}
+
+pub fn query_runoff_rounds(app: &App, proposal_multiple: &Addr, id: u64) -> RunoffRoundsResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_multiple,
+            &QueryMsg::RunoffRounds { proposal_id: id },
+        )
+        .unwrap()
+}
@@ -1,11 +1,12 @@
 use cosmwasm_std::{
-    to_json_binary, Addr, Coin, CosmosMsg, Decimal, Empty, Timestamp, Uint128, WasmMsg,
+    coins, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal, Empty, Timestamp,
+    Uint128, WasmMsg,
 };
 use cw20::Cw20Coin;
 use cw_denom::{CheckedDenom, UncheckedDenom};
 use cw_hooks::HooksResponse;
 use cw_multi_test::{next_block, App, BankSudo, Contract, ContractWrapper, Executor, SudoMsg};
-use cw_utils::Duration;
+use cw_utils::{Duration, Expiration};
 use dao_interface::state::ProposalModule;
 use dao_interface::state::{Admin, ModuleInstantiateInfo};
 use dao_voting::multiple_choice::MultipleChoiceAutoVote;
@@ -17,22 +18,30 @@ use dao_voting::{
         VotingModuleTokenType,
     },
     multiple_choice::{
-        CheckedMultipleChoiceOption, MultipleChoiceOption, MultipleChoiceOptionType,
-        MultipleChoiceOptions, MultipleChoiceVote, MultipleChoiceVotes, VotingStrategy,
-        MAX_NUM_CHOICES,
+        CheckedMultipleChoiceOption, FundingRequest, MultipleChoiceOption,
+        MultipleChoiceOptionType, MultipleChoiceOptions, MultipleChoiceVote, MultipleChoiceVotes,
+        TieBreaker, UncheckedBudget, VotingStrategy, MAX_NUM_CHOICES,
     },
     pre_propose::PreProposeInfo,
     proposal::MultipleChoiceProposeMsg as ProposeMsg,
+    signed_vote::{SignedVoteError, SignedVotePayload},
+    stats::ProposalStatsResponse,
     status::Status,
     threshold::{ActiveThreshold, PercentageThreshold, Threshold},
+    voting::VotingPeriodRange,
 };
+use k256::ecdsa::{signature::hazmat::PrehashSigner, Signature, SigningKey};
 use std::ops::Add;
 use std::panic;
 
 use crate::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     proposal::MultipleChoiceProposal,
-    query::{ProposalListResponse, ProposalResponse, VoteInfo, VoteListResponse, VoteResponse},
+    query::{
+        ProposalListResponse, ProposalResponse, VoteInfo, VoteListResponse, VoteResponse,
+        VoterVoteInfo,
+    },
+    runoff::RunoffResult,
     state::Config,
     testing::{
         do_votes::do_test_votes_cw20_balances,
@@ -45,8 +54,11 @@ use crate::{
         queries::{
             query_balance_cw20, query_balance_native, query_cw20_token_staking_contracts,
             query_dao_token, query_deposit_config_and_pre_propose_module, query_list_proposals,
-            query_list_proposals_reverse, query_multiple_proposal_module, query_proposal,
-            query_proposal_config, query_proposal_hooks, query_vote_hooks,
+            query_list_proposals_by_proposer, query_list_proposals_by_status,
+            query_list_proposals_reverse, query_list_votes_by_voter,
+            query_multiple_proposal_module, query_proposal, query_proposal_config,
+            query_proposal_hooks, query_proposal_stats, query_runoff_rounds, query_vote_hooks,
+            query_vote_nonce,
         },
     },
     ContractError,
@@ -143,6 +155,8 @@ fn test_propose() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -159,6 +173,8 @@ fn test_propose() {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
         veto: None,
+        voting_period_range: None,
+        tie_breaker: TieBreaker::Fail {},
     };
     assert_eq!(config, expected);
 
@@ -167,11 +183,13 @@ fn test_propose() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
 
@@ -196,10 +214,18 @@ fn test_propose() {
         total_power: Uint128::new(100_000_000),
         votes: MultipleChoiceVotes {
             vote_weights: vec![Uint128::zero(); 3],
+            power_cast: Some(Uint128::zero()),
+            participation: vec![],
         },
         allow_revoting: false,
         min_voting_period: None,
         veto: None,
+        execute_after: None,
+        depends_on: vec![],
+        runoff: None,
+        tie_breaker: TieBreaker::Fail {},
+        weights_changed_at: vec![],
+        budget: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -225,6 +251,8 @@ fn test_propose_wrong_num_choices() {
         voting_strategy: voting_strategy.clone(),
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -241,6 +269,8 @@ fn test_propose_wrong_num_choices() {
         dao: core_addr,
         voting_strategy,
         veto: None,
+        voting_period_range: None,
+        tie_breaker: TieBreaker::Fail {},
     };
     assert_eq!(config, expected);
 
@@ -257,6 +287,10 @@ fn test_propose_wrong_num_choices() {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     );
@@ -267,6 +301,7 @@ fn test_propose_wrong_num_choices() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         };
         std::convert::TryInto::try_into(MAX_NUM_CHOICES + 1).unwrap()
     ];
@@ -284,6 +319,10 @@ fn test_propose_wrong_num_choices() {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     );
@@ -305,6 +344,8 @@ fn test_proposal_count_initialized_to_zero() {
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
     let core_addr = instantiate_with_staked_balances_governance(&mut app, msg, None);
 
@@ -344,6 +385,8 @@ fn test_propose_auto_vote_winner() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -360,6 +403,8 @@ fn test_propose_auto_vote_winner() {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
         veto: None,
+        voting_period_range: None,
+        tie_breaker: TieBreaker::Fail {},
     };
     assert_eq!(config, expected);
 
@@ -368,11 +413,13 @@ fn test_propose_auto_vote_winner() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
 
@@ -385,7 +432,11 @@ fn test_propose_auto_vote_winner() {
         CREATOR_ADDR,
         mc_options.clone(),
         Some(MultipleChoiceAutoVote {
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: Some("rationale".to_string()),
         }),
     );
@@ -406,16 +457,243 @@ fn test_propose_auto_vote_winner() {
         total_power: Uint128::new(100_000_000),
         votes: MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(100_000_000), Uint128::zero(), Uint128::zero()],
+            power_cast: Some(Uint128::new(100_000_000)),
+            participation: vec![],
         },
         allow_revoting: false,
         min_voting_period: None,
         veto: None,
+        execute_after: None,
+        depends_on: vec![],
+        runoff: None,
+        tie_breaker: TieBreaker::Fail {},
+        weights_changed_at: vec![],
+        budget: None,
     };
 
     assert_eq!(created.proposal, expected);
     assert_eq!(created.id, 1u64);
 }
 
+#[test]
+fn test_execute_after() {
+    let mut app = App::default();
+    let _govmod_id = app.store_code(proposal_multiple_contract());
+
+    let instantiate = InstantiateMsg {
+        max_voting_period: Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
+    };
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let options = vec![
+        MultipleChoiceOption {
+            description: "multiple choice option 1".to_string(),
+            msgs: vec![],
+            title: "title".to_string(),
+            funding: None,
+        },
+        MultipleChoiceOption {
+            description: "multiple choice option 2".to_string(),
+            msgs: vec![],
+            title: "title".to_string(),
+            funding: None,
+        },
+    ];
+
+    // The earliest execution time must be in blocks as the max voting
+    // period is.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            govmod.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                choices: MultipleChoiceOptions {
+                    options: options.clone(),
+                },
+                proposer: None,
+                vote: None,
+                execute_after: Some(Expiration::AtTime(app.block_info().time.plus_days(1))),
+                depends_on: vec![],
+                voting_period: None,
+                budget: None,
+            }),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::VotingError(dao_voting::error::VotingError::ExecuteAfterUnitsConflict {})
+    );
+
+    let execute_after = Expiration::AtHeight(app.block_info().height + 10);
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        govmod.clone(),
+        &ExecuteMsg::Propose(ProposeMsg {
+            title: "title".to_string(),
+            description: "description".to_string(),
+            choices: MultipleChoiceOptions { options },
+            proposer: None,
+            vote: Some(MultipleChoiceAutoVote {
+                vote: MultipleChoiceVote {
+                    option_id: 0,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
+                rationale: None,
+            }),
+            execute_after: Some(execute_after),
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
+        }),
+        &[],
+    )
+    .unwrap();
+
+    let proposal: ProposalResponse = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+    assert_eq!(proposal.proposal.execute_after, Some(execute_after));
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            govmod.clone(),
+            &ExecuteMsg::Execute { proposal_id: 1 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ExecutionLocked { execute_after });
+
+    app.update_block(|b| b.height += 10);
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        govmod.clone(),
+        &ExecuteMsg::Execute { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+
+    let proposal: ProposalResponse = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.status, Status::Executed);
+}
+
+#[test]
+fn test_proposal_dependencies() {
+    let mut app = App::default();
+    let _govmod_id = app.store_code(proposal_multiple_contract());
+
+    let instantiate = InstantiateMsg {
+        max_voting_period: Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
+    };
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let propose = |app: &mut App, option_id: u32, depends_on: Vec<(String, u64)>| {
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            govmod.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                choices: MultipleChoiceOptions {
+                    options: vec![
+                        MultipleChoiceOption {
+                            description: "multiple choice option 1".to_string(),
+                            msgs: vec![],
+                            title: "title".to_string(),
+                            funding: None,
+                        },
+                        MultipleChoiceOption {
+                            description: "multiple choice option 2".to_string(),
+                            msgs: vec![],
+                            title: "title".to_string(),
+                            funding: None,
+                        },
+                    ],
+                },
+                proposer: None,
+                vote: Some(MultipleChoiceAutoVote {
+                    vote: MultipleChoiceVote {
+                        option_id,
+                        additional_option_ids: vec![],
+                        scores: vec![],
+                    },
+                    rationale: None,
+                }),
+                execute_after: None,
+                depends_on,
+                voting_period: None,
+                budget: None,
+            }),
+            &[],
+        )
+        .unwrap();
+    };
+    let execute = |app: &mut App, proposal_id: u64| {
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            govmod.clone(),
+            &ExecuteMsg::Execute { proposal_id },
+            &[],
+        )
+    };
+
+    propose(&mut app, 0, vec![]);
+    propose(&mut app, 0, vec![(govmod.to_string(), 1)]);
+
+    let err: ContractError = execute(&mut app, 2).unwrap_err().downcast().unwrap();
+    assert_eq!(
+        err,
+        ContractError::DependencyNotExecuted {
+            module: govmod.to_string(),
+            proposal_id: 1
+        }
+    );
+
+    execute(&mut app, 1).unwrap();
+    execute(&mut app, 2).unwrap();
+    let proposal: ProposalResponse = query_proposal(&app, &govmod, 2);
+    assert_eq!(proposal.proposal.status, Status::Executed);
+
+    // Voting for "none of the above" rejects the dependency, so the
+    // proposal depending on it is closed when executed.
+    propose(&mut app, 2, vec![]);
+    propose(&mut app, 0, vec![(govmod.to_string(), 3)]);
+    execute(&mut app, 4).unwrap();
+    let proposal: ProposalResponse = query_proposal(&app, &govmod, 4);
+    assert_eq!(proposal.proposal.status, Status::Closed);
+}
+
 #[test]
 fn test_propose_auto_vote_reject() {
     let mut app = App::default();
@@ -435,6 +713,8 @@ fn test_propose_auto_vote_reject() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -451,6 +731,8 @@ fn test_propose_auto_vote_reject() {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
         veto: None,
+        voting_period_range: None,
+        tie_breaker: TieBreaker::Fail {},
     };
     assert_eq!(config, expected);
 
@@ -459,11 +741,13 @@ fn test_propose_auto_vote_reject() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
 
@@ -476,7 +760,11 @@ fn test_propose_auto_vote_reject() {
         CREATOR_ADDR,
         mc_options.clone(),
         Some(MultipleChoiceAutoVote {
-            vote: MultipleChoiceVote { option_id: 2 },
+            vote: MultipleChoiceVote {
+                option_id: 2,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: Some("rationale".to_string()),
         }),
     );
@@ -497,10 +785,18 @@ fn test_propose_auto_vote_reject() {
         total_power: Uint128::new(100_000_000),
         votes: MultipleChoiceVotes {
             vote_weights: vec![Uint128::zero(), Uint128::zero(), Uint128::new(100_000_000)],
+            power_cast: Some(Uint128::new(100_000_000)),
+            participation: vec![],
         },
         allow_revoting: false,
         min_voting_period: None,
         veto: None,
+        execute_after: None,
+        depends_on: vec![],
+        runoff: None,
+        tie_breaker: TieBreaker::Fail {},
+        weights_changed_at: vec![],
+        budget: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -527,6 +823,8 @@ fn test_propose_non_member_auto_vote_fail() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -543,6 +841,8 @@ fn test_propose_non_member_auto_vote_fail() {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
         veto: None,
+        voting_period_range: None,
+        tie_breaker: TieBreaker::Fail {},
     };
     assert_eq!(config, expected);
 
@@ -551,11 +851,13 @@ fn test_propose_non_member_auto_vote_fail() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
 
@@ -568,7 +870,11 @@ fn test_propose_non_member_auto_vote_fail() {
         "anyone",
         mc_options.clone(),
         Some(MultipleChoiceAutoVote {
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: Some("rationale".to_string()),
         }),
     );
@@ -589,6 +895,8 @@ fn test_no_early_pass_with_min_duration() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -620,11 +928,13 @@ fn test_no_early_pass_with_min_duration() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
 
@@ -639,6 +949,10 @@ fn test_no_early_pass_with_min_duration() {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -651,7 +965,11 @@ fn test_no_early_pass_with_min_duration() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -685,6 +1003,8 @@ fn test_propose_with_messages() {
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -722,6 +1042,8 @@ fn test_propose_with_messages() {
         allow_revoting: false,
         dao: "dao".to_string(),
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     let wasm_msg = WasmMsg::Execute {
@@ -735,11 +1057,13 @@ fn test_propose_with_messages() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![CosmosMsg::Wasm(wasm_msg)],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
 
@@ -754,6 +1078,10 @@ fn test_propose_with_messages() {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -764,7 +1092,11 @@ fn test_propose_with_messages() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -807,6 +1139,8 @@ fn test_min_duration_units_missmatch() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
     instantiate_with_staked_balances_governance(
         &mut app,
@@ -840,6 +1174,8 @@ fn test_min_duration_larger_than_proposal_duration() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
     instantiate_with_staked_balances_governance(
         &mut app,
@@ -872,6 +1208,8 @@ fn test_min_duration_same_as_proposal_duration() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -903,11 +1241,13 @@ fn test_min_duration_same_as_proposal_duration() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
 
@@ -922,6 +1262,10 @@ fn test_min_duration_same_as_proposal_duration() {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -934,7 +1278,11 @@ fn test_min_duration_same_as_proposal_duration() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -951,7 +1299,11 @@ fn test_min_duration_same_as_proposal_duration() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 2 },
+            vote: MultipleChoiceVote {
+                option_id: 2,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -996,6 +1348,8 @@ fn test_voting_module_token_proposal_deposit_instantiate() {
             false,
         ),
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -1072,6 +1426,8 @@ fn test_different_token_proposal_deposit() {
             false,
         ),
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -1134,6 +1490,8 @@ fn test_bad_token_proposal_deposit() {
             false,
         ),
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -1167,6 +1525,8 @@ fn test_take_proposal_deposit() {
             false,
         ),
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     let core_addr = instantiate_with_cw20_balances_governance(
@@ -1192,11 +1552,13 @@ fn test_take_proposal_deposit() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
 
@@ -1218,6 +1580,10 @@ fn test_take_proposal_deposit() {
                     description: "description".to_string(),
                     choices: mc_options.clone(),
                     vote: None,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
+                    budget: None,
                 },
             },
             &[],
@@ -1275,6 +1641,8 @@ fn test_take_native_proposal_deposit() {
             false,
         ),
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     let core_addr = instantiate_with_native_staked_balances_governance(
@@ -1300,11 +1668,13 @@ fn test_take_native_proposal_deposit() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
 
@@ -1326,6 +1696,10 @@ fn test_take_native_proposal_deposit() {
                     description: "description".to_string(),
                     choices: mc_options.clone(),
                     vote: None,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
+                    budget: None,
                 },
             },
             &[],
@@ -1369,6 +1743,8 @@ fn test_native_proposal_deposit() {
             false,
         ),
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -1405,11 +1781,13 @@ fn test_native_proposal_deposit() {
                     description: "multiple choice option 1".to_string(),
                     msgs: vec![],
                     title: "title".to_string(),
+                    funding: None,
                 },
                 MultipleChoiceOption {
                     description: "multiple choice option 2".to_string(),
                     msgs: vec![],
                     title: "title".to_string(),
+                    funding: None,
                 },
             ],
         };
@@ -1424,6 +1802,10 @@ fn test_native_proposal_deposit() {
                     description: "description".to_string(),
                     choices: mc_options.clone(),
                     vote: None,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
+                    budget: None,
                 },
             },
             &[],
@@ -1457,7 +1839,11 @@ fn test_native_proposal_deposit() {
             govmod.clone(),
             &ExecuteMsg::Vote {
                 proposal_id: 1,
-                vote: MultipleChoiceVote { option_id: 1 },
+                vote: MultipleChoiceVote {
+                    option_id: 1,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
                 rationale: None,
             },
             &[],
@@ -1494,7 +1880,11 @@ fn test_deposit_return_on_execute() {
     let (mut app, core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 0 },
+            position: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -1557,7 +1947,11 @@ fn test_deposit_return_zero() {
     let (mut app, core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 0 },
+            position: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -1605,13 +1999,21 @@ fn test_query_list_votes() {
         vec![
             TestMultipleChoiceVote {
                 voter: "blue".to_string(),
-                position: MultipleChoiceVote { option_id: 0 },
+                position: MultipleChoiceVote {
+                    option_id: 0,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
                 weight: Uint128::new(10),
                 should_execute: ShouldExecute::Yes,
             },
             TestMultipleChoiceVote {
                 voter: "note".to_string(),
-                position: MultipleChoiceVote { option_id: 1 },
+                position: MultipleChoiceVote {
+                    option_id: 1,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
                 weight: Uint128::new(20),
                 should_execute: ShouldExecute::Yes,
             },
@@ -1649,13 +2051,21 @@ fn test_query_list_votes() {
     let expected = vec![
         VoteInfo {
             voter: Addr::unchecked("blue"),
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             power: Uint128::new(10),
             rationale: None,
         },
         VoteInfo {
             voter: Addr::unchecked("note"),
-            vote: MultipleChoiceVote { option_id: 1 },
+            vote: MultipleChoiceVote {
+                option_id: 1,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             power: Uint128::new(20),
             rationale: None,
         },
@@ -1670,7 +2080,11 @@ fn test_invalid_quorum() {
     let (_app, _core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 2 },
+            position: MultipleChoiceVote {
+                option_id: 2,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -1690,7 +2104,11 @@ fn test_cant_vote_executed_or_closed() {
     let (mut app, core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 2 },
+            position: MultipleChoiceVote {
+                option_id: 2,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -1727,7 +2145,11 @@ fn test_cant_vote_executed_or_closed() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -1738,7 +2160,11 @@ fn test_cant_vote_executed_or_closed() {
     let (mut app, _core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 0 },
+            position: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -1766,7 +2192,11 @@ fn test_cant_vote_executed_or_closed() {
         govmod,
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -1800,6 +2230,8 @@ fn test_cant_propose_zero_power() {
             false,
         ),
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     let core_addr = instantiate_with_cw20_balances_governance(
@@ -1831,11 +2263,13 @@ fn test_cant_propose_zero_power() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
 
@@ -1872,6 +2306,10 @@ fn test_cant_propose_zero_power() {
                 description: "A simple text proposal".to_string(),
                 choices: mc_options.clone(),
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
+                budget: None,
             },
         },
         &[],
@@ -1888,6 +2326,10 @@ fn test_cant_propose_zero_power() {
                 description: "A simple text proposal".to_string(),
                 choices: mc_options,
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
+                budget: None,
             },
         },
         &[],
@@ -1901,7 +2343,11 @@ fn test_cant_vote_not_registered() {
     let (mut app, core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 2 },
+            position: MultipleChoiceVote {
+                option_id: 2,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -1936,7 +2382,11 @@ fn test_cant_vote_not_registered() {
             govmod,
             &ExecuteMsg::Vote {
                 proposal_id: 1,
-                vote: MultipleChoiceVote { option_id: 0 },
+                vote: MultipleChoiceVote {
+                    option_id: 0,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
                 rationale: None,
             },
             &[],
@@ -1969,6 +2419,8 @@ fn test_cant_execute_not_member() {
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -1987,11 +2439,13 @@ fn test_cant_execute_not_member() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
 
@@ -2006,6 +2460,10 @@ fn test_cant_execute_not_member() {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -2017,7 +2475,11 @@ fn test_cant_execute_not_member() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -2061,6 +2523,8 @@ fn test_cant_execute_not_member_when_proposal_created() {
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -2079,11 +2543,13 @@ fn test_cant_execute_not_member_when_proposal_created() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
 
@@ -2098,6 +2564,10 @@ fn test_cant_execute_not_member_when_proposal_created() {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -2109,7 +2579,11 @@ fn test_cant_execute_not_member_when_proposal_created() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -2180,6 +2654,8 @@ fn test_open_proposal_submission() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: get_pre_propose_info(&mut app, None, true),
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
     let govmod = query_multiple_proposal_module(&app, &core_addr);
@@ -2194,11 +2670,13 @@ fn test_open_proposal_submission() {
                     description: "multiple choice option 1".to_string(),
                     msgs: vec![],
                     title: "title".to_string(),
+                    funding: None,
                 },
                 MultipleChoiceOption {
                     description: "multiple choice option 2".to_string(),
                     msgs: vec![],
                     title: "title".to_string(),
+                    funding: None,
                 },
             ],
         },
@@ -2228,6 +2706,7 @@ fn test_open_proposal_submission() {
                 vote_count: Uint128::zero(),
                 index: 0,
                 title: "title".to_string(),
+                funding: None,
             },
             CheckedMultipleChoiceOption {
                 description: "multiple choice option 2".to_string(),
@@ -2236,6 +2715,7 @@ fn test_open_proposal_submission() {
                 vote_count: Uint128::zero(),
                 index: 1,
                 title: "title".to_string(),
+                funding: None,
             },
             CheckedMultipleChoiceOption {
                 description: "None of the above".to_string(),
@@ -2244,12 +2724,21 @@ fn test_open_proposal_submission() {
                 vote_count: Uint128::zero(),
                 index: 2,
                 title: "None of the above".to_string(),
+                funding: None,
             },
         ],
         votes: MultipleChoiceVotes {
             vote_weights: vec![Uint128::zero(); 3],
+            power_cast: Some(Uint128::zero()),
+            participation: vec![],
         },
         veto: None,
+        execute_after: None,
+        depends_on: vec![],
+        runoff: None,
+        tie_breaker: TieBreaker::Fail {},
+        weights_changed_at: vec![],
+        budget: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -2261,7 +2750,11 @@ fn test_close_open_proposal() {
     let (mut app, core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 2 },
+            position: MultipleChoiceVote {
+                option_id: 2,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -2331,7 +2824,11 @@ fn test_no_refund_failed_proposal() {
     let (mut app, core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 2 },
+            position: MultipleChoiceVote {
+                option_id: 2,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -2391,7 +2888,11 @@ fn test_zero_deposit() {
     do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 0 },
+            position: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -2413,7 +2914,11 @@ fn test_deposit_return_on_close() {
     let (mut app, core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 2 },
+            position: MultipleChoiceVote {
+                option_id: 2,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -2482,6 +2987,8 @@ fn test_execute_expired_proposal() {
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -2513,11 +3020,13 @@ fn test_execute_expired_proposal() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
 
@@ -2532,6 +3041,10 @@ fn test_execute_expired_proposal() {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -2542,7 +3055,11 @@ fn test_execute_expired_proposal() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -2597,7 +3114,11 @@ fn test_update_config() {
     let (mut app, core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 0 },
+            position: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -2646,6 +3167,8 @@ fn test_update_config() {
             allow_revoting: false,
             dao: dao.to_string(),
             veto: None,
+            voting_period_range: None,
+            tie_breaker: None,
         },
         &[],
     )
@@ -2666,6 +3189,8 @@ fn test_update_config() {
             allow_revoting: false,
             dao: Addr::unchecked(CREATOR_ADDR).to_string(),
             veto: None,
+            voting_period_range: None,
+            tie_breaker: None,
         },
         &[],
     )
@@ -2684,6 +3209,8 @@ fn test_update_config() {
         allow_revoting: false,
         dao: Addr::unchecked(CREATOR_ADDR),
         veto: None,
+        voting_period_range: None,
+        tie_breaker: TieBreaker::Fail {},
     };
     assert_eq!(govmod_config, expected);
 
@@ -2703,6 +3230,8 @@ fn test_update_config() {
             allow_revoting: false,
             dao: Addr::unchecked(CREATOR_ADDR).to_string(),
             veto: None,
+            voting_period_range: None,
+            tie_breaker: None,
         },
         &[],
     )
@@ -2714,7 +3243,11 @@ fn test_no_return_if_no_refunds() {
     let (mut app, core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 2 },
+            position: MultipleChoiceVote {
+                option_id: 2,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -2781,6 +3314,8 @@ fn test_query_list_proposals() {
         voting_strategy: voting_strategy.clone(),
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
     let gov_addr = instantiate_with_staked_balances_governance(
         &mut app,
@@ -2810,11 +3345,13 @@ fn test_query_list_proposals() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
 
@@ -2830,6 +3367,10 @@ fn test_query_list_proposals() {
                 choices: mc_options.clone(),
                 proposer: None,
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
+                budget: None,
             }),
             &[],
         )
@@ -2859,10 +3400,18 @@ fn test_query_list_proposals() {
             total_power: Uint128::new(100),
             votes: MultipleChoiceVotes {
                 vote_weights: vec![Uint128::zero(); 3],
+                power_cast: Some(Uint128::zero()),
+                participation: vec![],
             },
             allow_revoting: false,
             min_voting_period: None,
             veto: None,
+            execute_after: None,
+            depends_on: vec![],
+            runoff: None,
+            tie_breaker: TieBreaker::Fail {},
+            weights_changed_at: vec![],
+            budget: None,
         },
     };
     assert_eq!(proposals_forward.proposals[0], expected);
@@ -2888,10 +3437,18 @@ fn test_query_list_proposals() {
             total_power: Uint128::new(100),
             votes: MultipleChoiceVotes {
                 vote_weights: vec![Uint128::zero(); 3],
+                power_cast: Some(Uint128::zero()),
+                participation: vec![],
             },
             allow_revoting: false,
             min_voting_period: None,
             veto: None,
+            execute_after: None,
+            depends_on: vec![],
+            runoff: None,
+            tie_breaker: TieBreaker::Fail {},
+            weights_changed_at: vec![],
+            budget: None,
         },
     };
     assert_eq!(proposals_forward.proposals[0], expected);
@@ -2902,66 +3459,1145 @@ fn test_query_list_proposals() {
 }
 
 #[test]
-fn test_hooks() {
+fn test_query_list_proposals_by_status_and_proposer() {
     let mut app = App::default();
-    let _govmod_id = app.store_code(proposal_multiple_contract());
-
-    let quorum = PercentageThreshold::Majority {};
-    let voting_strategy = VotingStrategy::SingleChoice { quorum };
-    let max_voting_period = cw_utils::Duration::Height(6);
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
-        max_voting_period,
+        max_voting_period: cw_utils::Duration::Height(6),
         only_members_execute: false,
         allow_revoting: false,
-        voting_strategy,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
-
-    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![Cw20Coin {
+            address: CREATOR_ADDR.to_string(),
+            amount: Uint128::new(100),
+        }]),
+    );
     let govmod = query_multiple_proposal_module(&app, &core_addr);
-
-    let govmod_config: Config = query_proposal_config(&app, &govmod);
-    let dao = govmod_config.dao;
-
-    // Expect no hooks
-    let hooks: HooksResponse = query_proposal_hooks(&app, &govmod);
-    assert_eq!(hooks.hooks.len(), 0);
-
-    let hooks: HooksResponse = query_vote_hooks(&app, &govmod);
-    assert_eq!(hooks.hooks.len(), 0);
-
-    let msg = ExecuteMsg::AddProposalHook {
-        address: "some_addr".to_string(),
+    let options = MultipleChoiceOptions {
+        options: vec![
+            MultipleChoiceOption {
+                description: "multiple choice option 1".to_string(),
+                msgs: vec![],
+                title: "title".to_string(),
+                funding: None,
+            },
+            MultipleChoiceOption {
+                description: "multiple choice option 2".to_string(),
+                msgs: vec![],
+                title: "title".to_string(),
+                funding: None,
+            },
+        ],
+    };
+    let ids = |res: ProposalListResponse| -> Vec<u64> {
+        res.proposals.into_iter().map(|p| p.id).collect()
     };
 
-    // Expect error as sender is not DAO
-    let _err = app
-        .execute_contract(Addr::unchecked(CREATOR_ADDR), govmod.clone(), &msg, &[])
-        .unwrap_err();
-
-    // Expect success as sender is now DAO
-    let _res = app
-        .execute_contract(dao.clone(), govmod.clone(), &msg, &[])
-        .unwrap();
-
-    let hooks: HooksResponse = query_proposal_hooks(&app, &govmod);
-    assert_eq!(hooks.hooks.len(), 1);
-
-    // Expect error as hook is already set
-    let _err = app
-        .execute_contract(dao.clone(), govmod.clone(), &msg, &[])
-        .unwrap_err();
+    for proposer in [CREATOR_ADDR, CREATOR_ADDR, "ekez"] {
+        make_proposal(&mut app, &govmod, proposer, options.clone(), None);
+    }
+    // The proposals are rejected once they expire, though their
+    // stored statuses are still open.
+    app.update_block(|b| b.height += 6);
+    make_proposal(&mut app, &govmod, "ekez", options, None);
+
+    let res = query_list_proposals_by_status(&app, &govmod, Status::Open, None, None);
+    assert_eq!(ids(res), vec![4]);
+    let res = query_list_proposals_by_status(&app, &govmod, Status::Rejected, None, None);
+    assert_eq!(ids(res), vec![1, 2, 3]);
+    let res = query_list_proposals_by_status(&app, &govmod, Status::Rejected, Some(1), Some(1));
+    assert_eq!(ids(res), vec![2]);
+    let res = query_list_proposals_by_status(&app, &govmod, Status::Passed, None, None);
+    assert_eq!(ids(res), vec![]);
+
+    let res = query_list_proposals_by_proposer(&app, &govmod, CREATOR_ADDR, None, None);
+    assert_eq!(ids(res), vec![1, 2]);
+    let res = query_list_proposals_by_proposer(&app, &govmod, "ekez", Some(3), None);
+    assert_eq!(ids(res), vec![4]);
+}
 
-    // Expect error as hook does not exist
-    let _err = app
-        .execute_contract(
-            dao.clone(),
-            govmod.clone(),
-            &ExecuteMsg::RemoveProposalHook {
-                address: "not_exist".to_string(),
+#[test]
+fn test_query_list_votes_by_voter() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        max_voting_period: cw_utils::Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: true,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
+    };
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(100),
+            },
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(100),
+            },
+        ]),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+    let options = MultipleChoiceOptions {
+        options: vec![
+            MultipleChoiceOption {
+                description: "multiple choice option 1".to_string(),
+                msgs: vec![],
+                title: "title".to_string(),
+                funding: None,
+            },
+            MultipleChoiceOption {
+                description: "multiple choice option 2".to_string(),
+                msgs: vec![],
+                title: "title".to_string(),
+                funding: None,
+            },
+        ],
+    };
+    for _ in 0..2 {
+        make_proposal(&mut app, &govmod, CREATOR_ADDR, options.clone(), None);
+    }
+    let mut vote = |proposal_id, option_id| {
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id,
+                vote: MultipleChoiceVote {
+                    option_id,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+    };
+    vote(1, 0);
+    vote(2, 0);
+    // Revoting does not list the proposal twice.
+    vote(2, 1);
+
+    let votes = query_list_votes_by_voter(&app, &govmod, CREATOR_ADDR, None, None);
+    assert_eq!(
+        votes.votes,
+        vec![
+            VoterVoteInfo {
+                proposal_id: 1,
+                vote: MultipleChoiceVote {
+                    option_id: 0,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
+                power: Uint128::new(100),
+                rationale: None,
+            },
+            VoterVoteInfo {
+                proposal_id: 2,
+                vote: MultipleChoiceVote {
+                    option_id: 1,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
+                power: Uint128::new(100),
+                rationale: None,
+            },
+        ]
+    );
+    let votes = query_list_votes_by_voter(&app, &govmod, CREATOR_ADDR, Some(1), None);
+    assert_eq!(votes.votes.len(), 1);
+    let votes = query_list_votes_by_voter(&app, &govmod, "ekez", None, None);
+    assert_eq!(votes.votes, vec![]);
+}
+
+#[test]
+fn test_proposer_voting_period() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        max_voting_period: cw_utils::Duration::Height(10),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        veto: None,
+        voting_period_range: Some(VotingPeriodRange {
+            min: cw_utils::Duration::Height(2),
+            max: cw_utils::Duration::Height(10),
+        }),
+        tie_breaker: None,
+    };
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![Cw20Coin {
+            address: CREATOR_ADDR.to_string(),
+            amount: Uint128::new(100),
+        }]),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+    let mut propose = |voting_period| {
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            govmod.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                choices: MultipleChoiceOptions {
+                    options: vec![MultipleChoiceOption {
+                        description: "multiple choice option 1".to_string(),
+                        msgs: vec![],
+                        title: "title".to_string(),
+                        funding: None,
+                    }],
+                },
+                proposer: None,
+                vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period,
+                budget: None,
+            }),
+            &[],
+        )
+    };
+
+    propose(Some(cw_utils::Duration::Height(10))).unwrap();
+    let err: ContractError = propose(Some(cw_utils::Duration::Height(11)))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::VotingError(dao_voting::error::VotingError::VotingPeriodOutOfRange {})
+    );
+
+    let proposal = query_proposal(&app, &govmod, 1);
+    assert_eq!(
+        proposal.proposal.expiration,
+        Expiration::AtHeight(app.block_info().height + 10)
+    );
+}
+
+#[test]
+fn test_proposal_stats() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        min_voting_period: Some(cw_utils::Duration::Height(3)),
+        close_proposal_on_execution_failure: true,
+        max_voting_period: cw_utils::Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Percent(Decimal::percent(20)),
+        },
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
+    };
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(10),
+            },
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(30),
+            },
+            Cw20Coin {
+                address: "keze".to_string(),
+                amount: Uint128::new(60),
+            },
+        ]),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+    let options = MultipleChoiceOptions {
+        options: vec![
+            MultipleChoiceOption {
+                description: "multiple choice option 1".to_string(),
+                msgs: vec![],
+                title: "title".to_string(),
+                funding: None,
+            },
+            MultipleChoiceOption {
+                description: "multiple choice option 2".to_string(),
+                msgs: vec![],
+                title: "title".to_string(),
+                funding: None,
+            },
+        ],
+    };
+    make_proposal(&mut app, &govmod, CREATOR_ADDR, options, None);
+    let vote = |app: &mut App, voter: &str, option_id| {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id: 1,
+                vote: MultipleChoiceVote {
+                    option_id,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+    };
+
+    // Votes needed to reject are votes for "None of the above", and
+    // nothing passes until quorum is met.
+    vote(&mut app, CREATOR_ADDR, 1);
+    assert_eq!(
+        query_proposal_stats(&app, &govmod, 1),
+        ProposalStatsResponse {
+            turnout: Decimal::percent(10),
+            votes_needed_to_pass: Some(Uint128::new(10)),
+            votes_needed_to_reject: Some(Uint128::zero()),
+            votes_needed_for_quorum: Some(Uint128::new(10)),
+            outcome_locked: false,
+        }
+    );
+
+    vote(&mut app, "ekez", 0);
+    assert_eq!(
+        query_proposal_stats(&app, &govmod, 1),
+        ProposalStatsResponse {
+            turnout: Decimal::percent(40),
+            votes_needed_to_pass: Some(Uint128::zero()),
+            votes_needed_to_reject: Some(Uint128::new(30)),
+            votes_needed_for_quorum: Some(Uint128::zero()),
+            outcome_locked: false,
+        }
+    );
+
+    // The outcome is decided, though the minimum voting period holds
+    // the proposal open.
+    vote(&mut app, "keze", 0);
+    assert_eq!(
+        query_proposal(&app, &govmod, 1).proposal.status,
+        Status::Open
+    );
+    assert_eq!(
+        query_proposal_stats(&app, &govmod, 1),
+        ProposalStatsResponse {
+            turnout: Decimal::one(),
+            votes_needed_to_pass: Some(Uint128::zero()),
+            votes_needed_to_reject: None,
+            votes_needed_for_quorum: Some(Uint128::zero()),
+            outcome_locked: true,
+        }
+    );
+}
+
+#[test]
+fn test_approval_voting() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        max_voting_period: cw_utils::Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::Approval {
+            quorum: PercentageThreshold::Percent(Decimal::percent(20)),
+            max_selections: 2,
+        },
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
+    };
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(10),
+            },
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(30),
+            },
+            Cw20Coin {
+                address: "keze".to_string(),
+                amount: Uint128::new(60),
+            },
+        ]),
+    );
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: coins(30, "ujuno"),
+    }))
+    .unwrap();
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let pay = |recipient: &str| MultipleChoiceOption {
+        description: format!("pay {recipient}"),
+        msgs: vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: coins(10, "ujuno"),
+        })],
+        title: "title".to_string(),
+        funding: None,
+    };
+    let options = MultipleChoiceOptions {
+        options: vec![pay("a"), pay("b"), pay("c")],
+    };
+    make_proposal(&mut app, &govmod, CREATOR_ADDR, options, None);
+    let vote = |app: &mut App, voter: &str, option_ids: &[u32]| {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id: 1,
+                vote: MultipleChoiceVote {
+                    option_id: option_ids[0],
+                    additional_option_ids: option_ids[1..].to_vec(),
+                    scores: vec![],
+                },
+                rationale: None,
+            },
+            &[],
+        )
+    };
+
+    // Votes may not select more than `max_selections` options, the
+    // same option twice, "None of the above" alongside other options,
+    // or options that do not exist.
+    for option_ids in [vec![0, 1, 2], vec![0, 0], vec![0, 3], vec![0, 4]] {
+        let err: ContractError = vote(&mut app, "ekez", &option_ids)
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+        assert_eq!(err, ContractError::InvalidVote {});
+    }
+
+    vote(&mut app, CREATOR_ADDR, &[2]).unwrap();
+    vote(&mut app, "ekez", &[0, 1]).unwrap();
+    let proposal = query_proposal(&app, &govmod, 1);
+    assert_eq!(
+        proposal.proposal.votes.vote_weights,
+        vec![
+            Uint128::new(30),
+            Uint128::new(30),
+            Uint128::new(10),
+            Uint128::zero()
+        ]
+    );
+    // Each voter's power is only counted once toward quorum.
+    assert_eq!(proposal.proposal.votes.total(), Uint128::new(40));
+    assert_eq!(proposal.proposal.status, Status::Open);
+
+    // The two options with the most votes win once no outstanding
+    // votes can change them.
+    vote(&mut app, "keze", &[0, 2]).unwrap();
+    let proposal = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        govmod.clone(),
+        &ExecuteMsg::Execute { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(query_balance_native(&app, "a", "ujuno"), Uint128::new(10));
+    assert_eq!(query_balance_native(&app, "b", "ujuno"), Uint128::zero());
+    assert_eq!(query_balance_native(&app, "c", "ujuno"), Uint128::new(10));
+}
+
+#[test]
+fn test_ranked_choice_voting() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        max_voting_period: cw_utils::Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::RankedChoice {
+            quorum: PercentageThreshold::Percent(Decimal::percent(20)),
+        },
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
+    };
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(40),
+            },
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(35),
+            },
+            Cw20Coin {
+                address: "keze".to_string(),
+                amount: Uint128::new(25),
+            },
+        ]),
+    );
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: coins(30, "ujuno"),
+    }))
+    .unwrap();
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let pay = |recipient: &str| MultipleChoiceOption {
+        description: format!("pay {recipient}"),
+        msgs: vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: coins(10, "ujuno"),
+        })],
+        title: "title".to_string(),
+        funding: None,
+    };
+    let options = MultipleChoiceOptions {
+        options: vec![pay("a"), pay("b"), pay("c")],
+    };
+    make_proposal(&mut app, &govmod, CREATOR_ADDR, options.clone(), None);
+    let vote = |app: &mut App, voter: &str, proposal_id: u64, ranking: &[u32]| {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id,
+                vote: MultipleChoiceVote {
+                    option_id: ranking[0],
+                    additional_option_ids: ranking[1..].to_vec(),
+                    scores: vec![],
+                },
+                rationale: None,
+            },
+            &[],
+        )
+    };
+
+    // Rankings may not rank the same option twice or options that do
+    // not exist.
+    for ranking in [vec![0, 0], vec![0, 4]] {
+        let err: ContractError = vote(&mut app, "ekez", 1, &ranking)
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+        assert_eq!(err, ContractError::InvalidVote {});
+    }
+
+    vote(&mut app, CREATOR_ADDR, 1, &[0]).unwrap();
+    vote(&mut app, "ekez", 1, &[1, 0]).unwrap();
+    let proposal = query_proposal(&app, &govmod, 1);
+    // Vote weights count first preferences.
+    assert_eq!(
+        proposal.proposal.votes.vote_weights,
+        vec![
+            Uint128::new(40),
+            Uint128::new(35),
+            Uint128::zero(),
+            Uint128::zero()
+        ]
+    );
+    assert_eq!(proposal.proposal.status, Status::Open);
+    assert_eq!(proposal.proposal.runoff, None);
+
+    // Once all voting power has voted the runoff is tallied. Option 0
+    // leads on first preferences, but option 1 wins once option 2 is
+    // eliminated.
+    vote(&mut app, "keze", 1, &[2, 1]).unwrap();
+    let proposal = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+    assert_eq!(
+        proposal.proposal.runoff,
+        Some(RunoffResult::Winner { option_id: 1 })
+    );
+    let rounds = query_runoff_rounds(&app, &govmod, 1).rounds;
+    assert_eq!(rounds.len(), 3);
+    assert_eq!(rounds[1].eliminated, vec![2]);
+    assert_eq!(
+        rounds[2].vote_weights,
+        vec![
+            Uint128::new(40),
+            Uint128::new(60),
+            Uint128::zero(),
+            Uint128::zero()
+        ]
+    );
+
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        govmod.clone(),
+        &ExecuteMsg::Execute { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(query_balance_native(&app, "a", "ujuno"), Uint128::zero());
+    assert_eq!(query_balance_native(&app, "b", "ujuno"), Uint128::new(10));
+
+    // Otherwise the runoff is tallied when the proposal is executed
+    // or closed after voting ends. A majority ranking "None of the
+    // above" first rejects the proposal.
+    make_proposal(&mut app, &govmod, CREATOR_ADDR, options, None);
+    vote(&mut app, CREATOR_ADDR, 2, &[3, 0]).unwrap();
+    vote(&mut app, "ekez", 2, &[0]).unwrap();
+    app.update_block(|b| b.height += 10);
+    let proposal = query_proposal(&app, &govmod, 2);
+    assert_eq!(proposal.proposal.status, Status::Open);
+    assert!(query_runoff_rounds(&app, &govmod, 2).rounds.is_empty());
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            govmod.clone(),
+            &ExecuteMsg::Execute { proposal_id: 2 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NotPassed {});
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        govmod.clone(),
+        &ExecuteMsg::Close { proposal_id: 2 },
+        &[],
+    )
+    .unwrap();
+    let proposal = query_proposal(&app, &govmod, 2);
+    assert_eq!(proposal.proposal.status, Status::Closed);
+    assert_eq!(
+        proposal.proposal.runoff,
+        Some(RunoffResult::Winner { option_id: 3 })
+    );
+    assert_eq!(query_runoff_rounds(&app, &govmod, 2).rounds.len(), 1);
+}
+
+#[test]
+fn test_score_voting() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        max_voting_period: cw_utils::Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::Score {
+            quorum: PercentageThreshold::Percent(Decimal::percent(20)),
+            max_score: 10,
+        },
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
+    };
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(10),
+            },
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(30),
+            },
+            Cw20Coin {
+                address: "keze".to_string(),
+                amount: Uint128::new(60),
+            },
+        ]),
+    );
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: coins(30, "ujuno"),
+    }))
+    .unwrap();
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let pay = |recipient: &str| MultipleChoiceOption {
+        description: format!("pay {recipient}"),
+        msgs: vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: coins(10, "ujuno"),
+        })],
+        title: "title".to_string(),
+        funding: None,
+    };
+    let options = MultipleChoiceOptions {
+        options: vec![pay("a"), pay("b"), pay("c")],
+    };
+    make_proposal(&mut app, &govmod, CREATOR_ADDR, options, None);
+    let vote = |app: &mut App, voter: &str, scores: &[(u32, u32)]| {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id: 1,
+                vote: MultipleChoiceVote {
+                    option_id: scores[0].0,
+                    additional_option_ids: scores[1..].iter().map(|(id, _)| *id).collect(),
+                    scores: scores.iter().map(|(_, score)| *score).collect(),
+                },
+                rationale: None,
+            },
+            &[],
+        )
+    };
+
+    // Scores may not exceed the maximum score, and every selected
+    // option must be given exactly one score.
+    let err: ContractError = vote(&mut app, "ekez", &[(0, 11)])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidVote {});
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id: 1,
+                vote: MultipleChoiceVote {
+                    option_id: 0,
+                    additional_option_ids: vec![1],
+                    scores: vec![5],
+                },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidVote {});
+
+    // Option 2 has the highest average score, but too little voting
+    // power scored it to meet quorum.
+    vote(&mut app, CREATOR_ADDR, &[(2, 10)]).unwrap();
+    vote(&mut app, "ekez", &[(0, 6), (1, 9)]).unwrap();
+    let proposal = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.status, Status::Open);
+
+    vote(&mut app, "keze", &[(0, 8), (1, 5)]).unwrap();
+    let proposal = query_proposal(&app, &govmod, 1);
+    assert_eq!(
+        proposal.proposal.votes.vote_weights,
+        vec![
+            Uint128::new(660),
+            Uint128::new(570),
+            Uint128::new(100),
+            Uint128::zero()
+        ]
+    );
+    assert_eq!(proposal.proposal.votes.participation(0), Uint128::new(90));
+    assert_eq!(proposal.proposal.votes.participation(2), Uint128::new(10));
+    assert_eq!(proposal.proposal.status, Status::Passed);
+
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        govmod.clone(),
+        &ExecuteMsg::Execute { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(query_balance_native(&app, "a", "ujuno"), Uint128::new(10));
+    assert_eq!(query_balance_native(&app, "c", "ujuno"), Uint128::zero());
+}
+
+#[test]
+fn test_tie_breaker_runoff() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        max_voting_period: cw_utils::Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        pre_propose_info: get_pre_propose_info(&mut app, None, false),
+        veto: None,
+        voting_period_range: None,
+        tie_breaker: Some(TieBreaker::Runoff {}),
+    };
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(50),
+            },
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(50),
+            },
+        ]),
+    );
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: coins(10, "ujuno"),
+    }))
+    .unwrap();
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+    assert_eq!(
+        query_proposal_config(&app, &govmod).tie_breaker,
+        TieBreaker::Runoff {}
+    );
+
+    // Updating the config without a tie breaker keeps the current one.
+    app.execute_contract(
+        core_addr.clone(),
+        govmod.clone(),
+        &ExecuteMsg::UpdateConfig {
+            voting_strategy: VotingStrategy::SingleChoice {
+                quorum: PercentageThreshold::Majority {},
+            },
+            min_voting_period: None,
+            close_proposal_on_execution_failure: true,
+            max_voting_period: cw_utils::Duration::Height(6),
+            only_members_execute: false,
+            allow_revoting: false,
+            dao: core_addr.to_string(),
+            veto: None,
+            voting_period_range: None,
+            tie_breaker: None,
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        query_proposal_config(&app, &govmod).tie_breaker,
+        TieBreaker::Runoff {}
+    );
+
+    let pay = |recipient: &str| MultipleChoiceOption {
+        description: format!("pay {recipient}"),
+        msgs: vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: coins(10, "ujuno"),
+        })],
+        title: "title".to_string(),
+        funding: None,
+    };
+    let options = MultipleChoiceOptions {
+        options: vec![pay("a"), pay("b"), pay("c")],
+    };
+    make_proposal(&mut app, &govmod, CREATOR_ADDR, options, None);
+    let completed_hook_called = |res: &cw_multi_test::AppResponse| {
+        res.events.iter().any(|e| {
+            e.attributes
+                .iter()
+                .any(|a| a.key == "method" && a.value == "execute_proposal_completed_hook")
+        })
+    };
+    let vote = |app: &mut App, voter: &str, proposal_id: u64, option_id: u32| {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id,
+                vote: MultipleChoiceVote {
+                    option_id,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap()
+    };
+
+    // Options 0 and 2 tie, so the proposal is rejected and recorded
+    // as having been decided by a runoff.
+    vote(&mut app, CREATOR_ADDR, 1, 0);
+    vote(&mut app, "ekez", 1, 2);
+    let proposal = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.status, Status::Rejected);
+    assert_eq!(proposal.proposal.tie_breaker, TieBreaker::Runoff {});
+
+    // Closing it creates a runoff between the tied options, and tells
+    // the pre-propose module that the tied proposal has completed.
+    let res = app
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            govmod.clone(),
+            &ExecuteMsg::Close { proposal_id: 1 },
+            &[],
+        )
+        .unwrap();
+    assert!(res.events.iter().any(|e| e
+        .attributes
+        .iter()
+        .any(|a| a.key == "runoff_proposal_id" && a.value == "2")));
+    assert!(completed_hook_called(&res));
+    let runoff = query_proposal(&app, &govmod, 2).proposal;
+    assert_eq!(runoff.title, format!("Runoff: {}", proposal.proposal.title));
+    assert_eq!(runoff.status, Status::Open);
+    assert_eq!(runoff.tie_breaker, TieBreaker::Fail {});
+    let descriptions: Vec<String> = runoff
+        .choices
+        .iter()
+        .map(|choice| choice.description.clone())
+        .collect();
+    assert_eq!(descriptions, vec!["pay a", "pay c", "None of the above"]);
+
+    vote(&mut app, CREATOR_ADDR, 2, 1);
+    vote(&mut app, "ekez", 2, 1);
+    assert_eq!(
+        query_proposal(&app, &govmod, 2).proposal.status,
+        Status::Passed
+    );
+    // The runoff was not created through the pre-propose module, so
+    // it is not told when the runoff completes.
+    let res = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            govmod.clone(),
+            &ExecuteMsg::Execute { proposal_id: 2 },
+            &[],
+        )
+        .unwrap();
+    assert!(!completed_hook_called(&res));
+    assert_eq!(query_balance_native(&app, "c", "ujuno"), Uint128::new(10));
+}
+
+#[test]
+fn test_budget_proposals() {
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        max_voting_period: cw_utils::Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::Budget {
+            quorum: PercentageThreshold::Majority {},
+            min_share: Decimal::percent(25),
+        },
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
+    };
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(50),
+            },
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(30),
+            },
+            Cw20Coin {
+                address: "keze".to_string(),
+                amount: Uint128::new(20),
+            },
+        ]),
+    );
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: coins(100, "ujuno"),
+    }))
+    .unwrap();
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let request = |recipient: &str, amount: u128| MultipleChoiceOption {
+        description: format!("fund {recipient}"),
+        msgs: vec![],
+        title: "title".to_string(),
+        funding: Some(FundingRequest {
+            recipient: recipient.to_string(),
+            amount: Uint128::new(amount),
+        }),
+    };
+    let propose = |app: &mut App, options: Vec<MultipleChoiceOption>, budget| {
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            govmod.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "budget".to_string(),
+                description: "budget".to_string(),
+                choices: MultipleChoiceOptions { options },
+                proposer: None,
+                vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
+                budget,
+            }),
+            &[],
+        )
+    };
+    let budget = Some(UncheckedBudget {
+        denom: UncheckedDenom::Native("ujuno".to_string()),
+        amount: Uint128::new(100),
+    });
+
+    // Budget proposals must have a budget, and every option must
+    // request funding.
+    let err: ContractError = propose(&mut app, vec![request("a", 60), request("b", 100)], None)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidBudget {});
+    let mut unfunded = request("b", 100);
+    unfunded.funding = None;
+    let err: ContractError = propose(&mut app, vec![request("a", 60), unfunded], budget.clone())
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidFundingRequest {});
+
+    propose(
+        &mut app,
+        vec![request("a", 60), request("b", 100), request("c", 50)],
+        budget,
+    )
+    .unwrap();
+    for (voter, option_id) in [(CREATOR_ADDR, 0), ("ekez", 1), ("keze", 2)] {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id: 1,
+                vote: MultipleChoiceVote {
+                    option_id,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+    let proposal = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+
+    // Options are funded in proportion to their votes, except for
+    // option 2 which received less than the minimum share. The rest
+    // of the budget stays in the treasury.
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        govmod.clone(),
+        &ExecuteMsg::Execute { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(query_balance_native(&app, "a", "ujuno"), Uint128::new(50));
+    assert_eq!(query_balance_native(&app, "b", "ujuno"), Uint128::new(30));
+    assert_eq!(query_balance_native(&app, "c", "ujuno"), Uint128::zero());
+    assert_eq!(
+        query_balance_native(&app, core_addr.as_str(), "ujuno"),
+        Uint128::new(20)
+    );
+}
+
+#[test]
+fn test_hooks() {
+    let mut app = App::default();
+    let _govmod_id = app.store_code(proposal_multiple_contract());
+
+    let quorum = PercentageThreshold::Majority {};
+    let voting_strategy = VotingStrategy::SingleChoice { quorum };
+    let max_voting_period = cw_utils::Duration::Height(6);
+    let instantiate = InstantiateMsg {
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        max_voting_period,
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
+    };
+
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let govmod_config: Config = query_proposal_config(&app, &govmod);
+    let dao = govmod_config.dao;
+
+    // Expect no hooks
+    let hooks: HooksResponse = query_proposal_hooks(&app, &govmod);
+    assert_eq!(hooks.hooks.len(), 0);
+
+    let hooks: HooksResponse = query_vote_hooks(&app, &govmod);
+    assert_eq!(hooks.hooks.len(), 0);
+
+    let msg = ExecuteMsg::AddProposalHook {
+        address: "some_addr".to_string(),
+    };
+
+    // Expect error as sender is not DAO
+    let _err = app
+        .execute_contract(Addr::unchecked(CREATOR_ADDR), govmod.clone(), &msg, &[])
+        .unwrap_err();
+
+    // Expect success as sender is now DAO
+    let _res = app
+        .execute_contract(dao.clone(), govmod.clone(), &msg, &[])
+        .unwrap();
+
+    let hooks: HooksResponse = query_proposal_hooks(&app, &govmod);
+    assert_eq!(hooks.hooks.len(), 1);
+
+    // Expect error as hook is already set
+    let _err = app
+        .execute_contract(dao.clone(), govmod.clone(), &msg, &[])
+        .unwrap_err();
+
+    // Expect error as hook does not exist
+    let _err = app
+        .execute_contract(
+            dao.clone(),
+            govmod.clone(),
+            &ExecuteMsg::RemoveProposalHook {
+                address: "not_exist".to_string(),
             },
             &[],
         )
@@ -3045,6 +4681,8 @@ fn test_active_threshold_absolute() {
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     let core_addr = instantiate_with_staking_active_threshold(
@@ -3083,11 +4721,13 @@ fn test_active_threshold_absolute() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
 
@@ -3104,6 +4744,10 @@ fn test_active_threshold_absolute() {
                 choices: mc_options.clone(),
                 proposer: None,
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
+                budget: None,
             }),
             &[],
         )
@@ -3130,6 +4774,10 @@ fn test_active_threshold_absolute() {
                 choices: mc_options.clone(),
                 proposer: None,
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
+                budget: None,
             }),
             &[],
         )
@@ -3154,6 +4802,10 @@ fn test_active_threshold_absolute() {
                 choices: mc_options,
                 proposer: None,
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
+                budget: None,
             }),
             &[],
         )
@@ -3176,6 +4828,8 @@ fn test_active_threshold_percent() {
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     // 20% needed to be active, 20% of 100000000 is 20000000
@@ -3215,11 +4869,13 @@ fn test_active_threshold_percent() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
 
@@ -3236,6 +4892,10 @@ fn test_active_threshold_percent() {
                 choices: mc_options.clone(),
                 proposer: None,
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
+                budget: None,
             }),
             &[],
         )
@@ -3262,6 +4922,10 @@ fn test_active_threshold_percent() {
                 choices: mc_options.clone(),
                 proposer: None,
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
+                budget: None,
             }),
             &[],
         )
@@ -3286,6 +4950,10 @@ fn test_active_threshold_percent() {
                 choices: mc_options,
                 proposer: None,
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
+                budget: None,
             }),
             &[],
         )
@@ -3308,6 +4976,8 @@ fn test_active_threshold_none() {
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     let core_addr =
@@ -3350,11 +5020,13 @@ fn test_active_threshold_none() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
 
@@ -3371,6 +5043,10 @@ fn test_active_threshold_none() {
                 choices: mc_options.clone(),
                 proposer: None,
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
+                budget: None,
             }),
             &[],
         )
@@ -3397,6 +5073,10 @@ fn test_active_threshold_none() {
                 choices: mc_options,
                 proposer: None,
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
+                budget: None,
             }),
             &[],
         )
@@ -3421,6 +5101,8 @@ fn test_revoting() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            voting_period_range: None,
+            tie_breaker: None,
         },
         Some(vec![
             Cw20Coin {
@@ -3441,11 +5123,13 @@ fn test_revoting() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
     let mc_options = MultipleChoiceOptions { options };
@@ -3460,6 +5144,10 @@ fn test_revoting() {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -3471,7 +5159,11 @@ fn test_revoting() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -3484,7 +5176,11 @@ fn test_revoting() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 1 },
+            vote: MultipleChoiceVote {
+                option_id: 1,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -3516,7 +5212,11 @@ fn test_revoting() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -3555,6 +5255,8 @@ fn test_allow_revoting_config_changes() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            voting_period_range: None,
+            tie_breaker: None,
         },
         Some(vec![
             Cw20Coin {
@@ -3575,11 +5277,13 @@ fn test_allow_revoting_config_changes() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
     let mc_options = MultipleChoiceOptions { options };
@@ -3594,6 +5298,10 @@ fn test_allow_revoting_config_changes() {
             choices: mc_options.clone(),
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -3614,6 +5322,8 @@ fn test_allow_revoting_config_changes() {
             },
             close_proposal_on_execution_failure: false,
             veto: None,
+            voting_period_range: None,
+            tie_breaker: None,
         },
         &[],
     )
@@ -3628,7 +5338,11 @@ fn test_allow_revoting_config_changes() {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -3639,7 +5353,11 @@ fn test_allow_revoting_config_changes() {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 1 },
+            vote: MultipleChoiceVote {
+                option_id: 1,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -3656,6 +5374,10 @@ fn test_allow_revoting_config_changes() {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -3666,7 +5388,11 @@ fn test_allow_revoting_config_changes() {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 2,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -3679,7 +5405,11 @@ fn test_allow_revoting_config_changes() {
             proposal_module,
             &ExecuteMsg::Vote {
                 proposal_id: 2,
-                vote: MultipleChoiceVote { option_id: 1 },
+                vote: MultipleChoiceVote {
+                    option_id: 1,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
                 rationale: None,
             },
             &[],
@@ -3710,6 +5440,8 @@ fn test_revoting_same_vote_twice() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            voting_period_range: None,
+            tie_breaker: None,
         },
         Some(vec![
             Cw20Coin {
@@ -3730,11 +5462,13 @@ fn test_revoting_same_vote_twice() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
     let mc_options = MultipleChoiceOptions { options };
@@ -3749,6 +5483,10 @@ fn test_revoting_same_vote_twice() {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -3760,7 +5498,11 @@ fn test_revoting_same_vote_twice() {
         proprosal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -3774,7 +5516,11 @@ fn test_revoting_same_vote_twice() {
             proprosal_module,
             &ExecuteMsg::Vote {
                 proposal_id: 1,
-                vote: MultipleChoiceVote { option_id: 0 },
+                vote: MultipleChoiceVote {
+                    option_id: 0,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
                 rationale: None,
             },
             &[],
@@ -3806,6 +5552,8 @@ fn test_invalid_revote_does_not_invalidate_initial_vote() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            voting_period_range: None,
+            tie_breaker: None,
         },
         Some(vec![
             Cw20Coin {
@@ -3826,11 +5574,13 @@ fn test_invalid_revote_does_not_invalidate_initial_vote() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
     let mc_options = MultipleChoiceOptions { options };
@@ -3845,6 +5595,10 @@ fn test_invalid_revote_does_not_invalidate_initial_vote() {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -3856,7 +5610,11 @@ fn test_invalid_revote_does_not_invalidate_initial_vote() {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -3869,7 +5627,11 @@ fn test_invalid_revote_does_not_invalidate_initial_vote() {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 1 },
+            vote: MultipleChoiceVote {
+                option_id: 1,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -3901,7 +5663,11 @@ fn test_invalid_revote_does_not_invalidate_initial_vote() {
             proposal_module,
             &ExecuteMsg::Vote {
                 proposal_id: 1,
-                vote: MultipleChoiceVote { option_id: 99 },
+                vote: MultipleChoiceVote {
+                    option_id: 99,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
                 rationale: None,
             },
             &[],
@@ -3926,7 +5692,11 @@ fn test_return_deposit_to_dao_on_proposal_failure() {
     let (mut app, core_addr) = do_test_votes_cw20_balances(
         vec![TestMultipleChoiceVote {
             voter: "blue".to_string(),
-            position: MultipleChoiceVote { option_id: 2 },
+            position: MultipleChoiceVote {
+                option_id: 2,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             weight: Uint128::new(10),
             should_execute: ShouldExecute::Yes,
         }],
@@ -4001,6 +5771,8 @@ fn test_close_failed_proposal() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     let core_addr = instantiate_with_staking_active_threshold(&mut app, instantiate, None, None);
@@ -4057,11 +5829,13 @@ fn test_close_failed_proposal() {
             }
             .into()],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "Don't burn".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
 
@@ -4077,6 +5851,10 @@ fn test_close_failed_proposal() {
             choices: mc_options.clone(),
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -4088,7 +5866,11 @@ fn test_close_failed_proposal() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -4137,22 +5919,30 @@ fn test_close_failed_proposal() {
                                     dao: original.dao.to_string(),
                                     close_proposal_on_execution_failure: false,
                                     veto: None,
+                                    voting_period_range: None,
+                                    tie_breaker: None,
                                 })
                                 .unwrap(),
                                 funds: vec![],
                             }
                             .into()],
                             title: "title".to_string(),
+                            funding: None,
                         },
                         MultipleChoiceOption {
                             description: "Don't disable".to_string(),
                             msgs: vec![],
                             title: "title".to_string(),
+                            funding: None,
                         },
                     ],
                 },
                 proposer: None,
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
+                budget: None,
             }),
             &[],
         )
@@ -4164,7 +5954,11 @@ fn test_close_failed_proposal() {
             govmod.clone(),
             &ExecuteMsg::Vote {
                 proposal_id: 2,
-                vote: MultipleChoiceVote { option_id: 0 },
+                vote: MultipleChoiceVote {
+                    option_id: 0,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
                 rationale: None,
             },
             &[],
@@ -4191,6 +5985,10 @@ fn test_close_failed_proposal() {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -4202,7 +6000,11 @@ fn test_close_failed_proposal() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 3,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -4256,6 +6058,8 @@ fn test_no_double_refund_on_execute_fail_and_close() {
             false,
         ),
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
 
     let core_addr = instantiate_with_staking_active_threshold(
@@ -4350,11 +6154,13 @@ fn test_no_double_refund_on_execute_fail_and_close() {
                 }
                 .into()],
                 title: "title".to_string(),
+                funding: None,
             },
             MultipleChoiceOption {
                 description: "hi there".to_string(),
                 msgs: vec![],
                 title: "title".to_string(),
+                funding: None,
             },
         ],
     };
@@ -4373,7 +6179,11 @@ fn test_no_double_refund_on_execute_fail_and_close() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -4436,6 +6246,8 @@ pub fn test_not_allow_voting_on_expired_proposal() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
     };
     let core_addr = instantiate_with_staked_balances_governance(
         &mut app,
@@ -4459,11 +6271,13 @@ pub fn test_not_allow_voting_on_expired_proposal() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
     let mc_options = MultipleChoiceOptions { options };
@@ -4478,6 +6292,10 @@ pub fn test_not_allow_voting_on_expired_proposal() {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -4496,7 +6314,11 @@ pub fn test_not_allow_voting_on_expired_proposal() {
             govmod,
             &ExecuteMsg::Vote {
                 proposal_id: 1,
-                vote: MultipleChoiceVote { option_id: 0 },
+                vote: MultipleChoiceVote {
+                    option_id: 0,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
                 rationale: None,
             },
             &[],
@@ -4529,6 +6351,8 @@ fn test_next_proposal_id() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            voting_period_range: None,
+            tie_breaker: None,
         },
         Some(vec![
             Cw20Coin {
@@ -4555,11 +6379,13 @@ fn test_next_proposal_id() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
     let mc_options = MultipleChoiceOptions { options };
@@ -4574,6 +6400,10 @@ fn test_next_proposal_id() {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -4602,6 +6432,8 @@ fn test_vote_with_rationale() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            voting_period_range: None,
+            tie_breaker: None,
         },
         Some(vec![
             Cw20Coin {
@@ -4628,59 +6460,253 @@ fn test_vote_with_rationale() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
-            title: "title 1".to_string(),
+            title: "title 1".to_string(),
+            funding: None,
+        },
+        MultipleChoiceOption {
+            description: "multiple choice option 2".to_string(),
+            msgs: vec![],
+            title: "title 2".to_string(),
+            funding: None,
+        },
+    ];
+
+    let mc_options = MultipleChoiceOptions { options };
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        govmod.clone(),
+        &ExecuteMsg::Propose(ProposeMsg {
+            title: "A proposal".to_string(),
+            description: "A simple proposal".to_string(),
+            choices: mc_options,
+            proposer: None,
+            vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
+        }),
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked("blue"),
+        govmod.clone(),
+        &ExecuteMsg::Vote {
+            proposal_id: 1,
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
+            rationale: Some("I think this is a good idea".to_string()),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Query rationale
+    let vote_resp: VoteResponse = app
+        .wrap()
+        .query_wasm_smart(
+            govmod,
+            &QueryMsg::GetVote {
+                proposal_id: 1,
+                voter: "blue".to_string(),
+            },
+        )
+        .unwrap();
+
+    let vote = vote_resp.vote.unwrap();
+    assert_eq!(vote.vote.option_id, 0);
+    assert_eq!(
+        vote.rationale,
+        Some("I think this is a good idea".to_string())
+    );
+}
+
+#[test]
+fn test_vote_with_signature() {
+    // The address of the secp256k1 key below.
+    const SIGNER: &str = "juno16l746xap3c5p6ud5la5llgr9kawcc4yttal2tp";
+    let mut key = [0u8; 32];
+    key[31] = 0x1d;
+    let signing_key = SigningKey::from_slice(&key).unwrap();
+    let pubkey = Binary::from(signing_key.verifying_key().to_sec1_bytes().to_vec());
+    let sign = |payload: &SignedVotePayload<MultipleChoiceVote>| {
+        let signature: Signature = signing_key
+            .sign_prehash(&payload.hash(&Addr::unchecked(SIGNER)).unwrap())
+            .unwrap();
+        Binary::from(signature.to_bytes().to_vec())
+    };
+
+    let mut app = App::default();
+    let instantiate = InstantiateMsg {
+        max_voting_period: Duration::Height(6),
+        only_members_execute: false,
+        allow_revoting: false,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        veto: None,
+        voting_period_range: None,
+        tie_breaker: None,
+    };
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: SIGNER.to_string(),
+                amount: Uint128::new(100),
+            },
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(100),
+            },
+        ]),
+    );
+    let proposal_module = query_multiple_proposal_module(&app, &core_addr);
+    let options = vec![
+        MultipleChoiceOption {
+            description: "multiple choice option 1".to_string(),
+            msgs: vec![],
+            title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
-            title: "title 2".to_string(),
+            title: "title".to_string(),
+            funding: None,
         },
     ];
+    let id = make_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        MultipleChoiceOptions { options },
+        None,
+    );
 
-    let mc_options = MultipleChoiceOptions { options };
-    app.execute_contract(
-        Addr::unchecked(CREATOR_ADDR),
-        govmod.clone(),
-        &ExecuteMsg::Propose(ProposeMsg {
-            title: "A proposal".to_string(),
-            description: "A simple proposal".to_string(),
-            choices: mc_options,
-            proposer: None,
-            vote: None,
-        }),
-        &[],
-    )
-    .unwrap();
+    let vote = |option_id| MultipleChoiceVote {
+        option_id,
+        additional_option_ids: vec![],
+        scores: vec![],
+    };
+    let payload = SignedVotePayload {
+        chain_id: app.block_info().chain_id,
+        module: proposal_module.to_string(),
+        proposal_id: id,
+        vote: vote(0),
+        nonce: 0,
+        expiry: Expiration::AtHeight(app.block_info().height + 10),
+    };
+    let vote_with_signature =
+        |app: &mut App, voter: &str, payload: &SignedVotePayload<MultipleChoiceVote>| {
+            app.execute_contract(
+                Addr::unchecked("relayer"),
+                proposal_module.clone(),
+                &ExecuteMsg::VoteWithSignature {
+                    voter: voter.to_string(),
+                    payload: payload.clone(),
+                    pubkey: pubkey.clone(),
+                    signature: sign(payload),
+                },
+                &[],
+            )
+        };
 
-    app.execute_contract(
-        Addr::unchecked("blue"),
-        govmod.clone(),
-        &ExecuteMsg::Vote {
-            proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
-            rationale: Some("I think this is a good idea".to_string()),
-        },
-        &[],
-    )
-    .unwrap();
+    // The key may not be used to vote for someone else.
+    let err: ContractError = vote_with_signature(&mut app, CREATOR_ADDR, &payload)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::SignedVoteError(SignedVoteError::PubkeyMismatch {
+            voter: CREATOR_ADDR.to_string()
+        })
+    );
 
-    // Query rationale
+    // A relayer may submit the signed vote.
+    vote_with_signature(&mut app, SIGNER, &payload).unwrap();
     let vote_resp: VoteResponse = app
         .wrap()
         .query_wasm_smart(
-            govmod,
+            &proposal_module,
             &QueryMsg::GetVote {
-                proposal_id: 1,
-                voter: "blue".to_string(),
+                proposal_id: id,
+                voter: SIGNER.to_string(),
             },
         )
         .unwrap();
+    assert_eq!(vote_resp.vote.unwrap().vote, vote(0));
+    assert_eq!(query_vote_nonce(&app, &proposal_module, SIGNER), 1);
 
-    let vote = vote_resp.vote.unwrap();
-    assert_eq!(vote.vote.option_id, 0);
+    // Signed votes may not be replayed.
+    let err: ContractError = vote_with_signature(&mut app, SIGNER, &payload)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
     assert_eq!(
-        vote.rationale,
-        Some("I think this is a good idea".to_string())
+        err,
+        ContractError::SignedVoteError(SignedVoteError::InvalidNonce {
+            nonce: 0,
+            expected: 1
+        })
+    );
+
+    // Signatures must match the payload.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("relayer"),
+            proposal_module.clone(),
+            &ExecuteMsg::VoteWithSignature {
+                voter: SIGNER.to_string(),
+                payload: SignedVotePayload {
+                    nonce: 1,
+                    vote: vote(1),
+                    ..payload.clone()
+                },
+                pubkey: pubkey.clone(),
+                signature: sign(&SignedVotePayload {
+                    nonce: 1,
+                    ..payload.clone()
+                }),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::SignedVoteError(SignedVoteError::InvalidSignature {})
+    );
+
+    // Expired signed votes are rejected.
+    app.update_block(|b| b.height += 10);
+    let err: ContractError = vote_with_signature(
+        &mut app,
+        SIGNER,
+        &SignedVotePayload {
+            nonce: 1,
+            ..payload.clone()
+        },
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(
+        err,
+        ContractError::SignedVoteError(SignedVoteError::Expired {
+            expiry: payload.expiry
+        })
     );
 }
 
@@ -4700,6 +6726,8 @@ fn test_revote_with_rationale() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            voting_period_range: None,
+            tie_breaker: None,
         },
         Some(vec![
             Cw20Coin {
@@ -4727,11 +6755,13 @@ fn test_revote_with_rationale() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title 1".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title 2".to_string(),
+            funding: None,
         },
     ];
 
@@ -4745,6 +6775,10 @@ fn test_revote_with_rationale() {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -4755,7 +6789,11 @@ fn test_revote_with_rationale() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: Some("I think this is a good idea".to_string()),
         },
         &[],
@@ -4787,7 +6825,11 @@ fn test_revote_with_rationale() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 1 },
+            vote: MultipleChoiceVote {
+                option_id: 1,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: Some("Nah".to_string()),
         },
         &[],
@@ -4816,7 +6858,11 @@ fn test_revote_with_rationale() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 2 },
+            vote: MultipleChoiceVote {
+                option_id: 2,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -4856,6 +6902,8 @@ fn test_update_rationale() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            voting_period_range: None,
+            tie_breaker: None,
         },
         Some(vec![
             Cw20Coin {
@@ -4883,11 +6931,13 @@ fn test_update_rationale() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title 1".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title 2".to_string(),
+            funding: None,
         },
     ];
 
@@ -4902,6 +6952,10 @@ fn test_update_rationale() {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -4913,7 +6967,11 @@ fn test_update_rationale() {
         govmod.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: Some("I think this is a good idea".to_string()),
         },
         &[],
@@ -4980,6 +7038,8 @@ fn test_open_proposal_passes_with_zero_timelock_veto_duration() {
         vetoer: "vetoer".to_string(),
         early_execute: false,
         veto_before_passed: true,
+        additional_vetoers: vec![],
+        veto_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -4995,6 +7055,8 @@ fn test_open_proposal_passes_with_zero_timelock_veto_duration() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            voting_period_range: None,
+            tie_breaker: None,
         },
         Some(vec![
             Cw20Coin {
@@ -5022,11 +7084,13 @@ fn test_open_proposal_passes_with_zero_timelock_veto_duration() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
     let mc_options = MultipleChoiceOptions { options };
@@ -5041,6 +7105,10 @@ fn test_open_proposal_passes_with_zero_timelock_veto_duration() {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -5052,7 +7120,11 @@ fn test_open_proposal_passes_with_zero_timelock_veto_duration() {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -5089,6 +7161,8 @@ fn test_veto_non_existing_prop_id() {
         vetoer: "vetoer".to_string(),
         early_execute: false,
         veto_before_passed: true,
+        additional_vetoers: vec![],
+        veto_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -5104,6 +7178,8 @@ fn test_veto_non_existing_prop_id() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            voting_period_range: None,
+            tie_breaker: None,
         },
         Some(vec![
             Cw20Coin {
@@ -5150,6 +7226,8 @@ fn test_veto_with_no_veto_configuration() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            voting_period_range: None,
+            tie_breaker: None,
         },
         Some(vec![
             Cw20Coin {
@@ -5176,11 +7254,13 @@ fn test_veto_with_no_veto_configuration() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
     let mc_options = MultipleChoiceOptions { options };
@@ -5195,6 +7275,10 @@ fn test_veto_with_no_veto_configuration() {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -5227,6 +7311,8 @@ fn test_veto_open_prop_with_veto_before_passed_disabled() {
         vetoer: "vetoer".to_string(),
         early_execute: false,
         veto_before_passed: false,
+        additional_vetoers: vec![],
+        veto_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -5242,6 +7328,8 @@ fn test_veto_open_prop_with_veto_before_passed_disabled() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            voting_period_range: None,
+            tie_breaker: None,
         },
         Some(vec![
             Cw20Coin {
@@ -5269,11 +7357,13 @@ fn test_veto_open_prop_with_veto_before_passed_disabled() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
     let mc_options = MultipleChoiceOptions { options };
@@ -5288,6 +7378,10 @@ fn test_veto_open_prop_with_veto_before_passed_disabled() {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -5298,7 +7392,11 @@ fn test_veto_open_prop_with_veto_before_passed_disabled() {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -5335,6 +7433,8 @@ fn test_veto_when_veto_timelock_expired() -> anyhow::Result<()> {
         vetoer: "vetoer".to_string(),
         early_execute: false,
         veto_before_passed: false,
+        additional_vetoers: vec![],
+        veto_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -5350,6 +7450,8 @@ fn test_veto_when_veto_timelock_expired() -> anyhow::Result<()> {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            voting_period_range: None,
+            tie_breaker: None,
         },
         Some(vec![
             Cw20Coin {
@@ -5377,11 +7479,13 @@ fn test_veto_when_veto_timelock_expired() -> anyhow::Result<()> {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
     let mc_options = MultipleChoiceOptions { options };
@@ -5396,6 +7500,10 @@ fn test_veto_when_veto_timelock_expired() -> anyhow::Result<()> {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -5406,7 +7514,11 @@ fn test_veto_when_veto_timelock_expired() -> anyhow::Result<()> {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -5450,6 +7562,8 @@ fn test_veto_sets_prop_status_to_vetoed() -> anyhow::Result<()> {
         vetoer: "vetoer".to_string(),
         early_execute: false,
         veto_before_passed: false,
+        additional_vetoers: vec![],
+        veto_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -5465,6 +7579,8 @@ fn test_veto_sets_prop_status_to_vetoed() -> anyhow::Result<()> {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            voting_period_range: None,
+            tie_breaker: None,
         },
         Some(vec![
             Cw20Coin {
@@ -5492,11 +7608,13 @@ fn test_veto_sets_prop_status_to_vetoed() -> anyhow::Result<()> {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
     let mc_options = MultipleChoiceOptions { options };
@@ -5511,6 +7629,10 @@ fn test_veto_sets_prop_status_to_vetoed() -> anyhow::Result<()> {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -5521,7 +7643,11 @@ fn test_veto_sets_prop_status_to_vetoed() -> anyhow::Result<()> {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -5561,6 +7687,8 @@ fn test_veto_from_catchall_state() {
         vetoer: "vetoer".to_string(),
         early_execute: true,
         veto_before_passed: false,
+        additional_vetoers: vec![],
+        veto_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -5576,6 +7704,8 @@ fn test_veto_from_catchall_state() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            voting_period_range: None,
+            tie_breaker: None,
         },
         Some(vec![
             Cw20Coin {
@@ -5603,11 +7733,13 @@ fn test_veto_from_catchall_state() {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
     let mc_options = MultipleChoiceOptions { options };
@@ -5622,6 +7754,10 @@ fn test_veto_from_catchall_state() {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -5632,7 +7768,11 @@ fn test_veto_from_catchall_state() {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -5682,6 +7822,8 @@ fn test_veto_timelock_early_execute_happy() -> anyhow::Result<()> {
         vetoer: "vetoer".to_string(),
         early_execute: true,
         veto_before_passed: false,
+        additional_vetoers: vec![],
+        veto_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -5697,6 +7839,8 @@ fn test_veto_timelock_early_execute_happy() -> anyhow::Result<()> {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            voting_period_range: None,
+            tie_breaker: None,
         },
         Some(vec![
             Cw20Coin {
@@ -5724,11 +7868,13 @@ fn test_veto_timelock_early_execute_happy() -> anyhow::Result<()> {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
     let mc_options = MultipleChoiceOptions { options };
@@ -5743,6 +7889,10 @@ fn test_veto_timelock_early_execute_happy() -> anyhow::Result<()> {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -5753,7 +7903,11 @@ fn test_veto_timelock_early_execute_happy() -> anyhow::Result<()> {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -5797,6 +7951,135 @@ fn test_veto_timelock_early_execute_happy() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_veto_council_early_execute() -> anyhow::Result<()> {
+    let mut app = App::default();
+    let timelock_duration = Duration::Height(3);
+    let veto_config = VetoConfig {
+        timelock_duration,
+        vetoer: "vetoer".to_string(),
+        early_execute: true,
+        veto_before_passed: false,
+        additional_vetoers: vec!["council1".to_string(), "council2".to_string()],
+        veto_threshold: Some(2),
+    };
+
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        InstantiateMsg {
+            min_voting_period: None,
+            max_voting_period: Duration::Height(6),
+            only_members_execute: true,
+            allow_revoting: false,
+            voting_strategy: VotingStrategy::SingleChoice {
+                quorum: PercentageThreshold::Majority {},
+            },
+            close_proposal_on_execution_failure: false,
+            pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+            veto: Some(veto_config),
+            voting_period_range: None,
+            tie_breaker: None,
+        },
+        Some(vec![Cw20Coin {
+            address: "a-1".to_string(),
+            amount: Uint128::new(110_000_000),
+        }]),
+    );
+    let proposal_module = query_multiple_proposal_module(&app, &core_addr);
+
+    let options = vec![
+        MultipleChoiceOption {
+            description: "multiple choice option 1".to_string(),
+            msgs: vec![],
+            title: "title".to_string(),
+            funding: None,
+        },
+        MultipleChoiceOption {
+            description: "multiple choice option 2".to_string(),
+            msgs: vec![],
+            title: "title".to_string(),
+            funding: None,
+        },
+    ];
+    app.execute_contract(
+        Addr::unchecked("a-1"),
+        proposal_module.clone(),
+        &ExecuteMsg::Propose(ProposeMsg {
+            title: "A simple text proposal".to_string(),
+            description: "A simple text proposal".to_string(),
+            choices: MultipleChoiceOptions { options },
+            proposer: None,
+            vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
+        }),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked("a-1"),
+        proposal_module.clone(),
+        &ExecuteMsg::Vote {
+            proposal_id: 1,
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
+            rationale: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let proposal: ProposalResponse = query_proposal(&app, &proposal_module, 1);
+    let timelocked = Status::VetoTimelock {
+        expiration: proposal.proposal.expiration.add(timelock_duration)?,
+    };
+    assert_eq!(proposal.proposal.status, timelocked);
+
+    // A single council member may not execute the proposal early.
+    app.execute_contract(
+        Addr::unchecked("council1"),
+        proposal_module.clone(),
+        &ExecuteMsg::Execute { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+    let proposal: ProposalResponse = query_proposal(&app, &proposal_module, 1);
+    assert_eq!(proposal.proposal.status, timelocked);
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("council1"),
+            proposal_module.clone(),
+            &ExecuteMsg::Execute { proposal_id: 1 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::VetoError(VetoError::AlreadyApprovedEarlyExecute {})
+    );
+
+    // A second approval reaches the threshold.
+    app.execute_contract(
+        Addr::unchecked("vetoer"),
+        proposal_module.clone(),
+        &ExecuteMsg::Execute { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+    let proposal: ProposalResponse = query_proposal(&app, &proposal_module, 1);
+    assert_eq!(proposal.proposal.status, Status::Executed);
+
+    Ok(())
+}
+
 #[test]
 fn test_veto_timelock_expires_happy() -> anyhow::Result<()> {
     let mut app = App::default();
@@ -5806,6 +8089,8 @@ fn test_veto_timelock_expires_happy() -> anyhow::Result<()> {
         vetoer: "vetoer".to_string(),
         early_execute: false,
         veto_before_passed: false,
+        additional_vetoers: vec![],
+        veto_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -5821,6 +8106,8 @@ fn test_veto_timelock_expires_happy() -> anyhow::Result<()> {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            voting_period_range: None,
+            tie_breaker: None,
         },
         Some(vec![
             Cw20Coin {
@@ -5848,11 +8135,13 @@ fn test_veto_timelock_expires_happy() -> anyhow::Result<()> {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
     let mc_options = MultipleChoiceOptions { options };
@@ -5867,6 +8156,10 @@ fn test_veto_timelock_expires_happy() -> anyhow::Result<()> {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -5877,7 +8170,11 @@ fn test_veto_timelock_expires_happy() -> anyhow::Result<()> {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -5919,6 +8216,8 @@ fn test_veto_only_members_execute_proposal() -> anyhow::Result<()> {
         vetoer: "vetoer".to_string(),
         early_execute: true,
         veto_before_passed: false,
+        additional_vetoers: vec![],
+        veto_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -5934,6 +8233,8 @@ fn test_veto_only_members_execute_proposal() -> anyhow::Result<()> {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            voting_period_range: None,
+            tie_breaker: None,
         },
         Some(vec![
             Cw20Coin {
@@ -5961,11 +8262,13 @@ fn test_veto_only_members_execute_proposal() -> anyhow::Result<()> {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         },
     ];
     let mc_options = MultipleChoiceOptions { options };
@@ -5980,6 +8283,10 @@ fn test_veto_only_members_execute_proposal() -> anyhow::Result<()> {
             choices: mc_options,
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
+            budget: None,
         }),
         &[],
     )
@@ -5990,7 +8297,11 @@ fn test_veto_only_members_execute_proposal() -> anyhow::Result<()> {
         proposal_module.clone(),
         &ExecuteMsg::Vote {
             proposal_id: 1,
-            vote: MultipleChoiceVote { option_id: 0 },
+            vote: MultipleChoiceVote {
+                option_id: 0,
+                additional_option_ids: vec![],
+                scores: vec![],
+            },
             rationale: None,
         },
         &[],
@@ -6038,3 +8349,136 @@ fn test_veto_only_members_execute_proposal() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_index_after_migration() {
+    use crate::contract::{execute, migrate};
+    use crate::msg::MigrateMsg;
+    use crate::state::{Ballot, BALLOTS};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cw_storage_plus::Map;
+
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("anyone", &[]);
+
+    // Migrating a module with nothing to index does not start
+    // indexing.
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        ExecuteMsg::IndexProposals { limit: None },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::NothingToIndex {}));
+
+    // Proposals and ballots saved by a version of the module without
+    // their indexes.
+    let options = MultipleChoiceOptions {
+        options: vec![
+            MultipleChoiceOption {
+                description: "multiple choice option 1".to_string(),
+                msgs: vec![],
+                title: "title".to_string(),
+                funding: None,
+            },
+            MultipleChoiceOption {
+                description: "multiple choice option 2".to_string(),
+                msgs: vec![],
+                title: "title".to_string(),
+                funding: None,
+            },
+        ],
+    };
+    let proposal = MultipleChoiceProposal {
+        title: "title".to_string(),
+        description: "description".to_string(),
+        proposer: Addr::unchecked(CREATOR_ADDR),
+        start_height: env.block.height,
+        expiration: Duration::Height(6).after(&env.block),
+        choices: options.into_checked().unwrap().options,
+        status: Status::Open,
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        total_power: Uint128::new(100_000_000),
+        votes: MultipleChoiceVotes {
+            vote_weights: vec![Uint128::zero(); 3],
+            power_cast: Some(Uint128::zero()),
+            participation: vec![],
+        },
+        allow_revoting: false,
+        min_voting_period: None,
+        veto: None,
+        execute_after: None,
+        depends_on: vec![],
+        runoff: None,
+        tie_breaker: TieBreaker::Fail {},
+        weights_changed_at: vec![],
+        budget: None,
+    };
+    let unindexed: Map<u64, MultipleChoiceProposal> = Map::new("proposals");
+    for id in 1..=2 {
+        unindexed
+            .save(deps.as_mut().storage, id, &proposal)
+            .unwrap();
+    }
+    BALLOTS
+        .save(
+            deps.as_mut().storage,
+            (1, &Addr::unchecked(CREATOR_ADDR)),
+            &Ballot {
+                power: Uint128::new(1),
+                vote: MultipleChoiceVote {
+                    option_id: 0,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
+                rationale: None,
+            },
+        )
+        .unwrap();
+
+    let index = |deps: &mut cosmwasm_std::OwnedDeps<_, _, _>, msg: ExecuteMsg| {
+        execute(deps.as_mut(), mock_env(), info.clone(), msg).map(|res| {
+            let attr = |key: &str| {
+                res.attributes
+                    .iter()
+                    .find(|attr| attr.key == key)
+                    .unwrap()
+                    .value
+                    .clone()
+            };
+            (attr("indexed"), attr("complete"))
+        })
+    };
+
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
+    assert_eq!(
+        index(&mut deps, ExecuteMsg::IndexProposals { limit: Some(1) }).unwrap(),
+        ("1".to_string(), "false".to_string())
+    );
+
+    // Migrating again continues indexing where it left off.
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
+    assert_eq!(
+        index(&mut deps, ExecuteMsg::IndexProposals { limit: Some(2) }).unwrap(),
+        ("1".to_string(), "true".to_string())
+    );
+    assert_eq!(
+        index(&mut deps, ExecuteMsg::IndexBallots { limit: None }).unwrap(),
+        ("1".to_string(), "true".to_string())
+    );
+
+    // Once indexing is complete, migrating does not restart it.
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
+    for msg in [
+        ExecuteMsg::IndexProposals { limit: None },
+        ExecuteMsg::IndexBallots { limit: None },
+    ] {
+        let err = index(&mut deps, msg).unwrap_err();
+        assert!(matches!(err, ContractError::NothingToIndex {}));
+    }
+}
@@ -1,8 +1,9 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Addr, Attribute, Binary, Deps, DepsMut, Env, MessageInfo, Order, Reply,
-    Response, StdResult, Storage, SubMsg, WasmMsg,
+    to_json_binary, Addr, Attribute, Binary, BlockInfo, CosmosMsg, Decimal, Deps, DepsMut, Empty,
+    Env, MessageInfo, Order, Reply, Response, StdResult, Storage, SubMsg, SubMsgResponse, Uint128,
+    WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
 use cw_hooks::Hooks;
@@ -15,19 +16,30 @@ use dao_hooks::vote::new_vote_hooks;
 use dao_interface::voting::IsActiveResponse;
 use dao_voting::pre_propose::{PreProposeInfo, ProposalCreationPolicy};
 use dao_voting::proposal::{
+    dependency_status, is_closable, query_proposal_status, validate_depends_on, DependencyStatus,
     SingleChoiceProposeMsg as ProposeMsg, DEFAULT_LIMIT, MAX_PROPOSAL_SIZE,
 };
 use dao_voting::reply::{
-    failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id, TaggedReplyId,
+    failed_pre_propose_module_hook_id, mask_proposal_execution_result_id,
+    mask_proposal_message_execution_id, TaggedReplyId,
 };
+use dao_voting::signed_vote::SignedVotePayload;
 use dao_voting::status::Status;
-use dao_voting::threshold::Threshold;
-use dao_voting::veto::{VetoConfig, VetoError};
-use dao_voting::voting::{get_total_power, get_voting_power, validate_voting_period, Vote, Votes};
+use dao_voting::threshold::{update_turnout_average, Threshold};
+use dao_voting::veto::{VetoConfig, VetoError, VetoTallyResponse};
+use dao_voting::voting::{
+    get_total_power, get_voting_power, validate_execute_after, validate_no_with_veto_threshold,
+    validate_voting_period, Vote, Votes, VotingPeriodRange,
+};
 
 use crate::msg::MigrateMsg;
-use crate::proposal::{next_proposal_id, SingleChoiceProposal};
-use crate::state::{Config, CREATION_POLICY};
+use crate::proposal::{
+    next_proposal_id, CommitRevealState, MessageExecutionResult, SingleChoiceProposal,
+};
+use crate::state::{CommitRevealConfig, Commitment, Config, COMMITMENTS, CREATION_POLICY};
+use crate::threshold_rules::{
+    apply_threshold_rule, select_threshold_rule, validate_threshold_rules, ThresholdRule,
+};
 use crate::v1_state::{
     v1_duration_to_v2, v1_expiration_to_v2, v1_status_to_v2, v1_threshold_to_v2, v1_votes_to_v2,
 };
@@ -36,10 +48,20 @@ use crate::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     proposal::advance_proposal_id,
     query::ProposalListResponse,
-    query::{ProposalResponse, VoteInfo, VoteListResponse, VoteResponse},
-    state::{Ballot, BALLOTS, CONFIG, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS, VOTE_HOOKS},
+    query::{
+        ExecutionResultResponse, ProposalResponse, VoteInfo, VoteListResponse, VoteResponse,
+        VoterVoteInfo, VoterVoteListResponse,
+    },
+    state::{
+        expiration_key, proposals, Ballot, BALLOTS, BALLOT_INDEX_CURSOR, CONFIG,
+        EARLY_EXECUTE_VOTES, EXECUTING_PROPOSALS, EXECUTION_RESULTS, EXPIRES_AT_HEIGHT,
+        EXPIRES_AT_TIME, PENDING_TURNOUTS, PROPOSAL_COUNT, PROPOSAL_HOOKS, PROPOSAL_INDEX_CURSOR,
+        TURNOUT_AVERAGE, VETO_VOTES, VOTER_PROPOSALS, VOTE_HOOKS, VOTE_NONCES,
+    },
 };
 use cw_proposal_single_v1 as v1;
+use sha2::{Digest, Sha256};
+use std::ops::Add;
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-proposal-single";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -68,6 +90,15 @@ pub fn instantiate(
         veto_config.validate(&deps.as_ref(), &max_voting_period)?;
     };
 
+    validate_threshold_rules(&msg.threshold_rules, max_voting_period)?;
+    if let Some(commit_reveal) = &msg.commit_reveal {
+        commit_reveal.validate(&max_voting_period)?;
+    }
+    if let Some(range) = &msg.voting_period_range {
+        range.validate(min_voting_period, max_voting_period)?;
+    }
+    validate_no_with_veto_threshold(msg.no_with_veto_threshold)?;
+
     let config = Config {
         threshold: msg.threshold,
         max_voting_period,
@@ -76,7 +107,12 @@ pub fn instantiate(
         dao: dao.clone(),
         allow_revoting: msg.allow_revoting,
         close_proposal_on_execution_failure: msg.close_proposal_on_execution_failure,
+        continue_on_execution_failure: msg.continue_on_execution_failure,
         veto: msg.veto,
+        threshold_rules: msg.threshold_rules,
+        commit_reveal: msg.commit_reveal,
+        voting_period_range: msg.voting_period_range,
+        no_with_veto_threshold: msg.no_with_veto_threshold,
     };
 
     // Initialize proposal count to zero so that queries return zero
@@ -105,11 +141,31 @@ pub fn execute(
             vote,
             rationale,
         } => execute_vote(deps, env, info.sender, proposal_id, vote, rationale),
+        ExecuteMsg::VoteWithSignature {
+            voter,
+            payload,
+            pubkey,
+            signature,
+        } => execute_vote_with_signature(deps, env, info, voter, payload, pubkey, signature),
+        ExecuteMsg::CommitVote {
+            proposal_id,
+            commitment,
+        } => execute_commit_vote(deps, env, info.sender, proposal_id, commitment),
+        ExecuteMsg::RevealVote {
+            proposal_id,
+            vote,
+            salt,
+            rationale,
+        } => execute_reveal_vote(deps, env, info.sender, proposal_id, vote, salt, rationale),
         ExecuteMsg::UpdateRationale {
             proposal_id,
             rationale,
         } => execute_update_rationale(deps, info, proposal_id, rationale),
         ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, info, proposal_id),
+        ExecuteMsg::RetryFailedMessages {
+            proposal_id,
+            msg_indices,
+        } => execute_retry_failed_messages(deps, info, proposal_id, msg_indices),
         ExecuteMsg::Close { proposal_id } => execute_close(deps, env, info, proposal_id),
         ExecuteMsg::UpdateConfig {
             threshold,
@@ -119,7 +175,12 @@ pub fn execute(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            continue_on_execution_failure,
             veto,
+            threshold_rules,
+            commit_reveal,
+            voting_period_range,
+            no_with_veto_threshold,
         } => execute_update_config(
             deps,
             info,
@@ -130,11 +191,18 @@ pub fn execute(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            continue_on_execution_failure,
             veto,
+            threshold_rules,
+            commit_reveal,
+            voting_period_range,
+            no_with_veto_threshold,
         ),
         ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
             execute_update_proposal_creation_policy(deps, info, new_info)
         }
+        ExecuteMsg::IndexProposals { limit } => execute_index_proposals(deps, limit),
+        ExecuteMsg::IndexBallots { limit } => execute_index_ballots(deps, limit),
         ExecuteMsg::AddProposalHook { address } => {
             execute_add_proposal_hook(deps, env, info, address)
         }
@@ -159,6 +227,9 @@ pub fn execute_propose(
         msgs,
         proposer,
         vote,
+        execute_after,
+        depends_on,
+        voting_period,
     }: ProposeMsg,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
@@ -198,9 +269,73 @@ pub fn execute_propose(
         return Err(ContractError::InactiveDao {});
     }
 
-    let expiration = config.max_voting_period.after(&env.block);
+    let execute_after = validate_execute_after(execute_after, &config.max_voting_period)?;
+    let depends_on = validate_depends_on(deps.api, depends_on, |module, proposal_id| {
+        query_dependency_status(deps.as_ref(), &env, module, proposal_id)
+    })?;
+
+    // Save the statuses of the proposals that expired first so that
+    // the status index does not drift as they expire, and record the
+    // turnouts of the proposals whose voting ended first.
+    let status_changed_hooks = sync_proposal_statuses(deps.storage, &env.block)?;
 
     let total_power = get_total_power(deps.as_ref(), &config.dao, Some(env.block.height))?;
+    let average_turnout = TURNOUT_AVERAGE.may_load(deps.storage)?;
+
+    // Proposals containing sensitive actions use the threshold and
+    // minimum voting period of the strictest matching rule, where
+    // they are stricter than the module's.
+    let threshold_rule = select_threshold_rule(
+        &config.threshold_rules,
+        &config.dao,
+        &msgs,
+        total_power,
+        average_turnout,
+    );
+    let (threshold, min_voting_period) = match &threshold_rule {
+        Some(rule) => apply_threshold_rule(
+            rule,
+            config.threshold,
+            config.min_voting_period,
+            total_power,
+            average_turnout,
+        ),
+        None => (config.threshold, config.min_voting_period),
+    };
+    // Dynamic quorums are fixed when the proposal is created.
+    let threshold = threshold.snapshot_quorum(average_turnout);
+
+    // Proposers may choose a voting period within the configured
+    // range. It may not be shorter than the proposal's minimum voting
+    // period, and must have the same units as the veto timelock.
+    let voting_period = match voting_period {
+        Some(voting_period) => {
+            let voting_period = config
+                .voting_period_range
+                .ok_or(ContractError::VotingPeriodNotAllowed {})?
+                .check(voting_period)?;
+            validate_voting_period(min_voting_period, voting_period)?;
+            if let Some(veto_config) = &config.veto {
+                veto_config.validate(&deps.as_ref(), &voting_period)?;
+            }
+            voting_period
+        }
+        None => config.max_voting_period,
+    };
+    let expiration = voting_period.after(&env.block);
+
+    // Snapshot the commit-reveal config. Votes are revealed after the
+    // proposal expires.
+    let commit_reveal = config
+        .commit_reveal
+        .map(|commit_reveal| -> StdResult<_> {
+            Ok(CommitRevealState {
+                reveal_expiration: expiration.add(commit_reveal.reveal_period)?,
+                count_unrevealed_as_abstain: commit_reveal.count_unrevealed_as_abstain,
+                unrevealed_power: Uint128::zero(),
+            })
+        })
+        .transpose()?;
 
     let proposal = {
         // Limit mutability to this block.
@@ -209,15 +344,21 @@ pub fn execute_propose(
             description,
             proposer: proposer.clone(),
             start_height: env.block.height,
-            min_voting_period: config.min_voting_period.map(|min| min.after(&env.block)),
+            min_voting_period: min_voting_period.map(|min| min.after(&env.block)),
             expiration,
-            threshold: config.threshold,
+            threshold,
+            threshold_rule,
             total_power,
             msgs,
             status: Status::Open,
             votes: Votes::zero(),
             allow_revoting: config.allow_revoting,
             veto: config.veto,
+            execute_after,
+            depends_on,
+            message_results: vec![],
+            commit_reveal,
+            no_with_veto_threshold: config.no_with_veto_threshold,
         };
         // Update the proposal's status. Addresses case where proposal
         // expires on the same block as it is created.
@@ -248,7 +389,8 @@ pub fn execute_propose(
         });
     }
 
-    PROPOSALS.save(deps.storage, id, &proposal)?;
+    proposals().save(deps.storage, id, &proposal)?;
+    PENDING_TURNOUTS.save(deps.storage, turnout_key(id, &proposal), &Empty {})?;
 
     let hooks = new_proposal_hooks(PROPOSAL_HOOKS, deps.storage, id, proposer.as_str())?;
 
@@ -273,6 +415,7 @@ pub fn execute_propose(
     };
 
     Ok(Response::default()
+        .add_submessages(status_changed_hooks)
         .add_submessages(hooks)
         .add_submessages(vote_hooks)
         .add_attribute("action", "propose")
@@ -288,7 +431,7 @@ pub fn execute_veto(
     info: MessageInfo,
     proposal_id: u64,
 ) -> Result<Response, ContractError> {
-    let mut prop = PROPOSALS
+    let mut prop = proposals()
         .may_load(deps.storage, proposal_id)?
         .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
 
@@ -330,9 +473,24 @@ pub fn execute_veto(
         }
     }
 
+    // Record this vetoer's veto. The proposal is only vetoed once
+    // enough vetoers have vetoed it.
+    let vetoes = veto_config.record_veto(deps.storage, &VETO_VOTES, proposal_id, &info.sender)?;
+    if !vetoes.threshold_reached {
+        // Save the updated status, which may have changed from the
+        // update above.
+        proposals().save(deps.storage, proposal_id, &prop)?;
+        return Ok(Response::new()
+            .add_attribute("action", "veto")
+            .add_attribute("proposal_id", proposal_id.to_string())
+            .add_attribute("vetoes", vetoes.count.to_string())
+            .add_attribute("status", prop.status.to_string()));
+    }
+
     // Update proposal status to vetoed
     prop.status = Status::Vetoed;
-    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    proposals().save(deps.storage, proposal_id, &prop)?;
+    record_turnout(deps.storage, proposal_id, &prop)?;
 
     // Add proposal status change hooks
     let proposal_status_changed_hooks = proposal_status_changed_hooks(
@@ -346,7 +504,7 @@ pub fn execute_veto(
     // Add prepropose / deposit module hook which will handle deposit refunds.
     let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
     let proposal_completed_hooks =
-        proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status)?;
+        proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status, false)?;
 
     Ok(Response::new()
         .add_attribute("action", "veto")
@@ -361,7 +519,7 @@ pub fn execute_execute(
     info: MessageInfo,
     proposal_id: u64,
 ) -> Result<Response, ContractError> {
-    let mut prop = PROPOSALS
+    let mut prop = proposals()
         .may_load(deps.storage, proposal_id)?
         .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
 
@@ -401,9 +559,9 @@ pub fn execute_execute(
                 .as_ref()
                 .ok_or(VetoError::NoVetoConfiguration {})?;
 
-            // check that the sender is the vetoer
-            if veto_config.vetoer != info.sender {
-                // if the sender can normally execute, but is not the vetoer,
+            // check that the sender is a vetoer
+            if veto_config.check_is_vetoer(&info).is_err() {
+                // if the sender can normally execute, but is not a vetoer,
                 // return timelocked error. otherwise return unauthorized.
                 if sender_can_execute {
                     return Err(ContractError::VetoError(VetoError::Timelocked {}));
@@ -414,18 +572,95 @@ pub fn execute_execute(
 
             // if veto timelocked, only allow execution if early_execute enabled
             veto_config.check_early_execute_enabled()?;
+
+            // Like a veto, early execution needs the approval of
+            // enough vetoers.
+            let approvals = veto_config.record_early_execute(
+                deps.storage,
+                &EARLY_EXECUTE_VOTES,
+                proposal_id,
+                &info.sender,
+            )?;
+            if !approvals.threshold_reached {
+                proposals().save(deps.storage, proposal_id, &prop)?;
+                return Ok(Response::new()
+                    .add_attribute("action", "execute")
+                    .add_attribute("sender", info.sender)
+                    .add_attribute("proposal_id", proposal_id.to_string())
+                    .add_attribute("early_execute_approvals", approvals.count.to_string())
+                    .add_attribute("status", prop.status.to_string()));
+            }
         }
         _ => {
             return Err(ContractError::NotPassed {});
         }
     }
 
-    prop.status = Status::Executed;
+    // Regardless of any veto timelock, the proposal may not be
+    // executed before its earliest execution time.
+    if let Some(execute_after) = prop.execute_after {
+        if !execute_after.is_expired(&env.block) {
+            return Err(ContractError::ExecutionLocked { execute_after });
+        }
+    }
 
-    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    match dependency_status(&prop.depends_on, |module, proposal_id| {
+        query_dependency_status(deps.as_ref(), &env, module, proposal_id)
+    })? {
+        DependencyStatus::Executed => (),
+        DependencyStatus::Pending {
+            module,
+            proposal_id: dependency_id,
+        } => {
+            return Err(ContractError::DependencyNotExecuted {
+                module: module.into_string(),
+                proposal_id: dependency_id,
+            })
+        }
+        // This proposal can never be executed, so close it instead.
+        DependencyStatus::Failed {
+            module,
+            proposal_id: dependency_id,
+        } => {
+            return Ok(close_proposal(deps.storage, &env.block, proposal_id, prop)?
+                .add_attribute("action", "close")
+                .add_attribute("sender", info.sender)
+                .add_attribute("proposal_id", proposal_id.to_string())
+                .add_attribute("failed_dependency", format!("{module}:{dependency_id}")))
+        }
+    }
+
+    // Messages executed individually may fail, so the proposal is
+    // partially executed until every one of them has replied, at which
+    // point its final status is set and hooks are fired.
+    let execute_individually = config.continue_on_execution_failure && !prop.msgs.is_empty();
+    if execute_individually {
+        prop.status = Status::PartiallyExecuted;
+        prop.message_results = vec![MessageExecutionResult::Pending; prop.msgs.len()];
+        EXECUTING_PROPOSALS.save(deps.storage, proposal_id, &old_status)?;
+    } else {
+        prop.status = Status::Executed;
+    }
+
+    proposals().save(deps.storage, proposal_id, &prop)?;
+    record_turnout(deps.storage, proposal_id, &prop)?;
 
     let response = {
-        if !prop.msgs.is_empty() {
+        if prop.msgs.is_empty() {
+            Response::default()
+        } else if execute_individually {
+            // Hooks are fired once every message has replied.
+            return Ok(Response::default()
+                .add_submessages(individual_execution_submsgs(
+                    &config.dao,
+                    proposal_id,
+                    prop.msgs.into_iter().enumerate(),
+                )?)
+                .add_attribute("action", "execute")
+                .add_attribute("sender", info.sender)
+                .add_attribute("proposal_id", proposal_id.to_string())
+                .add_attribute("dao", config.dao));
+        } else {
             let execute_message = WasmMsg::Execute {
                 contract_addr: config.dao.to_string(),
                 msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook {
@@ -433,16 +668,18 @@ pub fn execute_execute(
                 })?,
                 funds: vec![],
             };
+            // Always reply on success so the execution result may be
+            // recorded. Only reply on error if failures should close
+            // the proposal instead of reverting the execution.
+            let masked_proposal_id = mask_proposal_execution_result_id(proposal_id);
             match config.close_proposal_on_execution_failure {
-                true => {
-                    let masked_proposal_id = mask_proposal_execution_proposal_id(proposal_id);
-                    Response::default()
-                        .add_submessage(SubMsg::reply_on_error(execute_message, masked_proposal_id))
-                }
-                false => Response::default().add_message(execute_message),
+                true => Response::default()
+                    .add_submessage(SubMsg::reply_always(execute_message, masked_proposal_id)),
+                false => Response::default().add_submessage(SubMsg::reply_on_success(
+                    execute_message,
+                    masked_proposal_id,
+                )),
             }
-        } else {
-            Response::default()
         }
     };
 
@@ -458,7 +695,7 @@ pub fn execute_execute(
     // Add prepropose / deposit module hook which will handle deposit refunds.
     let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
     let proposal_completed_hooks =
-        proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status)?;
+        proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status, false)?;
 
     Ok(response
         .add_submessages(proposal_status_changed_hooks)
@@ -469,6 +706,143 @@ pub fn execute_execute(
         .add_attribute("dao", config.dao))
 }
 
+pub fn execute_retry_failed_messages(
+    deps: DepsMut,
+    info: MessageInfo,
+    proposal_id: u64,
+    msg_indices: Option<Vec<u64>>,
+) -> Result<Response, ContractError> {
+    let mut prop = proposals()
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    // Proposals whose messages have not all replied are still being
+    // executed.
+    if prop.status != Status::PartiallyExecuted
+        || prop
+            .message_results
+            .contains(&MessageExecutionResult::Pending)
+    {
+        return Err(ContractError::NotPartiallyExecuted {});
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    if config.only_members_execute {
+        let power = get_voting_power(
+            deps.as_ref(),
+            info.sender.clone(),
+            &config.dao,
+            Some(prop.start_height),
+        )?;
+        if power.is_zero() {
+            return Err(ContractError::Unauthorized {});
+        }
+    }
+
+    // If no indices are specified, retry every failed message.
+    let msg_indices = msg_indices.unwrap_or_else(|| {
+        prop.message_results
+            .iter()
+            .enumerate()
+            .filter(|(_, result)| matches!(result, MessageExecutionResult::Failed { .. }))
+            .map(|(index, _)| index as u64)
+            .collect()
+    });
+
+    let mut msgs = Vec::with_capacity(msg_indices.len());
+    for index in msg_indices {
+        if !matches!(
+            prop.message_results.get(index as usize),
+            Some(MessageExecutionResult::Failed { .. })
+        ) {
+            return Err(ContractError::MessageNotFailed {
+                id: proposal_id,
+                index,
+            });
+        }
+        prop.message_results[index as usize] = MessageExecutionResult::Pending;
+        msgs.push((index as usize, prop.msgs[index as usize].clone()));
+    }
+
+    // The proposal remains partially executed until every retried
+    // message has replied.
+    proposals().save(deps.storage, proposal_id, &prop)?;
+    EXECUTING_PROPOSALS.save(deps.storage, proposal_id, &prop.status)?;
+
+    Ok(Response::default()
+        .add_submessages(individual_execution_submsgs(
+            &config.dao,
+            proposal_id,
+            msgs,
+        )?)
+        .add_attribute("action", "retry_failed_messages")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("dao", config.dao))
+}
+
+/// Adds the events and data from a successful execution of a
+/// proposal's messages to that proposal's execution result.
+fn record_execution_result(
+    storage: &mut dyn Storage,
+    proposal_id: u64,
+    response: SubMsgResponse,
+) -> StdResult<()> {
+    let mut result = EXECUTION_RESULTS
+        .may_load(storage, proposal_id)?
+        .unwrap_or_default();
+    result.record(response)?;
+    EXECUTION_RESULTS.save(storage, proposal_id, &result)
+}
+
+/// Creates submessages that have the DAO execute each of the given
+/// proposal messages on its own. Every submessage replies, whether it
+/// succeeds or fails, so that its result may be recorded on the
+/// proposal without reverting the other messages.
+fn individual_execution_submsgs(
+    dao: &Addr,
+    proposal_id: u64,
+    msgs: impl IntoIterator<Item = (usize, CosmosMsg)>,
+) -> StdResult<Vec<SubMsg>> {
+    msgs.into_iter()
+        .map(|(index, msg)| {
+            let execute_message = WasmMsg::Execute {
+                contract_addr: dao.to_string(),
+                msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook {
+                    msgs: vec![msg],
+                })?,
+                funds: vec![],
+            };
+            Ok(SubMsg::reply_always(
+                execute_message,
+                mask_proposal_message_execution_id(proposal_id, index as u64),
+            ))
+        })
+        .collect()
+}
+
+pub fn execute_vote_with_signature(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    voter: String,
+    payload: SignedVotePayload<Vote>,
+    pubkey: Binary,
+    signature: Binary,
+) -> Result<Response, ContractError> {
+    let voter = deps.api.addr_validate(&voter)?;
+    let nonce = VOTE_NONCES
+        .may_load(deps.storage, &voter)?
+        .unwrap_or_default();
+    payload.verify(deps.api, &env, &voter, nonce, &pubkey, &signature)?;
+    VOTE_NONCES.save(deps.storage, &voter, &(nonce + 1))?;
+
+    Ok(
+        execute_vote(deps, env, voter, payload.proposal_id, payload.vote, None)?
+            .add_attribute("relayer", info.sender),
+    )
+}
+
 pub fn execute_vote(
     deps: DepsMut,
     env: Env,
@@ -478,7 +852,7 @@ pub fn execute_vote(
     rationale: Option<String>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    let mut prop = PROPOSALS
+    let mut prop = proposals()
         .may_load(deps.storage, proposal_id)?
         .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
 
@@ -493,6 +867,12 @@ pub fn execute_vote(
         return Err(ContractError::Expired { id: proposal_id });
     }
 
+    // Votes on commit-reveal proposals go through `CommitVote` and
+    // `RevealVote`.
+    if prop.commit_reveal.is_some() {
+        return Err(ContractError::CommitRevealVoting { id: proposal_id });
+    }
+
     let vote_power = get_voting_power(
         deps.as_ref(),
         sender.clone(),
@@ -534,13 +914,14 @@ pub fn execute_vote(
             rationale: rationale.clone(),
         }),
     })?;
+    VOTER_PROPOSALS.save(deps.storage, (&sender, proposal_id), &Empty {})?;
 
     let old_status = prop.status;
 
     prop.votes.add_vote(vote, vote_power);
     prop.update_status(&env.block)?;
 
-    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    proposals().save(deps.storage, proposal_id, &prop)?;
 
     let new_status = prop.status;
     let change_hooks = proposal_status_changed_hooks(
@@ -573,6 +954,157 @@ pub fn execute_vote(
         .add_attribute("status", prop.status.to_string()))
 }
 
+/// Computes the commitment to a vote on a commit-reveal proposal: the
+/// SHA-256 hash of `"{voter}:{proposal_id}:{vote}:{salt}"`, where
+/// `vote` is one of `yes`, `no`, or `abstain`. Binding the voter and
+/// proposal prevents commitments from being copied by other voters or
+/// replayed on other proposals.
+pub fn vote_commitment(voter: &Addr, proposal_id: u64, vote: Vote, salt: &str) -> Binary {
+    Binary::from(Sha256::digest(format!("{voter}:{proposal_id}:{vote}:{salt}").as_bytes()).to_vec())
+}
+
+pub fn execute_commit_vote(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    proposal_id: u64,
+    commitment: Binary,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut prop = proposals()
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    // Votes may be committed until the proposal expires, and only
+    // while it is open. Proposals may be closed or vetoed before then.
+    if prop.expiration.is_expired(&env.block) {
+        return Err(ContractError::Expired { id: proposal_id });
+    }
+    prop.update_status(&env.block)?;
+    if prop.status != Status::Open {
+        return Err(ContractError::NotOpen {
+            id: proposal_id,
+            status: prop.status.to_string(),
+        });
+    }
+    let commit_reveal = prop
+        .commit_reveal
+        .as_mut()
+        .ok_or(ContractError::NotCommitReveal { id: proposal_id })?;
+
+    let vote_power = get_voting_power(
+        deps.as_ref(),
+        sender.clone(),
+        &config.dao,
+        Some(prop.start_height),
+    )?;
+    if vote_power.is_zero() {
+        return Err(ContractError::NotRegistered {});
+    }
+
+    // Recommitting replaces the previous commitment, and is only
+    // possible if revoting is allowed.
+    if let Some(previous) = COMMITMENTS.may_load(deps.storage, (proposal_id, &sender))? {
+        if !prop.allow_revoting {
+            return Err(ContractError::AlreadyCommitted {});
+        }
+        commit_reveal.unrevealed_power -= previous.power;
+    }
+    commit_reveal.unrevealed_power += vote_power;
+
+    COMMITMENTS.save(
+        deps.storage,
+        (proposal_id, &sender),
+        &Commitment {
+            power: vote_power,
+            commitment,
+        },
+    )?;
+    proposals().save(deps.storage, proposal_id, &prop)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "commit_vote")
+        .add_attribute("sender", sender)
+        .add_attribute("proposal_id", proposal_id.to_string()))
+}
+
+pub fn execute_reveal_vote(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    proposal_id: u64,
+    vote: Vote,
+    salt: String,
+    rationale: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut prop = proposals()
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    // Commit-reveal proposals remain open until their reveal period
+    // ends, unless they are completed sooner, such as by being vetoed.
+    if prop.commit_reveal.is_some() && prop.status != Status::Open {
+        return Err(ContractError::NotOpen {
+            id: proposal_id,
+            status: prop.status.to_string(),
+        });
+    }
+    let commit_reveal = prop
+        .commit_reveal
+        .as_mut()
+        .ok_or(ContractError::NotCommitReveal { id: proposal_id })?;
+    if !prop.expiration.is_expired(&env.block)
+        || commit_reveal.reveal_expiration.is_expired(&env.block)
+    {
+        return Err(ContractError::NotRevealPeriod { id: proposal_id });
+    }
+
+    let commitment = COMMITMENTS
+        .may_load(deps.storage, (proposal_id, &sender))?
+        .ok_or_else(|| ContractError::NoSuchCommitment {
+            id: proposal_id,
+            voter: sender.to_string(),
+        })?;
+    if vote_commitment(&sender, proposal_id, vote, &salt) != commitment.commitment {
+        return Err(ContractError::CommitmentMismatch {});
+    }
+    COMMITMENTS.remove(deps.storage, (proposal_id, &sender));
+
+    commit_reveal.unrevealed_power -= commitment.power;
+    prop.votes.add_vote(vote, commitment.power);
+    proposals().save(deps.storage, proposal_id, &prop)?;
+
+    BALLOTS.save(
+        deps.storage,
+        (proposal_id, &sender),
+        &Ballot {
+            power: commitment.power,
+            vote,
+            rationale: rationale.clone(),
+        },
+    )?;
+    VOTER_PROPOSALS.save(deps.storage, (&sender, proposal_id), &Empty {})?;
+
+    let vote_hooks = new_vote_hooks(
+        VOTE_HOOKS,
+        deps.storage,
+        proposal_id,
+        sender.to_string(),
+        vote.to_string(),
+    )?;
+
+    Ok(Response::default()
+        .add_submessages(vote_hooks)
+        .add_attribute("action", "reveal_vote")
+        .add_attribute("sender", sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("position", vote.to_string())
+        .add_attribute(
+            "rationale",
+            rationale.unwrap_or_else(|| "_none".to_string()),
+        ))
+}
+
 pub fn execute_update_rationale(
     deps: DepsMut,
     info: MessageInfo,
@@ -609,40 +1141,179 @@ pub fn execute_close(
     info: MessageInfo,
     proposal_id: u64,
 ) -> Result<Response, ContractError> {
-    let mut prop = PROPOSALS.load(deps.storage, proposal_id)?;
+    let mut prop = proposals().load(deps.storage, proposal_id)?;
 
     // Update status to ensure that proposals which were open and have
     // expired are moved to "rejected."
     prop.update_status(&env.block)?;
-    if prop.status != Status::Rejected {
+    // Proposals that have not completed may also be closed if one of
+    // their dependencies has failed.
+    if !is_closable(&prop.status, || {
+        dependency_status(&prop.depends_on, |module, proposal_id| {
+            query_dependency_status(deps.as_ref(), &env, module, proposal_id)
+        })
+    })? {
         return Err(ContractError::WrongCloseStatus {});
     }
 
+    Ok(close_proposal(deps.storage, &env.block, proposal_id, prop)?
+        .add_attribute("action", "close")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string()))
+}
+
+/// Closes a proposal, notifying proposal hooks and the proposal
+/// creation policy module of the change so that deposits may be
+/// refunded, or forfeited if the proposal was vetoed by its voters.
+fn close_proposal(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    proposal_id: u64,
+    mut prop: SingleChoiceProposal,
+) -> Result<Response, ContractError> {
     let old_status = prop.status;
+    let no_with_veto = prop.is_vetoed(block);
 
     prop.status = Status::Closed;
-    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    proposals().save(storage, proposal_id, &prop)?;
+    record_turnout(storage, proposal_id, &prop)?;
 
     // Add proposal status change hooks
     let proposal_status_changed_hooks = proposal_status_changed_hooks(
         PROPOSAL_HOOKS,
-        deps.storage,
+        storage,
         proposal_id,
         old_status.to_string(),
         prop.status.to_string(),
     )?;
 
     // Add prepropose / deposit module hook which will handle deposit refunds.
-    let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
-    let proposal_completed_hooks =
-        proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status)?;
+    let proposal_creation_policy = CREATION_POLICY.load(storage)?;
+    let proposal_completed_hooks = proposal_completed_hooks(
+        proposal_creation_policy,
+        proposal_id,
+        prop.status,
+        no_with_veto,
+    )?;
 
     Ok(Response::default()
         .add_submessages(proposal_status_changed_hooks)
-        .add_submessages(proposal_completed_hooks)
-        .add_attribute("action", "close")
-        .add_attribute("sender", info.sender)
-        .add_attribute("proposal_id", proposal_id.to_string()))
+        .add_submessages(proposal_completed_hooks))
+}
+
+/// The maximum number of proposals expiring at a height, and at a
+/// time, whose status is saved when a proposal is created.
+const MAX_STATUS_SYNCS: usize = 5;
+
+/// Saves the current status of the proposals whose stored status
+/// expired first, and records the turnout of the proposals whose
+/// voting ended first. Statuses change lazily as time passes, so
+/// otherwise the status index would only catch up, and turnouts only
+/// be recorded, when a proposal is next voted on, executed, or
+/// closed. Returns the status changed hooks for the proposals whose
+/// status was saved.
+pub(crate) fn sync_proposal_statuses(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+) -> Result<Vec<SubMsg>, ContractError> {
+    let mut hooks = vec![];
+    for (units, now) in [
+        (EXPIRES_AT_HEIGHT, block.height),
+        (EXPIRES_AT_TIME, block.time.nanos()),
+    ] {
+        let expired = proposals()
+            .idx
+            .status_expiration
+            .sub_prefix(units)
+            .range(
+                storage,
+                None,
+                Some(Bound::inclusive((now, u64::MAX))),
+                Order::Ascending,
+            )
+            .take(MAX_STATUS_SYNCS)
+            .collect::<StdResult<Vec<(u64, SingleChoiceProposal)>>>()?;
+        for (id, mut prop) in expired {
+            let old_status = prop.status;
+            prop.update_status(block)?;
+            if prop.status != old_status {
+                proposals().save(storage, id, &prop)?;
+                hooks.extend(proposal_status_changed_hooks(
+                    PROPOSAL_HOOKS,
+                    storage,
+                    id,
+                    old_status.to_string(),
+                    prop.status.to_string(),
+                )?);
+            }
+        }
+
+        let ended = PENDING_TURNOUTS
+            .sub_prefix(units)
+            .keys(
+                storage,
+                None,
+                Some(Bound::inclusive((now, u64::MAX))),
+                Order::Ascending,
+            )
+            .take(MAX_STATUS_SYNCS)
+            .collect::<StdResult<Vec<(u64, u64)>>>()?;
+        for (_, id) in ended {
+            let prop = proposals().load(storage, id)?;
+            record_turnout(storage, id, &prop)?;
+        }
+    }
+    Ok(hooks)
+}
+
+/// The key of a proposal in `PENDING_TURNOUTS`.
+fn turnout_key(proposal_id: u64, prop: &SingleChoiceProposal) -> (u8, u64, u64) {
+    let (units, voting_end) = expiration_key(prop.voting_end());
+    (units, voting_end, proposal_id)
+}
+
+/// Folds the turnout of a proposal whose votes are final into the
+/// moving average used by dynamic quorums, if the module uses one.
+/// Each proposal's turnout is only recorded once.
+fn record_turnout(
+    storage: &mut dyn Storage,
+    proposal_id: u64,
+    prop: &SingleChoiceProposal,
+) -> StdResult<()> {
+    let key = turnout_key(proposal_id, prop);
+    if !PENDING_TURNOUTS.has(storage, key) {
+        return Ok(());
+    }
+    PENDING_TURNOUTS.remove(storage, key);
+
+    let config = CONFIG.load(storage)?;
+    if let Threshold::DynamicQuorum { window, .. } = config.threshold {
+        if !prop.total_power.is_zero() {
+            let turnout = Decimal::from_ratio(prop.votes.total(), prop.total_power);
+            let average =
+                update_turnout_average(TURNOUT_AVERAGE.may_load(storage)?, turnout, window);
+            TURNOUT_AVERAGE.save(storage, &average)?;
+        }
+    }
+    Ok(())
+}
+
+/// Gets the current status of a proposal that another proposal
+/// depends on. Proposals in this module are loaded from storage
+/// directly instead of being queried.
+fn query_dependency_status(
+    deps: Deps,
+    env: &Env,
+    module: &Addr,
+    proposal_id: u64,
+) -> StdResult<Status> {
+    if *module == env.contract.address {
+        proposals()
+            .load(deps.storage, proposal_id)?
+            .current_status(&env.block)
+    } else {
+        query_proposal_status(&deps.querier, module, proposal_id)
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -656,7 +1327,12 @@ pub fn execute_update_config(
     allow_revoting: bool,
     dao: String,
     close_proposal_on_execution_failure: bool,
+    continue_on_execution_failure: bool,
     veto: Option<VetoConfig>,
+    threshold_rules: Vec<ThresholdRule>,
+    commit_reveal: Option<CommitRevealConfig>,
+    voting_period_range: Option<VotingPeriodRange>,
+    no_with_veto_threshold: Option<Decimal>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -675,6 +1351,15 @@ pub fn execute_update_config(
         veto_config.validate(&deps.as_ref(), &max_voting_period)?;
     };
 
+    validate_threshold_rules(&threshold_rules, max_voting_period)?;
+    if let Some(commit_reveal) = &commit_reveal {
+        commit_reveal.validate(&max_voting_period)?;
+    }
+    if let Some(range) = &voting_period_range {
+        range.validate(min_voting_period, max_voting_period)?;
+    }
+    validate_no_with_veto_threshold(no_with_veto_threshold)?;
+
     CONFIG.save(
         deps.storage,
         &Config {
@@ -685,7 +1370,12 @@ pub fn execute_update_config(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            continue_on_execution_failure,
             veto,
+            threshold_rules,
+            commit_reveal,
+            voting_period_range,
+            no_with_veto_threshold,
         },
     )?;
 
@@ -714,6 +1404,108 @@ pub fn execute_update_proposal_creation_policy(
         .add_attribute("new_policy", format!("{initial_policy:?}")))
 }
 
+pub fn execute_index_proposals(
+    deps: DepsMut,
+    limit: Option<u64>,
+) -> Result<Response, ContractError> {
+    let cursor = PROPOSAL_INDEX_CURSOR
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NothingToIndex {})?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+
+    let props = proposals()
+        .range(
+            deps.storage,
+            Some(Bound::exclusive(cursor)),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .collect::<StdResult<Vec<_>>>()?;
+    // Saving a proposal again adds it to the indexes.
+    for (id, prop) in &props {
+        proposals().save(deps.storage, *id, prop)?;
+    }
+
+    let complete = (props.len() as u64) < limit;
+    match props.last() {
+        Some((id, _)) if !complete => PROPOSAL_INDEX_CURSOR.save(deps.storage, id)?,
+        _ => PROPOSAL_INDEX_CURSOR.remove(deps.storage),
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "index_proposals")
+        .add_attribute("indexed", props.len().to_string())
+        .add_attribute("complete", complete.to_string()))
+}
+
+pub fn execute_index_ballots(deps: DepsMut, limit: Option<u64>) -> Result<Response, ContractError> {
+    let cursor = BALLOT_INDEX_CURSOR
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NothingToIndex {})?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+
+    let keys = BALLOTS
+        .keys(
+            deps.storage,
+            cursor
+                .as_ref()
+                .map(|(proposal_id, voter)| Bound::exclusive((*proposal_id, voter))),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (proposal_id, voter) in &keys {
+        VOTER_PROPOSALS.save(deps.storage, (voter, *proposal_id), &Empty {})?;
+    }
+
+    let complete = (keys.len() as u64) < limit;
+    match keys.last() {
+        Some(key) if !complete => BALLOT_INDEX_CURSOR.save(deps.storage, &Some(key.clone()))?,
+        _ => BALLOT_INDEX_CURSOR.remove(deps.storage),
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "index_ballots")
+        .add_attribute("indexed", keys.len().to_string())
+        .add_attribute("complete", complete.to_string()))
+}
+
+/// Starts indexing the proposals and ballots saved before their
+/// indexes were added, which is continued in batches with
+/// `IndexProposals` and `IndexBallots`. Indexing that is underway is
+/// not restarted, and indexing is not started for indexes that are
+/// already populated or that have nothing to index.
+fn start_indexing(storage: &mut dyn Storage) -> StdResult<()> {
+    let has_proposals = proposals()
+        .keys_raw(storage, None, None, Order::Ascending)
+        .next()
+        .is_some();
+    let proposals_indexed = proposals()
+        .idx
+        .proposer
+        .keys_raw(storage, None, None, Order::Ascending)
+        .next()
+        .is_some();
+    if has_proposals && !proposals_indexed && PROPOSAL_INDEX_CURSOR.may_load(storage)?.is_none() {
+        PROPOSAL_INDEX_CURSOR.save(storage, &0)?;
+    }
+
+    let has_ballots = BALLOTS
+        .keys_raw(storage, None, None, Order::Ascending)
+        .next()
+        .is_some();
+    let ballots_indexed = VOTER_PROPOSALS
+        .keys_raw(storage, None, None, Order::Ascending)
+        .next()
+        .is_some();
+    if has_ballots && !ballots_indexed && BALLOT_INDEX_CURSOR.may_load(storage)?.is_none() {
+        BALLOT_INDEX_CURSOR.save(storage, &None)?;
+    }
+    Ok(())
+}
+
 pub fn add_hook(
     hooks: Hooks,
     storage: &mut dyn Storage,
@@ -831,20 +1623,41 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         }
         QueryMsg::NextProposalId {} => query_next_proposal_id(deps),
         QueryMsg::ProposalCount {} => query_proposal_count(deps),
-        QueryMsg::GetVote { proposal_id, voter } => query_vote(deps, proposal_id, voter),
+        QueryMsg::GetVote { proposal_id, voter } => query_vote(deps, env, proposal_id, voter),
         QueryMsg::ListVotes {
             proposal_id,
             start_after,
             limit,
-        } => query_list_votes(deps, proposal_id, start_after, limit),
+        } => query_list_votes(deps, env, proposal_id, start_after, limit),
+        QueryMsg::ListVotesByVoter {
+            voter,
+            start_after,
+            limit,
+        } => query_list_votes_by_voter(deps, env, voter, start_after, limit),
         QueryMsg::Info {} => query_info(deps),
         QueryMsg::ReverseProposals {
             start_before,
             limit,
         } => query_reverse_proposals(deps, env, start_before, limit),
+        QueryMsg::ListProposalsByStatus {
+            status,
+            start_after,
+            limit,
+        } => query_list_proposals_by_status(deps, env, status, start_after, limit),
+        QueryMsg::ListProposalsByProposer {
+            proposer,
+            start_after,
+            limit,
+        } => query_list_proposals_by_proposer(deps, env, proposer, start_after, limit),
         QueryMsg::ProposalCreationPolicy {} => query_creation_policy(deps),
         QueryMsg::ProposalHooks {} => to_json_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
         QueryMsg::VoteHooks {} => to_json_binary(&VOTE_HOOKS.query_hooks(deps)?),
+        QueryMsg::VetoTally { proposal_id } => query_veto_tally(deps, proposal_id),
+        QueryMsg::VoteNonce { voter } => query_vote_nonce(deps, voter),
+        QueryMsg::ProposalExecutionResult { proposal_id } => {
+            query_proposal_execution_result(deps, proposal_id)
+        }
+        QueryMsg::ProposalStats { proposal_id } => query_proposal_stats(deps, env, proposal_id),
     }
 }
 
@@ -859,10 +1672,15 @@ pub fn query_dao(deps: Deps) -> StdResult<Binary> {
 }
 
 pub fn query_proposal(deps: Deps, env: Env, id: u64) -> StdResult<Binary> {
-    let proposal = PROPOSALS.load(deps.storage, id)?;
+    let proposal = proposals().load(deps.storage, id)?;
     to_json_binary(&proposal.into_response(&env.block, id)?)
 }
 
+pub fn query_proposal_stats(deps: Deps, env: Env, id: u64) -> StdResult<Binary> {
+    let proposal = proposals().load(deps.storage, id)?;
+    to_json_binary(&proposal.stats(&env.block)?)
+}
+
 pub fn query_creation_policy(deps: Deps) -> StdResult<Binary> {
     let policy = CREATION_POLICY.load(deps.storage)?;
     to_json_binary(&policy)
@@ -876,7 +1694,7 @@ pub fn query_list_proposals(
 ) -> StdResult<Binary> {
     let min = start_after.map(Bound::exclusive);
     let limit = limit.unwrap_or(DEFAULT_LIMIT);
-    let props: Vec<ProposalResponse> = PROPOSALS
+    let props: Vec<ProposalResponse> = proposals()
         .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
         .take(limit as usize)
         .collect::<Result<Vec<(u64, SingleChoiceProposal)>, _>>()?
@@ -895,7 +1713,7 @@ pub fn query_reverse_proposals(
 ) -> StdResult<Binary> {
     let limit = limit.unwrap_or(DEFAULT_LIMIT);
     let max = start_before.map(Bound::exclusive);
-    let props: Vec<ProposalResponse> = PROPOSALS
+    let props: Vec<ProposalResponse> = proposals()
         .range(deps.storage, None, max, cosmwasm_std::Order::Descending)
         .take(limit as usize)
         .collect::<Result<Vec<(u64, SingleChoiceProposal)>, _>>()?
@@ -906,6 +1724,81 @@ pub fn query_reverse_proposals(
     to_json_binary(&ProposalListResponse { proposals: props })
 }
 
+pub fn query_list_proposals_by_status(
+    deps: Deps,
+    env: Env,
+    status: Status,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT) as usize;
+    // Proposals with this status may be stored with any status that
+    // lazily becomes it, so each of those statuses is scanned and
+    // entries whose current status differs are skipped. The first
+    // `limit` matching proposals stored with each status contain the
+    // first `limit` matching proposals overall.
+    //
+    // The skipped entries are proposals whose stored status has
+    // expired. The scan is not bounded, but as each new proposal
+    // saves the status of the proposals that expired first, only
+    // proposals that expired since proposals were last created are
+    // usually skipped.
+    let mut props = vec![];
+    for key in status.stored_keys() {
+        let matching = proposals()
+            .idx
+            .status
+            .prefix(key.to_string())
+            .range(
+                deps.storage,
+                start_after.map(Bound::exclusive),
+                None,
+                cosmwasm_std::Order::Ascending,
+            )
+            .filter(|item| match item {
+                Ok((_, proposal)) => proposal
+                    .current_status(&env.block)
+                    .map_or(true, |current| current.key() == status.key()),
+                Err(_) => true,
+            })
+            .take(limit)
+            .collect::<StdResult<Vec<(u64, SingleChoiceProposal)>>>()?;
+        props.extend(matching);
+    }
+    props.sort_by_key(|(id, _)| *id);
+    let props = props
+        .into_iter()
+        .take(limit)
+        .map(|(id, proposal)| proposal.into_response(&env.block, id))
+        .collect::<StdResult<Vec<ProposalResponse>>>()?;
+
+    to_json_binary(&ProposalListResponse { proposals: props })
+}
+
+pub fn query_list_proposals_by_proposer(
+    deps: Deps,
+    env: Env,
+    proposer: String,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let proposer = deps.api.addr_validate(&proposer)?;
+    let min = start_after.map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let props: Vec<ProposalResponse> = proposals()
+        .idx
+        .proposer
+        .prefix(proposer)
+        .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
+        .take(limit as usize)
+        .collect::<Result<Vec<(u64, SingleChoiceProposal)>, _>>()?
+        .into_iter()
+        .map(|(id, proposal)| proposal.into_response(&env.block, id))
+        .collect::<StdResult<Vec<ProposalResponse>>>()?;
+
+    to_json_binary(&ProposalListResponse { proposals: props })
+}
+
 pub fn query_proposal_count(deps: Deps) -> StdResult<Binary> {
     let proposal_count = PROPOSAL_COUNT.load(deps.storage)?;
     to_json_binary(&proposal_count)
@@ -915,8 +1808,19 @@ pub fn query_next_proposal_id(deps: Deps) -> StdResult<Binary> {
     to_json_binary(&next_proposal_id(deps.storage)?)
 }
 
-pub fn query_vote(deps: Deps, proposal_id: u64, voter: String) -> StdResult<Binary> {
+/// Whether or not the votes on a proposal are hidden because its
+/// commit-reveal period has not ended.
+fn votes_hidden(deps: Deps, env: &Env, proposal_id: u64) -> StdResult<bool> {
+    Ok(proposals()
+        .may_load(deps.storage, proposal_id)?
+        .map_or(false, |prop| prop.votes_hidden(&env.block)))
+}
+
+pub fn query_vote(deps: Deps, env: Env, proposal_id: u64, voter: String) -> StdResult<Binary> {
     let voter = deps.api.addr_validate(&voter)?;
+    if votes_hidden(deps, &env, proposal_id)? {
+        return to_json_binary(&VoteResponse { vote: None });
+    }
     let ballot = BALLOTS.may_load(deps.storage, (proposal_id, &voter))?;
     let vote = ballot.map(|ballot| VoteInfo {
         voter,
@@ -929,10 +1833,14 @@ pub fn query_vote(deps: Deps, proposal_id: u64, voter: String) -> StdResult<Bina
 
 pub fn query_list_votes(
     deps: Deps,
+    env: Env,
     proposal_id: u64,
     start_after: Option<String>,
     limit: Option<u64>,
 ) -> StdResult<Binary> {
+    if votes_hidden(deps, &env, proposal_id)? {
+        return to_json_binary(&VoteListResponse { votes: vec![] });
+    }
     let limit = limit.unwrap_or(DEFAULT_LIMIT);
     let start_after = start_after
         .map(|addr| deps.api.addr_validate(&addr))
@@ -957,6 +1865,65 @@ pub fn query_list_votes(
     to_json_binary(&VoteListResponse { votes })
 }
 
+pub fn query_list_votes_by_voter(
+    deps: Deps,
+    env: Env,
+    voter: String,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let voter = deps.api.addr_validate(&voter)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let min = start_after.map(Bound::exclusive);
+
+    let votes = VOTER_PROPOSALS
+        .prefix(&voter)
+        .keys(deps.storage, min, None, Order::Ascending)
+        .map(|proposal_id| {
+            let proposal_id = proposal_id?;
+            if votes_hidden(deps, &env, proposal_id)? {
+                return Ok(None);
+            }
+            let ballot = BALLOTS.load(deps.storage, (proposal_id, &voter))?;
+            Ok(Some(VoterVoteInfo {
+                proposal_id,
+                vote: ballot.vote,
+                power: ballot.power,
+                rationale: ballot.rationale,
+            }))
+        })
+        .filter_map(StdResult::transpose)
+        .take(limit as usize)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&VoterVoteListResponse { votes })
+}
+
+pub fn query_proposal_execution_result(deps: Deps, proposal_id: u64) -> StdResult<Binary> {
+    let result = EXECUTION_RESULTS.may_load(deps.storage, proposal_id)?;
+    to_json_binary(&ExecutionResultResponse { result })
+}
+
+pub fn query_vote_nonce(deps: Deps, voter: String) -> StdResult<Binary> {
+    let voter = deps.api.addr_validate(&voter)?;
+    to_json_binary(
+        &VOTE_NONCES
+            .may_load(deps.storage, &voter)?
+            .unwrap_or_default(),
+    )
+}
+
+pub fn query_veto_tally(deps: Deps, proposal_id: u64) -> StdResult<Binary> {
+    let prop = proposals().load(deps.storage, proposal_id)?;
+    let vetoers = VETO_VOTES
+        .may_load(deps.storage, proposal_id)?
+        .unwrap_or_default();
+    to_json_binary(&VetoTallyResponse {
+        vetoers,
+        threshold: prop.veto.as_ref().map(VetoConfig::threshold),
+    })
+}
+
 pub fn query_info(deps: Deps) -> StdResult<Binary> {
     let info = cw2::get_contract_version(deps.storage)?;
     to_json_binary(&dao_interface::voting::InfoResponse { info })
@@ -1001,7 +1968,12 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                     allow_revoting: current_config.allow_revoting,
                     dao: current_config.dao.clone(),
                     close_proposal_on_execution_failure,
+                    continue_on_execution_failure: false,
                     veto,
+                    threshold_rules: vec![],
+                    commit_reveal: None,
+                    voting_period_range: None,
+                    no_with_veto_threshold: None,
                 },
             )?;
 
@@ -1047,21 +2019,35 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                         votes: v1_votes_to_v2(prop.votes),
                         allow_revoting: prop.allow_revoting,
                         veto: None,
+                        execute_after: None,
+                        depends_on: vec![],
+                        message_results: vec![],
+                        threshold_rule: None,
+                        commit_reveal: None,
+                        no_with_veto_threshold: None,
                     };
 
-                    PROPOSALS
+                    proposals()
                         .save(deps.storage, id, &migrated_proposal)
                         .map_err(|e| e.into())
                 })?;
 
+            // Migrated proposals are indexed as they are saved, but v1
+            // ballots are not.
+            start_indexing(deps.storage)?;
+
             Ok(Response::default()
                 .add_attribute("action", "migrate")
                 .add_attribute("from", "v1")
                 .add_submessages(pre_propose_messages))
         }
-        MigrateMsg::FromCompatible {} => Ok(Response::default()
-            .add_attribute("action", "migrate")
-            .add_attribute("from", "compatible")),
+        MigrateMsg::FromCompatible {} => {
+            start_indexing(deps.storage)?;
+
+            Ok(Response::default()
+                .add_attribute("action", "migrate")
+                .add_attribute("from", "compatible"))
+        }
     }
 }
 
@@ -1070,7 +2056,7 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
     let repl = TaggedReplyId::new(msg.id)?;
     match repl {
         TaggedReplyId::FailedProposalExecution(proposal_id) => {
-            PROPOSALS.update(deps.storage, proposal_id, |prop| match prop {
+            proposals().update(deps.storage, proposal_id, |prop| match prop {
                 Some(mut prop) => {
                     prop.status = Status::ExecutionFailed;
 
@@ -1134,5 +2120,106 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
             };
             Ok(Response::new().add_attribute("failed_prepropose_hook", format!("{addr}")))
         }
+        TaggedReplyId::ProposalExecutionResult(proposal_id) => match msg.result.into_result() {
+            Ok(response) => {
+                if !proposals().has(deps.storage, proposal_id) {
+                    return Err(ContractError::NoSuchProposal { id: proposal_id });
+                }
+                record_execution_result(deps.storage, proposal_id, response)?;
+
+                Ok(Response::new().add_attribute(
+                    "proposal_execution_result_recorded",
+                    proposal_id.to_string(),
+                ))
+            }
+            // Only fired on error if the proposal should be closed on
+            // execution failure.
+            Err(error) => {
+                proposals().update(deps.storage, proposal_id, |prop| match prop {
+                    Some(mut prop) => {
+                        prop.status = Status::ExecutionFailed;
+
+                        Ok(prop)
+                    }
+                    None => Err(ContractError::NoSuchProposal { id: proposal_id }),
+                })?;
+
+                Ok(Response::new()
+                    .add_attribute("proposal_execution_failed", proposal_id.to_string())
+                    .add_attribute("error", error))
+            }
+        },
+        TaggedReplyId::ProposalMessageExecution(proposal_id, msg_index) => {
+            let mut prop = proposals()
+                .may_load(deps.storage, proposal_id)?
+                .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+            if msg_index as usize >= prop.message_results.len() {
+                return Err(ContractError::InvalidReplyID { id: msg.id });
+            }
+
+            let (result, error) = match msg.result.into_result() {
+                Ok(response) => {
+                    record_execution_result(deps.storage, proposal_id, response)?;
+                    (MessageExecutionResult::Succeeded, None)
+                }
+                Err(error) => (
+                    MessageExecutionResult::Failed {
+                        error: error.clone(),
+                    },
+                    Some(error),
+                ),
+            };
+
+            prop.message_results[msg_index as usize] = result;
+            let response = Response::new()
+                .add_attribute(
+                    "proposal_message_executed",
+                    format!("{proposal_id}:{msg_index}"),
+                )
+                .add_attribute("error", error.unwrap_or_else(|| "_none".to_string()));
+            if prop
+                .message_results
+                .contains(&MessageExecutionResult::Pending)
+            {
+                proposals().save(deps.storage, proposal_id, &prop)?;
+                return Ok(response);
+            }
+
+            // Every message has replied, so the proposal's final status
+            // is known.
+            let old_status = EXECUTING_PROPOSALS.load(deps.storage, proposal_id)?;
+            EXECUTING_PROPOSALS.remove(deps.storage, proposal_id);
+            prop.status = prop.message_execution_status();
+            proposals().save(deps.storage, proposal_id, &prop)?;
+
+            let proposal_status_changed_hooks = proposal_status_changed_hooks(
+                PROPOSAL_HOOKS,
+                deps.storage,
+                proposal_id,
+                old_status.to_string(),
+                prop.status.to_string(),
+            )?;
+
+            // The pre-propose module is told the proposal completed
+            // when it is first executed, not when messages are
+            // retried. Partially executed proposals are reported as
+            // executed, which pre-propose modules treat the same and
+            // which those deployed before partial execution can read.
+            let proposal_completed_hooks = if old_status == Status::PartiallyExecuted {
+                vec![]
+            } else {
+                let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
+                proposal_completed_hooks(
+                    proposal_creation_policy,
+                    proposal_id,
+                    Status::Executed,
+                    false,
+                )?
+            };
+
+            Ok(response
+                .add_submessages(proposal_status_changed_hooks)
+                .add_submessages(proposal_completed_hooks))
+        }
     }
 }
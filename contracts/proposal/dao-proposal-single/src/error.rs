@@ -1,7 +1,7 @@
 use cosmwasm_std::StdError;
 use cw_hooks::HookError;
-use cw_utils::ParseReplyError;
-use dao_voting::{reply::error::TagError, veto::VetoError};
+use cw_utils::{Expiration, ParseReplyError};
+use dao_voting::{reply::error::TagError, signed_vote::SignedVoteError, veto::VetoError};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -18,6 +18,9 @@ pub enum ContractError {
     #[error(transparent)]
     VetoError(#[from] VetoError),
 
+    #[error(transparent)]
+    SignedVoteError(#[from] SignedVoteError),
+
     #[error("unauthorized")]
     Unauthorized {},
 
@@ -48,15 +51,48 @@ pub enum ContractError {
     #[error("already cast a vote with that option. change your vote to revote")]
     AlreadyCast {},
 
+    #[error("votes on proposal ({id}) must be committed and then revealed")]
+    CommitRevealVoting { id: u64 },
+
+    #[error("proposal ({id}) does not use commit-reveal voting")]
+    NotCommitReveal { id: u64 },
+
+    #[error("votes may only be committed or revealed on open proposals, and proposal ({id}) is ({status})")]
+    NotOpen { id: u64, status: String },
+
+    #[error("already committed a vote. this proposal does not support revoting")]
+    AlreadyCommitted {},
+
+    #[error("no vote commitment exists for proposal ({id}) and voter ({voter})")]
+    NoSuchCommitment { id: u64, voter: String },
+
+    #[error("revealed vote and salt do not match the commitment")]
+    CommitmentMismatch {},
+
+    #[error("votes on proposal ({id}) may only be revealed after voting ends and before the reveal period expires")]
+    NotRevealPeriod { id: u64 },
+
     #[error("proposal is not in 'passed' state")]
     NotPassed {},
 
+    #[error("proposal may not be executed until ({execute_after})")]
+    ExecutionLocked { execute_after: Expiration },
+
+    #[error("proposal ({proposal_id}) in module ({module}) must be executed first")]
+    DependencyNotExecuted { module: String, proposal_id: u64 },
+
     #[error("proposal has already been executed")]
     AlreadyExecuted {},
 
     #[error("proposal is closed")]
     Closed {},
 
+    #[error("proposal is not in 'partially_executed' state")]
+    NotPartiallyExecuted {},
+
+    #[error("message ({index}) of proposal ({id}) has not failed and can not be retried")]
+    MessageNotFailed { id: u64, index: u64 },
+
     #[error("only rejected proposals may be closed")]
     WrongCloseStatus {},
 
@@ -69,6 +105,9 @@ pub enum ContractError {
     #[error("min voting period must be less than or equal to max voting period")]
     InvalidMinVotingPeriod {},
 
+    #[error("this module does not allow proposers to choose a voting period")]
+    VotingPeriodNotAllowed {},
+
     #[error(
         "pre-propose modules must specify a proposer. lacking one, no proposer should be specified"
     )]
@@ -91,6 +130,9 @@ pub enum ContractError {
     #[error("can not migrate. current version is up to date")]
     AlreadyMigrated {},
 
+    #[error("nothing is waiting to be indexed")]
+    NothingToIndex {},
+
     #[error("incompatible migration version")]
     MigrationVersionError {},
 }
@@ -10,6 +10,7 @@ pub mod query;
 mod testing;
 
 pub mod state;
+pub mod threshold_rules;
 pub mod v1_state;
 
 pub use crate::error::ContractError;
@@ -1,11 +1,19 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Binary, Decimal};
 use cw_utils::Duration;
 use dao_dao_macros::proposal_module_query;
 use dao_voting::{
-    pre_propose::PreProposeInfo, proposal::SingleChoiceProposeMsg, threshold::Threshold,
-    veto::VetoConfig, voting::Vote,
+    pre_propose::PreProposeInfo,
+    proposal::SingleChoiceProposeMsg,
+    signed_vote::SignedVotePayload,
+    status::Status,
+    threshold::Threshold,
+    veto::VetoConfig,
+    voting::{Vote, VotingPeriodRange},
 };
 
+use crate::{state::CommitRevealConfig, threshold_rules::ThresholdRule};
+
 #[cw_serde]
 pub struct InstantiateMsg {
     /// The threshold a proposal must reach to complete.
@@ -38,12 +46,42 @@ pub struct InstantiateMsg {
     /// remain open until the DAO's treasury was large enough for it to be
     /// executed.
     pub close_proposal_on_execution_failure: bool,
+    /// If set to true each of a proposal's messages will be executed
+    /// on its own, and a failing message will not prevent the others
+    /// from executing. The result of each message is recorded on the
+    /// proposal, which becomes partially executed if any message
+    /// failed. Failed messages may then be retried with
+    /// `RetryFailedMessages`. When enabled,
+    /// `close_proposal_on_execution_failure` has no effect.
+    pub continue_on_execution_failure: bool,
     /// Optional veto configuration for proposal execution.
     /// If set, proposals can only be executed after the timelock
     /// delay expiration.
-    /// During this period an oversight account (`veto.vetoer`) can
-    /// veto the proposal.
+    /// During this period an oversight account (`veto.vetoer`), or
+    /// a council of them, can veto the proposal.
     pub veto: Option<VetoConfig>,
+    /// Thresholds to use in place of `threshold` for proposals
+    /// containing sensitive actions, such as migrating contracts or
+    /// large treasury spends. The strictest rule matching a
+    /// proposal's messages when it is created applies to it.
+    #[serde(default)]
+    pub threshold_rules: Vec<ThresholdRule>,
+    /// If set, votes are hidden until voting ends using a
+    /// commit-reveal scheme. Voters commit to a hash of their vote
+    /// while a proposal is open and reveal it during a reveal period
+    /// after the proposal expires.
+    #[serde(default)]
+    pub commit_reveal: Option<CommitRevealConfig>,
+    /// If set, proposers may choose a voting period within this range
+    /// when creating a proposal. Otherwise, proposals are open for
+    /// `max_voting_period`.
+    #[serde(default)]
+    pub voting_period_range: Option<VotingPeriodRange>,
+    /// If set, proposals are rejected when the share of votes cast
+    /// as `NoWithVeto` exceeds this threshold, and their deposits are
+    /// forfeited to the DAO regardless of the deposit refund policy.
+    #[serde(default)]
+    pub no_with_veto_threshold: Option<Decimal>,
 }
 
 #[cw_serde]
@@ -62,6 +100,44 @@ pub enum ExecuteMsg {
         /// the vote.
         rationale: Option<String>,
     },
+    /// Casts a vote signed by `voter` on their behalf, allowing
+    /// voters without gas tokens to have their votes relayed by
+    /// anyone. The payload must use the voter's current nonce, which
+    /// may be queried with `VoteNonce`.
+    VoteWithSignature {
+        /// The address of the voter that signed the vote.
+        voter: String,
+        /// The signed vote.
+        payload: SignedVotePayload<Vote>,
+        /// The voter's compressed secp256k1 public key.
+        pubkey: Binary,
+        /// The secp256k1 signature of the SHA-256 hash of the
+        /// payload's ADR-036 sign doc. See `SignedVotePayload::sign_doc`.
+        signature: Binary,
+    },
+    /// Commits to a hidden vote on a commit-reveal proposal. Callable
+    /// while the proposal is open. The vote must later be revealed
+    /// with `RevealVote` to count.
+    CommitVote {
+        /// The ID of the proposal to vote on.
+        proposal_id: u64,
+        /// The SHA-256 hash of the voter, proposal ID, vote, and a
+        /// secret salt, as computed by `contract::vote_commitment`.
+        commitment: Binary,
+    },
+    /// Reveals a vote previously committed to with `CommitVote`.
+    /// Callable after the proposal expires and before its reveal
+    /// period ends.
+    RevealVote {
+        /// The ID of the proposal the vote was committed on.
+        proposal_id: u64,
+        /// The committed position on the proposal.
+        vote: Vote,
+        /// The salt used when computing the commitment.
+        salt: String,
+        /// An optional rationale for why this vote was cast.
+        rationale: Option<String>,
+    },
     /// Updates the sender's rationale for their vote on the specified
     /// proposal. Errors if no vote vote has been cast.
     UpdateRationale {
@@ -74,6 +150,16 @@ pub enum ExecuteMsg {
         /// The ID of the proposal to execute.
         proposal_id: u64,
     },
+    /// Re-executes messages of a partially executed proposal that
+    /// failed to execute. Only available for proposals executed with
+    /// `continue_on_execution_failure` enabled.
+    RetryFailedMessages {
+        /// The ID of the proposal to retry messages for.
+        proposal_id: u64,
+        /// The indices of the failed messages to retry. If not set,
+        /// all failed messages are retried.
+        msg_indices: Option<Vec<u64>>,
+    },
     /// Callable only if veto is configured
     Veto {
         /// The ID of the proposal to veto.
@@ -121,13 +207,47 @@ pub enum ExecuteMsg {
         /// remain open until the DAO's treasury was large enough for it to be
         /// executed.
         close_proposal_on_execution_failure: bool,
+        /// If set to true each of a proposal's messages will be
+        /// executed on its own, and a failing message will not
+        /// prevent the others from executing. Failed messages may
+        /// then be retried with `RetryFailedMessages`. When enabled,
+        /// `close_proposal_on_execution_failure` has no effect.
+        continue_on_execution_failure: bool,
         /// Optional time delay on proposal execution, during which the
         /// proposal may be vetoed.
         veto: Option<VetoConfig>,
+        /// Thresholds to use in place of `threshold` for proposals
+        /// containing sensitive actions. Applies only to proposals
+        /// created after the update.
+        #[serde(default)]
+        threshold_rules: Vec<ThresholdRule>,
+        /// If set, votes on proposals created after the update are
+        /// hidden until voting ends using a commit-reveal scheme.
+        #[serde(default)]
+        commit_reveal: Option<CommitRevealConfig>,
+        /// If set, proposers may choose a voting period within this
+        /// range when creating a proposal.
+        #[serde(default)]
+        voting_period_range: Option<VotingPeriodRange>,
+        /// If set, proposals created after the update are rejected
+        /// when the share of votes cast as `NoWithVeto` exceeds this
+        /// threshold.
+        #[serde(default)]
+        no_with_veto_threshold: Option<Decimal>,
     },
     /// Update's the proposal creation policy used for this
     /// module. Only the DAO may call this method.
     UpdatePreProposeInfo { info: PreProposeInfo },
+    /// Populates the proposal indexes for up to `limit` proposals
+    /// created before the indexes were added. Callable by anyone after
+    /// migrating from a version without the indexes, until every
+    /// proposal has been indexed.
+    IndexProposals { limit: Option<u64> },
+    /// Populates the index of ballots by voter for up to `limit`
+    /// ballots cast before it was added. Callable by anyone after
+    /// migrating from a version without the index, until every ballot
+    /// has been indexed.
+    IndexBallots { limit: Option<u64> },
     /// Adds an address as a consumer of proposal hooks. Consumers of
     /// proposal hooks have hook messages executed on them whenever
     /// the status of a proposal changes or a proposal is created. If
@@ -181,6 +301,37 @@ pub enum QueryMsg {
         /// returned.
         limit: Option<u64>,
     },
+    /// Lists the proposals whose current status is `status` in
+    /// ascending order of proposal ID. The data of `VetoTimelock`
+    /// statuses is ignored when matching.
+    ///
+    /// Proposals are indexed by their stored status, which changes
+    /// lazily as voting and timelocks end and is saved when proposals
+    /// are created. Proposals whose status changed since proposals
+    /// were last created are scanned and skipped, so this query may
+    /// read more proposals than `limit`.
+    #[returns(crate::query::ProposalListResponse)]
+    ListProposalsByStatus {
+        status: Status,
+        /// The proposal ID to start listing proposals after.
+        start_after: Option<u64>,
+        /// The maximum number of proposals to return as part of this
+        /// query. If no limit is set a max of 30 proposals will be
+        /// returned.
+        limit: Option<u64>,
+    },
+    /// Lists the proposals created by `proposer` in ascending order
+    /// of proposal ID.
+    #[returns(crate::query::ProposalListResponse)]
+    ListProposalsByProposer {
+        proposer: String,
+        /// The proposal ID to start listing proposals after.
+        start_after: Option<u64>,
+        /// The maximum number of proposals to return as part of this
+        /// query. If no limit is set a max of 30 proposals will be
+        /// returned.
+        limit: Option<u64>,
+    },
     /// Returns a voters position on a propsal.
     #[returns(crate::query::VoteResponse)]
     GetVote { proposal_id: u64, voter: String },
@@ -197,6 +348,18 @@ pub enum QueryMsg {
         /// query. If no limit is specified a max of 30 are returned.
         limit: Option<u64>,
     },
+    /// Lists the votes cast by a voter in ascending order of proposal
+    /// ID.
+    #[returns(crate::query::VoterVoteListResponse)]
+    ListVotesByVoter {
+        /// The voter to list the votes of.
+        voter: String,
+        /// The proposal ID to start listing votes after.
+        start_after: Option<u64>,
+        /// The maximum number of votes to return in response to this
+        /// query. If no limit is specified a max of 30 are returned.
+        limit: Option<u64>,
+    },
     /// Returns the number of proposals that have been created in this module.
     #[returns(::std::primitive::u64)]
     ProposalCount {},
@@ -209,6 +372,21 @@ pub enum QueryMsg {
     /// Lists all of the consumers of vote hooks for this module.
     #[returns(::cw_hooks::HooksResponse)]
     VoteHooks {},
+    /// Gets the vetoes cast on a proposal so far.
+    #[returns(::dao_voting::veto::VetoTallyResponse)]
+    VetoTally { proposal_id: u64 },
+    /// Gets the nonce that the next signed vote from `voter` must
+    /// use.
+    #[returns(::std::primitive::u64)]
+    VoteNonce { voter: String },
+    /// Gets the events and data recorded when a proposal was
+    /// executed.
+    #[returns(crate::query::ExecutionResultResponse)]
+    ProposalExecutionResult { proposal_id: u64 },
+    /// Gets participation statistics for a proposal, such as its
+    /// turnout and the votes needed to pass or reject it.
+    #[returns(::dao_voting::stats::ProposalStatsResponse)]
+    ProposalStats { proposal_id: u64 },
 }
 
 #[cw_serde]
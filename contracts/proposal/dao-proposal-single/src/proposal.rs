@@ -2,13 +2,17 @@ use std::ops::Add;
 
 use crate::query::ProposalResponse;
 use crate::state::PROPOSAL_COUNT;
+use crate::threshold_rules::ThresholdRule;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, BlockInfo, CosmosMsg, Decimal, Empty, StdResult, Storage, Uint128};
 use cw_utils::Expiration;
+use dao_voting::stats::{turnout, votes_needed, votes_needed_for_quorum, ProposalStatsResponse};
 use dao_voting::status::Status;
 use dao_voting::threshold::{PercentageThreshold, Threshold};
 use dao_voting::veto::VetoConfig;
-use dao_voting::voting::{does_vote_count_fail, does_vote_count_pass, Votes};
+use dao_voting::voting::{
+    compare_vote_count, does_vote_count_fail, does_vote_count_pass, VoteCmp, Votes,
+};
 
 #[cw_serde]
 pub struct SingleChoiceProposal {
@@ -31,6 +35,16 @@ pub struct SingleChoiceProposal {
     pub expiration: Expiration,
     /// The threshold at which this proposal will pass.
     pub threshold: Threshold,
+    /// The threshold rule that applied to this proposal when it was
+    /// created, if any. If set, `threshold` is the stricter of the
+    /// rule's threshold and the module's.
+    #[serde(default)]
+    pub threshold_rule: Option<ThresholdRule>,
+    /// If set, votes on this proposal are committed while it is open
+    /// and revealed after it expires. `votes` only contains revealed
+    /// votes.
+    #[serde(default)]
+    pub commit_reveal: Option<CommitRevealState>,
     /// The total amount of voting power at the time of this
     /// proposal's creation.
     pub total_power: Uint128,
@@ -46,6 +60,52 @@ pub struct SingleChoiceProposal {
     /// Optional veto configuration. If set to `None`, veto option
     /// is disabled. Otherwise contains the configuration for veto flow.
     pub veto: Option<VetoConfig>,
+    /// An optional time before which this proposal may not be
+    /// executed, even if it has passed. This is independent of any
+    /// veto timelock.
+    #[serde(default)]
+    pub execute_after: Option<Expiration>,
+    /// Proposals that must be executed before this one may be, given
+    /// as (proposal module address, proposal ID) pairs.
+    #[serde(default)]
+    pub depends_on: Vec<(Addr, u64)>,
+    /// The result of executing each of this proposal's messages, in
+    /// the same order as `msgs`. Only populated if the proposal was
+    /// executed with `continue_on_execution_failure` enabled.
+    #[serde(default)]
+    pub message_results: Vec<MessageExecutionResult>,
+    /// The `NoWithVeto` threshold that applied to this proposal when
+    /// it was created, if any. If the share of votes cast as
+    /// `NoWithVeto` exceeds it, the proposal is rejected and its
+    /// deposit is forfeited.
+    #[serde(default)]
+    pub no_with_veto_threshold: Option<Decimal>,
+}
+
+/// The state of voting on a commit-reveal proposal.
+#[cw_serde]
+pub struct CommitRevealState {
+    /// Votes may be revealed from the proposal's expiration until
+    /// this time. The outcome of the proposal is not known until
+    /// then.
+    pub reveal_expiration: Expiration,
+    /// Whether unrevealed voting power counts toward quorum as
+    /// abstain votes once the reveal period ends.
+    pub count_unrevealed_as_abstain: bool,
+    /// The voting power of commitments that have not been revealed.
+    pub unrevealed_power: Uint128,
+}
+
+/// The outcome of executing one of a proposal's messages on its own.
+#[cw_serde]
+pub enum MessageExecutionResult {
+    /// The message has been dispatched but has not finished
+    /// executing.
+    Pending,
+    /// The message was executed successfully.
+    Succeeded,
+    /// The message failed to execute.
+    Failed { error: String },
 }
 
 pub fn next_proposal_id(store: &dyn Storage) -> StdResult<u64> {
@@ -66,13 +126,48 @@ impl SingleChoiceProposal {
     /// a vote has occurred, the status we read from the proposal status
     /// may be out of date. This method recomputes the status so that
     /// queries get accurate information.
+    ///
+    /// The votes on commit-reveal proposals are hidden until their
+    /// reveal period ends.
     pub fn into_response(mut self, block: &BlockInfo, id: u64) -> StdResult<ProposalResponse> {
         self.update_status(block)?;
+        if self.votes_hidden(block) {
+            self.votes = Votes::zero();
+        }
         Ok(ProposalResponse { id, proposal: self })
     }
 
+    /// Whether the votes on this proposal are hidden. The votes on
+    /// commit-reveal proposals are hidden until the reveal period ends
+    /// so that voters who have not yet revealed can not react to the
+    /// tally.
+    pub fn votes_hidden(&self, block: &BlockInfo) -> bool {
+        self.commit_reveal.as_ref().map_or(false, |commit_reveal| {
+            !commit_reveal.reveal_expiration.is_expired(block)
+        })
+    }
+
     /// Gets the current status of the proposal.
     pub fn current_status(&self, block: &BlockInfo) -> StdResult<Status> {
+        if let (Status::Open, Some(commit_reveal)) = (self.status, &self.commit_reveal) {
+            // The outcome of a commit-reveal proposal is unknown until
+            // its votes have been revealed.
+            if !commit_reveal.reveal_expiration.is_expired(block) {
+                return Ok(Status::Open);
+            }
+            // Afterwards, tally it as a regular proposal whose voting
+            // ended with the reveal period.
+            let mut tally = SingleChoiceProposal {
+                expiration: commit_reveal.reveal_expiration,
+                commit_reveal: None,
+                ..self.clone()
+            };
+            if commit_reveal.count_unrevealed_as_abstain {
+                tally.votes.abstain += commit_reveal.unrevealed_power;
+            }
+            return tally.current_status(block);
+        }
+
         match self.status {
             Status::Open if self.is_passed(block) => match &self.veto {
                 // if prop is passed and veto is configured, calculate timelock
@@ -112,6 +207,43 @@ impl SingleChoiceProposal {
         Ok(())
     }
 
+    /// When voting on this proposal ends, after which its votes are
+    /// final. For commit-reveal proposals, this is when revealing
+    /// ends.
+    pub fn voting_end(&self) -> Expiration {
+        match &self.commit_reveal {
+            Some(commit_reveal) => commit_reveal.reveal_expiration,
+            None => self.expiration,
+        }
+    }
+
+    /// The expiration after which this proposal's stored status is
+    /// sure to lazily change, or `Never` if it will not. Open
+    /// proposals change once voting has ended, and timelocked
+    /// proposals once their timelock has ended.
+    pub fn status_expiration(&self) -> Expiration {
+        match self.status {
+            Status::Open => self.voting_end(),
+            Status::VetoTimelock { expiration } => expiration,
+            _ => Expiration::Never {},
+        }
+    }
+
+    /// Gets the status of a proposal whose messages were executed
+    /// individually. If any message has failed the proposal is
+    /// partially executed, otherwise it is executed.
+    pub fn message_execution_status(&self) -> Status {
+        if self
+            .message_results
+            .iter()
+            .any(|result| matches!(result, MessageExecutionResult::Failed { .. }))
+        {
+            Status::PartiallyExecuted
+        } else {
+            Status::Executed
+        }
+    }
+
     /// Returns true iff this proposal is sure to pass (even before
     /// expiration if no future sequence of possible votes can cause
     /// it to fail).
@@ -131,6 +263,10 @@ impl SingleChoiceProposal {
                 return false;
             }
         }
+        // A proposal that may still be vetoed can not pass.
+        if self.may_be_vetoed(block) {
+            return false;
+        }
 
         match self.threshold {
             Threshold::AbsolutePercentage { percentage } => {
@@ -155,9 +291,155 @@ impl SingleChoiceProposal {
                 }
             }
             Threshold::AbsoluteCount { threshold } => self.votes.yes >= threshold,
+            Threshold::DynamicQuorum { .. } => self.with_quorum_snapshot().is_passed(block),
+        }
+    }
+
+    /// Computes participation statistics for this proposal. The
+    /// votes needed to pass or reject it are yes and no votes
+    /// respectively, and assume that voting ends afterwards.
+    pub fn stats(&self, block: &BlockInfo) -> StdResult<ProposalStatsResponse> {
+        let status = self.current_status(block)?;
+        // Hidden votes are not counted until they are revealed.
+        let votes = if self.votes_hidden(block) {
+            Votes::zero()
+        } else {
+            self.votes.clone()
+        };
+        // A proposal is held open until its minimum voting period
+        // ends, though its outcome may already be decided.
+        let decided_status = match status {
+            Status::Open => SingleChoiceProposal {
+                min_voting_period: None,
+                ..self.clone()
+            }
+            .current_status(block)?,
+            status => status,
+        };
+        let outcome_locked = decided_status != Status::Open;
+        let cast = votes.total();
+        let outstanding = match (outcome_locked, &self.commit_reveal) {
+            (true, _) => Uint128::zero(),
+            // Committed power, revealed or not, may no longer vote.
+            (false, Some(commit_reveal)) => self
+                .total_power
+                .saturating_sub(self.votes.total())
+                .saturating_sub(commit_reveal.unrevealed_power),
+            (false, None) => self.total_power.saturating_sub(cast),
+        };
+        let passed = matches!(
+            decided_status,
+            Status::Passed
+                | Status::VetoTimelock { .. }
+                | Status::Executed
+                | Status::PartiallyExecuted
+                | Status::ExecutionFailed
+        );
+
+        let (votes_needed_to_pass, votes_needed_to_reject) = if outcome_locked {
+            (passed.then(Uint128::zero), (!passed).then(Uint128::zero))
+        } else {
+            (
+                votes_needed(outstanding, |yes| {
+                    let mut votes = votes.clone();
+                    votes.yes += yes;
+                    self.ended_with(votes, block).is_passed(block)
+                }),
+                votes_needed(outstanding, |no| {
+                    let mut votes = votes.clone();
+                    votes.no += no;
+                    !self.ended_with(votes, block).is_passed(block)
+                }),
+            )
+        };
+        let votes_needed_for_quorum = match self.threshold.snapshot_quorum(None) {
+            Threshold::ThresholdQuorum { quorum, .. } => {
+                votes_needed_for_quorum(cast, outstanding, self.total_power, quorum)
+            }
+            _ => None,
+        };
+
+        Ok(ProposalStatsResponse {
+            turnout: turnout(cast, self.total_power),
+            votes_needed_to_pass,
+            votes_needed_to_reject,
+            votes_needed_for_quorum,
+            outcome_locked,
+        })
+    }
+
+    /// Returns a copy of this proposal as if voting had ended with
+    /// `votes`.
+    fn ended_with(&self, votes: Votes, block: &BlockInfo) -> SingleChoiceProposal {
+        SingleChoiceProposal {
+            votes,
+            expiration: Expiration::AtHeight(block.height),
+            min_voting_period: None,
+            commit_reveal: None,
+            ..self.clone()
+        }
+    }
+
+    /// Proposals fix dynamic quorums when they are created, so this is
+    /// only used for proposals which somehow hold an unfixed one. In
+    /// that case the most conservative quorum is used.
+    fn with_quorum_snapshot(&self) -> SingleChoiceProposal {
+        SingleChoiceProposal {
+            threshold: self.threshold.snapshot_quorum(None),
+            ..self.clone()
         }
     }
 
+    /// Returns true iff the share of votes cast as `NoWithVeto`
+    /// exceeds this proposal's `NoWithVeto` threshold. Before
+    /// expiration, this is only known if the vetoes exceed the
+    /// threshold of the total voting power, as no future votes can
+    /// bring their share back under it.
+    pub fn is_vetoed(&self, block: &BlockInfo) -> bool {
+        let threshold = match self.no_with_veto_threshold {
+            Some(threshold) => threshold,
+            None => return false,
+        };
+        if self.expiration.is_expired(block) {
+            compare_vote_count(
+                self.votes.no_with_veto,
+                VoteCmp::Greater,
+                self.votes.total(),
+                threshold,
+            )
+        } else if self.allow_revoting {
+            false
+        } else {
+            compare_vote_count(
+                self.votes.no_with_veto,
+                VoteCmp::Greater,
+                self.total_power,
+                threshold,
+            )
+        }
+    }
+
+    /// Returns true if some future sequence of votes could cause this
+    /// proposal to be vetoed, or if it already has been.
+    fn may_be_vetoed(&self, block: &BlockInfo) -> bool {
+        let threshold = match self.no_with_veto_threshold {
+            Some(threshold) => threshold,
+            None => return false,
+        };
+        if self.expiration.is_expired(block) {
+            return self.is_vetoed(block);
+        }
+        // The share of vetoes is largest if all outstanding voting
+        // power vetoes.
+        let outstanding = self.total_power.saturating_sub(self.votes.total());
+        compare_vote_count(
+            self.votes.no_with_veto + outstanding,
+            VoteCmp::Greater,
+            self.total_power,
+            threshold,
+        )
+    }
+
     /// As above for the passed check, used to check if a proposal is
     /// already rejected.
     pub fn is_rejected(&self, block: &BlockInfo) -> bool {
@@ -166,6 +448,9 @@ impl SingleChoiceProposal {
         if self.allow_revoting && !self.expiration.is_expired(block) {
             return false;
         }
+        if self.is_vetoed(block) {
+            return true;
+        }
 
         match self.threshold {
             Threshold::AbsolutePercentage {
@@ -268,6 +553,7 @@ impl SingleChoiceProposal {
                 let outstanding_votes = self.total_power - self.votes.total();
                 self.votes.yes + outstanding_votes < threshold
             }
+            Threshold::DynamicQuorum { .. } => self.with_quorum_snapshot().is_rejected(block),
         }
     }
 }
@@ -310,8 +596,14 @@ mod test {
             status: Status::Open,
             threshold,
             veto: None,
+            execute_after: None,
+            depends_on: vec![],
             total_power,
             votes,
+            message_results: vec![],
+            threshold_rule: None,
+            commit_reveal: None,
+            no_with_veto_threshold: None,
         };
         (prop, block)
     }
@@ -363,6 +655,7 @@ mod test {
             yes: Uint128::new(7),
             no: Uint128::new(4),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // 15 total votes. 7 yes and 2 abstain. Majority threshold. This
@@ -405,6 +698,7 @@ mod test {
             yes: Uint128::new(7),
             no: Uint128::new(4),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // Does not pass if min voting period is not expired.
@@ -446,6 +740,7 @@ mod test {
             yes: Uint128::new(4),
             no: Uint128::new(7),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // Proposal has not passed.
@@ -480,6 +775,7 @@ mod test {
             yes: Uint128::new(7),
             no: Uint128::new(4),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // 15 total votes. 7 yes and 2 abstain. Majority threshold. This
@@ -515,6 +811,7 @@ mod test {
             yes: Uint128::new(4),
             no: Uint128::new(7),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // Not expired, revoting allowed => no rejection.
@@ -552,6 +849,7 @@ mod test {
                 yes: Uint128::new(10),
                 no: Uint128::zero(),
                 abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(100),
             false,
@@ -564,7 +862,8 @@ mod test {
             Votes {
                 yes: Uint128::new(9),
                 no: Uint128::new(1),
-                abstain: Uint128::zero()
+                abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(10),
             false,
@@ -577,7 +876,8 @@ mod test {
             Votes {
                 yes: Uint128::new(9),
                 no: Uint128::new(1),
-                abstain: Uint128::zero()
+                abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(11),
             false,
@@ -590,7 +890,8 @@ mod test {
             Votes {
                 yes: Uint128::new(9),
                 no: Uint128::new(1),
-                abstain: Uint128::zero()
+                abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(11),
             false,
@@ -613,6 +914,7 @@ mod test {
                 yes: Uint128::new(10),
                 no: Uint128::zero(),
                 abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(100),
             false,
@@ -625,6 +927,7 @@ mod test {
                 yes: Uint128::new(10),
                 no: Uint128::zero(),
                 abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(100),
             true,
@@ -637,7 +940,8 @@ mod test {
             Votes {
                 yes: Uint128::new(9),
                 no: Uint128::new(1),
-                abstain: Uint128::zero()
+                abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(10),
             false,
@@ -649,7 +953,8 @@ mod test {
             Votes {
                 yes: Uint128::new(9),
                 no: Uint128::new(1),
-                abstain: Uint128::zero()
+                abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(10),
             true,
@@ -667,6 +972,7 @@ mod test {
             yes: Uint128::new(7),
             no: Uint128::new(6),
             abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
         };
         assert!(check_is_passed(
             threshold,
@@ -687,6 +993,7 @@ mod test {
             yes: Uint128::new(6),
             no: Uint128::new(7),
             abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
         };
         assert!(check_is_passed(
             threshold.clone(),
@@ -715,6 +1022,7 @@ mod test {
             yes: Uint128::new(7),
             no: Uint128::new(6),
             abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
         };
         assert!(check_is_passed(
             threshold.clone(),
@@ -745,6 +1053,7 @@ mod test {
             yes: Uint128::new(4),
             no: Uint128::new(7),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // 15 total voting power
@@ -816,18 +1125,21 @@ mod test {
             yes: Uint128::new(7),
             no: Uint128::new(3),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
         // abstain votes are not counted for threshold => yes / (yes + no + veto)
         let passes_ignoring_abstain = Votes {
             yes: Uint128::new(6),
             no: Uint128::new(6),
             abstain: Uint128::new(5),
+            no_with_veto: Uint128::zero(),
         };
         // fails any way you look at it
         let failing = Votes {
             yes: Uint128::new(6),
             no: Uint128::new(7),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // first, expired (voting period over)
@@ -928,18 +1240,21 @@ mod test {
             yes: Uint128::new(3),
             no: Uint128::new(8),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
         // abstain votes are not counted for threshold => yes / (yes + no)
         let rejected_ignoring_abstain = Votes {
             yes: Uint128::new(4),
             no: Uint128::new(8),
             abstain: Uint128::new(5),
+            no_with_veto: Uint128::zero(),
         };
         // fails any way you look at it
         let failing = Votes {
             yes: Uint128::new(5),
             no: Uint128::new(8),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // first, expired (voting period over)
@@ -1061,6 +1376,7 @@ mod test {
             yes: Uint128::new(9),
             no: Uint128::new(1),
             abstain: Uint128::new(0),
+            no_with_veto: Uint128::zero(),
         };
         assert!(!check_is_passed(
             quorum.clone(),
@@ -1084,6 +1400,7 @@ mod test {
             yes: Uint128::new(8),
             no: Uint128::new(4),
             abstain: Uint128::new(0),
+            no_with_veto: Uint128::zero(),
         };
         assert!(!check_is_passed(
             quorum.clone(),
@@ -1107,6 +1424,7 @@ mod test {
             yes: Uint128::new(9),
             no: Uint128::new(3),
             abstain: Uint128::new(0),
+            no_with_veto: Uint128::zero(),
         };
         assert!(check_is_passed(
             quorum.clone(),
@@ -1126,6 +1444,122 @@ mod test {
         ));
     }
 
+    #[test]
+    fn test_no_with_veto() {
+        let threshold = Threshold::AbsolutePercentage {
+            percentage: PercentageThreshold::Majority {},
+        };
+        // 10 of 30 votes cast are vetoes, which is over a third of
+        // the votes cast but under a third of the total power.
+        let votes = Votes {
+            yes: Uint128::new(20),
+            no: Uint128::new(10),
+            abstain: Uint128::zero(),
+            no_with_veto: Uint128::new(10),
+        };
+        let veto = |is_expired, allow_revoting| {
+            let (mut prop, block) = setup_prop(
+                threshold.clone(),
+                votes.clone(),
+                Uint128::new(100),
+                is_expired,
+                true,
+                allow_revoting,
+            );
+            prop.no_with_veto_threshold = Some(Decimal::percent(33));
+            (prop, block)
+        };
+
+        let (prop, block) = veto(false, false);
+        assert!(!prop.is_vetoed(&block));
+        assert!(!prop.is_passed(&block));
+        assert!(!prop.is_rejected(&block));
+
+        let (prop, block) = veto(true, false);
+        assert!(prop.is_vetoed(&block));
+        assert!(!prop.is_passed(&block));
+        assert!(prop.is_rejected(&block));
+
+        // Vetoes over the threshold of the total power reject the
+        // proposal early, unless revoting is enabled.
+        let (mut prop, block) = veto(false, false);
+        prop.votes.no += Uint128::new(30);
+        prop.votes.no_with_veto += Uint128::new(30);
+        assert!(prop.is_vetoed(&block));
+        assert!(prop.is_rejected(&block));
+
+        let (mut prop, block) = veto(false, true);
+        prop.votes.no += Uint128::new(30);
+        prop.votes.no_with_veto += Uint128::new(30);
+        assert!(!prop.is_vetoed(&block));
+        assert!(!prop.is_rejected(&block));
+
+        // Proposals only pass early if the outstanding voting power
+        // could not veto them.
+        let (mut prop, block) = veto(false, false);
+        prop.votes.yes += Uint128::new(40);
+        assert!(!prop.is_passed(&block));
+        prop.votes.yes += Uint128::new(10);
+        assert!(prop.is_passed(&block));
+
+        // Without a threshold vetoes count as no votes.
+        let (mut prop, block) = veto(true, false);
+        prop.no_with_veto_threshold = None;
+        assert!(!prop.is_vetoed(&block));
+        assert!(!prop.is_rejected(&block));
+    }
+
+    #[test]
+    fn test_absolute_count_stats() {
+        let (prop, block) = setup_prop(
+            Threshold::AbsoluteCount {
+                threshold: Uint128::new(15),
+            },
+            Votes::with_yes(Uint128::new(5)),
+            Uint128::new(100),
+            false,
+            true,
+            false,
+        );
+        assert_eq!(
+            prop.stats(&block).unwrap(),
+            ProposalStatsResponse {
+                turnout: Decimal::percent(5),
+                votes_needed_to_pass: Some(Uint128::new(10)),
+                votes_needed_to_reject: Some(Uint128::zero()),
+                votes_needed_for_quorum: None,
+                outcome_locked: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_stats_before_min_voting_period() {
+        let (prop, block) = setup_prop(
+            Threshold::AbsoluteCount {
+                threshold: Uint128::new(15),
+            },
+            Votes::with_yes(Uint128::new(20)),
+            Uint128::new(100),
+            false,
+            false,
+            false,
+        );
+        // The proposal has passed, but is held open by its minimum
+        // voting period.
+        assert_eq!(prop.current_status(&block).unwrap(), Status::Open);
+        assert_eq!(
+            prop.stats(&block).unwrap(),
+            ProposalStatsResponse {
+                turnout: Decimal::percent(20),
+                votes_needed_to_pass: Some(Uint128::zero()),
+                votes_needed_to_reject: None,
+                votes_needed_for_quorum: None,
+                outcome_locked: true,
+            }
+        );
+    }
+
     #[test]
     fn test_proposal_ids_advance() {
         // do they advance, lets find out!
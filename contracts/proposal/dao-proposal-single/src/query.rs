@@ -1,4 +1,4 @@
-use crate::proposal::SingleChoiceProposal;
+use crate::{proposal::SingleChoiceProposal, state::ExecutionResult};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint128};
 use dao_voting::voting::Vote;
@@ -37,9 +37,36 @@ pub struct VoteListResponse {
     pub votes: Vec<VoteInfo>,
 }
 
+/// Information about a vote cast by a voter on a proposal.
+#[cw_serde]
+pub struct VoterVoteInfo {
+    /// The proposal that was voted on.
+    pub proposal_id: u64,
+    /// Position on the vote.
+    pub vote: Vote,
+    /// The voting power behind the vote.
+    pub power: Uint128,
+    /// The rationale behind the vote.
+    pub rationale: Option<String>,
+}
+
+/// A list of votes returned by `ListVotesByVoter`.
+#[cw_serde]
+pub struct VoterVoteListResponse {
+    pub votes: Vec<VoterVoteInfo>,
+}
+
 /// A list of proposals returned by `ListProposals` and
 /// `ReverseProposals`.
 #[cw_serde]
 pub struct ProposalListResponse {
     pub proposals: Vec<ProposalResponse>,
 }
+
+/// The recorded result of a proposal's execution.
+#[cw_serde]
+pub struct ExecutionResultResponse {
+    /// None if the proposal has not been executed successfully,
+    /// Some otherwise.
+    pub result: Option<ExecutionResult>,
+}
@@ -1,13 +1,19 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{
+    to_json_vec, Addr, Binary, Decimal, Empty, Event, StdResult, SubMsgResponse, Uint128,
+};
 use cw_hooks::Hooks;
-use cw_storage_plus::{Item, Map};
-use cw_utils::Duration;
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
+use cw_utils::{Duration, Expiration};
 use dao_voting::{
-    pre_propose::ProposalCreationPolicy, threshold::Threshold, veto::VetoConfig, voting::Vote,
+    pre_propose::ProposalCreationPolicy,
+    status::Status,
+    threshold::Threshold,
+    veto::VetoConfig,
+    voting::{Vote, VotingPeriodRange},
 };
 
-use crate::proposal::SingleChoiceProposal;
+use crate::{proposal::SingleChoiceProposal, threshold_rules::ThresholdRule, ContractError};
 
 /// A vote cast for a proposal.
 #[cw_serde]
@@ -24,6 +30,90 @@ pub struct Ballot {
     pub rationale: Option<String>,
 }
 
+/// A hidden vote committed to a commit-reveal proposal.
+#[cw_serde]
+pub struct Commitment {
+    /// The amount of voting power behind the vote.
+    pub power: Uint128,
+    /// The SHA-256 hash of the vote and salt, see
+    /// `contract::vote_commitment`.
+    pub commitment: Binary,
+}
+
+/// Configuration for commit-reveal voting. Voters first commit to a
+/// hidden vote while the proposal is open and then reveal it once
+/// voting has ended.
+#[cw_serde]
+pub struct CommitRevealConfig {
+    /// How long after a proposal's voting period ends votes may be
+    /// revealed. Must have the same units as the max voting period.
+    pub reveal_period: Duration,
+    /// If true, the voting power of commitments that are never
+    /// revealed counts toward quorum as abstain votes. Otherwise it
+    /// does not count at all.
+    pub count_unrevealed_as_abstain: bool,
+}
+
+impl CommitRevealConfig {
+    /// Validates that the reveal period has the same units as the
+    /// max voting period.
+    pub fn validate(&self, max_voting_period: &Duration) -> Result<(), ContractError> {
+        match (self.reveal_period, max_voting_period) {
+            (Duration::Time(_), Duration::Time(_)) | (Duration::Height(_), Duration::Height(_)) => {
+                Ok(())
+            }
+            _ => Err(ContractError::DurationUnitsConflict {}),
+        }
+    }
+}
+
+/// The maximum size, in bytes, of a serialized execution result. Data
+/// and events that do not fit are dropped.
+pub const MAX_EXECUTION_RESULT_SIZE: usize = 10_000;
+
+/// The result of a proposal's execution as returned to this module by
+/// the DAO.
+#[cw_serde]
+#[derive(Default)]
+pub struct ExecutionResult {
+    /// The events emitted while executing the proposal's messages.
+    pub events: Vec<Event>,
+    /// The data returned by the execution, if any.
+    pub data: Option<Binary>,
+    /// True if some events or data were dropped because the result
+    /// was larger than `MAX_EXECUTION_RESULT_SIZE`.
+    pub truncated: bool,
+}
+
+impl ExecutionResult {
+    /// Adds the events and data from a submessage response to this
+    /// result, dropping whatever does not fit within
+    /// `MAX_EXECUTION_RESULT_SIZE`.
+    pub fn record(&mut self, response: SubMsgResponse) -> StdResult<()> {
+        if let Some(data) = response.data {
+            let previous = self.data.replace(data);
+            if to_json_vec(self)?.len() > MAX_EXECUTION_RESULT_SIZE {
+                self.data = previous;
+                self.truncated = true;
+            }
+        }
+
+        let mut size = to_json_vec(self)?.len();
+        for event in response.events {
+            // Events are separated by a comma once serialized.
+            let event_size = to_json_vec(&event)?.len() + 1;
+            if size + event_size > MAX_EXECUTION_RESULT_SIZE {
+                self.truncated = true;
+                break;
+            }
+            size += event_size;
+            self.events.push(event);
+        }
+
+        Ok(())
+    }
+}
+
 /// The governance module's configuration.
 #[cw_serde]
 pub struct Config {
@@ -58,9 +148,38 @@ pub struct Config {
     /// remain open until the DAO's treasury was large enough for it to be
     /// executed.
     pub close_proposal_on_execution_failure: bool,
+    /// If set to true each of a proposal's messages will be executed
+    /// on its own, and a failing message will not prevent the others
+    /// from executing. The result of each message is recorded on the
+    /// proposal, which becomes partially executed if any message
+    /// failed. Failed messages may then be retried. When enabled,
+    /// `close_proposal_on_execution_failure` has no effect.
+    #[serde(default)]
+    pub continue_on_execution_failure: bool,
     /// Optional veto configuration. If set to `None`, veto option
     /// is disabled. Otherwise contains the configuration for veto flow.
     pub veto: Option<VetoConfig>,
+    /// Stricter thresholds for proposals containing sensitive
+    /// actions. The strictest rule matching a proposal's messages when
+    /// it is created applies to it, where it is stricter than
+    /// `threshold` and `min_voting_period`. See
+    /// `select_threshold_rule` and `apply_threshold_rule`.
+    #[serde(default)]
+    pub threshold_rules: Vec<ThresholdRule>,
+    /// If set, votes on new proposals are hidden until voting ends
+    /// using a commit-reveal scheme.
+    #[serde(default)]
+    pub commit_reveal: Option<CommitRevealConfig>,
+    /// If set, proposers may choose a voting period within this range
+    /// when creating a proposal. Otherwise, proposals are open for
+    /// `max_voting_period`.
+    #[serde(default)]
+    pub voting_period_range: Option<VotingPeriodRange>,
+    /// If set, proposals are rejected when the share of votes cast
+    /// as `NoWithVeto` exceeds this threshold, and their deposits are
+    /// forfeited to the DAO regardless of the deposit refund policy.
+    #[serde(default)]
+    pub no_with_veto_threshold: Option<Decimal>,
 }
 
 /// The current top level config for the module.  The "config" key was
@@ -68,8 +187,45 @@ pub struct Config {
 pub const CONFIG: Item<Config> = Item::new("config_v2");
 /// The number of proposals that have been created.
 pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
-pub const PROPOSALS: Map<u64, SingleChoiceProposal> = Map::new("proposals_v2");
 pub const BALLOTS: Map<(u64, &Addr), Ballot> = Map::new("ballots");
+/// The proposals each address has voted on, keyed by voter and
+/// proposal ID. A reverse index of `BALLOTS`.
+pub const VOTER_PROPOSALS: Map<(&Addr, u64), Empty> = Map::new("voter_proposals");
+/// Unrevealed vote commitments on commit-reveal proposals.
+pub const COMMITMENTS: Map<(u64, &Addr), Commitment> = Map::new("commitments");
+/// The nonce the next signed vote from each voter must use.
+pub const VOTE_NONCES: Map<&Addr, u64> = Map::new("vote_nonces");
+/// The vetoers who have vetoed a proposal, keyed by proposal ID. A
+/// proposal is vetoed once the veto threshold is reached.
+pub const VETO_VOTES: Map<u64, Vec<Addr>> = Map::new("veto_votes");
+/// The vetoers who have approved executing a timelocked proposal
+/// early, keyed by proposal ID. A proposal may be executed early once
+/// the veto threshold is reached.
+pub const EARLY_EXECUTE_VOTES: Map<u64, Vec<Addr>> = Map::new("early_execute_votes");
+/// The key of the last ballot added to `VOTER_PROPOSALS` after
+/// migrating from a version without it, or `None` if none have been
+/// added yet. Removed once every ballot has been indexed.
+pub const BALLOT_INDEX_CURSOR: Item<Option<(u64, Addr)>> = Item::new("ballot_index_cursor");
+/// The ID of the last proposal saved again to populate the proposal
+/// indexes after migrating from a version without them. Removed once
+/// every proposal has been indexed.
+pub const PROPOSAL_INDEX_CURSOR: Item<u64> = Item::new("proposal_index_cursor");
+/// The exponential moving average of the turnout of completed
+/// proposals, used to compute dynamic quorums. Only updated while the
+/// module's threshold is a dynamic quorum.
+pub const TURNOUT_AVERAGE: Item<Decimal> = Item::new("turnout_average");
+/// The proposals whose turnout has not been folded into
+/// `TURNOUT_AVERAGE` yet, keyed by the `expiration_key` of when their
+/// voting ends and by proposal ID. Turnouts are recorded once voting
+/// ends, or once a proposal is completed if that is sooner.
+pub const PENDING_TURNOUTS: Map<(u8, u64, u64), Empty> = Map::new("pending_turnouts");
+/// The status each proposal had before its messages were dispatched
+/// to be executed individually, keyed by proposal ID. Removed once
+/// every message has replied, when the proposal's final status is
+/// set and status hooks are fired.
+pub const EXECUTING_PROPOSALS: Map<u64, Status> = Map::new("executing_proposals");
+/// The results of executing proposals, keyed by proposal ID.
+pub const EXECUTION_RESULTS: Map<u64, ExecutionResult> = Map::new("execution_results");
 /// Consumers of proposal state change hooks.
 pub const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
 /// Consumers of vote hooks.
@@ -77,3 +233,58 @@ pub const VOTE_HOOKS: Hooks = Hooks::new("vote_hooks");
 /// The address of the pre-propose module associated with this
 /// proposal module (if any).
 pub const CREATION_POLICY: Item<ProposalCreationPolicy> = Item::new("creation_policy");
+
+pub struct ProposalIndexes<'a> {
+    pub status: MultiIndex<'a, String, SingleChoiceProposal, u64>,
+    pub proposer: MultiIndex<'a, Addr, SingleChoiceProposal, u64>,
+    pub status_expiration: MultiIndex<'a, (u8, u64), SingleChoiceProposal, u64>,
+}
+
+impl<'a> IndexList<SingleChoiceProposal> for ProposalIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<SingleChoiceProposal>> + '_> {
+        let v: Vec<&dyn Index<SingleChoiceProposal>> =
+            vec![&self.status, &self.proposer, &self.status_expiration];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Prefixes the keys of `ProposalIndexes::status_expiration` with
+/// the units of each expiration.
+pub const EXPIRES_AT_HEIGHT: u8 = 0;
+pub const EXPIRES_AT_TIME: u8 = 1;
+pub const EXPIRES_NEVER: u8 = 2;
+
+/// The key of an expiration in `ProposalIndexes::status_expiration`.
+/// Keys sort by units and then by when they expire.
+pub fn expiration_key(expiration: Expiration) -> (u8, u64) {
+    match expiration {
+        Expiration::AtHeight(height) => (EXPIRES_AT_HEIGHT, height),
+        Expiration::AtTime(time) => (EXPIRES_AT_TIME, time.nanos()),
+        Expiration::Never {} => (EXPIRES_NEVER, 0),
+    }
+}
+
+/// The module's proposals, indexed by their stored status, by
+/// proposer, and by when their stored status will lazily change.
+/// Statuses are updated lazily, so the status index may lag behind a
+/// proposal's current status. See `Status::stored_keys`.
+pub fn proposals<'a>() -> IndexedMap<'a, u64, SingleChoiceProposal, ProposalIndexes<'a>> {
+    let indexes = ProposalIndexes {
+        status: MultiIndex::new(
+            |_pk: &[u8], p: &SingleChoiceProposal| p.status.key().to_string(),
+            "proposals_v2",
+            "proposals_v2__status",
+        ),
+        proposer: MultiIndex::new(
+            |_pk: &[u8], p: &SingleChoiceProposal| p.proposer.clone(),
+            "proposals_v2",
+            "proposals_v2__proposer",
+        ),
+        status_expiration: MultiIndex::new(
+            |_pk: &[u8], p: &SingleChoiceProposal| expiration_key(p.status_expiration()),
+            "proposals_v2",
+            "proposals_v2__status_expiration",
+        ),
+    };
+    IndexedMap::new("proposals_v2", indexes)
+}
@@ -180,6 +180,11 @@ pub fn test_executed_prop_state_remains_after_vote_swing() {
             false,
         ),
         close_proposal_on_execution_failure: true,
+        continue_on_execution_failure: false,
+        threshold_rules: vec![],
+        commit_reveal: None,
+        voting_period_range: None,
+        no_with_veto_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -279,6 +284,11 @@ pub fn test_passed_prop_state_remains_after_vote_swing() {
             false,
         ),
         close_proposal_on_execution_failure: true,
+        continue_on_execution_failure: false,
+        threshold_rules: vec![],
+        commit_reveal: None,
+        voting_period_range: None,
+        no_with_veto_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
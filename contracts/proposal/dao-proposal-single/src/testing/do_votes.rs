@@ -141,7 +141,12 @@ where
         only_members_execute: false,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        continue_on_execution_failure: false,
         pre_propose_info,
+        threshold_rules: vec![],
+        commit_reveal: None,
+        voting_period_range: None,
+        no_with_veto_threshold: None,
     };
 
     let core_addr = setup_governance(&mut app, instantiate, Some(initial_balances));
@@ -208,6 +213,9 @@ where
                 description: "This is a simple text proposal".to_string(),
                 msgs: vec![],
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
             },
         },
         &funds,
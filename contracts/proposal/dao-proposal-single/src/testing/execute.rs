@@ -82,6 +82,9 @@ pub(crate) fn make_proposal(
                     msgs: msgs.clone(),
                     proposer: None,
                     vote,
+                    execute_after: None,
+                    depends_on: vec![],
+                    voting_period: None,
                 }),
                 &[],
             )
@@ -96,6 +99,9 @@ pub(crate) fn make_proposal(
                         description: "description".to_string(),
                         msgs: msgs.clone(),
                         vote,
+                        execute_after: None,
+                        depends_on: vec![],
+                        voting_period: None,
                     },
                 },
                 &funds,
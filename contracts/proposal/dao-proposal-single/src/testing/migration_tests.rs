@@ -13,6 +13,7 @@ use dao_voting::{
     status::Status,
 };
 
+use crate::query::VoterVoteListResponse;
 use crate::testing::queries::query_list_proposals;
 use crate::testing::{
     execute::{execute_proposal, make_proposal, vote_on_proposal},
@@ -36,6 +37,8 @@ use crate::testing::{
 ///
 /// - Proposal count remains accurate after proposal migration.
 ///
+/// - v1 ballots can be indexed by voter after migration.
+///
 /// - Items are not overriden during migration.
 #[test]
 fn test_v1_v2_full_migration() {
@@ -344,6 +347,8 @@ fn test_v1_v2_full_migration() {
                             vetoer: sender.to_string(),
                             early_execute: true,
                             veto_before_passed: false,
+                            additional_vetoers: vec![],
+                            veto_threshold: None,
                         }),
                     })
                     .unwrap(),
@@ -386,6 +391,35 @@ fn test_v1_v2_full_migration() {
     let count = query_proposal_count(&app, &proposal);
     assert_eq!(count, 3);
 
+    // ----
+    // check that v1 ballots can be indexed by voter.
+    // ----
+    app.execute_contract(
+        sender.clone(),
+        proposal.clone(),
+        &crate::msg::ExecuteMsg::IndexBallots { limit: None },
+        &[],
+    )
+    .unwrap();
+    let VoterVoteListResponse { votes } = app
+        .wrap()
+        .query_wasm_smart(
+            &proposal,
+            &crate::msg::QueryMsg::ListVotesByVoter {
+                voter: sender.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        votes
+            .into_iter()
+            .map(|vote| vote.proposal_id)
+            .collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+
     let migrated_existing_props = query_list_proposals(&app, &proposal, None, None);
     // assert that even though we migrate with a veto config,
     // existing proposals are not affected
@@ -461,6 +495,8 @@ fn test_v1_v2_full_migration() {
             vetoer: sender.to_string(),
             early_execute: true,
             veto_before_passed: false,
+            additional_vetoers: vec![],
+            veto_threshold: None,
         })
     );
 
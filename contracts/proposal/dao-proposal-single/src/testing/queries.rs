@@ -4,11 +4,17 @@ use dao_interface::state::{ProposalModule, ProposalModuleStatus};
 
 use cw_hooks::HooksResponse;
 use dao_pre_propose_single as cppbps;
-use dao_voting::pre_propose::ProposalCreationPolicy;
+use dao_voting::{
+    pre_propose::ProposalCreationPolicy, stats::ProposalStatsResponse, status::Status,
+    veto::VetoTallyResponse,
+};
 
 use crate::{
     msg::QueryMsg,
-    query::{ProposalListResponse, ProposalResponse, VoteListResponse, VoteResponse},
+    query::{
+        ExecutionResultResponse, ProposalListResponse, ProposalResponse, VoteListResponse,
+        VoteResponse, VoterVoteListResponse,
+    },
     state::Config,
 };
 
@@ -53,6 +59,44 @@ pub(crate) fn query_list_proposals(
         .unwrap()
 }
 
+pub(crate) fn query_list_proposals_by_status(
+    app: &App,
+    proposal_single: &Addr,
+    status: Status,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> ProposalListResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::ListProposalsByStatus {
+                status,
+                start_after,
+                limit,
+            },
+        )
+        .unwrap()
+}
+
+pub(crate) fn query_list_proposals_by_proposer(
+    app: &App,
+    proposal_single: &Addr,
+    proposer: &str,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> ProposalListResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::ListProposalsByProposer {
+                proposer: proposer.to_string(),
+                start_after,
+                limit,
+            },
+        )
+        .unwrap()
+}
+
 pub(crate) fn query_list_votes(
     app: &App,
     proposal_single: &Addr,
@@ -72,6 +116,25 @@ pub(crate) fn query_list_votes(
         .unwrap()
 }
 
+pub(crate) fn query_list_votes_by_voter(
+    app: &App,
+    proposal_single: &Addr,
+    voter: &str,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> VoterVoteListResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::ListVotesByVoter {
+                voter: voter.to_string(),
+                start_after,
+                limit,
+            },
+        )
+        .unwrap()
+}
+
 pub(crate) fn query_vote(
     app: &App,
     proposal_module: &Addr,
@@ -212,3 +275,47 @@ pub(crate) fn query_proposal_count(app: &App, proposal_single: &Addr) -> u64 {
         .query_wasm_smart(proposal_single, &QueryMsg::ProposalCount {})
         .unwrap()
 }
+
+pub(crate) fn query_proposal_execution_result(
+    app: &App,
+    proposal_single: &Addr,
+    proposal_id: u64,
+) -> ExecutionResultResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::ProposalExecutionResult { proposal_id },
+        )
+        .unwrap()
+}
+
+pub(crate) fn query_veto_tally(
+    app: &App,
+    proposal_single: &Addr,
+    proposal_id: u64,
+) -> VetoTallyResponse {
+    app.wrap()
+        .query_wasm_smart(proposal_single, &QueryMsg::VetoTally { proposal_id })
+        .unwrap()
+}
+
+pub(crate) fn query_vote_nonce(app: &App, proposal_single: &Addr, voter: &str) -> u64 {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::VoteNonce {
+                voter: voter.to_string(),
+            },
+        )
+        .unwrap()
+}
+
+pub(crate) fn query_proposal_stats(
+    app: &App,
+    proposal_single: &Addr,
+    proposal_id: u64,
+) -> ProposalStatsResponse {
+    app.wrap()
+        .query_wasm_smart(proposal_single, &QueryMsg::ProposalStats { proposal_id })
+        .unwrap()
+}
@@ -1,42 +1,48 @@
 use std::ops::Add;
 
 use cosmwasm_std::{
-    coins,
-    testing::{mock_dependencies, mock_env},
-    to_json_binary, Addr, Attribute, BankMsg, Binary, ContractInfoResponse, CosmosMsg, Decimal,
-    Empty, Reply, StdError, SubMsgResult, Uint128, WasmMsg, WasmQuery,
+    coin, coins,
+    testing::{mock_dependencies, mock_env, mock_info},
+    to_json_binary, to_json_vec, Addr, Attribute, BankMsg, Binary, ContractInfoResponse, CosmosMsg,
+    Decimal, Empty, Event, IbcMsg, IbcTimeout, Reply, StdError, SubMsgResponse, SubMsgResult,
+    Uint128, WasmMsg, WasmQuery,
 };
 use cw2::ContractVersion;
 use cw20::Cw20Coin;
 use cw_denom::CheckedDenom;
 use cw_hooks::{HookError, HooksResponse};
 use cw_multi_test::{next_block, App, Executor};
-use cw_utils::Duration;
+use cw_utils::{Duration, Expiration};
 use dao_interface::{
     state::{Admin, ModuleInstantiateInfo},
     voting::InfoResponse,
 };
 use dao_testing::{ShouldExecute, TestSingleChoiceVote};
 use dao_voting::{
-    deposit::{CheckedDepositInfo, UncheckedDepositInfo, VotingModuleTokenType},
+    deposit::{
+        CheckedDepositInfo, DepositRefundPolicy, UncheckedDepositInfo, VotingModuleTokenType,
+    },
     pre_propose::{PreProposeInfo, PreProposeSubmissionPolicy, ProposalCreationPolicy},
     proposal::{SingleChoiceProposeMsg as ProposeMsg, MAX_PROPOSAL_SIZE},
     reply::{
         failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id,
         mask_proposal_hook_index, mask_vote_hook_index,
     },
+    signed_vote::{SignedVoteError, SignedVotePayload},
+    stats::ProposalStatsResponse,
     status::Status,
     threshold::{ActiveThreshold, PercentageThreshold, Threshold},
-    veto::{VetoConfig, VetoError},
-    voting::{SingleChoiceAutoVote, Vote, Votes},
+    veto::{VetoConfig, VetoError, VetoTallyResponse},
+    voting::{SingleChoiceAutoVote, Vote, Votes, VotingPeriodRange},
 };
+use k256::ecdsa::{signature::hazmat::PrehashSigner, Signature, SigningKey};
 
 use crate::{
-    contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION},
+    contract::{migrate, vote_commitment, CONTRACT_NAME, CONTRACT_VERSION},
     msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
-    proposal::SingleChoiceProposal,
-    query::{ProposalResponse, VoteInfo},
-    state::Config,
+    proposal::{MessageExecutionResult, SingleChoiceProposal},
+    query::{ProposalListResponse, ProposalResponse, VoteInfo, VoterVoteInfo},
+    state::{CommitRevealConfig, Config, ExecutionResult, MAX_EXECUTION_RESULT_SIZE},
     testing::{
         contracts::{pre_propose_single_contract, proposal_single_contract},
         execute::{
@@ -56,12 +62,15 @@ use crate::{
         queries::{
             query_balance_cw20, query_balance_native, query_creation_policy, query_dao_token,
             query_deposit_config_and_pre_propose_module, query_list_proposals,
-            query_list_proposals_reverse, query_list_votes, query_pre_proposal_single_config,
-            query_pre_proposal_single_deposit_info, query_proposal, query_proposal_config,
-            query_proposal_hooks, query_single_proposal_module, query_vote_hooks,
-            query_voting_module,
+            query_list_proposals_by_proposer, query_list_proposals_by_status,
+            query_list_proposals_reverse, query_list_votes, query_list_votes_by_voter,
+            query_pre_proposal_single_config, query_pre_proposal_single_deposit_info,
+            query_proposal, query_proposal_config, query_proposal_execution_result,
+            query_proposal_hooks, query_proposal_stats, query_single_proposal_module,
+            query_veto_tally, query_vote_hooks, query_vote_nonce, query_voting_module,
         },
     },
+    threshold_rules::{SensitiveAction, ThresholdRule},
     ContractError,
 };
 
@@ -130,7 +139,13 @@ fn test_simple_propose_staked_balances() {
         msgs: vec![],
         status: Status::Open,
         veto: None,
+        execute_after: None,
+        depends_on: vec![],
+        message_results: vec![],
         votes: Votes::zero(),
+        threshold_rule: None,
+        commit_reveal: None,
+        no_with_veto_threshold: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -180,7 +195,13 @@ fn test_simple_proposal_cw4_voting() {
         msgs: vec![],
         status: Status::Open,
         veto: None,
+        execute_after: None,
+        depends_on: vec![],
+        message_results: vec![],
         votes: Votes::zero(),
+        threshold_rule: None,
+        commit_reveal: None,
+        no_with_veto_threshold: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -232,11 +253,18 @@ fn test_simple_proposal_auto_vote_yes() {
         msgs: vec![],
         status: Status::Passed,
         veto: None,
+        execute_after: None,
+        depends_on: vec![],
+        message_results: vec![],
         votes: Votes {
             yes: Uint128::new(1),
             no: Uint128::zero(),
             abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
         },
+        threshold_rule: None,
+        commit_reveal: None,
+        no_with_veto_threshold: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -281,11 +309,18 @@ fn test_simple_proposal_auto_vote_no() {
         msgs: vec![],
         status: Status::Rejected,
         veto: None,
+        execute_after: None,
+        depends_on: vec![],
+        message_results: vec![],
         votes: Votes {
             yes: Uint128::zero(),
             no: Uint128::new(1),
             abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
         },
+        threshold_rule: None,
+        commit_reveal: None,
+        no_with_veto_threshold: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -384,6 +419,12 @@ fn test_instantiate_with_non_voting_module_cw20_deposit() {
         status: Status::Open,
         votes: Votes::zero(),
         veto: None,
+        execute_after: None,
+        depends_on: vec![],
+        message_results: vec![],
+        threshold_rule: None,
+        commit_reveal: None,
+        no_with_veto_threshold: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -393,91 +434,1386 @@ fn test_instantiate_with_non_voting_module_cw20_deposit() {
     let (_, pre_propose) = query_deposit_config_and_pre_propose_module(&app, &proposal_module);
     let deposit_response = query_pre_proposal_single_deposit_info(&app, &pre_propose, proposal_id);
 
-    assert_eq!(deposit_response.proposer, Addr::unchecked(CREATOR_ADDR));
+    assert_eq!(deposit_response.proposer, Addr::unchecked(CREATOR_ADDR));
+    assert_eq!(
+        deposit_response.deposit_info,
+        Some(CheckedDepositInfo {
+            denom: cw_denom::CheckedDenom::Cw20(alt_cw20),
+            amount: Uint128::new(10_000_000),
+            refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed
+        })
+    );
+}
+
+#[test]
+fn test_proposal_message_execution() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.close_proposal_on_execution_failure = false;
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let gov_token = query_dao_token(&app, &core_addr);
+
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        vec![
+            WasmMsg::Execute {
+                contract_addr: gov_token.to_string(),
+                msg: to_json_binary(&cw20::Cw20ExecuteMsg::Mint {
+                    recipient: CREATOR_ADDR.to_string(),
+                    amount: Uint128::new(10_000_000),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into(),
+            BankMsg::Send {
+                to_address: CREATOR_ADDR.to_string(),
+                amount: coins(10, "ujuno"),
+            }
+            .into(),
+        ],
+        None,
+    );
+    let cw20_balance = query_balance_cw20(&app, &gov_token, CREATOR_ADDR);
+    let native_balance = query_balance_native(&app, CREATOR_ADDR, "ujuno");
+    assert_eq!(cw20_balance, Uint128::zero());
+    assert_eq!(native_balance, Uint128::zero());
+
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+
+    // Can't use library function because we expect this to fail due
+    // to insufficent balance in the bank module.
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        proposal_module.clone(),
+        &ExecuteMsg::Execute { proposal_id },
+        &[],
+    )
+    .unwrap_err();
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+
+    mint_natives(&mut app, core_addr.as_str(), coins(10, "ujuno"));
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Executed);
+
+    let cw20_balance = query_balance_cw20(&app, &gov_token, CREATOR_ADDR);
+    let native_balance = query_balance_native(&app, CREATOR_ADDR, "ujuno");
+    assert_eq!(cw20_balance, Uint128::new(20_000_000));
+    assert_eq!(native_balance, Uint128::new(10));
+
+    // Sneak in a check here that proposals can't be executed more
+    // than once in the on close on execute config suituation.
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert!(matches!(err, ContractError::NotPassed {}))
+}
+
+#[test]
+fn test_proposal_execution_result() {
+    let CommonTest {
+        mut app,
+        core_addr,
+        proposal_module,
+        gov_token: _,
+        proposal_id,
+    } = setup_test(vec![BankMsg::Send {
+        to_address: "ekez".to_string(),
+        amount: coins(10, "ujuno"),
+    }
+    .into()]);
+
+    mint_natives(&mut app, core_addr.as_str(), coins(10, "ujuno"));
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+
+    // Nothing is recorded before execution.
+    let res = query_proposal_execution_result(&app, &proposal_module, proposal_id);
+    assert_eq!(res.result, None);
+
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+
+    let result = query_proposal_execution_result(&app, &proposal_module, proposal_id)
+        .result
+        .unwrap();
+    assert!(!result.truncated);
+    assert!(result.events.iter().any(|event| event.ty == "transfer"));
+    assert!(result.events.iter().any(|event| {
+        event.ty == "wasm"
+            && event
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "action" && attr.value == "execute_proposal_hook")
+    }));
+}
+
+#[test]
+fn test_execution_result_size_capped() {
+    let mut result = ExecutionResult::default();
+    result
+        .record(SubMsgResponse {
+            events: (0..MAX_EXECUTION_RESULT_SIZE)
+                .map(|i| Event::new("big").add_attribute("index", i.to_string()))
+                .collect(),
+            data: Some(Binary::from(vec![0; MAX_EXECUTION_RESULT_SIZE])),
+        })
+        .unwrap();
+
+    assert!(result.truncated);
+    assert_eq!(result.data, None);
+    assert!(!result.events.is_empty());
+    assert!(to_json_vec(&result).unwrap().len() <= MAX_EXECUTION_RESULT_SIZE);
+}
+
+#[test]
+fn test_proposer_voting_period() {
+    let propose = |app: &mut App, proposal_module: &Addr, voting_period| {
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                proposer: None,
+                vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period,
+            }),
+            &[],
+        )
+    };
+
+    // Proposers may not choose a voting period unless a range is
+    // configured.
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let err: ContractError = propose(&mut app, &proposal_module, Some(Duration::Time(86400)))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::VotingPeriodNotAllowed {});
+
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    instantiate.min_voting_period = Some(Duration::Time(3600));
+    instantiate.voting_period_range = Some(VotingPeriodRange {
+        min: Duration::Time(3600),
+        max: Duration::Time(604800),
+    });
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    // Without a chosen voting period the max voting period is used.
+    propose(&mut app, &proposal_module, None).unwrap();
+    let proposal = query_proposal(&app, &proposal_module, 1);
+    assert_eq!(
+        proposal.proposal.expiration,
+        Expiration::AtTime(app.block_info().time.plus_seconds(604800))
+    );
+
+    propose(&mut app, &proposal_module, Some(Duration::Time(3600))).unwrap();
+    let proposal = query_proposal(&app, &proposal_module, 2);
+    assert_eq!(
+        proposal.proposal.expiration,
+        Expiration::AtTime(app.block_info().time.plus_seconds(3600))
+    );
+
+    for voting_period in [3599, 604801] {
+        let err: ContractError = propose(
+            &mut app,
+            &proposal_module,
+            Some(Duration::Time(voting_period)),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+        assert_eq!(
+            err,
+            ContractError::VotingError(dao_voting::error::VotingError::VotingPeriodOutOfRange {})
+        );
+    }
+
+    let err: ContractError = propose(&mut app, &proposal_module, Some(Duration::Height(100)))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::VotingError(dao_voting::error::VotingError::DurationUnitsConflict {})
+    );
+}
+
+#[test]
+fn test_no_with_veto() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = get_pre_propose_info(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: dao_voting::deposit::DepositToken::VotingModuleToken {
+                token_type: VotingModuleTokenType::Cw20,
+            },
+            amount: Uint128::new(10_000_000),
+            refund_policy: DepositRefundPolicy::Always,
+        }),
+        false,
+    );
+    instantiate.no_with_veto_threshold = Some(Decimal::percent(33));
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(55),
+            },
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(30),
+            },
+            Cw20Coin {
+                address: "keze".to_string(),
+                amount: Uint128::new(15),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let gov_token = query_dao_token(&app, &core_addr);
+
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![], None);
+
+    // A majority has voted yes, but the proposal may not pass while
+    // the outstanding voting power could still veto it.
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        "ekez",
+        proposal_id,
+        Vote::NoWithVeto,
+    );
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Open);
+
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        "keze",
+        proposal_id,
+        Vote::NoWithVeto,
+    );
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Rejected);
+    assert_eq!(
+        proposal.proposal.votes,
+        Votes {
+            yes: Uint128::new(55),
+            no: Uint128::new(45),
+            abstain: Uint128::zero(),
+            no_with_veto: Uint128::new(45),
+        }
+    );
+    let vote = query_vote(&app, &proposal_module, "ekez", proposal_id);
+    assert_eq!(vote.vote.unwrap().vote, Vote::NoWithVeto);
+
+    // The deposit is forfeited to the DAO despite the refund policy.
+    close_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    let balance = query_balance_cw20(&app, &gov_token, CREATOR_ADDR);
+    assert_eq!(balance, Uint128::zero());
+    let balance = query_balance_cw20(&app, &gov_token, core_addr.as_str());
+    assert_eq!(balance, Uint128::new(10_000_000));
+
+    // Vetoes under the threshold count as no votes.
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![], None);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        "ekez",
+        proposal_id,
+        Vote::NoWithVeto,
+    );
+    vote_on_proposal(&mut app, &proposal_module, "keze", proposal_id, Vote::Yes);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    let balance = query_balance_cw20(&app, &gov_token, CREATOR_ADDR);
+    assert_eq!(balance, Uint128::new(10_000_000));
+}
+
+#[test]
+fn test_proposal_stats() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.threshold = Threshold::ThresholdQuorum {
+        threshold: PercentageThreshold::Majority {},
+        quorum: PercentageThreshold::Percent(Decimal::percent(20)),
+    };
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(10),
+            },
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(30),
+            },
+            Cw20Coin {
+                address: "keze".to_string(),
+                amount: Uint128::new(60),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![], None);
+
+    let stats = query_proposal_stats(&app, &proposal_module, proposal_id);
+    assert_eq!(
+        stats,
+        ProposalStatsResponse {
+            turnout: Decimal::zero(),
+            votes_needed_to_pass: Some(Uint128::new(20)),
+            votes_needed_to_reject: Some(Uint128::zero()),
+            votes_needed_for_quorum: Some(Uint128::new(20)),
+            outcome_locked: false,
+        }
+    );
+
+    // Yes votes must now both meet quorum and outweigh the no vote.
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::No,
+    );
+    let stats = query_proposal_stats(&app, &proposal_module, proposal_id);
+    assert_eq!(
+        stats,
+        ProposalStatsResponse {
+            turnout: Decimal::percent(10),
+            votes_needed_to_pass: Some(Uint128::new(11)),
+            votes_needed_to_reject: Some(Uint128::zero()),
+            votes_needed_for_quorum: Some(Uint128::new(10)),
+            outcome_locked: false,
+        }
+    );
+
+    vote_on_proposal(&mut app, &proposal_module, "ekez", proposal_id, Vote::Yes);
+    let stats = query_proposal_stats(&app, &proposal_module, proposal_id);
+    assert_eq!(
+        stats,
+        ProposalStatsResponse {
+            turnout: Decimal::percent(40),
+            votes_needed_to_pass: Some(Uint128::zero()),
+            votes_needed_to_reject: Some(Uint128::new(20)),
+            votes_needed_for_quorum: Some(Uint128::zero()),
+            outcome_locked: false,
+        }
+    );
+
+    vote_on_proposal(&mut app, &proposal_module, "keze", proposal_id, Vote::Yes);
+    let stats = query_proposal_stats(&app, &proposal_module, proposal_id);
+    assert_eq!(
+        stats,
+        ProposalStatsResponse {
+            turnout: Decimal::one(),
+            votes_needed_to_pass: Some(Uint128::zero()),
+            votes_needed_to_reject: None,
+            votes_needed_for_quorum: Some(Uint128::zero()),
+            outcome_locked: true,
+        }
+    );
+}
+
+#[test]
+fn test_execute_after() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    // The earliest execution time must have the same units as the max
+    // voting period, which is in time.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                proposer: None,
+                vote: None,
+                execute_after: Some(Expiration::AtHeight(app.block_info().height + 100)),
+                depends_on: vec![],
+                voting_period: None,
+            }),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::VotingError(dao_voting::error::VotingError::ExecuteAfterUnitsConflict {})
+    );
+
+    let execute_after = Expiration::AtTime(app.block_info().time.plus_days(30));
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        proposal_module.clone(),
+        &ExecuteMsg::Propose(ProposeMsg {
+            title: "title".to_string(),
+            description: "description".to_string(),
+            msgs: vec![],
+            proposer: None,
+            vote: Some(SingleChoiceAutoVote {
+                vote: Vote::Yes,
+                rationale: None,
+            }),
+            execute_after: Some(execute_after),
+            depends_on: vec![],
+            voting_period: None,
+        }),
+        &[],
+    )
+    .unwrap();
+    let proposal_id = 1;
+
+    // The proposal passes, but may not be executed yet.
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+    assert_eq!(proposal.proposal.execute_after, Some(execute_after));
+
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert_eq!(err, ContractError::ExecutionLocked { execute_after });
+
+    // Still locked after the voting period ends.
+    app.update_block(|b| b.time = b.time.plus_days(29));
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert_eq!(err, ContractError::ExecutionLocked { execute_after });
+
+    app.update_block(|b| b.time = b.time.plus_days(1));
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Executed);
+}
+
+#[test]
+fn test_list_proposals_by_status_and_proposer() {
+    let mut app = App::default();
+    let instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(100),
+            },
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(1),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let ids = |res: ProposalListResponse| -> Vec<u64> {
+        res.proposals.into_iter().map(|p| p.id).collect()
+    };
+
+    let passed = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![], None);
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, passed, Vote::Yes);
+    let rejected = make_proposal(&mut app, &proposal_module, "ekez", vec![], None);
+
+    // The second proposal is rejected once it expires, though its
+    // stored status is still open.
+    app.update_block(|b| b.time = b.time.plus_seconds(604800));
+    let open = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![], None);
+
+    let by_status = |app: &App, status, start_after| {
+        ids(query_list_proposals_by_status(
+            app,
+            &proposal_module,
+            status,
+            start_after,
+            None,
+        ))
+    };
+    assert_eq!(by_status(&app, Status::Open, None), vec![open]);
+    assert_eq!(by_status(&app, Status::Passed, None), vec![passed]);
+    assert_eq!(by_status(&app, Status::Passed, Some(passed)), vec![]);
+    assert_eq!(by_status(&app, Status::Rejected, None), vec![rejected]);
+    assert_eq!(by_status(&app, Status::Closed, None), vec![]);
+
+    close_proposal(&mut app, &proposal_module, CREATOR_ADDR, rejected);
+    assert_eq!(by_status(&app, Status::Rejected, None), vec![]);
+    assert_eq!(by_status(&app, Status::Closed, None), vec![rejected]);
+
+    let by_proposer = |app: &App, proposer, start_after, limit| {
+        ids(query_list_proposals_by_proposer(
+            app,
+            &proposal_module,
+            proposer,
+            start_after,
+            limit,
+        ))
+    };
+    assert_eq!(
+        by_proposer(&app, CREATOR_ADDR, None, None),
+        vec![passed, open]
+    );
+    assert_eq!(by_proposer(&app, CREATOR_ADDR, None, Some(1)), vec![passed]);
+    assert_eq!(
+        by_proposer(&app, CREATOR_ADDR, Some(passed), None),
+        vec![open]
+    );
+    assert_eq!(by_proposer(&app, "ekez", None, None), vec![rejected]);
+    assert_eq!(by_proposer(&app, "someone", None, None), vec![]);
+}
+
+#[test]
+fn test_list_votes_by_voter() {
+    let mut app = App::default();
+    let instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(100),
+            },
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(100),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let first = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![], None);
+    let second = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![], None);
+    let third = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![], None);
+    vote_on_proposal_with_rationale(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        first,
+        Vote::Yes,
+        Some("lgtm".to_string()),
+    );
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, third, Vote::No);
+    vote_on_proposal(&mut app, &proposal_module, "ekez", second, Vote::Abstain);
+
+    let votes = query_list_votes_by_voter(&app, &proposal_module, CREATOR_ADDR, None, None);
+    assert_eq!(
+        votes.votes,
+        vec![
+            VoterVoteInfo {
+                proposal_id: first,
+                vote: Vote::Yes,
+                power: Uint128::new(100),
+                rationale: Some("lgtm".to_string()),
+            },
+            VoterVoteInfo {
+                proposal_id: third,
+                vote: Vote::No,
+                power: Uint128::new(100),
+                rationale: None,
+            },
+        ]
+    );
+
+    let votes =
+        query_list_votes_by_voter(&app, &proposal_module, CREATOR_ADDR, Some(first), Some(1));
+    assert_eq!(
+        votes
+            .votes
+            .into_iter()
+            .map(|v| v.proposal_id)
+            .collect::<Vec<_>>(),
+        vec![third]
+    );
+
+    let votes = query_list_votes_by_voter(&app, &proposal_module, "ekez", None, None);
+    assert_eq!(
+        votes.votes,
+        vec![VoterVoteInfo {
+            proposal_id: second,
+            vote: Vote::Abstain,
+            power: Uint128::new(100),
+            rationale: None,
+        }]
+    );
+
+    let votes = query_list_votes_by_voter(&app, &proposal_module, "someone", None, None);
+    assert_eq!(votes.votes, vec![]);
+}
+
+#[test]
+fn test_vote_with_signature() {
+    // The address of the secp256k1 key below.
+    const SIGNER: &str = "juno16l746xap3c5p6ud5la5llgr9kawcc4yttal2tp";
+    let mut key = [0u8; 32];
+    key[31] = 0x1d;
+    let signing_key = SigningKey::from_slice(&key).unwrap();
+    let pubkey = Binary::from(signing_key.verifying_key().to_sec1_bytes().to_vec());
+    let sign = |payload: &SignedVotePayload<Vote>| {
+        let signature: Signature = signing_key
+            .sign_prehash(&payload.hash(&Addr::unchecked(SIGNER)).unwrap())
+            .unwrap();
+        Binary::from(signature.to_bytes().to_vec())
+    };
+
+    let mut app = App::default();
+    let instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: SIGNER.to_string(),
+                amount: Uint128::new(100),
+            },
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(100),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![], None);
+
+    let payload = SignedVotePayload {
+        chain_id: app.block_info().chain_id,
+        module: proposal_module.to_string(),
+        proposal_id: id,
+        vote: Vote::Yes,
+        nonce: 0,
+        expiry: Expiration::AtHeight(app.block_info().height + 10),
+    };
+    let vote_with_signature = |app: &mut App, voter: &str, payload: &SignedVotePayload<Vote>| {
+        app.execute_contract(
+            Addr::unchecked("relayer"),
+            proposal_module.clone(),
+            &ExecuteMsg::VoteWithSignature {
+                voter: voter.to_string(),
+                payload: payload.clone(),
+                pubkey: pubkey.clone(),
+                signature: sign(payload),
+            },
+            &[],
+        )
+    };
+
+    // The key may not be used to vote for someone else.
+    let err: ContractError = vote_with_signature(&mut app, CREATOR_ADDR, &payload)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::SignedVoteError(SignedVoteError::PubkeyMismatch {
+            voter: CREATOR_ADDR.to_string()
+        })
+    );
+
+    // A relayer may submit the signed vote.
+    vote_with_signature(&mut app, SIGNER, &payload).unwrap();
+    let vote = query_vote(&app, &proposal_module, SIGNER, id);
+    assert_eq!(vote.vote.unwrap().vote, Vote::Yes);
+    assert_eq!(query_vote_nonce(&app, &proposal_module, SIGNER), 1);
+
+    // Signed votes may not be replayed.
+    let err: ContractError = vote_with_signature(&mut app, SIGNER, &payload)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::SignedVoteError(SignedVoteError::InvalidNonce {
+            nonce: 0,
+            expected: 1
+        })
+    );
+
+    // Signatures must match the payload.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("relayer"),
+            proposal_module.clone(),
+            &ExecuteMsg::VoteWithSignature {
+                voter: SIGNER.to_string(),
+                payload: SignedVotePayload {
+                    nonce: 1,
+                    vote: Vote::No,
+                    ..payload.clone()
+                },
+                pubkey: pubkey.clone(),
+                signature: sign(&SignedVotePayload {
+                    nonce: 1,
+                    ..payload.clone()
+                }),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::SignedVoteError(SignedVoteError::InvalidSignature {})
+    );
+
+    // Expired signed votes are rejected.
+    app.update_block(|b| b.height += 10);
+    let err: ContractError = vote_with_signature(
+        &mut app,
+        SIGNER,
+        &SignedVotePayload {
+            nonce: 1,
+            ..payload.clone()
+        },
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(
+        err,
+        ContractError::SignedVoteError(SignedVoteError::Expired {
+            expiry: payload.expiry
+        })
+    );
+}
+
+#[test]
+fn test_commit_reveal_voting() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.threshold = Threshold::ThresholdQuorum {
+        threshold: PercentageThreshold::Majority {},
+        quorum: PercentageThreshold::Percent(Decimal::percent(50)),
+    };
+    instantiate.commit_reveal = Some(CommitRevealConfig {
+        reveal_period: Duration::Time(100),
+        count_unrevealed_as_abstain: false,
+    });
+    instantiate.veto = Some(VetoConfig {
+        timelock_duration: Duration::Time(0),
+        vetoer: "oversight".to_string(),
+        early_execute: false,
+        veto_before_passed: true,
+        additional_vetoers: vec![],
+        veto_threshold: None,
+    });
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: "whale".to_string(),
+                amount: Uint128::new(60),
+            },
+            Cw20Coin {
+                address: "minnow".to_string(),
+                amount: Uint128::new(40),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let id = make_proposal(&mut app, &proposal_module, "whale", vec![], None);
+
+    // Regular votes are not allowed.
+    let err = vote_on_proposal_should_fail(&mut app, &proposal_module, "whale", id, Vote::Yes);
+    assert_eq!(err, ContractError::CommitRevealVoting { id });
+
+    let commit = |app: &mut App, voter: &str, proposal_id: u64, commitment: Binary| {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            proposal_module.clone(),
+            &ExecuteMsg::CommitVote {
+                proposal_id,
+                commitment,
+            },
+            &[],
+        )
+    };
+
+    // The minnow copies the whale's commitment, but as commitments
+    // are bound to the voter it can not reveal the whale's vote.
+    let whale_commitment = vote_commitment(&Addr::unchecked("whale"), id, Vote::Yes, "whale");
+    commit(&mut app, "whale", id, whale_commitment.clone()).unwrap();
+    commit(&mut app, "minnow", id, whale_commitment).unwrap();
+
+    // Votes committed to a proposal that is then vetoed may not be
+    // revealed.
+    let vetoed_after_commit = make_proposal(&mut app, &proposal_module, "whale", vec![], None);
+    commit(
+        &mut app,
+        "whale",
+        vetoed_after_commit,
+        vote_commitment(
+            &Addr::unchecked("whale"),
+            vetoed_after_commit,
+            Vote::Yes,
+            "whale",
+        ),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked("oversight"),
+        proposal_module.clone(),
+        &ExecuteMsg::Veto {
+            proposal_id: vetoed_after_commit,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Votes may only be committed on open proposals.
+    let vetoed = make_proposal(&mut app, &proposal_module, "whale", vec![], None);
+    app.execute_contract(
+        Addr::unchecked("oversight"),
+        proposal_module.clone(),
+        &ExecuteMsg::Veto {
+            proposal_id: vetoed,
+        },
+        &[],
+    )
+    .unwrap();
+    let err: ContractError = commit(
+        &mut app,
+        "whale",
+        vetoed,
+        vote_commitment(&Addr::unchecked("whale"), vetoed, Vote::Yes, "whale"),
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(
+        err,
+        ContractError::NotOpen {
+            id: vetoed,
+            status: Status::Vetoed.to_string()
+        }
+    );
+
+    let reveal = |app: &mut App, voter: &str, vote: Vote, salt: &str| {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            proposal_module.clone(),
+            &ExecuteMsg::RevealVote {
+                proposal_id: id,
+                vote,
+                salt: salt.to_string(),
+                rationale: None,
+            },
+            &[],
+        )
+    };
+
+    // Votes may not be revealed while the proposal is open.
+    let err: ContractError = reveal(&mut app, "whale", Vote::Yes, "whale")
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NotRevealPeriod { id });
+
+    // The outcome is unknown until the reveal period ends.
+    app.update_block(|b| b.time = b.time.plus_seconds(604800));
+    let proposal = query_proposal(&app, &proposal_module, id);
+    assert_eq!(proposal.proposal.status, Status::Open);
+    assert_eq!(
+        proposal.proposal.commit_reveal.unwrap().unrevealed_power,
+        Uint128::new(100)
+    );
+
+    let err: ContractError = reveal(&mut app, "whale", Vote::No, "whale")
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::CommitmentMismatch {});
+    reveal(&mut app, "whale", Vote::Yes, "whale").unwrap();
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("whale"),
+            proposal_module.clone(),
+            &ExecuteMsg::RevealVote {
+                proposal_id: vetoed_after_commit,
+                vote: Vote::Yes,
+                salt: "whale".to_string(),
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::NotOpen {
+            id: vetoed_after_commit,
+            status: Status::Vetoed.to_string()
+        }
+    );
+
+    let err: ContractError = reveal(&mut app, "minnow", Vote::Yes, "whale")
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::CommitmentMismatch {});
+
+    // Votes, and the tally, are hidden until the reveal period ends.
+    let votes = query_list_votes(&app, &proposal_module, id, None, None);
+    assert_eq!(votes.votes, vec![]);
+    let proposal = query_proposal(&app, &proposal_module, id);
+    assert_eq!(proposal.proposal.votes, Votes::zero());
+
+    // The minnow never reveals, so its vote does not count.
+    app.update_block(|b| b.time = b.time.plus_seconds(100));
+    let proposal = query_proposal(&app, &proposal_module, id);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+    assert_eq!(proposal.proposal.votes.yes, Uint128::new(60));
+    assert_eq!(proposal.proposal.votes.no, Uint128::zero());
+
+    let votes = query_list_votes(&app, &proposal_module, id, None, None);
+    assert_eq!(
+        votes.votes,
+        vec![VoteInfo {
+            voter: Addr::unchecked("whale"),
+            vote: Vote::Yes,
+            power: Uint128::new(60),
+            rationale: None,
+        }]
+    );
+
+    let err: ContractError = reveal(&mut app, "minnow", Vote::No, "minnow")
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NotRevealPeriod { id });
+}
+
+#[test]
+fn test_dynamic_quorum() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    // A window of one makes the quorum follow the turnout of the last
+    // completed proposal.
+    instantiate.threshold = Threshold::DynamicQuorum {
+        threshold: PercentageThreshold::Majority {},
+        window: 1,
+        min_quorum: Decimal::percent(5),
+        max_quorum: Decimal::percent(50),
+    };
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: "whale".to_string(),
+                amount: Uint128::new(90),
+            },
+            Cw20Coin {
+                address: "minnow".to_string(),
+                amount: Uint128::new(10),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let quorum =
+        |app: &App, id: u64| match query_proposal(app, &proposal_module, id).proposal.threshold {
+            Threshold::ThresholdQuorum { quorum, .. } => quorum,
+            threshold => panic!("unexpected threshold {threshold:?}"),
+        };
+
+    // Without any completed proposals the max quorum is used.
+    let id = make_proposal(&mut app, &proposal_module, "whale", vec![], None);
+    assert_eq!(
+        quorum(&app, id),
+        PercentageThreshold::Percent(Decimal::percent(50))
+    );
+    vote_on_proposal(&mut app, &proposal_module, "whale", id, Vote::Yes);
+    execute_proposal(&mut app, &proposal_module, "whale", id);
+
+    // High turnout is clamped to the max quorum.
+    let id = make_proposal(&mut app, &proposal_module, "minnow", vec![], None);
+    assert_eq!(
+        quorum(&app, id),
+        PercentageThreshold::Percent(Decimal::percent(50))
+    );
+    vote_on_proposal(&mut app, &proposal_module, "minnow", id, Vote::Yes);
+    app.update_block(|b| b.time = b.time.plus_seconds(604800));
+    let proposal = query_proposal(&app, &proposal_module, id);
+    assert_eq!(proposal.proposal.status, Status::Rejected);
+
+    // The low turnout of the rejected proposal lowers the quorum once
+    // its voting has ended, without it being closed, so the same
+    // turnout now passes.
+    let id = make_proposal(&mut app, &proposal_module, "minnow", vec![], None);
+    assert_eq!(
+        quorum(&app, id),
+        PercentageThreshold::Percent(Decimal::percent(10))
+    );
+    vote_on_proposal(&mut app, &proposal_module, "minnow", id, Vote::Yes);
+    app.update_block(|b| b.time = b.time.plus_seconds(604800));
+    let proposal = query_proposal(&app, &proposal_module, id);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+}
+
+#[test]
+fn test_threshold_rules() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    let migrate_threshold = Threshold::AbsolutePercentage {
+        percentage: PercentageThreshold::Percent(Decimal::percent(75)),
+    };
+    let spend_threshold = Threshold::ThresholdQuorum {
+        threshold: PercentageThreshold::Percent(Decimal::percent(67)),
+        quorum: PercentageThreshold::Percent(Decimal::percent(50)),
+    };
+    instantiate.threshold_rules = vec![
+        ThresholdRule {
+            action: SensitiveAction::TreasurySpend {
+                denom: "ujuno".to_string(),
+                amount: Uint128::new(100),
+            },
+            threshold: spend_threshold.clone(),
+            min_voting_period: None,
+        },
+        ThresholdRule {
+            action: SensitiveAction::Migrate {},
+            threshold: migrate_threshold.clone(),
+            min_voting_period: Some(Duration::Time(3600)),
+        },
+        ThresholdRule {
+            action: SensitiveAction::UpdateAdmin {},
+            threshold: Threshold::AbsolutePercentage {
+                percentage: PercentageThreshold::Percent(Decimal::percent(1)),
+            },
+            min_voting_period: Some(Duration::Time(60)),
+        },
+    ];
+    let default_threshold = instantiate.threshold.clone();
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let gov_token = query_dao_token(&app, &core_addr);
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 30_000_000);
+
+    let send = |amount: u128| -> CosmosMsg {
+        BankMsg::Send {
+            to_address: "ekez".to_string(),
+            amount: coins(amount, "ujuno"),
+        }
+        .into()
+    };
+
+    // Small spends use the module's threshold.
+    let id = make_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        vec![send(50)],
+        None,
+    );
+    let proposal = query_proposal(&app, &proposal_module, id).proposal;
+    assert_eq!(proposal.threshold, default_threshold);
+    assert_eq!(proposal.threshold_rule, None);
+
+    // Spends are summed across messages.
+    let id = make_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        vec![send(60), send(60)],
+        None,
+    );
+    let proposal = query_proposal(&app, &proposal_module, id).proposal;
+    assert_eq!(proposal.threshold, spend_threshold);
+    assert_eq!(
+        proposal.threshold_rule.map(|rule| rule.action),
+        Some(SensitiveAction::TreasurySpend {
+            denom: "ujuno".to_string(),
+            amount: Uint128::new(100),
+        })
+    );
+    assert_eq!(proposal.min_voting_period, None);
+
+    // Funds attached to wasm messages and IBC transfers are counted
+    // too.
+    let id = make_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        vec![
+            WasmMsg::Execute {
+                contract_addr: core_addr.to_string(),
+                msg: to_json_binary(&Empty {}).unwrap(),
+                funds: coins(60, "ujuno"),
+            }
+            .into(),
+            IbcMsg::Transfer {
+                channel_id: "channel-0".to_string(),
+                to_address: "ekez".to_string(),
+                amount: coin(60, "ujuno"),
+                timeout: IbcTimeout::with_timestamp(app.block_info().time),
+            }
+            .into(),
+        ],
+        None,
+    );
+    let proposal = query_proposal(&app, &proposal_module, id).proposal;
+    assert_eq!(proposal.threshold, spend_threshold);
+
+    // Unclassifiable messages use the strictest treasury spend rule,
+    // as what they spend is unknown.
+    let id = make_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        vec![CosmosMsg::Stargate {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: Binary::default(),
+        }],
+        None,
+    );
+    let proposal = query_proposal(&app, &proposal_module, id).proposal;
+    assert_eq!(proposal.threshold, spend_threshold);
+
+    // Rules looser than the module's threshold do not loosen it, but
+    // their longer minimum voting periods still apply.
+    let id = make_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        vec![WasmMsg::ClearAdmin {
+            contract_addr: core_addr.to_string(),
+        }
+        .into()],
+        None,
+    );
+    let proposal = query_proposal(&app, &proposal_module, id).proposal;
+    assert_eq!(proposal.threshold, default_threshold);
+    assert_eq!(
+        proposal.threshold_rule.map(|rule| rule.action),
+        Some(SensitiveAction::UpdateAdmin {})
+    );
+    assert_eq!(
+        proposal.min_voting_period,
+        Some(Expiration::AtTime(app.block_info().time.plus_seconds(60)))
+    );
+
+    // The strictest matching rule applies, regardless of order.
+    let id = make_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        vec![
+            send(1000),
+            WasmMsg::Migrate {
+                contract_addr: core_addr.to_string(),
+                new_code_id: 1,
+                msg: to_json_binary(&Empty {}).unwrap(),
+            }
+            .into(),
+        ],
+        None,
+    );
+    let proposal = query_proposal(&app, &proposal_module, id).proposal;
+    assert_eq!(proposal.threshold, migrate_threshold);
+    assert_eq!(
+        proposal.min_voting_period,
+        Some(Expiration::AtTime(app.block_info().time.plus_seconds(3600)))
+    );
+
+    // Rules are validated when the config is updated.
+    let config = query_proposal_config(&app, &proposal_module);
+    let err: ContractError = app
+        .execute_contract(
+            core_addr,
+            proposal_module,
+            &ExecuteMsg::UpdateConfig {
+                veto: None,
+                threshold: config.threshold,
+                max_voting_period: config.max_voting_period,
+                min_voting_period: config.min_voting_period,
+                only_members_execute: config.only_members_execute,
+                allow_revoting: config.allow_revoting,
+                dao: config.dao.into_string(),
+                close_proposal_on_execution_failure: false,
+                continue_on_execution_failure: false,
+                threshold_rules: vec![ThresholdRule {
+                    action: SensitiveAction::UpdateAdmin {},
+                    threshold: migrate_threshold,
+                    min_voting_period: Some(Duration::Height(10)),
+                }],
+                commit_reveal: None,
+                voting_period_range: None,
+                no_with_veto_threshold: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
     assert_eq!(
-        deposit_response.deposit_info,
-        Some(CheckedDepositInfo {
-            denom: cw_denom::CheckedDenom::Cw20(alt_cw20),
-            amount: Uint128::new(10_000_000),
-            refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed
-        })
+        err,
+        ContractError::VotingError(dao_voting::error::VotingError::DurationUnitsConflict {})
     );
 }
 
 #[test]
-fn test_proposal_message_execution() {
+fn test_treasury_spend_cw20() {
+    let action = SensitiveAction::TreasurySpend {
+        denom: "token".to_string(),
+        amount: Uint128::new(100),
+    };
+    let dao = Addr::unchecked("dao");
+    let cw20 = |contract_addr: &str, msg: &cw20::Cw20ExecuteMsg| -> CosmosMsg {
+        WasmMsg::Execute {
+            contract_addr: contract_addr.to_string(),
+            msg: to_json_binary(msg).unwrap(),
+            funds: vec![],
+        }
+        .into()
+    };
+    let transfer = |amount: u128| cw20::Cw20ExecuteMsg::Transfer {
+        recipient: "ekez".to_string(),
+        amount: Uint128::new(amount),
+    };
+    let send = |amount: u128| cw20::Cw20ExecuteMsg::Send {
+        contract: "ekez".to_string(),
+        amount: Uint128::new(amount),
+        msg: Binary::default(),
+    };
+
+    assert!(!action.matches(&dao, &[cw20("token", &transfer(100))]));
+    assert!(action.matches(&dao, &[cw20("token", &transfer(101))]));
+    assert!(action.matches(
+        &dao,
+        &[cw20("token", &transfer(60)), cw20("token", &send(60))]
+    ));
+    // Transfers of other tokens are not counted.
+    assert!(!action.matches(&dao, &[cw20("other", &transfer(1000))]));
+}
+
+#[test]
+fn test_proposal_dependencies() {
     let mut app = App::default();
     let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
-    instantiate.close_proposal_on_execution_failure = false;
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
     let proposal_module = query_single_proposal_module(&app, &core_addr);
-    let gov_token = query_dao_token(&app, &core_addr);
 
-    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
-    let proposal_id = make_proposal(
-        &mut app,
-        &proposal_module,
-        CREATOR_ADDR,
-        vec![
-            WasmMsg::Execute {
-                contract_addr: gov_token.to_string(),
-                msg: to_json_binary(&cw20::Cw20ExecuteMsg::Mint {
-                    recipient: CREATOR_ADDR.to_string(),
-                    amount: Uint128::new(10_000_000),
-                })
-                .unwrap(),
-                funds: vec![],
-            }
-            .into(),
-            BankMsg::Send {
-                to_address: CREATOR_ADDR.to_string(),
-                amount: coins(10, "ujuno"),
-            }
-            .into(),
-        ],
-        None,
-    );
-    let cw20_balance = query_balance_cw20(&app, &gov_token, CREATOR_ADDR);
-    let native_balance = query_balance_native(&app, CREATOR_ADDR, "ujuno");
-    assert_eq!(cw20_balance, Uint128::zero());
-    assert_eq!(native_balance, Uint128::zero());
+    let propose = |app: &mut App, depends_on: Vec<(String, u64)>| {
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                proposer: None,
+                vote: None,
+                execute_after: None,
+                depends_on,
+                voting_period: None,
+            }),
+            &[],
+        )
+    };
 
-    vote_on_proposal(
-        &mut app,
-        &proposal_module,
-        CREATOR_ADDR,
-        proposal_id,
-        Vote::Yes,
+    // Dependencies must exist.
+    propose(&mut app, vec![(proposal_module.to_string(), 1)]).unwrap_err();
+
+    propose(&mut app, vec![]).unwrap();
+    propose(&mut app, vec![(proposal_module.to_string(), 1)]).unwrap();
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, 1, Vote::Yes);
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, 2, Vote::Yes);
+
+    let proposal = query_proposal(&app, &proposal_module, 2);
+    assert_eq!(
+        proposal.proposal.depends_on,
+        vec![(proposal_module.clone(), 1)]
     );
-    let proposal = query_proposal(&app, &proposal_module, proposal_id);
-    assert_eq!(proposal.proposal.status, Status::Passed);
 
-    // Can't use library function because we expect this to fail due
-    // to insufficent balance in the bank module.
-    app.execute_contract(
-        Addr::unchecked(CREATOR_ADDR),
-        proposal_module.clone(),
-        &ExecuteMsg::Execute { proposal_id },
-        &[],
-    )
-    .unwrap_err();
-    let proposal = query_proposal(&app, &proposal_module, proposal_id);
-    assert_eq!(proposal.proposal.status, Status::Passed);
+    // Proposal 2 may not be executed before proposal 1.
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, 2);
+    assert_eq!(
+        err,
+        ContractError::DependencyNotExecuted {
+            module: proposal_module.to_string(),
+            proposal_id: 1
+        }
+    );
 
-    mint_natives(&mut app, core_addr.as_str(), coins(10, "ujuno"));
-    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
-    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, 1);
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, 2);
+    let proposal = query_proposal(&app, &proposal_module, 2);
     assert_eq!(proposal.proposal.status, Status::Executed);
 
-    let cw20_balance = query_balance_cw20(&app, &gov_token, CREATOR_ADDR);
-    let native_balance = query_balance_native(&app, CREATOR_ADDR, "ujuno");
-    assert_eq!(cw20_balance, Uint128::new(20_000_000));
-    assert_eq!(native_balance, Uint128::new(10));
+    // If a dependency is rejected, executing closes the proposal.
+    propose(&mut app, vec![]).unwrap();
+    propose(&mut app, vec![(proposal_module.to_string(), 3)]).unwrap();
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, 3, Vote::No);
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, 4, Vote::Yes);
 
-    // Sneak in a check here that proposals can't be executed more
-    // than once in the on close on execute config suituation.
-    let err = execute_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
-    assert!(matches!(err, ContractError::NotPassed {}))
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, 4);
+    let proposal = query_proposal(&app, &proposal_module, 4);
+    assert_eq!(proposal.proposal.status, Status::Closed);
+
+    // Open proposals whose dependencies failed may be closed.
+    propose(&mut app, vec![(proposal_module.to_string(), 3)]).unwrap();
+    let err = close_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, 1);
+    assert_eq!(err, ContractError::WrongCloseStatus {});
+    close_proposal(&mut app, &proposal_module, CREATOR_ADDR, 5);
+    let proposal = query_proposal(&app, &proposal_module, 5);
+    assert_eq!(proposal.proposal.status, Status::Closed);
 }
 
 #[test]
@@ -489,6 +1825,8 @@ fn test_proposal_message_timelock_execution() -> anyhow::Result<()> {
         vetoer: "oversight".to_string(),
         early_execute: false,
         veto_before_passed: false,
+        additional_vetoers: vec![],
+        veto_threshold: None,
     };
     instantiate.close_proposal_on_execution_failure = false;
     instantiate.veto = Some(veto_config.clone());
@@ -612,6 +1950,8 @@ fn test_open_proposal_veto_unauthorized() {
         vetoer: "oversight".to_string(),
         early_execute: false,
         veto_before_passed: true,
+        additional_vetoers: vec![],
+        veto_threshold: None,
     };
     instantiate.veto = Some(veto_config.clone());
     let core_addr = instantiate_with_staked_balances_governance(
@@ -675,6 +2015,8 @@ fn test_open_proposal_veto_with_early_veto_flag_disabled() {
         vetoer: "oversight".to_string(),
         early_execute: false,
         veto_before_passed: false,
+        additional_vetoers: vec![],
+        veto_threshold: None,
     };
     instantiate.veto = Some(veto_config.clone());
     let core_addr = instantiate_with_staked_balances_governance(
@@ -799,6 +2141,8 @@ fn test_vetoed_proposal_veto() {
         vetoer: "oversight".to_string(),
         early_execute: false,
         veto_before_passed: true,
+        additional_vetoers: vec![],
+        veto_threshold: None,
     };
     instantiate.veto = Some(veto_config.clone());
     let core_addr = instantiate_with_staked_balances_governance(
@@ -877,6 +2221,8 @@ fn test_open_proposal_veto_early() {
         vetoer: "oversight".to_string(),
         early_execute: false,
         veto_before_passed: true,
+        additional_vetoers: vec![],
+        veto_threshold: None,
     };
     instantiate.veto = Some(veto_config.clone());
     let core_addr = instantiate_with_staked_balances_governance(
@@ -938,6 +2284,8 @@ fn test_timelocked_proposal_veto_unauthorized() -> anyhow::Result<()> {
         vetoer: "oversight".to_string(),
         early_execute: true,
         veto_before_passed: false,
+        additional_vetoers: vec![],
+        veto_threshold: None,
     };
     instantiate.veto = Some(veto_config.clone());
     let core_addr = instantiate_with_staked_balances_governance(
@@ -1039,6 +2387,8 @@ fn test_timelocked_proposal_veto_expired_timelock() -> anyhow::Result<()> {
         vetoer: "oversight".to_string(),
         early_execute: true,
         veto_before_passed: false,
+        additional_vetoers: vec![],
+        veto_threshold: None,
     };
     instantiate.veto = Some(veto_config.clone());
     let core_addr = instantiate_with_staked_balances_governance(
@@ -1131,6 +2481,8 @@ fn test_timelocked_proposal_execute_no_early_exec() -> anyhow::Result<()> {
         vetoer: "oversight".to_string(),
         early_execute: false,
         veto_before_passed: false,
+        additional_vetoers: vec![],
+        veto_threshold: None,
     };
     instantiate.veto = Some(veto_config.clone());
     let core_addr = instantiate_with_staked_balances_governance(
@@ -1215,6 +2567,8 @@ fn test_timelocked_proposal_execute_early() -> anyhow::Result<()> {
         vetoer: "oversight".to_string(),
         early_execute: true,
         veto_before_passed: false,
+        additional_vetoers: vec![],
+        veto_threshold: None,
     };
     instantiate.veto = Some(veto_config.clone());
     let core_addr = instantiate_with_staked_balances_governance(
@@ -1305,6 +2659,8 @@ fn test_timelocked_proposal_execute_active_timelock_unauthorized() -> anyhow::Re
         vetoer: "oversight".to_string(),
         early_execute: true,
         veto_before_passed: false,
+        additional_vetoers: vec![],
+        veto_threshold: None,
     };
     instantiate.veto = Some(veto_config.clone());
     let core_addr = instantiate_with_staked_balances_governance(
@@ -1396,6 +2752,8 @@ fn test_timelocked_proposal_execute_expired_timelock_not_vetoer() -> anyhow::Res
         vetoer: "oversight".to_string(),
         early_execute: true,
         veto_before_passed: false,
+        additional_vetoers: vec![],
+        veto_threshold: None,
     };
     instantiate.veto = Some(veto_config.clone());
     let core_addr = instantiate_with_staked_balances_governance(
@@ -1482,6 +2840,8 @@ fn test_proposal_message_timelock_veto() -> anyhow::Result<()> {
         vetoer: "oversight".to_string(),
         early_execute: false,
         veto_before_passed: false,
+        additional_vetoers: vec![],
+        veto_threshold: None,
     };
     instantiate.veto = Some(veto_config.clone());
     let core_addr = instantiate_with_staked_balances_governance(
@@ -1519,16 +2879,190 @@ fn test_proposal_message_timelock_veto() -> anyhow::Result<()> {
         ],
         None,
     );
-    let cw20_balance = query_balance_cw20(&app, &gov_token, CREATOR_ADDR);
-    let native_balance = query_balance_native(&app, CREATOR_ADDR, "ujuno");
-    assert_eq!(cw20_balance, Uint128::zero());
-    assert_eq!(native_balance, Uint128::zero());
+    let cw20_balance = query_balance_cw20(&app, &gov_token, CREATOR_ADDR);
+    let native_balance = query_balance_native(&app, CREATOR_ADDR, "ujuno");
+    assert_eq!(cw20_balance, Uint128::zero());
+    assert_eq!(native_balance, Uint128::zero());
+
+    // Vetoer can't veto early
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("oversight"),
+            proposal_module.clone(),
+            &ExecuteMsg::Veto { proposal_id },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::VetoError(VetoError::NoVetoBeforePassed {})
+    );
+
+    // Vote on proposal to pass it
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+
+    // Proposal is timelocked to the moment of prop expiring + timelock delay
+    assert_eq!(
+        proposal.proposal.status,
+        Status::VetoTimelock {
+            expiration: proposal
+                .proposal
+                .expiration
+                .add(veto_config.timelock_duration)?,
+        }
+    );
+
+    mint_natives(&mut app, core_addr.as_str(), coins(10, "ujuno"));
+
+    // Non-vetoer cannot veto
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Veto { proposal_id },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::VetoError(VetoError::Unauthorized {}));
+
+    // Oversite vetos prop
+    app.execute_contract(
+        Addr::unchecked("oversight"),
+        proposal_module.clone(),
+        &ExecuteMsg::Veto { proposal_id },
+        &[],
+    )
+    .unwrap();
+
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Vetoed);
+
+    Ok(())
+}
+
+#[test]
+fn test_veto_council() -> anyhow::Result<()> {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    let veto_config = VetoConfig {
+        timelock_duration: Duration::Time(100),
+        vetoer: "oversight".to_string(),
+        early_execute: true,
+        veto_before_passed: false,
+        additional_vetoers: vec!["council1".to_string(), "council2".to_string()],
+        veto_threshold: Some(2),
+    };
+    instantiate.veto = Some(veto_config.clone());
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![Cw20Coin {
+            address: CREATOR_ADDR.to_string(),
+            amount: Uint128::new(85),
+        }]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let gov_token = query_dao_token(&app, &core_addr);
+
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![], None);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    let timelocked = Status::VetoTimelock {
+        expiration: proposal
+            .proposal
+            .expiration
+            .add(veto_config.timelock_duration)?,
+    };
+    assert_eq!(proposal.proposal.status, timelocked);
+
+    let tally = query_veto_tally(&app, &proposal_module, proposal_id);
+    assert_eq!(
+        tally,
+        VetoTallyResponse {
+            vetoers: vec![],
+            threshold: Some(2),
+        }
+    );
+
+    // A single council member's veto is not enough to veto the
+    // proposal.
+    app.execute_contract(
+        Addr::unchecked("council1"),
+        proposal_module.clone(),
+        &ExecuteMsg::Veto { proposal_id },
+        &[],
+    )
+    .unwrap();
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, timelocked);
+    let tally = query_veto_tally(&app, &proposal_module, proposal_id);
+    assert_eq!(tally.vetoers, vec![Addr::unchecked("council1")]);
+
+    // Vetoers may not veto twice.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("council1"),
+            proposal_module.clone(),
+            &ExecuteMsg::Veto { proposal_id },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::VetoError(VetoError::AlreadyVetoed {}));
+
+    // Members that are not vetoers may not execute early.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Execute { proposal_id },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::VetoError(VetoError::Timelocked {}));
+
+    // A second veto reaches the threshold.
+    app.execute_contract(
+        Addr::unchecked("oversight"),
+        proposal_module.clone(),
+        &ExecuteMsg::Veto { proposal_id },
+        &[],
+    )
+    .unwrap();
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Vetoed);
+    let tally = query_veto_tally(&app, &proposal_module, proposal_id);
+    assert_eq!(
+        tally.vetoers,
+        vec![Addr::unchecked("council1"), Addr::unchecked("oversight")]
+    );
 
-    // Vetoer can't veto early
+    // The last council member may no longer veto.
     let err: ContractError = app
         .execute_contract(
-            Addr::unchecked("oversight"),
-            proposal_module.clone(),
+            Addr::unchecked("council2"),
+            proposal_module,
             &ExecuteMsg::Veto { proposal_id },
             &[],
         )
@@ -1537,10 +3071,38 @@ fn test_proposal_message_timelock_veto() -> anyhow::Result<()> {
         .unwrap();
     assert_eq!(
         err,
-        ContractError::VetoError(VetoError::NoVetoBeforePassed {})
+        ContractError::VetoError(VetoError::InvalidProposalStatus {
+            status: Status::Vetoed.to_string(),
+        })
     );
 
-    // Vote on proposal to pass it
+    Ok(())
+}
+
+#[test]
+fn test_veto_council_early_execute() -> anyhow::Result<()> {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    let veto_config = VetoConfig {
+        timelock_duration: Duration::Time(100),
+        vetoer: "oversight".to_string(),
+        early_execute: true,
+        veto_before_passed: false,
+        additional_vetoers: vec!["council1".to_string(), "council2".to_string()],
+        veto_threshold: Some(2),
+    };
+    instantiate.veto = Some(veto_config.clone());
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![Cw20Coin {
+            address: CREATOR_ADDR.to_string(),
+            amount: Uint128::new(85),
+        }]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![], None);
     vote_on_proposal(
         &mut app,
         &proposal_module,
@@ -1549,44 +3111,54 @@ fn test_proposal_message_timelock_veto() -> anyhow::Result<()> {
         Vote::Yes,
     );
     let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    let timelocked = Status::VetoTimelock {
+        expiration: proposal
+            .proposal
+            .expiration
+            .add(veto_config.timelock_duration)?,
+    };
+    assert_eq!(proposal.proposal.status, timelocked);
 
-    // Proposal is timelocked to the moment of prop expiring + timelock delay
-    assert_eq!(
-        proposal.proposal.status,
-        Status::VetoTimelock {
-            expiration: proposal
-                .proposal
-                .expiration
-                .add(veto_config.timelock_duration)?,
-        }
-    );
-
-    mint_natives(&mut app, core_addr.as_str(), coins(10, "ujuno"));
+    // A single council member may not execute the proposal early.
+    app.execute_contract(
+        Addr::unchecked("council1"),
+        proposal_module.clone(),
+        &ExecuteMsg::Execute { proposal_id },
+        &[],
+    )
+    .unwrap();
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, timelocked);
 
-    // Non-vetoer cannot veto
     let err: ContractError = app
         .execute_contract(
-            Addr::unchecked(CREATOR_ADDR),
+            Addr::unchecked("council1"),
             proposal_module.clone(),
-            &ExecuteMsg::Veto { proposal_id },
+            &ExecuteMsg::Execute { proposal_id },
             &[],
         )
         .unwrap_err()
         .downcast()
         .unwrap();
-    assert_eq!(err, ContractError::VetoError(VetoError::Unauthorized {}));
+    assert_eq!(
+        err,
+        ContractError::VetoError(VetoError::AlreadyApprovedEarlyExecute {})
+    );
 
-    // Oversite vetos prop
+    // Approvals to execute early are not vetoes.
+    let tally = query_veto_tally(&app, &proposal_module, proposal_id);
+    assert!(tally.vetoers.is_empty());
+
+    // A second approval reaches the threshold.
     app.execute_contract(
-        Addr::unchecked("oversight"),
+        Addr::unchecked("council2"),
         proposal_module.clone(),
-        &ExecuteMsg::Veto { proposal_id },
+        &ExecuteMsg::Execute { proposal_id },
         &[],
     )
     .unwrap();
-
     let proposal = query_proposal(&app, &proposal_module, proposal_id);
-    assert_eq!(proposal.proposal.status, Status::Vetoed);
+    assert_eq!(proposal.proposal.status, Status::Executed);
 
     Ok(())
 }
@@ -1601,6 +3173,8 @@ fn test_proposal_message_timelock_early_execution() -> anyhow::Result<()> {
         vetoer: "oversight".to_string(),
         early_execute: true,
         veto_before_passed: false,
+        additional_vetoers: vec![],
+        veto_threshold: None,
     };
     instantiate.veto = Some(veto_config.clone());
     let core_addr = instantiate_with_staked_balances_governance(
@@ -1689,6 +3263,8 @@ fn test_proposal_message_timelock_veto_before_passed() {
         vetoer: "oversight".to_string(),
         early_execute: false,
         veto_before_passed: true,
+        additional_vetoers: vec![],
+        veto_threshold: None,
     });
     let core_addr = instantiate_with_staked_balances_governance(
         &mut app,
@@ -1767,6 +3343,8 @@ fn test_veto_only_members_execute_proposal() -> anyhow::Result<()> {
         vetoer: "oversight".to_string(),
         early_execute: true,
         veto_before_passed: false,
+        additional_vetoers: vec![],
+        veto_threshold: None,
     };
     instantiate.veto = Some(veto_config.clone());
     let core_addr = instantiate_with_staked_balances_governance(
@@ -2063,6 +3641,8 @@ fn test_update_config() {
                     vetoer: CREATOR_ADDR.to_string(),
                     early_execute: false,
                     veto_before_passed: false,
+                    additional_vetoers: vec![],
+                    veto_threshold: None,
                 }),
                 threshold: Threshold::AbsoluteCount {
                     threshold: Uint128::new(10_000),
@@ -2073,6 +3653,11 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                continue_on_execution_failure: false,
+                threshold_rules: vec![],
+                commit_reveal: None,
+                voting_period_range: None,
+                no_with_veto_threshold: None,
             })
             .unwrap(),
             funds: vec![],
@@ -2098,6 +3683,8 @@ fn test_update_config() {
                 vetoer: CREATOR_ADDR.to_string(),
                 early_execute: false,
                 veto_before_passed: false,
+                additional_vetoers: vec![],
+                veto_threshold: None,
             }),
             threshold: Threshold::AbsoluteCount {
                 threshold: Uint128::new(10_000)
@@ -2108,6 +3695,11 @@ fn test_update_config() {
             allow_revoting: false,
             dao: core_addr.clone(),
             close_proposal_on_execution_failure: false,
+            continue_on_execution_failure: false,
+            threshold_rules: vec![],
+            commit_reveal: None,
+            voting_period_range: None,
+            no_with_veto_threshold: None,
         }
     );
 
@@ -2127,6 +3719,11 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                continue_on_execution_failure: false,
+                threshold_rules: vec![],
+                commit_reveal: None,
+                voting_period_range: None,
+                no_with_veto_threshold: None,
             },
             &[],
         )
@@ -2146,6 +3743,8 @@ fn test_update_config() {
                     vetoer: CREATOR_ADDR.to_string(),
                     early_execute: false,
                     veto_before_passed: false,
+                    additional_vetoers: vec![],
+                    veto_threshold: None,
                 }),
                 threshold: Threshold::AbsoluteCount {
                     threshold: Uint128::new(10_000),
@@ -2156,6 +3755,11 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                continue_on_execution_failure: false,
+                threshold_rules: vec![],
+                commit_reveal: None,
+                voting_period_range: None,
+                no_with_veto_threshold: None,
             },
             &[],
         )
@@ -2242,9 +3846,16 @@ fn test_anyone_may_propose_and_proposal_listing() {
                 votes: Votes {
                     yes: Uint128::new(100_000_000),
                     no: Uint128::zero(),
-                    abstain: Uint128::zero()
+                    abstain: Uint128::zero(),
+                    no_with_veto: Uint128::zero(),
                 },
-                veto: None
+                veto: None,
+                execute_after: None,
+                depends_on: vec![],
+                message_results: vec![],
+                threshold_rule: None,
+                commit_reveal: None,
+                no_with_veto_threshold: None,
             }
         }
     )
@@ -2428,6 +4039,9 @@ fn test_active_threshold_absolute() {
                 msgs: vec![],
                 proposer: None,
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
             }),
             &[],
         )
@@ -2467,6 +4081,9 @@ fn test_active_threshold_absolute() {
                 msgs: vec![],
                 proposer: None,
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
             }),
             &[],
         )
@@ -2511,6 +4128,9 @@ fn test_active_threshold_percent() {
                 msgs: vec![],
                 proposer: None,
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
             }),
             &[],
         )
@@ -2551,6 +4171,9 @@ fn test_active_threshold_percent() {
                 msgs: vec![],
                 proposer: None,
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
             }),
             &[],
         )
@@ -2749,6 +4372,11 @@ fn test_allow_revoting_config_changes() {
             allow_revoting: false,
             dao: core_addr.to_string(),
             close_proposal_on_execution_failure: false,
+            continue_on_execution_failure: false,
+            threshold_rules: vec![],
+            commit_reveal: None,
+            voting_period_range: None,
+            no_with_veto_threshold: None,
         },
         &[],
     )
@@ -3053,6 +4681,11 @@ fn test_proposal_count_initialized_to_zero() {
             allow_revoting: false,
             pre_propose_info,
             close_proposal_on_execution_failure: true,
+            continue_on_execution_failure: false,
+            threshold_rules: vec![],
+            commit_reveal: None,
+            voting_period_range: None,
+            no_with_veto_threshold: None,
         },
         Some(vec![
             Cw20Coin {
@@ -3107,6 +4740,121 @@ fn test_migrate_from_compatible() {
 
     let end_config = query_proposal_config(&app, &proposal_module);
     assert_eq!(start_config, end_config);
+
+    // The module's proposals and ballots are already indexed, so
+    // there is nothing to index.
+    for msg in [
+        ExecuteMsg::IndexProposals { limit: None },
+        ExecuteMsg::IndexBallots { limit: None },
+    ] {
+        let err: ContractError = app
+            .execute_contract(
+                Addr::unchecked("anyone"),
+                proposal_module.clone(),
+                &msg,
+                &[],
+            )
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+        assert!(matches!(err, ContractError::NothingToIndex {}));
+    }
+}
+
+#[test]
+fn test_index_after_migration() {
+    use crate::contract::execute;
+    use crate::state::{Ballot, BALLOTS};
+    use cw_storage_plus::Map;
+
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("anyone", &[]);
+    cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "2.4.0").unwrap();
+
+    // Proposals and ballots saved by a version of the module without
+    // their indexes.
+    let proposal = SingleChoiceProposal {
+        title: "A simple text proposal".to_string(),
+        description: "This is a simple text proposal".to_string(),
+        proposer: Addr::unchecked(CREATOR_ADDR),
+        start_height: env.block.height,
+        expiration: Duration::Height(6).after(&env.block),
+        min_voting_period: None,
+        threshold: Threshold::AbsolutePercentage {
+            percentage: PercentageThreshold::Majority {},
+        },
+        allow_revoting: false,
+        total_power: Uint128::new(100_000_000),
+        msgs: vec![],
+        status: Status::Open,
+        veto: None,
+        execute_after: None,
+        depends_on: vec![],
+        message_results: vec![],
+        votes: Votes::zero(),
+        threshold_rule: None,
+        commit_reveal: None,
+        no_with_veto_threshold: None,
+    };
+    let unindexed: Map<u64, SingleChoiceProposal> = Map::new("proposals_v2");
+    for id in 1..=2 {
+        unindexed
+            .save(deps.as_mut().storage, id, &proposal)
+            .unwrap();
+    }
+    BALLOTS
+        .save(
+            deps.as_mut().storage,
+            (1, &Addr::unchecked(CREATOR_ADDR)),
+            &Ballot {
+                power: Uint128::new(1),
+                vote: Vote::Yes,
+                rationale: None,
+            },
+        )
+        .unwrap();
+
+    let index = |deps: &mut cosmwasm_std::OwnedDeps<_, _, _>, msg: ExecuteMsg| {
+        execute(deps.as_mut(), mock_env(), info.clone(), msg).map(|res| {
+            let attr = |key: &str| {
+                res.attributes
+                    .iter()
+                    .find(|attr| attr.key == key)
+                    .unwrap()
+                    .value
+                    .clone()
+            };
+            (attr("indexed"), attr("complete"))
+        })
+    };
+
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
+    assert_eq!(
+        index(&mut deps, ExecuteMsg::IndexProposals { limit: Some(1) }).unwrap(),
+        ("1".to_string(), "false".to_string())
+    );
+
+    // Migrating again continues indexing where it left off.
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
+    assert_eq!(
+        index(&mut deps, ExecuteMsg::IndexProposals { limit: Some(2) }).unwrap(),
+        ("1".to_string(), "true".to_string())
+    );
+    assert_eq!(
+        index(&mut deps, ExecuteMsg::IndexBallots { limit: None }).unwrap(),
+        ("1".to_string(), "true".to_string())
+    );
+
+    // Once indexing is complete, migrating does not restart it.
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
+    for msg in [
+        ExecuteMsg::IndexProposals { limit: None },
+        ExecuteMsg::IndexBallots { limit: None },
+    ] {
+        let err = index(&mut deps, msg).unwrap_err();
+        assert!(matches!(err, ContractError::NothingToIndex {}));
+    }
 }
 
 #[test]
@@ -3462,6 +5210,11 @@ fn test_execution_failed() {
             dao: config.dao.into_string(),
             // Disable.
             close_proposal_on_execution_failure: false,
+            continue_on_execution_failure: false,
+            threshold_rules: vec![],
+            commit_reveal: None,
+            voting_period_range: None,
+            no_with_veto_threshold: None,
         },
         &[],
     )
@@ -3498,16 +5251,184 @@ fn test_execution_failed() {
     assert_eq!(balance, Uint128::zero());
 }
 
+// Test that when continue on execution failure is enabled each
+// message is executed on its own, failures are recorded without
+// reverting the successful messages, and failed messages may be
+// retried.
+#[test]
+fn test_continue_on_execution_failure() {
+    let CommonTest {
+        mut app,
+        core_addr,
+        proposal_module,
+        gov_token,
+        proposal_id,
+    } = setup_test(vec![
+        BankMsg::Send {
+            to_address: "ekez".to_string(),
+            amount: coins(10, "ujuno"),
+        }
+        .into(),
+        BankMsg::Send {
+            to_address: "ekez".to_string(),
+            amount: coins(10, "uatom"),
+        }
+        .into(),
+    ]);
+
+    let config = query_proposal_config(&app, &proposal_module);
+    app.execute_contract(
+        core_addr.clone(),
+        proposal_module.clone(),
+        &ExecuteMsg::UpdateConfig {
+            veto: None,
+            threshold: config.threshold,
+            max_voting_period: config.max_voting_period,
+            min_voting_period: config.min_voting_period,
+            only_members_execute: config.only_members_execute,
+            allow_revoting: config.allow_revoting,
+            dao: config.dao.into_string(),
+            close_proposal_on_execution_failure: true,
+            continue_on_execution_failure: true,
+            threshold_rules: vec![],
+            commit_reveal: None,
+            voting_period_range: None,
+            no_with_veto_threshold: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Only the first message can be paid for.
+    mint_natives(&mut app, core_addr.as_str(), coins(10, "ujuno"));
+
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    let completed_hooks = |res: &cw_multi_test::AppResponse| {
+        res.events
+            .iter()
+            .flat_map(|e| e.attributes.iter())
+            .filter(|a| a.key == "method" && a.value == "execute_proposal_completed_hook")
+            .count()
+    };
+    let res = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Execute { proposal_id },
+            &[],
+        )
+        .unwrap();
+    // The pre-propose module is told once every message has replied.
+    assert_eq!(completed_hooks(&res), 1);
+
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::PartiallyExecuted);
+    assert_eq!(
+        proposal.proposal.message_results[0],
+        MessageExecutionResult::Succeeded
+    );
+    assert!(matches!(
+        proposal.proposal.message_results[1],
+        MessageExecutionResult::Failed { .. }
+    ));
+    assert_eq!(
+        query_balance_native(&app, "ekez", "ujuno"),
+        Uint128::new(10)
+    );
+
+    // The deposit is returned as the proposal was executed.
+    let balance = query_balance_cw20(&app, &gov_token, CREATOR_ADDR);
+    assert_eq!(balance, Uint128::new(10_000_000));
+
+    // Messages that did not fail may not be retried.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::RetryFailedMessages {
+                proposal_id,
+                msg_indices: Some(vec![0]),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::MessageNotFailed {
+            id: proposal_id,
+            index: 0
+        }
+    );
+
+    // Fund the second message and retry it.
+    mint_natives(&mut app, core_addr.as_str(), coins(10, "uatom"));
+    let res = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::RetryFailedMessages {
+                proposal_id,
+                msg_indices: None,
+            },
+            &[],
+        )
+        .unwrap();
+    // It was told when the proposal was first executed.
+    assert_eq!(completed_hooks(&res), 0);
+
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Executed);
+    assert_eq!(
+        proposal.proposal.message_results,
+        vec![
+            MessageExecutionResult::Succeeded,
+            MessageExecutionResult::Succeeded
+        ]
+    );
+    assert_eq!(
+        query_balance_native(&app, "ekez", "ujuno"),
+        Uint128::new(10)
+    );
+    assert_eq!(
+        query_balance_native(&app, "ekez", "uatom"),
+        Uint128::new(10)
+    );
+
+    // Nothing is left to retry.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module,
+            &ExecuteMsg::RetryFailedMessages {
+                proposal_id,
+                msg_indices: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NotPartiallyExecuted {});
+}
+
 #[test]
 fn test_reply_proposal_mock() {
     use crate::contract::reply;
-    use crate::state::PROPOSALS;
+    use crate::state::proposals;
 
     let mut deps = mock_dependencies();
     let env = mock_env();
 
     let m_proposal_id = mask_proposal_execution_proposal_id(1);
-    PROPOSALS
+    proposals()
         .save(
             deps.as_mut().storage,
             1,
@@ -3526,7 +5447,13 @@ fn test_reply_proposal_mock() {
                 msgs: vec![],
                 status: Status::Open,
                 veto: None,
+                execute_after: None,
+                depends_on: vec![],
+                message_results: vec![],
                 votes: Votes::zero(),
+                threshold_rule: None,
+                commit_reveal: None,
+                no_with_veto_threshold: None,
             },
         )
         .unwrap();
@@ -3545,10 +5472,75 @@ fn test_reply_proposal_mock() {
         }
     );
 
-    let prop = PROPOSALS.load(deps.as_mut().storage, 1).unwrap();
+    let prop = proposals().load(deps.as_mut().storage, 1).unwrap();
     assert_eq!(prop.status, Status::ExecutionFailed);
 }
 
+#[test]
+fn test_sync_proposal_statuses() {
+    use crate::contract::sync_proposal_statuses;
+    use crate::state::proposals;
+
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+
+    let proposal = |expiration: Duration| SingleChoiceProposal {
+        title: "A simple text proposal".to_string(),
+        description: "This is a simple text proposal".to_string(),
+        proposer: Addr::unchecked(CREATOR_ADDR),
+        start_height: env.block.height,
+        expiration: expiration.after(&env.block),
+        min_voting_period: None,
+        threshold: Threshold::AbsolutePercentage {
+            percentage: PercentageThreshold::Majority {},
+        },
+        allow_revoting: false,
+        total_power: Uint128::new(100_000_000),
+        msgs: vec![],
+        status: Status::Open,
+        veto: None,
+        execute_after: None,
+        depends_on: vec![],
+        message_results: vec![],
+        votes: Votes::zero(),
+        threshold_rule: None,
+        commit_reveal: None,
+        no_with_veto_threshold: None,
+    };
+    // More long running proposals than are synced at once, followed
+    // by shorter ones with each units of expiration.
+    for id in 1..=6 {
+        proposals()
+            .save(deps.as_mut().storage, id, &proposal(Duration::Height(60)))
+            .unwrap();
+    }
+    proposals()
+        .save(deps.as_mut().storage, 7, &proposal(Duration::Height(6)))
+        .unwrap();
+    proposals()
+        .save(deps.as_mut().storage, 8, &proposal(Duration::Time(60)))
+        .unwrap();
+
+    // The shorter proposals are rejected once they expire. Their
+    // stored statuses are saved without them being voted on or
+    // closed.
+    env.block.height += 6;
+    env.block.time = env.block.time.plus_seconds(60);
+    sync_proposal_statuses(deps.as_mut().storage, &env.block).unwrap();
+
+    let stored = |storage: &dyn cosmwasm_std::Storage, key: &str| -> Vec<u64> {
+        proposals()
+            .idx
+            .status
+            .prefix(key.to_string())
+            .keys(storage, None, None, cosmwasm_std::Order::Ascending)
+            .collect::<Result<_, _>>()
+            .unwrap()
+    };
+    assert_eq!(stored(&deps.storage, "rejected"), vec![7, 8]);
+    assert_eq!(stored(&deps.storage, "open"), vec![1, 2, 3, 4, 5, 6]);
+}
+
 #[test]
 fn test_proposal_too_large() {
     let mut app = App::default();
@@ -3567,6 +5559,9 @@ fn test_proposal_too_large() {
                 msgs: vec![],
                 proposer: None,
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
             }),
             &[],
         )
@@ -3619,6 +5614,9 @@ fn test_proposal_creation_permissions() {
                 msgs: vec![],
                 proposer: None,
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
             }),
             &[],
         )
@@ -3645,6 +5643,9 @@ fn test_proposal_creation_permissions() {
                 msgs: vec![],
                 proposer: None,
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
             }),
             &[],
         )
@@ -3676,6 +5677,9 @@ fn test_proposal_creation_permissions() {
                 msgs: vec![],
                 proposer: Some("ekez".to_string()),
                 vote: None,
+                execute_after: None,
+                depends_on: vec![],
+                voting_period: None,
             }),
             &[],
         )
@@ -0,0 +1,243 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    from_json, Addr, BankMsg, Coin, CosmosMsg, Decimal, Empty, IbcMsg, Uint128, WasmMsg,
+};
+use cw20::Cw20ExecuteMsg;
+use cw_utils::Duration;
+use dao_voting::{
+    threshold::{PercentageThreshold, Threshold},
+    voting::validate_voting_period,
+};
+
+use crate::ContractError;
+
+/// A kind of proposal message that may require a stricter threshold
+/// to pass.
+#[cw_serde]
+pub enum SensitiveAction {
+    /// Migrating any contract.
+    Migrate {},
+    /// Updating or clearing the admin of any contract.
+    UpdateAdmin {},
+    /// Updating the voting module or proposal modules of the DAO.
+    UpdateDaoModules {},
+    /// Sending more than `amount` of `denom` out of the treasury,
+    /// summed across all of the proposal's messages. Bank sends, funds
+    /// attached to wasm messages, and IBC transfers are counted. cw20
+    /// tokens are denominated by their contract address, and their
+    /// transfers, sends, and allowance increases are counted.
+    TreasurySpend { denom: String, amount: Uint128 },
+}
+
+/// A threshold to use in place of the module's configured threshold
+/// for proposals containing a sensitive action.
+#[cw_serde]
+pub struct ThresholdRule {
+    /// The action that causes this rule to apply.
+    pub action: SensitiveAction,
+    /// The threshold proposals matching this rule must reach.
+    pub threshold: Threshold,
+    /// The minimum voting period for proposals matching this
+    /// rule. If `None`, the module's minimum voting period is used.
+    pub min_voting_period: Option<Duration>,
+}
+
+impl SensitiveAction {
+    /// Whether or not the messages of a proposal to `dao` contain
+    /// this action.
+    pub fn matches(&self, dao: &Addr, msgs: &[CosmosMsg<Empty>]) -> bool {
+        match self {
+            SensitiveAction::Migrate {} => msgs
+                .iter()
+                .any(|msg| matches!(msg, CosmosMsg::Wasm(WasmMsg::Migrate { .. }))),
+            SensitiveAction::UpdateAdmin {} => msgs.iter().any(|msg| {
+                matches!(
+                    msg,
+                    CosmosMsg::Wasm(WasmMsg::UpdateAdmin { .. } | WasmMsg::ClearAdmin { .. })
+                )
+            }),
+            SensitiveAction::UpdateDaoModules {} => msgs.iter().any(|msg| match msg {
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr, msg, ..
+                }) if contract_addr == dao => matches!(
+                    from_json(msg),
+                    Ok(dao_interface::msg::ExecuteMsg::UpdateVotingModule { .. })
+                        | Ok(dao_interface::msg::ExecuteMsg::UpdateProposalModules { .. })
+                ),
+                _ => false,
+            }),
+            SensitiveAction::TreasurySpend { denom, amount } => {
+                let spent: Uint128 = msgs
+                    .iter()
+                    .filter_map(spends)
+                    .flatten()
+                    .filter(|(spent_denom, _)| spent_denom == denom)
+                    .map(|(_, amount)| amount)
+                    .sum();
+                spent > *amount
+            }
+        }
+    }
+}
+
+/// The funds a message sends out of the treasury, as (denom, amount)
+/// pairs, or `None` if the message can not be classified. Stargate
+/// messages are opaque, and so are never classified.
+fn spends(msg: &CosmosMsg<Empty>) -> Option<Vec<(&str, Uint128)>> {
+    let coins = |funds: &[Coin]| -> Vec<(&str, Uint128)> {
+        funds
+            .iter()
+            .map(|coin| (coin.denom.as_str(), coin.amount))
+            .collect()
+    };
+    match msg {
+        CosmosMsg::Bank(BankMsg::Send { amount, .. }) => Some(coins(amount)),
+        CosmosMsg::Bank(BankMsg::Burn { .. }) => Some(vec![]),
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            msg,
+            funds,
+        }) => {
+            let mut spends = coins(funds);
+            match from_json(msg) {
+                Ok(Cw20ExecuteMsg::Transfer { amount, .. })
+                | Ok(Cw20ExecuteMsg::Send { amount, .. })
+                | Ok(Cw20ExecuteMsg::IncreaseAllowance { amount, .. }) => {
+                    spends.push((contract_addr.as_str(), amount))
+                }
+                _ => (),
+            }
+            Some(spends)
+        }
+        CosmosMsg::Wasm(WasmMsg::Instantiate { funds, .. }) => Some(coins(funds)),
+        CosmosMsg::Wasm(
+            WasmMsg::Migrate { .. } | WasmMsg::UpdateAdmin { .. } | WasmMsg::ClearAdmin { .. },
+        ) => Some(vec![]),
+        CosmosMsg::Ibc(IbcMsg::Transfer { amount, .. }) => {
+            Some(vec![(amount.denom.as_str(), amount.amount)])
+        }
+        CosmosMsg::Ibc(IbcMsg::SendPacket { .. } | IbcMsg::CloseChannel { .. }) => Some(vec![]),
+        CosmosMsg::Gov(_) => Some(vec![]),
+        _ => None,
+    }
+}
+
+/// The smallest share of the total voting power that must vote yes
+/// for a proposal using `threshold` to pass. Dynamic quorums are
+/// compared by the quorum they would be fixed to given
+/// `average_turnout`.
+fn min_yes_share(
+    threshold: &Threshold,
+    total_power: Uint128,
+    average_turnout: Option<Decimal>,
+) -> Decimal {
+    let share = |percentage: &PercentageThreshold| match percentage {
+        PercentageThreshold::Majority {} => Decimal::percent(50),
+        PercentageThreshold::Percent(percent) => *percent,
+    };
+    match threshold {
+        Threshold::AbsolutePercentage { percentage } => share(percentage),
+        Threshold::ThresholdQuorum { threshold, quorum } => share(threshold) * share(quorum),
+        Threshold::AbsoluteCount { threshold } => {
+            Decimal::checked_from_ratio(*threshold, total_power).unwrap_or(Decimal::MAX)
+        }
+        Threshold::DynamicQuorum {
+            threshold,
+            min_quorum,
+            max_quorum,
+            ..
+        } => {
+            share(threshold)
+                * average_turnout
+                    .unwrap_or(*max_quorum)
+                    .clamp(*min_quorum, *max_quorum)
+        }
+    }
+}
+
+/// Validates a set of threshold rules against the module's maximum
+/// voting period.
+pub fn validate_threshold_rules(
+    rules: &[ThresholdRule],
+    max_voting_period: Duration,
+) -> Result<(), ContractError> {
+    for rule in rules {
+        rule.threshold.validate()?;
+        validate_voting_period(rule.min_voting_period, max_voting_period)?;
+    }
+    Ok(())
+}
+
+/// The length of a minimum voting period in its units, which are the
+/// same for every minimum voting period as they are validated against
+/// the module's maximum voting period.
+fn period_length(min_voting_period: Option<Duration>) -> u64 {
+    match min_voting_period {
+        Some(Duration::Height(blocks)) => blocks,
+        Some(Duration::Time(seconds)) => seconds,
+        None => 0,
+    }
+}
+
+/// Selects the rule that applies to a proposal to `dao` with
+/// `msgs`. If several rules match, the strictest applies: the one
+/// requiring the largest share of `total_power` to vote yes, then the
+/// one with the longest minimum voting period, then the first
+/// listed. If any message can not be classified, its spends are
+/// unknown, so every `TreasurySpend` rule is treated as matching.
+pub fn select_threshold_rule(
+    rules: &[ThresholdRule],
+    dao: &Addr,
+    msgs: &[CosmosMsg<Empty>],
+    total_power: Uint128,
+    average_turnout: Option<Decimal>,
+) -> Option<ThresholdRule> {
+    let unclassifiable = msgs.iter().any(|msg| spends(msg).is_none());
+    let strictness = |rule: &ThresholdRule| {
+        (
+            min_yes_share(&rule.threshold, total_power, average_turnout),
+            period_length(rule.min_voting_period),
+        )
+    };
+    rules
+        .iter()
+        .filter(|rule| {
+            rule.action.matches(dao, msgs)
+                || (unclassifiable && matches!(rule.action, SensitiveAction::TreasurySpend { .. }))
+        })
+        .fold(
+            None,
+            |strictest: Option<&ThresholdRule>, rule| match strictest {
+                Some(strictest) if strictness(strictest) >= strictness(rule) => Some(strictest),
+                _ => Some(rule),
+            },
+        )
+        .cloned()
+}
+
+/// The threshold and minimum voting period of a proposal that `rule`
+/// applies to, given the module's `threshold` and
+/// `min_voting_period`. Each is the stricter of the rule's and the
+/// module's, so a rule never makes a proposal easier to pass.
+pub fn apply_threshold_rule(
+    rule: &ThresholdRule,
+    threshold: Threshold,
+    min_voting_period: Option<Duration>,
+    total_power: Uint128,
+    average_turnout: Option<Decimal>,
+) -> (Threshold, Option<Duration>) {
+    let threshold = if min_yes_share(&rule.threshold, total_power, average_turnout)
+        >= min_yes_share(&threshold, total_power, average_turnout)
+    {
+        rule.threshold.clone()
+    } else {
+        threshold
+    };
+    let min_voting_period =
+        if period_length(rule.min_voting_period) > period_length(min_voting_period) {
+            rule.min_voting_period
+        } else {
+            min_voting_period
+        };
+    (threshold, min_voting_period)
+}
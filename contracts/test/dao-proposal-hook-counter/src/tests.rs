@@ -155,7 +155,12 @@ fn test_counters() {
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         close_proposal_on_execution_failure: true,
+        continue_on_execution_failure: false,
         veto: None,
+        threshold_rules: vec![],
+        commit_reveal: None,
+        voting_period_range: None,
+        no_with_veto_threshold: None,
     };
 
     let governance_addr =
@@ -261,6 +266,9 @@ fn test_counters() {
             msgs: vec![],
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
         }),
         &[],
     )
@@ -362,6 +370,9 @@ fn test_counters() {
             msgs: vec![],
             proposer: None,
             vote: None,
+            execute_after: None,
+            depends_on: vec![],
+            voting_period: None,
         }),
         &[],
     )
@@ -395,8 +395,13 @@ fn test_factory() {
                 allow_revoting: false,
                 only_members_execute: true,
                 close_proposal_on_execution_failure: false,
+                continue_on_execution_failure: false,
                 pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                 veto: None,
+                threshold_rules: vec![],
+                commit_reveal: None,
+                voting_period_range: None,
+                no_with_veto_threshold: None,
             })
             .unwrap(),
             admin: Some(Admin::CoreModule {}),
@@ -505,8 +510,13 @@ fn test_factory_funds_pass_through() {
                 allow_revoting: false,
                 only_members_execute: true,
                 close_proposal_on_execution_failure: false,
+                continue_on_execution_failure: false,
                 pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                 veto: None,
+                threshold_rules: vec![],
+                commit_reveal: None,
+                voting_period_range: None,
+                no_with_veto_threshold: None,
             })
             .unwrap(),
             admin: Some(Admin::CoreModule {}),
@@ -631,8 +641,13 @@ fn test_factory_no_callback() {
                 allow_revoting: false,
                 only_members_execute: true,
                 close_proposal_on_execution_failure: false,
+                continue_on_execution_failure: false,
                 pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                 veto: None,
+                threshold_rules: vec![],
+                commit_reveal: None,
+                voting_period_range: None,
+                no_with_veto_threshold: None,
             })
             .unwrap(),
             admin: Some(Admin::CoreModule {}),
@@ -712,8 +727,13 @@ fn test_factory_wrong_callback() {
                 allow_revoting: false,
                 only_members_execute: true,
                 close_proposal_on_execution_failure: false,
+                continue_on_execution_failure: false,
                 pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                 veto: None,
+                threshold_rules: vec![],
+                commit_reveal: None,
+                voting_period_range: None,
+                no_with_veto_threshold: None,
             })
             .unwrap(),
             admin: Some(Admin::CoreModule {}),
@@ -246,8 +246,13 @@ impl TestEnvBuilder {
                     allow_revoting: false,
                     only_members_execute: true,
                     close_proposal_on_execution_failure: false,
+                    continue_on_execution_failure: false,
                     pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                     veto: None,
+                    threshold_rules: vec![],
+                    commit_reveal: None,
+                    voting_period_range: None,
+                    no_with_veto_threshold: None,
                 })
                 .unwrap(),
                 admin: Some(Admin::CoreModule {}),
@@ -96,11 +96,14 @@ pub fn proposal_status_changed_hooks(
 /// module, if one is installed.
 pub type PreProposeHookMsg = dao_pre_propose_base::msg::ExecuteMsg<Empty, Empty>;
 
-/// Adds prepropose / deposit module hook which will handle deposit refunds.
+/// Adds prepropose / deposit module hook which will handle deposit
+/// refunds. `no_with_veto` should be set if the proposal was rejected
+/// by `NoWithVeto` votes, in which case its deposit is forfeited.
 pub fn proposal_completed_hooks(
     proposal_creation_policy: ProposalCreationPolicy,
     proposal_id: u64,
     new_status: Status,
+    no_with_veto: bool,
 ) -> StdResult<Vec<SubMsg>> {
     let mut hooks: Vec<SubMsg> = vec![];
     match proposal_creation_policy {
@@ -109,6 +112,7 @@ pub fn proposal_completed_hooks(
             let msg = to_json_binary(&PreProposeHookMsg::ProposalCompletedHook {
                 proposal_id,
                 new_status,
+                no_with_veto,
             })?;
             hooks.push(SubMsg::reply_on_error(
                 WasmMsg::Execute {
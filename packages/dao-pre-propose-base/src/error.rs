@@ -8,6 +8,8 @@ use dao_voting::{
     deposit::DepositError, pre_propose::PreProposeSubmissionPolicyError, status::Status,
 };
 
+use crate::templates::TemplateError;
+
 #[derive(Error, Debug, PartialEq)]
 pub enum PreProposeError {
     #[error(transparent)]
@@ -28,6 +30,9 @@ pub enum PreProposeError {
     #[error(transparent)]
     SubmissionPolicy(#[from] PreProposeSubmissionPolicyError),
 
+    #[error(transparent)]
+    Template(#[from] TemplateError),
+
     #[error("Message sender is not proposal module")]
     NotModule {},
 
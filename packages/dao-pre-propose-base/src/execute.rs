@@ -117,7 +117,14 @@ where
             ExecuteMsg::ProposalCompletedHook {
                 proposal_id,
                 new_status,
-            } => self.execute_proposal_completed_hook(deps.as_ref(), info, proposal_id, new_status),
+                no_with_veto,
+            } => self.execute_proposal_completed_hook(
+                deps.as_ref(),
+                info,
+                proposal_id,
+                new_status,
+                no_with_veto,
+            ),
 
             ExecuteMsg::Extension { .. } => Ok(Response::default()),
         }
@@ -452,6 +459,7 @@ where
         info: MessageInfo,
         id: u64,
         new_status: Status,
+        no_with_veto: bool,
     ) -> Result<Response, PreProposeError> {
         let proposal_module = self.proposal_module.load(deps.storage)?;
         if info.sender != proposal_module {
@@ -465,6 +473,7 @@ where
         // module and open proposal submission to anyone.
         if new_status != Status::Closed
             && new_status != Status::Executed
+            && new_status != Status::PartiallyExecuted
             && new_status != Status::Vetoed
         {
             return Err(PreProposeError::NotCompleted { status: new_status });
@@ -473,11 +482,16 @@ where
         match self.deposits.may_load(deps.storage, id)? {
             Some((deposit_info, proposer)) => {
                 let messages = if let Some(ref deposit_info) = deposit_info {
-                    // Determine if refund can be issued
-                    let should_refund_to_proposer =
-                        match (new_status, deposit_info.clone().refund_policy) {
+                    // Determine if refund can be issued. Proposals vetoed
+                    // by their voters forfeit their deposit regardless
+                    // of the refund policy.
+                    let should_refund_to_proposer = !no_with_veto
+                        && match (new_status, deposit_info.clone().refund_policy) {
                             // If policy is refund only passed props, refund for executed status
-                            (Status::Executed, DepositRefundPolicy::OnlyPassed) => true,
+                            (
+                                Status::Executed | Status::PartiallyExecuted,
+                                DepositRefundPolicy::OnlyPassed,
+                            ) => true,
                             // Don't refund other statuses for OnlyPassed policy
                             (_, DepositRefundPolicy::OnlyPassed) => false,
                             // Refund if the refund policy is always refund
@@ -4,6 +4,7 @@ pub mod error;
 pub mod execute;
 pub mod msg;
 pub mod state;
+pub mod templates;
 
 #[cfg(test)]
 mod tests;
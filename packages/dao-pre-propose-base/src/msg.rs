@@ -101,6 +101,14 @@ pub enum ExecuteMsg<ProposalMessage, ExecuteExt> {
     ProposalCompletedHook {
         proposal_id: u64,
         new_status: Status,
+        /// True if the proposal was rejected because the share of
+        /// `NoWithVeto` votes exceeded the proposal module's
+        /// threshold. If so, the deposit is forfeited to the DAO
+        /// regardless of the refund policy. Only serialized when set,
+        /// so that hooks remain readable by pre-propose modules
+        /// deployed before it was added.
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        no_with_veto: bool,
     },
 }
 
@@ -0,0 +1,457 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    from_json, to_json_binary, to_json_string, Addr, Api, CosmosMsg, Decimal, Empty, StdError,
+    Uint128,
+};
+use serde_cw_value::Value;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum TemplateError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error("Template ({name}) already exists")]
+    TemplateExists { name: String },
+
+    #[error("Template ({name}) does not exist")]
+    TemplateNotFound { name: String },
+
+    #[error("Template parameter ({name}) is declared more than once")]
+    DuplicateParam { name: String },
+
+    #[error("Template parameter ({name}) does not appear in the template")]
+    UnusedParam { name: String },
+
+    #[error("Template placeholder ({name}) is not a declared parameter")]
+    UndeclaredPlaceholder { name: String },
+
+    #[error("Template placeholder is missing its closing braces")]
+    UnclosedPlaceholder {},
+
+    #[error("Missing value for template parameter ({name})")]
+    MissingParam { name: String },
+
+    #[error("Template has no parameter named ({name})")]
+    UnknownParam { name: String },
+
+    #[error("Invalid value for template parameter ({name}), expected {kind:?}")]
+    InvalidParam { name: String, kind: ParamKind },
+
+    #[error("Template does not render to a list of messages: {reason}")]
+    InvalidMessages { reason: String },
+}
+
+/// The type of a template parameter. Values are validated against
+/// their parameter's type before being filled in.
+#[cw_serde]
+#[derive(Copy)]
+pub enum ParamKind {
+    String,
+    Address,
+    Uint128,
+    Decimal,
+}
+
+#[cw_serde]
+pub struct TemplateParam {
+    pub name: String,
+    pub kind: ParamKind,
+}
+
+/// A parameterized list of messages that proposals may be created
+/// from.
+#[cw_serde]
+pub struct Template {
+    /// A JSON list of `CosmosMsg`s. Each `{{name}}` placeholder is
+    /// replaced by the value of the parameter `name`. Placeholders
+    /// must appear inside of JSON strings, as values are escaped to
+    /// be string contents. The `msg` of a wasm message may be given
+    /// as JSON rather than base64, so that placeholders may appear in
+    /// it, and is base64 encoded once they are filled in.
+    pub msgs: String,
+    /// The parameters used by `msgs`.
+    pub params: Vec<TemplateParam>,
+}
+
+#[cw_serde]
+pub struct TemplateResponse {
+    pub name: String,
+    pub template: Template,
+}
+
+impl ParamKind {
+    /// Validates `value` as this kind of parameter, returning it in
+    /// canonical form escaped for use inside of a JSON string.
+    fn check(self, api: &dyn Api, name: &str, value: &str) -> Result<String, TemplateError> {
+        let invalid = || TemplateError::InvalidParam {
+            name: name.to_string(),
+            kind: self,
+        };
+        let value = match self {
+            ParamKind::String => value.to_string(),
+            ParamKind::Address => api
+                .addr_validate(value)
+                .map_err(|_| invalid())?
+                .into_string(),
+            ParamKind::Uint128 => Uint128::from_str(value).map_err(|_| invalid())?.to_string(),
+            ParamKind::Decimal => Decimal::from_str(value).map_err(|_| invalid())?.to_string(),
+        };
+        let escaped = to_json_string(&value)?;
+        // Strip the quotes around the serialized string.
+        Ok(escaped[1..escaped.len() - 1].to_string())
+    }
+}
+
+impl Template {
+    /// Checks that every placeholder in the template is a declared
+    /// parameter, that every parameter is declared once and used, and
+    /// that the template renders to a list of messages. `sample_addr`
+    /// is filled in for address parameters.
+    pub fn validate(&self, sample_addr: &Addr) -> Result<(), TemplateError> {
+        for (i, param) in self.params.iter().enumerate() {
+            if self.params[..i].iter().any(|p| p.name == param.name) {
+                return Err(TemplateError::DuplicateParam {
+                    name: param.name.clone(),
+                });
+            }
+        }
+
+        let mut used = vec![];
+        let rendered = fill(&self.msgs, |name| {
+            let kind = self.param_kind(name)?;
+            used.push(name.to_string());
+            Ok(match kind {
+                ParamKind::String => String::new(),
+                ParamKind::Address => sample_addr.to_string(),
+                ParamKind::Uint128 | ParamKind::Decimal => "0".to_string(),
+            })
+        })?;
+        if let Some(param) = self.params.iter().find(|p| !used.contains(&p.name)) {
+            return Err(TemplateError::UnusedParam {
+                name: param.name.clone(),
+            });
+        }
+        parse_msgs(&rendered).map(|_| ())
+    }
+
+    /// Fills in the template's placeholders with `params` and parses
+    /// the result into messages. Every declared parameter must be
+    /// given a valid value and no others may be given.
+    pub fn render(
+        &self,
+        api: &dyn Api,
+        params: &BTreeMap<String, String>,
+    ) -> Result<Vec<CosmosMsg<Empty>>, TemplateError> {
+        if let Some(name) = params
+            .keys()
+            .find(|name| !self.params.iter().any(|p| &&p.name == name))
+        {
+            return Err(TemplateError::UnknownParam { name: name.clone() });
+        }
+        let rendered = fill(&self.msgs, |name| {
+            let kind = self.param_kind(name)?;
+            let value = params
+                .get(name)
+                .ok_or_else(|| TemplateError::MissingParam {
+                    name: name.to_string(),
+                })?;
+            kind.check(api, name, value)
+        })?;
+        parse_msgs(&rendered)
+    }
+
+    fn param_kind(&self, name: &str) -> Result<ParamKind, TemplateError> {
+        self.params
+            .iter()
+            .find(|p| p.name == name)
+            .map(|p| p.kind)
+            .ok_or_else(|| TemplateError::UndeclaredPlaceholder {
+                name: name.to_string(),
+            })
+    }
+}
+
+/// Replaces each `{{name}}` placeholder in `template` with
+/// `value(name)` in a single pass, so that filled in values are never
+/// themselves treated as placeholders.
+fn fill(
+    template: &str,
+    mut value: impl FnMut(&str) -> Result<String, TemplateError>,
+) -> Result<String, TemplateError> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let end = match rest[start..].find("}}") {
+            Some(end) => start + end,
+            None => return Err(TemplateError::UnclosedPlaceholder {}),
+        };
+        rendered.push_str(&rest[..start]);
+        rendered.push_str(&value(&rest[start + 2..end])?);
+        rest = &rest[end + 2..];
+    }
+    rendered.push_str(rest);
+    Ok(rendered)
+}
+
+fn parse_msgs(rendered: &str) -> Result<Vec<CosmosMsg<Empty>>, TemplateError> {
+    let invalid = |reason: String| TemplateError::InvalidMessages { reason };
+    let mut msgs: Vec<Value> = from_json(rendered).map_err(|e| invalid(e.to_string()))?;
+    for msg in msgs.iter_mut() {
+        encode_wasm_msg(msg)?;
+    }
+    msgs.into_iter()
+        .map(|msg| msg.deserialize_into().map_err(|e| invalid(e.to_string())))
+        .collect()
+}
+
+/// If `msg` is a wasm message whose own `msg` is given as JSON,
+/// replaces it with its base64 encoding as `WasmMsg` expects.
+fn encode_wasm_msg(msg: &mut Value) -> Result<(), TemplateError> {
+    let wasm = match msg {
+        Value::Map(msg) => msg.get_mut(&Value::String("wasm".to_string())),
+        _ => None,
+    };
+    let Some(Value::Map(wasm)) = wasm else {
+        return Ok(());
+    };
+    for variant in wasm.values_mut() {
+        let Value::Map(fields) = variant else {
+            continue;
+        };
+        if let Some(inner) = fields.get_mut(&Value::String("msg".to_string())) {
+            if matches!(inner, Value::Map(_) | Value::Seq(_)) {
+                *inner = Value::String(to_json_binary(inner)?.to_base64());
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{coins, testing::MockApi, BankMsg, Binary, WasmMsg};
+
+    use super::*;
+
+    fn payout() -> Template {
+        Template {
+            msgs: r#"[{"bank":{"send":{"to_address":"{{recipient}}","amount":[{"denom":"{{denom}}","amount":"{{amount}}"}]}}}]"#.to_string(),
+            params: vec![
+                TemplateParam {
+                    name: "recipient".to_string(),
+                    kind: ParamKind::Address,
+                },
+                TemplateParam {
+                    name: "denom".to_string(),
+                    kind: ParamKind::String,
+                },
+                TemplateParam {
+                    name: "amount".to_string(),
+                    kind: ParamKind::Uint128,
+                },
+            ],
+        }
+    }
+
+    fn params(values: &[(&str, &str)]) -> BTreeMap<String, String> {
+        values
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_render() {
+        let api = MockApi::default();
+        let template = payout();
+        template.validate(&Addr::unchecked("contract")).unwrap();
+
+        let msgs = template
+            .render(
+                &api,
+                &params(&[("recipient", "ekez"), ("denom", "ujuno"), ("amount", "10")]),
+            )
+            .unwrap();
+        assert_eq!(
+            msgs,
+            vec![CosmosMsg::Bank(BankMsg::Send {
+                to_address: "ekez".to_string(),
+                amount: coins(10, "ujuno"),
+            })]
+        );
+
+        let err = template
+            .render(
+                &api,
+                &params(&[("recipient", "ekez"), ("denom", "ujuno"), ("amount", "ten")]),
+            )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            TemplateError::InvalidParam {
+                name: "amount".to_string(),
+                kind: ParamKind::Uint128
+            }
+        );
+
+        let err = template
+            .render(&api, &params(&[("recipient", "ekez"), ("denom", "ujuno")]))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            TemplateError::MissingParam {
+                name: "amount".to_string()
+            }
+        );
+
+        let err = template
+            .render(
+                &api,
+                &params(&[
+                    ("recipient", "ekez"),
+                    ("denom", "ujuno"),
+                    ("amount", "10"),
+                    ("memo", "hi"),
+                ]),
+            )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            TemplateError::UnknownParam {
+                name: "memo".to_string()
+            }
+        );
+
+        // String values may not escape their JSON string or be
+        // treated as placeholders.
+        let msgs = template
+            .render(
+                &api,
+                &params(&[
+                    ("recipient", "ekez"),
+                    ("denom", "\"}{{amount}}"),
+                    ("amount", "10"),
+                ]),
+            )
+            .unwrap();
+        assert_eq!(
+            msgs,
+            vec![CosmosMsg::Bank(BankMsg::Send {
+                to_address: "ekez".to_string(),
+                amount: coins(10, "\"}{{amount}}"),
+            })]
+        );
+    }
+
+    #[test]
+    fn test_render_wasm_msg() {
+        let api = MockApi::default();
+        let template = Template {
+            msgs: r#"[{"wasm":{"execute":{"contract_addr":"{{token}}","msg":{"transfer":{"recipient":"{{recipient}}","amount":"{{amount}}"}},"funds":[]}}}]"#.to_string(),
+            params: vec![
+                TemplateParam {
+                    name: "token".to_string(),
+                    kind: ParamKind::Address,
+                },
+                TemplateParam {
+                    name: "recipient".to_string(),
+                    kind: ParamKind::Address,
+                },
+                TemplateParam {
+                    name: "amount".to_string(),
+                    kind: ParamKind::Uint128,
+                },
+            ],
+        };
+        template.validate(&Addr::unchecked("contract")).unwrap();
+
+        let msgs = template
+            .render(
+                &api,
+                &params(&[("token", "cw20"), ("recipient", "ekez"), ("amount", "10")]),
+            )
+            .unwrap();
+        assert_eq!(
+            msgs,
+            vec![CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "cw20".to_string(),
+                msg: Binary::from(br#"{"transfer":{"amount":"10","recipient":"ekez"}}"#.as_slice()),
+                funds: vec![],
+            })]
+        );
+
+        // Messages already encoded as base64 are left as they are.
+        let template = Template {
+            msgs: r#"[{"wasm":{"execute":{"contract_addr":"{{token}}","msg":"e30=","funds":[]}}}]"#
+                .to_string(),
+            params: vec![TemplateParam {
+                name: "token".to_string(),
+                kind: ParamKind::Address,
+            }],
+        };
+        let msgs = template
+            .render(&api, &params(&[("token", "cw20")]))
+            .unwrap();
+        assert_eq!(
+            msgs,
+            vec![CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "cw20".to_string(),
+                msg: Binary::from(b"{}".as_slice()),
+                funds: vec![],
+            })]
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let addr = Addr::unchecked("contract");
+
+        let mut template = payout();
+        template.params.push(TemplateParam {
+            name: "memo".to_string(),
+            kind: ParamKind::String,
+        });
+        assert_eq!(
+            template.validate(&addr),
+            Err(TemplateError::UnusedParam {
+                name: "memo".to_string()
+            })
+        );
+
+        let mut template = payout();
+        template.params.pop();
+        assert_eq!(
+            template.validate(&addr),
+            Err(TemplateError::UndeclaredPlaceholder {
+                name: "amount".to_string()
+            })
+        );
+
+        let mut template = payout();
+        template.params.push(template.params[0].clone());
+        assert_eq!(
+            template.validate(&addr),
+            Err(TemplateError::DuplicateParam {
+                name: "recipient".to_string()
+            })
+        );
+
+        let mut template = payout();
+        template.msgs = r#"["{{recipient"]"#.to_string();
+        assert_eq!(
+            template.validate(&addr),
+            Err(TemplateError::UnclosedPlaceholder {})
+        );
+
+        let mut template = payout();
+        template.msgs = template.msgs.replace("bank", "banana");
+        assert!(matches!(
+            template.validate(&addr),
+            Err(TemplateError::InvalidMessages { .. })
+        ));
+    }
+}
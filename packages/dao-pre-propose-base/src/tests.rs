@@ -33,6 +33,7 @@ fn test_completed_hook_status_invariant() {
         ExecuteMsg::ProposalCompletedHook {
             proposal_id: 1,
             new_status: Status::Passed,
+            no_with_veto: false,
         },
     );
 
@@ -44,6 +45,49 @@ fn test_completed_hook_status_invariant() {
     );
 }
 
+#[test]
+fn test_completed_hook_readable_by_existing_modules() {
+    // the hook message as it was before `no_with_veto` was added.
+    #[cosmwasm_schema::cw_serde]
+    enum PreviousExecuteMsg {
+        ProposalCompletedHook {
+            proposal_id: u64,
+            new_status: Status,
+        },
+    }
+
+    let msg = to_json_binary(&ExecuteMsg::<Empty, Empty>::ProposalCompletedHook {
+        proposal_id: 1,
+        new_status: Status::Executed,
+        no_with_veto: false,
+    })
+    .unwrap();
+    assert_eq!(
+        from_json::<PreviousExecuteMsg>(&msg).unwrap(),
+        PreviousExecuteMsg::ProposalCompletedHook {
+            proposal_id: 1,
+            new_status: Status::Executed,
+        }
+    );
+
+    // forfeited deposits may only be reported to upgraded modules.
+    let msg = to_json_binary(&ExecuteMsg::<Empty, Empty>::ProposalCompletedHook {
+        proposal_id: 1,
+        new_status: Status::Rejected,
+        no_with_veto: true,
+    })
+    .unwrap();
+    from_json::<PreviousExecuteMsg>(&msg).unwrap_err();
+    assert_eq!(
+        from_json::<ExecuteMsg<Empty, Empty>>(&msg).unwrap(),
+        ExecuteMsg::ProposalCompletedHook {
+            proposal_id: 1,
+            new_status: Status::Rejected,
+            no_with_veto: true,
+        }
+    );
+}
+
 #[test]
 fn test_completed_hook_auth() {
     let mut deps = mock_dependencies();
@@ -62,6 +106,7 @@ fn test_completed_hook_auth() {
         ExecuteMsg::ProposalCompletedHook {
             proposal_id: 1,
             new_status: Status::Passed,
+            no_with_veto: false,
         },
     );
 
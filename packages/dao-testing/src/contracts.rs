@@ -79,6 +79,15 @@ pub fn pre_propose_single_contract() -> Box<dyn Contract<Empty>> {
     Box::new(contract)
 }
 
+pub fn pre_propose_condorcet_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_pre_propose_condorcet::contract::execute,
+        dao_pre_propose_condorcet::contract::instantiate,
+        dao_pre_propose_condorcet::contract::query,
+    );
+    Box::new(contract)
+}
+
 pub fn pre_propose_multiple_contract() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
         cppm::contract::execute,
@@ -11,4 +11,19 @@ pub enum VotingError {
 
     #[error("Min voting period must be less than or equal to max voting period")]
     InvalidMinVotingPeriod {},
+
+    #[error("execute_after must have the same units as max_voting_period (height or time)")]
+    ExecuteAfterUnitsConflict {},
+
+    #[error("voting period must be within the allowed voting period range")]
+    VotingPeriodOutOfRange {},
+
+    #[error("voting period range may not allow voting periods longer than max_voting_period")]
+    VotingPeriodRangeTooLong {},
+
+    #[error("no_with_veto_threshold must be greater than zero and less than one")]
+    InvalidNoWithVetoThreshold {},
+
+    #[error("a proposal may depend on at most {max} proposals")]
+    TooManyDependencies { max: usize },
 }
@@ -7,6 +7,8 @@ pub mod multiple_choice;
 pub mod pre_propose;
 pub mod proposal;
 pub mod reply;
+pub mod signed_vote;
+pub mod stats;
 pub mod status;
 pub mod threshold;
 pub mod veto;
@@ -1,5 +1,6 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{CosmosMsg, Empty, StdError, StdResult, Uint128};
+use cosmwasm_std::{CosmosMsg, Decimal, Deps, Empty, StdError, StdResult, Uint128};
+use cw_denom::{CheckedDenom, DenomError, UncheckedDenom};
 
 use crate::threshold::{validate_quorum, PercentageThreshold, ThresholdError};
 
@@ -11,34 +12,147 @@ const NONE_OPTION_DESCRIPTION: &str = "None of the above";
 /// Determines how many choices may be selected.
 #[cw_serde]
 pub enum VotingStrategy {
-    SingleChoice { quorum: PercentageThreshold },
+    SingleChoice {
+        quorum: PercentageThreshold,
+    },
+    /// Voters may select up to `max_selections` options, each of
+    /// which receives their full voting power. The `max_selections`
+    /// options with the most votes win, so long as they received more
+    /// votes than "None of the above".
+    Approval {
+        quorum: PercentageThreshold,
+        max_selections: u32,
+    },
+    /// Voters rank options in order of preference. Once voting has
+    /// ended, the option with the fewest first preferences is
+    /// eliminated and its votes transferred to their next preference
+    /// until an option has a majority of the remaining votes.
+    RankedChoice {
+        quorum: PercentageThreshold,
+    },
+    /// Voters score the options they choose from zero to `max_score`,
+    /// weighted by their voting power. The option with the highest
+    /// average score wins. Options are only eligible to win if the
+    /// voting power that scored them meets `quorum`.
+    Score {
+        quorum: PercentageThreshold,
+        max_score: u32,
+    },
+    /// Each option requests funding from the proposal's budget, and
+    /// the budget is split between the options in proportion to the
+    /// votes they receive, up to the amount they requested. Options
+    /// that receive less than `min_share` of the votes are not funded.
+    Budget {
+        quorum: PercentageThreshold,
+        min_share: Decimal,
+    },
 }
 
 impl VotingStrategy {
     pub fn validate(&self) -> Result<(), ThresholdError> {
         match self {
             VotingStrategy::SingleChoice { quorum } => validate_quorum(quorum),
+            VotingStrategy::Approval {
+                quorum,
+                max_selections,
+            } => {
+                if *max_selections == 0 || *max_selections > MAX_NUM_CHOICES {
+                    return Err(ThresholdError::InvalidMaxSelections {
+                        max: MAX_NUM_CHOICES,
+                    });
+                }
+                validate_quorum(quorum)
+            }
+            VotingStrategy::RankedChoice { quorum } => validate_quorum(quorum),
+            VotingStrategy::Score { quorum, max_score } => {
+                if *max_score == 0 {
+                    return Err(ThresholdError::InvalidMaxScore {});
+                }
+                validate_quorum(quorum)
+            }
+            VotingStrategy::Budget { quorum, min_share } => {
+                if *min_share > Decimal::one() {
+                    return Err(ThresholdError::InvalidMinShare {});
+                }
+                validate_quorum(quorum)
+            }
         }
     }
 
     pub fn get_quorum(&self) -> PercentageThreshold {
         match self {
             VotingStrategy::SingleChoice { quorum } => *quorum,
+            VotingStrategy::Approval { quorum, .. } => *quorum,
+            VotingStrategy::RankedChoice { quorum } => *quorum,
+            VotingStrategy::Score { quorum, .. } => *quorum,
+            VotingStrategy::Budget { quorum, .. } => *quorum,
         }
     }
 }
 
+/// Determines how proposals whose leading options are tied are
+/// decided. Ties with "None of the above" are never broken.
+#[cw_serde]
+#[derive(Copy, Default)]
+pub enum TieBreaker {
+    /// Tied proposals are rejected.
+    #[default]
+    Fail {},
+    /// The tied option that was listed first wins.
+    LowestIndex {},
+    /// The tied option that reached the tied vote weight first wins,
+    /// being the one whose vote weight last changed earliest.
+    EarliestToReach {},
+    /// Tied proposals are rejected, and a runoff proposal between the
+    /// tied options is created when they are closed.
+    Runoff {},
+}
+
 /// A multiple choice vote, picking the desired option
 #[cw_serde]
-#[derive(Copy)]
 pub struct MultipleChoiceVote {
     // A vote indicates which option the user has selected.
     pub option_id: u32,
+    /// Under the `Approval` strategy, the other options that the
+    /// voter has selected. Under the `RankedChoice` strategy, the
+    /// voter's remaining preferences in order, `option_id` being
+    /// their first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub additional_option_ids: Vec<u32>,
+    /// Under the `Score` strategy, the score given to each selected
+    /// option, in the same order as the options. Empty otherwise.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scores: Vec<u32>,
+}
+
+impl MultipleChoiceVote {
+    /// All of the options selected by this vote.
+    pub fn option_ids(&self) -> impl Iterator<Item = u32> + '_ {
+        std::iter::once(self.option_id).chain(self.additional_option_ids.iter().copied())
+    }
+
+    /// The options selected by this vote paired with their scores.
+    /// Votes without scores give each option they select a score of
+    /// one.
+    pub fn scored_option_ids(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        self.option_ids()
+            .enumerate()
+            .map(|(i, option_id)| (option_id, self.scores.get(i).copied().unwrap_or(1)))
+    }
 }
 
 impl std::fmt::Display for MultipleChoiceVote {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.option_id)
+        for (i, option_id) in self.option_ids().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{option_id}")?;
+            if let Some(score) = self.scores.get(i) {
+                write!(f, "={score}")?;
+            }
+        }
+        Ok(())
     }
 }
 
@@ -46,29 +160,80 @@ impl std::fmt::Display for MultipleChoiceVote {
 #[cw_serde]
 pub struct MultipleChoiceVotes {
     // Vote counts is a vector of integers indicating the vote weight for each option
-    // (the index corresponds to the option).
+    // (the index corresponds to the option). For scored votes, this is the sum of
+    // each voter's power multiplied by their score.
     pub vote_weights: Vec<Uint128>,
+    /// The voting power that has voted. Tracked separately from vote
+    /// weights as a vote may select more than one option. `None` for
+    /// tallies made before it was tracked, which only allowed one
+    /// option per vote.
+    #[serde(default)]
+    pub power_cast: Option<Uint128>,
+    /// The voting power that has scored each option, indexed by
+    /// option. Empty until a scored vote is cast.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub participation: Vec<Uint128>,
 }
 
 impl MultipleChoiceVotes {
-    /// Sum of all vote weights
+    /// Sum of the voting power that has voted
     pub fn total(&self) -> Uint128 {
-        self.vote_weights.iter().sum()
+        self.power_cast
+            .unwrap_or_else(|| self.vote_weights.iter().sum())
+    }
+
+    /// The voting power that has voted for an option. For scored
+    /// votes, this is the power that has scored it.
+    pub fn participation(&self, option_id: u32) -> Uint128 {
+        if self.participation.is_empty() {
+            self.vote_weights[option_id as usize]
+        } else {
+            self.participation[option_id as usize]
+        }
     }
 
     // Add a vote to the tally
-    pub fn add_vote(&mut self, vote: MultipleChoiceVote, weight: Uint128) -> StdResult<()> {
-        self.vote_weights[vote.option_id as usize] = self.vote_weights[vote.option_id as usize]
-            .checked_add(weight)
-            .map_err(StdError::overflow)?;
+    pub fn add_vote(&mut self, vote: &MultipleChoiceVote, weight: Uint128) -> StdResult<()> {
+        if !vote.scores.is_empty() && self.participation.is_empty() {
+            self.participation = vec![Uint128::zero(); self.vote_weights.len()];
+        }
+        for (option_id, score) in vote.scored_option_ids() {
+            let scored = weight
+                .checked_mul(Uint128::from(score))
+                .map_err(StdError::overflow)?;
+            self.vote_weights[option_id as usize] = self.vote_weights[option_id as usize]
+                .checked_add(scored)
+                .map_err(StdError::overflow)?;
+            if !vote.scores.is_empty() {
+                self.participation[option_id as usize] = self.participation[option_id as usize]
+                    .checked_add(weight)
+                    .map_err(StdError::overflow)?;
+            }
+        }
+        if let Some(power_cast) = self.power_cast {
+            self.power_cast = Some(power_cast.checked_add(weight).map_err(StdError::overflow)?);
+        }
         Ok(())
     }
 
     // Remove a vote from the tally
-    pub fn remove_vote(&mut self, vote: MultipleChoiceVote, weight: Uint128) -> StdResult<()> {
-        self.vote_weights[vote.option_id as usize] = self.vote_weights[vote.option_id as usize]
-            .checked_sub(weight)
-            .map_err(StdError::overflow)?;
+    pub fn remove_vote(&mut self, vote: &MultipleChoiceVote, weight: Uint128) -> StdResult<()> {
+        for (option_id, score) in vote.scored_option_ids() {
+            let scored = weight
+                .checked_mul(Uint128::from(score))
+                .map_err(StdError::overflow)?;
+            self.vote_weights[option_id as usize] = self.vote_weights[option_id as usize]
+                .checked_sub(scored)
+                .map_err(StdError::overflow)?;
+            if !vote.scores.is_empty() {
+                self.participation[option_id as usize] = self.participation[option_id as usize]
+                    .checked_sub(weight)
+                    .map_err(StdError::overflow)?;
+            }
+        }
+        if let Some(power_cast) = self.power_cast {
+            self.power_cast = Some(power_cast.checked_sub(weight).map_err(StdError::overflow)?);
+        }
         Ok(())
     }
 
@@ -76,6 +241,8 @@ impl MultipleChoiceVotes {
     pub fn zero(num_choices: usize) -> Self {
         Self {
             vote_weights: vec![Uint128::zero(); num_choices],
+            power_cast: Some(Uint128::zero()),
+            participation: vec![],
         }
     }
 }
@@ -102,6 +269,43 @@ pub struct MultipleChoiceOption {
     pub title: String,
     pub description: String,
     pub msgs: Vec<CosmosMsg<Empty>>,
+    /// The funding this option requests from the proposal's budget.
+    /// Required by, and only allowed for, the `Budget` voting
+    /// strategy.
+    #[serde(default)]
+    pub funding: Option<FundingRequest>,
+}
+
+/// A request for funding from a budget proposal.
+#[cw_serde]
+pub struct FundingRequest {
+    /// The address the funding is sent to.
+    pub recipient: String,
+    /// The most funding the option may receive.
+    pub amount: Uint128,
+}
+
+/// The funds a budget proposal splits between its options.
+#[cw_serde]
+pub struct UncheckedBudget {
+    pub denom: UncheckedDenom,
+    pub amount: Uint128,
+}
+
+/// Counterpart to `UncheckedBudget` whose denom has been validated.
+#[cw_serde]
+pub struct CheckedBudget {
+    pub denom: CheckedDenom,
+    pub amount: Uint128,
+}
+
+impl UncheckedBudget {
+    pub fn into_checked(self, deps: Deps) -> Result<CheckedBudget, DenomError> {
+        Ok(CheckedBudget {
+            denom: self.denom.into_checked(deps)?,
+            amount: self.amount,
+        })
+    }
 }
 
 /// Multiple choice options that have been verified for correctness, and have all fields
@@ -122,6 +326,9 @@ pub struct CheckedMultipleChoiceOption {
     pub description: String,
     pub msgs: Vec<CosmosMsg<Empty>>,
     pub vote_count: Uint128,
+    /// The funding this option requests from a budget proposal.
+    #[serde(default)]
+    pub funding: Option<FundingRequest>,
 }
 
 impl MultipleChoiceOptions {
@@ -147,6 +354,7 @@ impl MultipleChoiceOptions {
                     msgs: choice.msgs,
                     vote_count: Uint128::zero(),
                     title: choice.title,
+                    funding: choice.funding,
                 };
                 checked_options.push(checked_option)
             });
@@ -159,6 +367,7 @@ impl MultipleChoiceOptions {
             msgs: vec![],
             vote_count: Uint128::zero(),
             title: NONE_OPTION_DESCRIPTION.to_string(),
+            funding: None,
         };
 
         checked_options.push(none_option);
@@ -188,34 +397,168 @@ mod test {
 
     #[test]
     fn test_display_multiple_choice_vote() {
-        let vote = MultipleChoiceVote { option_id: 0 };
-        assert_eq!("0", vote.to_string())
+        let vote = MultipleChoiceVote {
+            option_id: 0,
+            additional_option_ids: vec![],
+            scores: vec![],
+        };
+        assert_eq!("0", vote.to_string());
+
+        let vote = MultipleChoiceVote {
+            option_id: 0,
+            additional_option_ids: vec![2, 1],
+            scores: vec![],
+        };
+        assert_eq!("0,2,1", vote.to_string())
     }
 
     #[test]
     fn test_multiple_choice_votes() {
         let mut votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(10), Uint128::new(100)],
+            power_cast: Some(Uint128::new(110)),
+            participation: vec![],
         };
         let total = votes.total();
         assert_eq!(total, Uint128::new(110));
 
         votes
-            .add_vote(MultipleChoiceVote { option_id: 0 }, Uint128::new(10))
+            .add_vote(
+                &MultipleChoiceVote {
+                    option_id: 0,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
+                Uint128::new(10),
+            )
             .unwrap();
         let total = votes.total();
         assert_eq!(total, Uint128::new(120));
 
         votes
-            .remove_vote(MultipleChoiceVote { option_id: 0 }, Uint128::new(20))
+            .remove_vote(
+                &MultipleChoiceVote {
+                    option_id: 0,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
+                Uint128::new(20),
+            )
             .unwrap();
         votes
-            .remove_vote(MultipleChoiceVote { option_id: 1 }, Uint128::new(100))
+            .remove_vote(
+                &MultipleChoiceVote {
+                    option_id: 1,
+                    additional_option_ids: vec![],
+                    scores: vec![],
+                },
+                Uint128::new(100),
+            )
             .unwrap();
 
         assert_eq!(votes, MultipleChoiceVotes::zero(2))
     }
 
+    #[test]
+    fn test_multiple_selection_votes() {
+        let vote = MultipleChoiceVote {
+            option_id: 0,
+            additional_option_ids: vec![2],
+            scores: vec![],
+        };
+        let mut votes = MultipleChoiceVotes::zero(3);
+        votes.add_vote(&vote, Uint128::new(10)).unwrap();
+        assert_eq!(
+            votes.vote_weights,
+            vec![Uint128::new(10), Uint128::zero(), Uint128::new(10)]
+        );
+        // Each vote counts once toward the total.
+        assert_eq!(votes.total(), Uint128::new(10));
+
+        votes.remove_vote(&vote, Uint128::new(10)).unwrap();
+        assert_eq!(votes, MultipleChoiceVotes::zero(3));
+
+        // Tallies that predate tracking the power cast sum their vote
+        // weights.
+        let votes = MultipleChoiceVotes {
+            vote_weights: vec![Uint128::new(10), Uint128::new(5)],
+            power_cast: None,
+            participation: vec![],
+        };
+        assert_eq!(votes.total(), Uint128::new(15));
+    }
+
+    #[test]
+    fn test_validate_approval_strategy() {
+        let quorum = PercentageThreshold::Majority {};
+        let approval = |max_selections| VotingStrategy::Approval {
+            quorum,
+            max_selections,
+        };
+        approval(1).validate().unwrap();
+        approval(MAX_NUM_CHOICES).validate().unwrap();
+        assert_eq!(
+            approval(0).validate(),
+            Err(ThresholdError::InvalidMaxSelections {
+                max: MAX_NUM_CHOICES
+            })
+        );
+        assert_eq!(
+            approval(MAX_NUM_CHOICES + 1).validate(),
+            Err(ThresholdError::InvalidMaxSelections {
+                max: MAX_NUM_CHOICES
+            })
+        );
+    }
+
+    #[test]
+    fn test_scored_votes() {
+        let vote = MultipleChoiceVote {
+            option_id: 0,
+            additional_option_ids: vec![2],
+            scores: vec![7, 0],
+        };
+        assert_eq!(vote.to_string(), "0=7,2=0");
+
+        let mut votes = MultipleChoiceVotes::zero(3);
+        votes.add_vote(&vote, Uint128::new(10)).unwrap();
+        votes
+            .add_vote(
+                &MultipleChoiceVote {
+                    option_id: 0,
+                    additional_option_ids: vec![],
+                    scores: vec![4],
+                },
+                Uint128::new(5),
+            )
+            .unwrap();
+        // Vote weights sum scores weighted by voting power, and
+        // participation counts the power that scored each option.
+        assert_eq!(
+            votes.vote_weights,
+            vec![Uint128::new(90), Uint128::zero(), Uint128::zero()]
+        );
+        assert_eq!(votes.participation(0), Uint128::new(15));
+        assert_eq!(votes.participation(1), Uint128::zero());
+        assert_eq!(votes.participation(2), Uint128::new(10));
+        assert_eq!(votes.total(), Uint128::new(15));
+
+        votes.remove_vote(&vote, Uint128::new(10)).unwrap();
+        assert_eq!(
+            votes.vote_weights,
+            vec![Uint128::new(20), Uint128::zero(), Uint128::zero()]
+        );
+        assert_eq!(votes.participation(0), Uint128::new(5));
+        assert_eq!(votes.participation(2), Uint128::zero());
+
+        let score = |max_score| VotingStrategy::Score {
+            quorum: PercentageThreshold::Majority {},
+            max_score,
+        };
+        score(10).validate().unwrap();
+        assert_eq!(score(0).validate(), Err(ThresholdError::InvalidMaxScore {}));
+    }
+
     #[test]
     fn test_into_checked() {
         let options = vec![
@@ -223,11 +566,13 @@ mod test {
                 description: "multiple choice option 1".to_string(),
                 msgs: vec![],
                 title: "title".to_string(),
+                funding: None,
             },
             super::MultipleChoiceOption {
                 description: "multiple choice option 2".to_string(),
                 msgs: vec![],
                 title: "title".to_string(),
+                funding: None,
             },
         ];
 
@@ -268,6 +613,7 @@ mod test {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
             title: "title".to_string(),
+            funding: None,
         }];
 
         let mc_options = super::MultipleChoiceOptions { options };
@@ -1,14 +1,19 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{CosmosMsg, Empty};
+use cosmwasm_std::{Addr, Api, CosmosMsg, Empty, QuerierWrapper, StdResult};
+use cw_utils::{Duration, Expiration};
 
 use crate::{
-    multiple_choice::{MultipleChoiceAutoVote, MultipleChoiceOptions},
+    error::VotingError,
+    multiple_choice::{MultipleChoiceAutoVote, MultipleChoiceOptions, UncheckedBudget},
+    status::Status,
     voting::SingleChoiceAutoVote,
 };
 
 /// Default limit for proposal pagination.
 pub const DEFAULT_LIMIT: u64 = 30;
 pub const MAX_PROPOSAL_SIZE: u64 = 30_000;
+/// The maximum number of proposals that a proposal may depend on.
+pub const MAX_DEPENDENCIES: usize = 10;
 
 /// The contents of a message to create a proposal in the single
 /// choice proposal module.
@@ -38,6 +43,21 @@ pub struct SingleChoiceProposeMsg {
     pub proposer: Option<String>,
     /// An optional vote cast by the proposer.
     pub vote: Option<SingleChoiceAutoVote>,
+    /// An optional time before which the proposal may not be
+    /// executed, even if it has passed. Must have the same units
+    /// (height or time) as the proposal module's max voting period.
+    pub execute_after: Option<Expiration>,
+    /// Proposals that must be executed before this one may be,
+    /// given as (proposal module address, proposal ID) pairs. At most
+    /// `MAX_DEPENDENCIES` may be given. If any of them fails (see
+    /// `is_failed_dependency`), this proposal will be closed instead
+    /// of executed.
+    #[serde(default)]
+    pub depends_on: Vec<(String, u64)>,
+    /// An optional voting period chosen by the proposer. Must be
+    /// within the proposal module's allowed voting period range. If
+    /// `None`, the module's max voting period is used.
+    pub voting_period: Option<Duration>,
 }
 
 /// The contents of a message to create a proposal in the multiple
@@ -67,4 +87,386 @@ pub struct MultipleChoiceProposeMsg {
     pub proposer: Option<String>,
     /// An optional vote cast by the proposer.
     pub vote: Option<MultipleChoiceAutoVote>,
+    /// An optional time before which the proposal may not be
+    /// executed, even if it has passed. Must have the same units
+    /// (height or time) as the proposal module's max voting period.
+    pub execute_after: Option<Expiration>,
+    /// Proposals that must be executed before this one may be,
+    /// given as (proposal module address, proposal ID) pairs. At most
+    /// `MAX_DEPENDENCIES` may be given. If any of them fails (see
+    /// `is_failed_dependency`), this proposal will be closed instead
+    /// of executed.
+    #[serde(default)]
+    pub depends_on: Vec<(String, u64)>,
+    /// An optional voting period chosen by the proposer. Must be
+    /// within the proposal module's allowed voting period range. If
+    /// `None`, the module's max voting period is used.
+    pub voting_period: Option<Duration>,
+    /// The funds split between the options of a proposal using the
+    /// `Budget` voting strategy. Required by, and only allowed for,
+    /// that strategy.
+    #[serde(default)]
+    pub budget: Option<UncheckedBudget>,
+}
+
+/// The `Proposal` query shared by the single and multiple choice
+/// proposal modules.
+#[cw_serde]
+enum ProposalQuery {
+    Proposal { proposal_id: u64 },
+}
+
+/// The `Proposal` query of the condorcet proposal module.
+#[cw_serde]
+enum CondorcetProposalQuery {
+    Proposal { id: u32 },
+}
+
+/// The subset of a `Proposal` query response needed to read a
+/// proposal's status. Unknown fields are ignored so that this may be
+/// used with any proposal module whose proposals have a `status`.
+#[derive(::cosmwasm_schema::serde::Deserialize)]
+#[serde(crate = "::cosmwasm_schema::serde")]
+struct ProposalStatusResponse {
+    proposal: ProposalStatus,
+}
+
+#[derive(::cosmwasm_schema::serde::Deserialize)]
+#[serde(crate = "::cosmwasm_schema::serde")]
+struct ProposalStatus {
+    status: Status,
+}
+
+/// The subset of a condorcet proposal module `Proposal` query
+/// response needed to read a proposal's status. The module updates
+/// `last_status` before responding, so it is always current.
+#[derive(::cosmwasm_schema::serde::Deserialize)]
+#[serde(crate = "::cosmwasm_schema::serde")]
+struct CondorcetProposalStatusResponse {
+    proposal: CondorcetProposalStatus,
+}
+
+#[derive(::cosmwasm_schema::serde::Deserialize)]
+#[serde(crate = "::cosmwasm_schema::serde")]
+struct CondorcetProposalStatus {
+    last_status: CondorcetStatus,
+}
+
+/// A condorcet proposal's status. Passed statuses carry the winning
+/// choice, which dependent proposals do not need. This mirrors the
+/// condorcet module's `Status`, whose tests check that every one of
+/// its variants may be read as one of these.
+#[derive(::cosmwasm_schema::serde::Deserialize)]
+#[serde(crate = "::cosmwasm_schema::serde", rename_all = "snake_case")]
+pub enum CondorcetStatus {
+    Open,
+    Rejected,
+    Passed {},
+    Executed,
+    Closed,
+    ExecutionFailed,
+    VetoTimelock { expiration: Expiration },
+    Vetoed,
+}
+
+impl From<CondorcetStatus> for Status {
+    fn from(status: CondorcetStatus) -> Self {
+        match status {
+            CondorcetStatus::Open => Status::Open,
+            CondorcetStatus::Rejected => Status::Rejected,
+            CondorcetStatus::Passed {} => Status::Passed,
+            CondorcetStatus::Executed => Status::Executed,
+            CondorcetStatus::Closed => Status::Closed,
+            CondorcetStatus::ExecutionFailed => Status::ExecutionFailed,
+            CondorcetStatus::VetoTimelock { expiration } => Status::VetoTimelock { expiration },
+            CondorcetStatus::Vetoed => Status::Vetoed,
+        }
+    }
+}
+
+/// Queries the current status of a proposal in another proposal
+/// module. Used to check the status of proposal dependencies. Single
+/// choice, multiple choice, and condorcet proposal modules are
+/// supported.
+pub fn query_proposal_status(
+    querier: &QuerierWrapper,
+    module: &Addr,
+    proposal_id: u64,
+) -> StdResult<Status> {
+    let err = match querier.query_wasm_smart::<ProposalStatusResponse>(
+        module,
+        &ProposalQuery::Proposal { proposal_id },
+    ) {
+        Ok(response) => return Ok(response.proposal.status),
+        Err(err) => err,
+    };
+    // Condorcet proposal IDs are `u32`s and are queried by `id`. If
+    // this fails too the module is not supported, or the proposal
+    // does not exist, so the original error is returned.
+    match u32::try_from(proposal_id) {
+        Ok(id) => querier
+            .query_wasm_smart::<CondorcetProposalStatusResponse>(
+                module,
+                &CondorcetProposalQuery::Proposal { id },
+            )
+            .map(|response| response.proposal.last_status.into())
+            .map_err(|_| err),
+        Err(_) => Err(err),
+    }
+}
+
+/// Returns true if a proposal that depends on a proposal with the
+/// given status may never be executed. Proposals whose execution
+/// failed count as failed, but those that only partially succeeded
+/// do not, as their failed messages may still be retried.
+pub fn is_failed_dependency(status: &Status) -> bool {
+    matches!(
+        status,
+        Status::Rejected | Status::Vetoed | Status::Closed | Status::ExecutionFailed
+    )
+}
+
+/// The combined status of a proposal's dependencies.
+#[derive(Debug, PartialEq, Eq)]
+pub enum DependencyStatus {
+    /// Every dependency has been executed.
+    Executed,
+    /// A dependency has not been executed, but still may be.
+    Pending { module: Addr, proposal_id: u64 },
+    /// A dependency has failed, and so will never be executed.
+    Failed { module: Addr, proposal_id: u64 },
+}
+
+/// Validates the dependencies of a new proposal, checking that there
+/// are at most `MAX_DEPENDENCIES` of them and that each exists by
+/// getting its status with `status_of`.
+pub fn validate_depends_on(
+    api: &dyn Api,
+    depends_on: Vec<(String, u64)>,
+    mut status_of: impl FnMut(&Addr, u64) -> StdResult<Status>,
+) -> Result<Vec<(Addr, u64)>, VotingError> {
+    if depends_on.len() > MAX_DEPENDENCIES {
+        return Err(VotingError::TooManyDependencies {
+            max: MAX_DEPENDENCIES,
+        });
+    }
+    depends_on
+        .into_iter()
+        .map(|(module, proposal_id)| {
+            let module = api.addr_validate(&module)?;
+            status_of(&module, proposal_id)?;
+            Ok((module, proposal_id))
+        })
+        .collect()
+}
+
+/// Gets the combined status of a proposal's dependencies, getting the
+/// status of each with `status_of`. A failed dependency takes
+/// precedence over a pending one.
+pub fn dependency_status(
+    depends_on: &[(Addr, u64)],
+    mut status_of: impl FnMut(&Addr, u64) -> StdResult<Status>,
+) -> StdResult<DependencyStatus> {
+    let mut pending = None;
+    for (module, proposal_id) in depends_on {
+        let status = status_of(module, *proposal_id)?;
+        if is_failed_dependency(&status) {
+            return Ok(DependencyStatus::Failed {
+                module: module.clone(),
+                proposal_id: *proposal_id,
+            });
+        }
+        if status != Status::Executed && pending.is_none() {
+            pending = Some(DependencyStatus::Pending {
+                module: module.clone(),
+                proposal_id: *proposal_id,
+            });
+        }
+    }
+    Ok(pending.unwrap_or(DependencyStatus::Executed))
+}
+
+/// Returns true if a proposal with the given status may be closed.
+/// Rejected proposals may always be closed. Proposals that have not
+/// completed may also be closed once one of their dependencies has
+/// failed, which is only checked, by calling `dependencies`, if
+/// needed.
+pub fn is_closable(
+    status: &Status,
+    dependencies: impl FnOnce() -> StdResult<DependencyStatus>,
+) -> StdResult<bool> {
+    Ok(match status {
+        Status::Rejected => true,
+        Status::Open | Status::Passed | Status::VetoTimelock { .. } => {
+            matches!(dependencies()?, DependencyStatus::Failed { .. })
+        }
+        _ => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, testing::mock_dependencies, StdError};
+
+    use super::*;
+
+    fn status_of<'a>(statuses: &'a [Status]) -> impl FnMut(&Addr, u64) -> StdResult<Status> + 'a {
+        move |_, proposal_id| Ok(statuses[proposal_id as usize])
+    }
+
+    #[test]
+    fn test_dependency_status() {
+        let depends_on = |n: u64| {
+            (0..n)
+                .map(|id| (Addr::unchecked("module"), id))
+                .collect::<Vec<_>>()
+        };
+
+        let statuses = [Status::Executed, Status::Executed];
+        assert_eq!(
+            dependency_status(&depends_on(2), status_of(&statuses)).unwrap(),
+            DependencyStatus::Executed
+        );
+
+        let statuses = [Status::Executed, Status::Open, Status::Passed];
+        assert_eq!(
+            dependency_status(&depends_on(3), status_of(&statuses)).unwrap(),
+            DependencyStatus::Pending {
+                module: Addr::unchecked("module"),
+                proposal_id: 1
+            }
+        );
+
+        // Failed dependencies take precedence over pending ones.
+        for failed in [
+            Status::Rejected,
+            Status::Vetoed,
+            Status::Closed,
+            Status::ExecutionFailed,
+        ] {
+            let statuses = [Status::Open, failed];
+            assert_eq!(
+                dependency_status(&depends_on(2), status_of(&statuses)).unwrap(),
+                DependencyStatus::Failed {
+                    module: Addr::unchecked("module"),
+                    proposal_id: 1
+                }
+            );
+        }
+
+        // A partially executed dependency is pending until its failed
+        // messages are retried and it is executed.
+        let statuses = [Status::PartiallyExecuted];
+        assert_eq!(
+            dependency_status(&depends_on(1), status_of(&statuses)).unwrap(),
+            DependencyStatus::Pending {
+                module: Addr::unchecked("module"),
+                proposal_id: 0
+            }
+        );
+        let statuses = [Status::Executed];
+        assert_eq!(
+            dependency_status(&depends_on(1), status_of(&statuses)).unwrap(),
+            DependencyStatus::Executed
+        );
+
+        assert_eq!(
+            dependency_status(&[], status_of(&[])).unwrap(),
+            DependencyStatus::Executed
+        );
+    }
+
+    #[test]
+    fn test_validate_depends_on() {
+        let deps = mock_dependencies();
+        let statuses = [Status::Executed; MAX_DEPENDENCIES + 1];
+        let depends_on = |n: usize| {
+            (0..n as u64)
+                .map(|id| ("module".to_string(), id))
+                .collect::<Vec<_>>()
+        };
+
+        let validated = validate_depends_on(
+            &deps.api,
+            depends_on(MAX_DEPENDENCIES),
+            status_of(&statuses),
+        )
+        .unwrap();
+        assert_eq!(validated.len(), MAX_DEPENDENCIES);
+
+        let err = validate_depends_on(
+            &deps.api,
+            depends_on(MAX_DEPENDENCIES + 1),
+            status_of(&statuses),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            VotingError::TooManyDependencies {
+                max: MAX_DEPENDENCIES
+            }
+        );
+
+        // Dependencies that do not exist are rejected.
+        let err = validate_depends_on(&deps.api, depends_on(1), |_, _| {
+            Err(StdError::not_found("proposal"))
+        })
+        .unwrap_err();
+        assert_eq!(err, VotingError::Std(StdError::not_found("proposal")));
+    }
+
+    #[test]
+    fn test_is_closable() {
+        let failed = || -> StdResult<DependencyStatus> {
+            Ok(DependencyStatus::Failed {
+                module: Addr::unchecked("module"),
+                proposal_id: 1,
+            })
+        };
+        let pending = || -> StdResult<DependencyStatus> {
+            Ok(DependencyStatus::Pending {
+                module: Addr::unchecked("module"),
+                proposal_id: 1,
+            })
+        };
+        let unchecked = || -> StdResult<DependencyStatus> { panic!("dependencies queried") };
+
+        assert!(is_closable(&Status::Rejected, unchecked).unwrap());
+        assert!(is_closable(&Status::Open, failed).unwrap());
+        assert!(is_closable(&Status::Passed, failed).unwrap());
+        assert!(!is_closable(&Status::Passed, pending).unwrap());
+        assert!(!is_closable(&Status::Executed, unchecked).unwrap());
+        assert!(!is_closable(&Status::Closed, unchecked).unwrap());
+    }
+
+    #[test]
+    fn test_condorcet_status() {
+        let status = |json: &str| -> Status {
+            from_json::<CondorcetProposalStatusResponse>(json.as_bytes())
+                .unwrap()
+                .proposal
+                .last_status
+                .into()
+        };
+        assert_eq!(
+            status(r#"{"proposal":{"id":1,"last_status":"open"},"tally":{}}"#),
+            Status::Open
+        );
+        assert_eq!(
+            status(r#"{"proposal":{"last_status":{"passed":{"winner":2}}}}"#),
+            Status::Passed
+        );
+        assert_eq!(
+            status(r#"{"proposal":{"last_status":"execution_failed"}}"#),
+            Status::ExecutionFailed
+        );
+        assert_eq!(
+            status(
+                r#"{"proposal":{"last_status":{"veto_timelock":{"winner":0,"expiration":{"at_height":10}}}}}"#
+            ),
+            Status::VetoTimelock {
+                expiration: Expiration::AtHeight(10)
+            }
+        );
+    }
 }
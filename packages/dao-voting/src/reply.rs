@@ -10,9 +10,19 @@ const FAILED_VOTE_HOOK_MASK: u64 = 0b010;
 const PRE_PROPOSE_MODULE_INSTANTIATION_ID: u64 = 0b011;
 const FAILED_PRE_PROPOSE_MODULE_HOOK_ID: u64 = 0b100;
 
+const PROPOSAL_MESSAGE_EXECUTION_MASK: u64 = 0b101;
+const PROPOSAL_EXECUTION_RESULT_MASK: u64 = 0b110;
+
 const BITS_RESERVED_FOR_REPLY_TYPE: u8 = 3;
 const REPLY_TYPE_MASK: u64 = (1 << BITS_RESERVED_FOR_REPLY_TYPE) - 1;
 
+/// Proposal message execution replies need to convey both the
+/// proposal ID and the index of the message being executed. The
+/// lowest bits after the reply type hold the message index, and the
+/// remaining bits hold the proposal ID.
+const BITS_RESERVED_FOR_MESSAGE_INDEX: u8 = 16;
+const MESSAGE_INDEX_MASK: u64 = (1 << BITS_RESERVED_FOR_MESSAGE_INDEX) - 1;
+
 /// Since we can only pass `id`, and we need to perform different actions in reply,
 /// we decided to take few bits to identify "Reply Type".
 /// See <https://github.com/DA0-DA0/dao-contracts/pull/385#discussion_r916324843>
@@ -32,6 +42,14 @@ pub enum TaggedReplyId {
     FailedPreProposeModuleHook,
     /// Fired when a pre-propose module is successfully instantiated.
     PreProposeModuleInstantiation,
+    /// Fired when one of a proposal's messages that is executed on its
+    /// own finishes executing, whether it succeeded or failed. Holds
+    /// the proposal ID and the index of the message.
+    ProposalMessageExecution(u64, u64),
+    /// Fired when a proposal's messages finish executing. Holds the
+    /// proposal ID. Depending on the proposal module's configuration
+    /// this may fire only on success, or on both success and failure.
+    ProposalExecutionResult(u64),
 }
 
 impl TaggedReplyId {
@@ -50,6 +68,13 @@ impl TaggedReplyId {
             FAILED_VOTE_HOOK_MASK => Ok(TaggedReplyId::FailedVoteHook(id_after_shift)),
             PRE_PROPOSE_MODULE_INSTANTIATION_ID => Ok(TaggedReplyId::PreProposeModuleInstantiation),
             FAILED_PRE_PROPOSE_MODULE_HOOK_ID => Ok(TaggedReplyId::FailedPreProposeModuleHook),
+            PROPOSAL_MESSAGE_EXECUTION_MASK => Ok(TaggedReplyId::ProposalMessageExecution(
+                id_after_shift >> BITS_RESERVED_FOR_MESSAGE_INDEX,
+                id_after_shift & MESSAGE_INDEX_MASK,
+            )),
+            PROPOSAL_EXECUTION_RESULT_MASK => {
+                Ok(TaggedReplyId::ProposalExecutionResult(id_after_shift))
+            }
             _ => Err(error::TagError::UnknownReplyId { id }),
         }
     }
@@ -68,6 +93,20 @@ pub const fn mask_vote_hook_index(index: u64) -> u64 {
     FAILED_VOTE_HOOK_MASK | (index << BITS_RESERVED_FOR_REPLY_TYPE)
 }
 
+/// This function can drop bits, if you have more than `u45` proposals
+/// or more than `u16` messages in a proposal. The latter is not
+/// possible given `MAX_PROPOSAL_SIZE`.
+pub const fn mask_proposal_message_execution_id(proposal_id: u64, msg_index: u64) -> u64 {
+    PROPOSAL_MESSAGE_EXECUTION_MASK
+        | (((proposal_id << BITS_RESERVED_FOR_MESSAGE_INDEX) | (msg_index & MESSAGE_INDEX_MASK))
+            << BITS_RESERVED_FOR_REPLY_TYPE)
+}
+
+/// This function can drop bits, if you have more than `u(64-[`BITS_RESERVED_FOR_REPLY_TYPE`])` proposals.
+pub const fn mask_proposal_execution_result_id(proposal_id: u64) -> u64 {
+    PROPOSAL_EXECUTION_RESULT_MASK | (proposal_id << BITS_RESERVED_FOR_REPLY_TYPE)
+}
+
 pub const fn pre_propose_module_instantiation_id() -> u64 {
     PRE_PROPOSE_MODULE_INSTANTIATION_ID
 }
@@ -100,6 +139,10 @@ mod test {
         let m_proposal_id = mask_proposal_execution_proposal_id(proposal_id_max);
         let m_proposal_hook_idx = mask_proposal_hook_index(proposal_hook_idx);
         let m_vote_hook_idx = mask_vote_hook_index(vote_hook_idx);
+        // max u45
+        let message_proposal_id_max: u64 = 2_u64.pow(45) - 1;
+        let m_message_execution = mask_proposal_message_execution_id(message_proposal_id_max, 1234);
+        let m_execution_result = mask_proposal_execution_result_id(proposal_id_max);
 
         assert_eq!(
             TaggedReplyId::new(m_proposal_id).unwrap(),
@@ -114,8 +157,16 @@ mod test {
             TaggedReplyId::FailedVoteHook(vote_hook_idx)
         );
         assert_eq!(
-            TaggedReplyId::new(0b110).unwrap_err(),
-            error::TagError::UnknownReplyId { id: 0b110 }
+            TaggedReplyId::new(m_message_execution).unwrap(),
+            TaggedReplyId::ProposalMessageExecution(message_proposal_id_max, 1234)
+        );
+        assert_eq!(
+            TaggedReplyId::new(m_execution_result).unwrap(),
+            TaggedReplyId::ProposalExecutionResult(proposal_id_max)
+        );
+        assert_eq!(
+            TaggedReplyId::new(0b111).unwrap_err(),
+            error::TagError::UnknownReplyId { id: 0b111 }
         );
     }
 }
@@ -0,0 +1,301 @@
+use bech32::FromBase32;
+use cosmwasm_schema::{cw_serde, serde::Serialize};
+use cosmwasm_std::{
+    to_json_binary, to_json_vec, Addr, Api, Binary, Empty, Env, StdError, VerificationError,
+};
+use cw_utils::Expiration;
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum SignedVoteError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    Verification(#[from] VerificationError),
+
+    #[error("signed vote is for chain ({chain_id})")]
+    WrongChain { chain_id: String },
+
+    #[error("signed vote is for module ({module})")]
+    WrongModule { module: String },
+
+    #[error("signed vote expired at ({expiry})")]
+    Expired { expiry: Expiration },
+
+    #[error("invalid nonce ({nonce}), expected ({expected})")]
+    InvalidNonce { nonce: u64, expected: u64 },
+
+    #[error("public key does not belong to voter ({voter})")]
+    PubkeyMismatch { voter: String },
+
+    #[error("invalid signature")]
+    InvalidSignature {},
+}
+
+/// A vote signed by a voter so that it may be submitted on their
+/// behalf by someone else.
+#[cw_serde]
+pub struct SignedVotePayload<V> {
+    /// The chain the vote is valid on.
+    pub chain_id: String,
+    /// The proposal module the vote is valid for.
+    pub module: String,
+    /// The proposal being voted on.
+    pub proposal_id: u64,
+    /// The voter's position on the proposal.
+    pub vote: V,
+    /// The voter's next nonce in the proposal module. Each nonce may
+    /// only be used once, which prevents replay.
+    pub nonce: u64,
+    /// The time after which the signed vote may no longer be
+    /// submitted.
+    pub expiry: Expiration,
+}
+
+/// An amino JSON sign doc as specified by ADR-036 for signing
+/// arbitrary data, with empty chain ID, fee, memo, and sequence. Its
+/// fields are in alphabetical order so that it serializes as the
+/// canonical JSON that wallets sign.
+#[cw_serde]
+struct StdSignDoc {
+    account_number: String,
+    chain_id: String,
+    fee: StdFee,
+    memo: String,
+    msgs: Vec<MsgSignData>,
+    sequence: String,
+}
+
+#[cw_serde]
+struct StdFee {
+    amount: Vec<Empty>,
+    gas: String,
+}
+
+#[cw_serde]
+struct MsgSignData {
+    #[serde(rename = "type")]
+    msg_type: String,
+    value: MsgSignDataValue,
+}
+
+#[cw_serde]
+struct MsgSignDataValue {
+    data: Binary,
+    signer: String,
+}
+
+impl<V: Serialize> SignedVotePayload<V> {
+    /// The ADR-036 sign doc signed by `voter`, whose data is the
+    /// payload serialized as JSON. This is what wallets sign with,
+    /// for example, Keplr's `signArbitrary`.
+    pub fn sign_doc(&self, voter: &Addr) -> Result<Vec<u8>, StdError> {
+        to_json_vec(&StdSignDoc {
+            account_number: "0".to_string(),
+            chain_id: "".to_string(),
+            fee: StdFee {
+                amount: vec![],
+                gas: "0".to_string(),
+            },
+            memo: "".to_string(),
+            msgs: vec![MsgSignData {
+                msg_type: "sign/MsgSignData".to_string(),
+                value: MsgSignDataValue {
+                    data: to_json_binary(self)?,
+                    signer: voter.to_string(),
+                },
+            }],
+            sequence: "0".to_string(),
+        })
+    }
+
+    /// The hash signed by `voter`, being the SHA-256 hash of the
+    /// payload's sign doc.
+    pub fn hash(&self, voter: &Addr) -> Result<[u8; 32], StdError> {
+        Ok(Sha256::digest(self.sign_doc(voter)?).into())
+    }
+
+    /// Verifies that this payload may be submitted now to the current
+    /// contract, that it uses the voter's `expected_nonce`, and that
+    /// its sign doc was signed with the secp256k1 key belonging to
+    /// `voter`.
+    pub fn verify(
+        &self,
+        api: &dyn Api,
+        env: &Env,
+        voter: &Addr,
+        expected_nonce: u64,
+        pubkey: &Binary,
+        signature: &Binary,
+    ) -> Result<(), SignedVoteError> {
+        if self.chain_id != env.block.chain_id {
+            return Err(SignedVoteError::WrongChain {
+                chain_id: self.chain_id.clone(),
+            });
+        }
+        if self.module != env.contract.address {
+            return Err(SignedVoteError::WrongModule {
+                module: self.module.clone(),
+            });
+        }
+        if self.expiry.is_expired(&env.block) {
+            return Err(SignedVoteError::Expired {
+                expiry: self.expiry,
+            });
+        }
+        if self.nonce != expected_nonce {
+            return Err(SignedVoteError::InvalidNonce {
+                nonce: self.nonce,
+                expected: expected_nonce,
+            });
+        }
+        if !pubkey_matches_address(pubkey, voter.as_str()) {
+            return Err(SignedVoteError::PubkeyMismatch {
+                voter: voter.to_string(),
+            });
+        }
+        if !api.secp256k1_verify(&self.hash(voter)?, signature, pubkey)? {
+            return Err(SignedVoteError::InvalidSignature {});
+        }
+        Ok(())
+    }
+}
+
+/// Whether or not a secp256k1 public key belongs to a bech32
+/// address. Addresses are the RIPEMD-160 hash of the SHA-256 hash of
+/// the compressed public key.
+pub fn pubkey_matches_address(pubkey: &Binary, address: &str) -> bool {
+    let hash = Ripemd160::digest(Sha256::digest(pubkey.as_slice()));
+    bech32::decode(address)
+        .ok()
+        .and_then(|(_, data, _)| Vec::<u8>::from_base32(&data).ok())
+        .map_or(false, |data| data == hash.as_slice())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env},
+        Timestamp,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_pubkey_matches_address() {
+        let pubkey = Binary::from_base64("AsRNEscGXYEuis8o18uxn5AR7Nnp/fKBsOajteh9Iufb").unwrap();
+        assert!(pubkey_matches_address(
+            &pubkey,
+            "juno16l746xap3c5p6ud5la5llgr9kawcc4yttal2tp"
+        ));
+        // Any prefix may be used.
+        assert!(pubkey_matches_address(
+            &pubkey,
+            "cosmos16l746xap3c5p6ud5la5llgr9kawcc4yta0u3va"
+        ));
+        assert!(!pubkey_matches_address(&pubkey, "juno"));
+        assert!(!pubkey_matches_address(
+            &Binary::from(vec![2; 33]),
+            "juno16l746xap3c5p6ud5la5llgr9kawcc4yttal2tp"
+        ));
+    }
+
+    #[test]
+    fn test_sign_doc() {
+        let payload = SignedVotePayload {
+            chain_id: "juno-1".to_string(),
+            module: "module".to_string(),
+            proposal_id: 1,
+            vote: "yes".to_string(),
+            nonce: 0,
+            expiry: Expiration::Never {},
+        };
+        let data = Binary::from(
+            br#"{"chain_id":"juno-1","module":"module","proposal_id":1,"vote":"yes","nonce":0,"expiry":{"never":{}}}"#
+                .as_slice(),
+        );
+        let sign_doc = payload
+            .sign_doc(&Addr::unchecked(
+                "juno16l746xap3c5p6ud5la5llgr9kawcc4yttal2tp",
+            ))
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(sign_doc).unwrap(),
+            format!(
+                r#"{{"account_number":"0","chain_id":"","fee":{{"amount":[],"gas":"0"}},"memo":"","msgs":[{{"type":"sign/MsgSignData","value":{{"data":"{}","signer":"juno16l746xap3c5p6ud5la5llgr9kawcc4yttal2tp"}}}}],"sequence":"0"}}"#,
+                data.to_base64()
+            )
+        );
+    }
+
+    #[test]
+    fn test_verify_checks_payload() {
+        let deps = mock_dependencies();
+        let env = mock_env();
+        let voter = Addr::unchecked("juno16l746xap3c5p6ud5la5llgr9kawcc4yttal2tp");
+        let pubkey = Binary::from_base64("AsRNEscGXYEuis8o18uxn5AR7Nnp/fKBsOajteh9Iufb").unwrap();
+        let payload = SignedVotePayload {
+            chain_id: env.block.chain_id.clone(),
+            module: env.contract.address.to_string(),
+            proposal_id: 1,
+            vote: "yes".to_string(),
+            nonce: 0,
+            expiry: Expiration::Never {},
+        };
+        let verify = |payload: &SignedVotePayload<String>, nonce| {
+            payload.verify(
+                &deps.api,
+                &env,
+                &voter,
+                nonce,
+                &pubkey,
+                &Binary::from(vec![1; 64]),
+            )
+        };
+
+        let err = verify(
+            &SignedVotePayload {
+                chain_id: "other".to_string(),
+                ..payload.clone()
+            },
+            0,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            SignedVoteError::WrongChain {
+                chain_id: "other".to_string()
+            }
+        );
+
+        let err = verify(
+            &SignedVotePayload {
+                expiry: Expiration::AtTime(Timestamp::from_seconds(1)),
+                ..payload.clone()
+            },
+            0,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            SignedVoteError::Expired {
+                expiry: Expiration::AtTime(Timestamp::from_seconds(1))
+            }
+        );
+
+        let err = verify(&payload, 1).unwrap_err();
+        assert_eq!(
+            err,
+            SignedVoteError::InvalidNonce {
+                nonce: 0,
+                expected: 1
+            }
+        );
+
+        let err = verify(&payload, 0).unwrap_err();
+        assert_eq!(err, SignedVoteError::InvalidSignature {});
+    }
+}
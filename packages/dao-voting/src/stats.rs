@@ -0,0 +1,124 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal, Uint128};
+
+use crate::{threshold::PercentageThreshold, voting::does_vote_count_pass};
+
+/// Participation statistics for a proposal, as returned by the
+/// `ProposalStats` query of proposal modules.
+#[cw_serde]
+pub struct ProposalStatsResponse {
+    /// The share of the total voting power that has voted.
+    pub turnout: Decimal,
+    /// The additional voting power that would need to vote in favor
+    /// of the proposal for it to pass if voting then ended. `None` if
+    /// the outstanding voting power could not pass it.
+    pub votes_needed_to_pass: Option<Uint128>,
+    /// The additional voting power that would need to vote against
+    /// the proposal for it to be rejected if voting then ended.
+    /// `None` if the outstanding voting power could not reject it.
+    pub votes_needed_to_reject: Option<Uint128>,
+    /// The additional voting power that would need to vote for quorum
+    /// to be met. `None` if the proposal has no quorum or it can not
+    /// be met.
+    pub votes_needed_for_quorum: Option<Uint128>,
+    /// True if no further votes can change the outcome of the
+    /// proposal, including while its minimum voting period holds it
+    /// open.
+    pub outcome_locked: bool,
+}
+
+/// Computes the share of `total_power` that `votes` represents. Zero
+/// if there is no voting power.
+pub fn turnout(votes: Uint128, total_power: Uint128) -> Decimal {
+    if total_power.is_zero() {
+        Decimal::zero()
+    } else {
+        Decimal::from_ratio(votes, total_power)
+    }
+}
+
+/// Finds the smallest amount of voting power, no more than
+/// `outstanding`, for which `meets` holds. `meets` must not go from
+/// holding to not holding as voting power increases.
+pub fn votes_needed(outstanding: Uint128, meets: impl Fn(Uint128) -> bool) -> Option<Uint128> {
+    if meets(Uint128::zero()) {
+        return Some(Uint128::zero());
+    }
+    if !meets(outstanding) {
+        return None;
+    }
+    // Invariant: `meets(low)` does not hold and `meets(high)` does.
+    let (mut low, mut high) = (Uint128::zero(), outstanding);
+    while high - low > Uint128::one() {
+        let mid = low + (high - low) / Uint128::new(2);
+        if meets(mid) {
+            high = mid
+        } else {
+            low = mid
+        }
+    }
+    Some(high)
+}
+
+/// Computes the additional voting power, out of `outstanding`, that
+/// must vote for `cast` votes to meet `quorum`.
+pub fn votes_needed_for_quorum(
+    cast: Uint128,
+    outstanding: Uint128,
+    total_power: Uint128,
+    quorum: PercentageThreshold,
+) -> Option<Uint128> {
+    votes_needed(outstanding, |votes| {
+        does_vote_count_pass(cast + votes, total_power, quorum)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_votes_needed() {
+        let needed = |outstanding: u128, threshold: u128| {
+            votes_needed(Uint128::new(outstanding), |votes| {
+                votes >= Uint128::new(threshold)
+            })
+        };
+        assert_eq!(needed(10, 0), Some(Uint128::zero()));
+        assert_eq!(needed(10, 1), Some(Uint128::one()));
+        assert_eq!(needed(10, 7), Some(Uint128::new(7)));
+        assert_eq!(needed(10, 10), Some(Uint128::new(10)));
+        assert_eq!(needed(10, 11), None);
+        assert_eq!(needed(0, 1), None);
+        assert_eq!(
+            needed(u128::MAX, u128::MAX - 1),
+            Some(Uint128::new(u128::MAX - 1))
+        );
+    }
+
+    #[test]
+    fn test_votes_needed_for_quorum() {
+        let needed = |cast: u128, outstanding: u128, quorum| {
+            votes_needed_for_quorum(
+                Uint128::new(cast),
+                Uint128::new(outstanding),
+                Uint128::new(100),
+                quorum,
+            )
+        };
+        let quorum = PercentageThreshold::Percent(Decimal::percent(20));
+        assert_eq!(needed(5, 95, quorum), Some(Uint128::new(15)));
+        assert_eq!(needed(5, 10, quorum), None);
+        assert_eq!(needed(30, 70, quorum), Some(Uint128::zero()));
+        assert_eq!(needed(30, 0, quorum), Some(Uint128::zero()));
+        assert_eq!(
+            needed(0, 100, PercentageThreshold::Majority {}),
+            Some(Uint128::new(51))
+        );
+        assert_eq!(
+            turnout(Uint128::new(5), Uint128::new(20)),
+            Decimal::percent(25)
+        );
+        assert_eq!(turnout(Uint128::zero(), Uint128::zero()), Decimal::zero());
+    }
+}
@@ -22,6 +22,13 @@ pub enum Status {
     VetoTimelock { expiration: Expiration },
     /// The proposal has been vetoed.
     Vetoed,
+    /// The proposal has been executed, but one or more of its
+    /// messages failed. The failed messages may be retried.
+    ///
+    /// This status was added after proposal modules were first
+    /// deployed, so contracts that deserialize `Status` from proposal
+    /// queries must be upgraded to read it.
+    PartiallyExecuted,
 }
 
 impl std::fmt::Display for Status {
@@ -37,6 +44,39 @@ impl std::fmt::Display for Status {
                 write!(f, "veto_timelock_until_{:?}", expiration)
             }
             Status::Vetoed => write!(f, "vetoed"),
+            Status::PartiallyExecuted => write!(f, "partially_executed"),
+        }
+    }
+}
+
+impl Status {
+    /// A key identifying the status without any of its associated
+    /// data, for use in storage indexes.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Status::Open => "open",
+            Status::Rejected => "rejected",
+            Status::Passed => "passed",
+            Status::Executed => "executed",
+            Status::Closed => "closed",
+            Status::ExecutionFailed => "execution_failed",
+            Status::VetoTimelock { .. } => "veto_timelock",
+            Status::Vetoed => "vetoed",
+            Status::PartiallyExecuted => "partially_executed",
+        }
+    }
+
+    /// The keys of the statuses a proposal may be stored with while
+    /// its current status is `self`. Statuses are updated lazily, so
+    /// an open proposal may have passed or been rejected, and a
+    /// timelocked proposal may have passed, without its stored status
+    /// changing.
+    pub fn stored_keys(&self) -> Vec<&'static str> {
+        match self {
+            Status::Rejected => vec!["open", "rejected"],
+            Status::Passed => vec!["open", "veto_timelock", "passed"],
+            Status::VetoTimelock { .. } => vec!["open", "veto_timelock"],
+            status => vec![status.key()],
         }
     }
 }
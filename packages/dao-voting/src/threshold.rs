@@ -62,6 +62,18 @@ pub enum ThresholdError {
 
     #[error("Not possible to reach required (passing) threshold")]
     UnreachableThreshold {},
+
+    #[error("Dynamic quorum must average over at least one proposal and have min_quorum <= max_quorum <= 1")]
+    InvalidDynamicQuorum {},
+
+    #[error("Approval votes must allow between 1 and {max} selections")]
+    InvalidMaxSelections { max: u32 },
+
+    #[error("Score votes must allow a maximum score of at least 1")]
+    InvalidMaxScore {},
+
+    #[error("Budget proposals may not require a minimum share of more than 100%")]
+    InvalidMinShare {},
 }
 
 /// A percentage of voting power that must vote yes for a proposal to
@@ -118,6 +130,19 @@ pub enum Threshold {
     /// An absolute number of votes needed for something to cross the
     /// threshold. Useful for multisig style voting.
     AbsoluteCount { threshold: Uint128 },
+
+    /// Like `ThresholdQuorum`, but the quorum is an exponential moving
+    /// average of the turnout of the last `window` proposals whose
+    /// voting has ended, clamped between `min_quorum` and
+    /// `max_quorum`. Until voting on a proposal has ended the quorum
+    /// is `max_quorum`. The quorum is fixed for each proposal when it
+    /// is created.
+    DynamicQuorum {
+        threshold: PercentageThreshold,
+        window: u64,
+        min_quorum: Decimal,
+        max_quorum: Decimal,
+    },
 }
 
 /// Asserts that the 0.0 < percent <= 1.0
@@ -171,7 +196,58 @@ impl Threshold {
                     Ok(())
                 }
             }
+            Threshold::DynamicQuorum {
+                threshold,
+                window,
+                min_quorum,
+                max_quorum,
+            } => {
+                validate_percentage(threshold)?;
+                if *window == 0 || min_quorum > max_quorum || *max_quorum > Decimal::one() {
+                    Err(ThresholdError::InvalidDynamicQuorum {})
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    /// Fixes the quorum of a dynamic quorum threshold given the
+    /// average turnout of past proposals, returning the equivalent
+    /// `ThresholdQuorum`. Other thresholds are returned unchanged.
+    pub fn snapshot_quorum(&self, average_turnout: Option<Decimal>) -> Threshold {
+        match self {
+            Threshold::DynamicQuorum {
+                threshold,
+                min_quorum,
+                max_quorum,
+                ..
+            } => Threshold::ThresholdQuorum {
+                threshold: *threshold,
+                quorum: PercentageThreshold::Percent(
+                    average_turnout
+                        .unwrap_or(*max_quorum)
+                        .clamp(*min_quorum, *max_quorum),
+                ),
+            },
+            _ => self.clone(),
+        }
+    }
+}
+
+/// Folds the final turnout of a proposal into an exponential
+/// moving average over `window` proposals. If there is no average
+/// yet, the turnout becomes the average. Windows too large for a
+/// turnout to have any weight leave the average unchanged.
+pub fn update_turnout_average(average: Option<Decimal>, turnout: Decimal, window: u64) -> Decimal {
+    match average {
+        Some(average) => {
+            let alpha = window
+                .checked_add(1)
+                .map_or(Decimal::zero(), |n| Decimal::from_ratio(2u64, n));
+            turnout * alpha + average * (Decimal::one() - alpha)
         }
+        None => turnout,
     }
 }
 
@@ -232,4 +308,57 @@ mod tests {
             ThresholdError::UnreachableThreshold {}
         );
     }
+
+    #[test]
+    fn test_dynamic_quorum() {
+        let t = Threshold::DynamicQuorum {
+            threshold: PercentageThreshold::Majority {},
+            window: 0,
+            min_quorum: Decimal::percent(10),
+            max_quorum: Decimal::percent(40),
+        };
+        assert_eq!(
+            t.validate().unwrap_err(),
+            ThresholdError::InvalidDynamicQuorum {}
+        );
+
+        let t = Threshold::DynamicQuorum {
+            threshold: PercentageThreshold::Majority {},
+            window: 3,
+            min_quorum: Decimal::percent(50),
+            max_quorum: Decimal::percent(40),
+        };
+        assert_eq!(
+            t.validate().unwrap_err(),
+            ThresholdError::InvalidDynamicQuorum {}
+        );
+
+        let t = Threshold::DynamicQuorum {
+            threshold: PercentageThreshold::Majority {},
+            window: 3,
+            min_quorum: Decimal::percent(10),
+            max_quorum: Decimal::percent(40),
+        };
+        t.validate().unwrap();
+
+        let snapshot = |turnout| match t.snapshot_quorum(turnout) {
+            Threshold::ThresholdQuorum { quorum, .. } => quorum,
+            _ => panic!("expected threshold quorum"),
+        };
+        assert_eq!(snapshot(None), p!(40));
+        assert_eq!(snapshot(Some(Decimal::percent(25))), p!(25));
+        assert_eq!(snapshot(Some(Decimal::percent(5))), p!(10));
+        assert_eq!(snapshot(Some(Decimal::percent(90))), p!(40));
+
+        // With a window of 3 each new turnout has a weight of one
+        // half.
+        let average = update_turnout_average(None, Decimal::percent(20), 3);
+        assert_eq!(average, Decimal::percent(20));
+        let average = update_turnout_average(Some(average), Decimal::percent(60), 3);
+        assert_eq!(average, Decimal::percent(40));
+
+        // The largest windows do not overflow.
+        let average = update_turnout_average(Some(average), Decimal::percent(60), u64::MAX);
+        assert_eq!(average, Decimal::percent(40));
+    }
 }
@@ -1,5 +1,6 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Deps, MessageInfo, StdError};
+use cosmwasm_std::{Addr, Deps, MessageInfo, StdError, Storage};
+use cw_storage_plus::{Map, PrimaryKey};
 use cw_utils::Duration;
 use thiserror::Error;
 
@@ -31,6 +32,18 @@ pub enum VetoError {
 
     #[error("Only vetoer can veto a proposal.")]
     Unauthorized {},
+
+    #[error("Vetoer ({vetoer}) is listed more than once.")]
+    DuplicateVetoer { vetoer: String },
+
+    #[error("The veto threshold ({threshold}) must be between one and the number of vetoers ({vetoers}).")]
+    InvalidVetoThreshold { threshold: u64, vetoers: u64 },
+
+    #[error("Vetoer has already vetoed this proposal.")]
+    AlreadyVetoed {},
+
+    #[error("Vetoer has already approved executing this proposal early.")]
+    AlreadyApprovedEarlyExecute {},
 }
 
 #[cw_serde]
@@ -41,16 +54,66 @@ pub struct VetoConfig {
     /// The address able to veto proposals.
     pub vetoer: String,
     /// Whether or not the vetoer can execute a proposal early before the
-    /// timelock duration has expired
+    /// timelock duration has expired. A veto council must reach
+    /// `veto_threshold` approvals to do so.
     pub early_execute: bool,
     /// Whether or not the vetoer can veto a proposal before it passes.
     pub veto_before_passed: bool,
+    /// Additional addresses able to veto proposals. Together with
+    /// `vetoer` these form a veto council, each member of which has the
+    /// same permissions as `vetoer`.
+    #[serde(default)]
+    pub additional_vetoers: Vec<String>,
+    /// The number of vetoers that must veto a proposal before it is
+    /// vetoed, or approve executing it early before it is executed. If
+    /// `None`, a single vetoer is enough.
+    #[serde(default)]
+    pub veto_threshold: Option<u64>,
+}
+
+/// Information about the vetoes cast on a proposal so far.
+#[cw_serde]
+pub struct VetoTallyResponse {
+    /// The vetoers who have vetoed the proposal.
+    pub vetoers: Vec<Addr>,
+    /// The number of vetoes needed for the proposal to be vetoed, or
+    /// `None` if the proposal may not be vetoed.
+    pub threshold: Option<u64>,
+}
+
+/// The vetoers who have voted to veto, or to early execute, a
+/// proposal so far.
+#[derive(Debug, PartialEq)]
+pub struct VetoerVotes {
+    /// The number of vetoers who have voted.
+    pub count: u64,
+    /// Whether `count` has reached the veto threshold, in which case
+    /// the proposal should be vetoed or executed.
+    pub threshold_reached: bool,
 }
 
 impl VetoConfig {
     pub fn validate(&self, deps: &Deps, max_voting_period: &Duration) -> Result<(), VetoError> {
-        // Validate vetoer address.
-        deps.api.addr_validate(&self.vetoer)?;
+        // Validate vetoer addresses and make sure none is repeated.
+        let mut vetoers = Vec::with_capacity(self.additional_vetoers.len() + 1);
+        for vetoer in self.vetoers() {
+            let vetoer = deps.api.addr_validate(vetoer)?;
+            if vetoers.contains(&vetoer) {
+                return Err(VetoError::DuplicateVetoer {
+                    vetoer: vetoer.into_string(),
+                });
+            }
+            vetoers.push(vetoer);
+        }
+
+        // Validate the threshold may be reached.
+        let threshold = self.threshold();
+        if threshold == 0 || threshold > vetoers.len() as u64 {
+            return Err(VetoError::InvalidVetoThreshold {
+                threshold,
+                vetoers: vetoers.len() as u64,
+            });
+        }
 
         // Validate duration units match voting period.
         match (self.timelock_duration, max_voting_period) {
@@ -71,15 +134,85 @@ impl VetoConfig {
         }
     }
 
-    /// Checks whether the message sender is the vetoer.
+    /// All of the addresses able to veto proposals.
+    pub fn vetoers(&self) -> impl Iterator<Item = &String> {
+        std::iter::once(&self.vetoer).chain(self.additional_vetoers.iter())
+    }
+
+    /// The number of vetoes needed to veto a proposal.
+    pub fn threshold(&self) -> u64 {
+        self.veto_threshold.unwrap_or(1)
+    }
+
+    /// Checks whether the message sender is a vetoer.
     pub fn check_is_vetoer(&self, info: &MessageInfo) -> Result<(), VetoError> {
-        if self.vetoer == info.sender {
+        if self.vetoers().any(|vetoer| *vetoer == info.sender) {
             Ok(())
         } else {
             Err(VetoError::Unauthorized {})
         }
     }
 
+    /// Records `vetoer`'s veto of the proposal at `key` in `votes`.
+    /// Errors if they have already vetoed it.
+    pub fn record_veto<'a, K>(
+        &self,
+        storage: &mut dyn Storage,
+        votes: &Map<'a, K, Vec<Addr>>,
+        key: K,
+        vetoer: &Addr,
+    ) -> Result<VetoerVotes, VetoError>
+    where
+        K: PrimaryKey<'a> + Clone,
+    {
+        self.record_vote(storage, votes, key, vetoer)?
+            .ok_or(VetoError::AlreadyVetoed {})
+    }
+
+    /// Records `vetoer`'s approval to execute the timelocked proposal
+    /// at `key` early in `votes`. Like a veto, early execution needs
+    /// the approval of `threshold` vetoers. Errors if they have
+    /// already approved it.
+    pub fn record_early_execute<'a, K>(
+        &self,
+        storage: &mut dyn Storage,
+        votes: &Map<'a, K, Vec<Addr>>,
+        key: K,
+        vetoer: &Addr,
+    ) -> Result<VetoerVotes, VetoError>
+    where
+        K: PrimaryKey<'a> + Clone,
+    {
+        self.record_vote(storage, votes, key, vetoer)?
+            .ok_or(VetoError::AlreadyApprovedEarlyExecute {})
+    }
+
+    /// Adds `vetoer` to the vetoers who have voted at `key` in
+    /// `votes`, returning `None` if they have already voted.
+    fn record_vote<'a, K>(
+        &self,
+        storage: &mut dyn Storage,
+        votes: &Map<'a, K, Vec<Addr>>,
+        key: K,
+        vetoer: &Addr,
+    ) -> Result<Option<VetoerVotes>, VetoError>
+    where
+        K: PrimaryKey<'a> + Clone,
+    {
+        let mut vetoers = votes.may_load(storage, key.clone())?.unwrap_or_default();
+        if vetoers.contains(vetoer) {
+            return Ok(None);
+        }
+        vetoers.push(vetoer.clone());
+        votes.save(storage, key, &vetoers)?;
+
+        let count = vetoers.len() as u64;
+        Ok(Some(VetoerVotes {
+            count,
+            threshold_reached: count >= self.threshold(),
+        }))
+    }
+
     /// Checks whether veto_before_passed is enabled, errors if not
     pub fn check_veto_before_passed_enabled(&self) -> Result<(), VetoError> {
         if self.veto_before_passed {
@@ -89,3 +222,117 @@ impl VetoConfig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_dependencies;
+
+    use super::*;
+
+    fn council(additional_vetoers: &[&str], veto_threshold: Option<u64>) -> VetoConfig {
+        VetoConfig {
+            timelock_duration: Duration::Time(100),
+            vetoer: "oversight".to_string(),
+            early_execute: false,
+            veto_before_passed: false,
+            additional_vetoers: additional_vetoers.iter().map(|v| v.to_string()).collect(),
+            veto_threshold,
+        }
+    }
+
+    #[test]
+    fn test_validate_veto_council() {
+        let deps = mock_dependencies();
+        let period = Duration::Time(100);
+
+        council(&[], None)
+            .validate(&deps.as_ref(), &period)
+            .unwrap();
+        council(&["council1", "council2"], Some(3))
+            .validate(&deps.as_ref(), &period)
+            .unwrap();
+
+        let err = council(&["council1", "oversight"], None)
+            .validate(&deps.as_ref(), &period)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            VetoError::DuplicateVetoer {
+                vetoer: "oversight".to_string()
+            }
+        );
+
+        let err = council(&["council1"], Some(3))
+            .validate(&deps.as_ref(), &period)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            VetoError::InvalidVetoThreshold {
+                threshold: 3,
+                vetoers: 2
+            }
+        );
+
+        let err = council(&["council1"], Some(0))
+            .validate(&deps.as_ref(), &period)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            VetoError::InvalidVetoThreshold {
+                threshold: 0,
+                vetoers: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_record_vetoer_votes() {
+        let mut deps = mock_dependencies();
+        let votes: Map<u64, Vec<Addr>> = Map::new("votes");
+        let config = council(&["council1", "council2"], Some(2));
+        let oversight = Addr::unchecked("oversight");
+        let council1 = Addr::unchecked("council1");
+
+        let recorded = config
+            .record_veto(deps.as_mut().storage, &votes, 1, &oversight)
+            .unwrap();
+        assert_eq!(
+            recorded,
+            VetoerVotes {
+                count: 1,
+                threshold_reached: false
+            }
+        );
+
+        let err = config
+            .record_veto(deps.as_mut().storage, &votes, 1, &oversight)
+            .unwrap_err();
+        assert_eq!(err, VetoError::AlreadyVetoed {});
+
+        // Votes on other proposals are counted separately.
+        let recorded = config
+            .record_veto(deps.as_mut().storage, &votes, 2, &council1)
+            .unwrap();
+        assert_eq!(recorded.count, 1);
+
+        let recorded = config
+            .record_veto(deps.as_mut().storage, &votes, 1, &council1)
+            .unwrap();
+        assert_eq!(
+            recorded,
+            VetoerVotes {
+                count: 2,
+                threshold_reached: true
+            }
+        );
+
+        let approvals: Map<u64, Vec<Addr>> = Map::new("approvals");
+        config
+            .record_early_execute(deps.as_mut().storage, &approvals, 1, &council1)
+            .unwrap();
+        let err = config
+            .record_early_execute(deps.as_mut().storage, &approvals, 1, &council1)
+            .unwrap_err();
+        assert_eq!(err, VetoError::AlreadyApprovedEarlyExecute {});
+    }
+}
@@ -1,6 +1,6 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Decimal, Deps, StdResult, Uint128, Uint256};
-use cw_utils::Duration;
+use cw_utils::{Duration, Expiration};
 use dao_interface::voting;
 
 use crate::threshold::PercentageThreshold;
@@ -12,8 +12,14 @@ const PRECISION_FACTOR: u128 = 10u128.pow(9);
 #[cw_serde]
 pub struct Votes {
     pub yes: Uint128,
+    /// All opposition to the proposal, including `NoWithVeto` votes.
     pub no: Uint128,
     pub abstain: Uint128,
+    /// The portion of `no` that was cast as `NoWithVeto`. This is
+    /// already counted in `no` and is tracked separately so that
+    /// vetoes can be compared against a threshold.
+    #[serde(default)]
+    pub no_with_veto: Uint128,
 }
 
 #[cw_serde]
@@ -27,6 +33,10 @@ pub enum Vote {
     /// Marks participation but does not count towards the ratio of
     /// support / opposed.
     Abstain,
+    /// Marks opposition to the proposal and counts towards vetoing
+    /// it. If enough voting power vetoes a proposal, it is rejected
+    /// and its deposit is forfeited.
+    NoWithVeto,
 }
 
 #[cw_serde]
@@ -143,6 +153,7 @@ impl Votes {
             yes: Uint128::zero(),
             no: Uint128::zero(),
             abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
         }
     }
 
@@ -154,6 +165,7 @@ impl Votes {
             yes,
             no: Uint128::zero(),
             abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
         }
     }
 
@@ -163,6 +175,10 @@ impl Votes {
             Vote::Yes => self.yes += power,
             Vote::No => self.no += power,
             Vote::Abstain => self.abstain += power,
+            Vote::NoWithVeto => {
+                self.no += power;
+                self.no_with_veto += power;
+            }
         }
     }
 
@@ -174,6 +190,10 @@ impl Votes {
             Vote::Yes => self.yes -= power,
             Vote::No => self.no -= power,
             Vote::Abstain => self.abstain -= power,
+            Vote::NoWithVeto => {
+                self.no -= power;
+                self.no_with_veto -= power;
+            }
         }
     }
 
@@ -195,6 +215,7 @@ impl std::fmt::Display for Vote {
             Vote::Yes => write!(f, "yes"),
             Vote::No => write!(f, "no"),
             Vote::Abstain => write!(f, "abstain"),
+            Vote::NoWithVeto => write!(f, "no_with_veto"),
         }
     }
 }
@@ -248,6 +269,87 @@ pub fn validate_voting_period(
     Ok((min, max))
 }
 
+/// A range of voting periods that proposers may choose from when
+/// creating a proposal.
+#[cw_serde]
+#[derive(Copy)]
+pub struct VotingPeriodRange {
+    /// The shortest voting period a proposer may choose.
+    pub min: Duration,
+    /// The longest voting period a proposer may choose.
+    pub max: Duration,
+}
+
+impl VotingPeriodRange {
+    /// Validates the range against a proposal module's voting
+    /// periods. The range must have the same units as the max voting
+    /// period, and every voting period in it must be between the min
+    /// and max voting periods.
+    pub fn validate(
+        &self,
+        min_voting_period: Option<Duration>,
+        max_voting_period: Duration,
+    ) -> Result<(), crate::error::VotingError> {
+        validate_voting_period(Some(self.min), self.max)?;
+        validate_voting_period(min_voting_period, self.min)?;
+        let within_max = match (self.max, max_voting_period) {
+            (Duration::Time(max), Duration::Time(limit))
+            | (Duration::Height(max), Duration::Height(limit)) => max <= limit,
+            _ => return Err(crate::error::VotingError::DurationUnitsConflict {}),
+        };
+        if within_max {
+            Ok(())
+        } else {
+            Err(crate::error::VotingError::VotingPeriodRangeTooLong {})
+        }
+    }
+
+    /// Checks that a voting period chosen by a proposer is within the
+    /// range. Passes the voting period through the function.
+    pub fn check(&self, voting_period: Duration) -> Result<Duration, crate::error::VotingError> {
+        let in_range = match (self.min, voting_period, self.max) {
+            (Duration::Time(min), Duration::Time(period), Duration::Time(max))
+            | (Duration::Height(min), Duration::Height(period), Duration::Height(max)) => {
+                min <= period && period <= max
+            }
+            _ => return Err(crate::error::VotingError::DurationUnitsConflict {}),
+        };
+        if in_range {
+            Ok(voting_period)
+        } else {
+            Err(crate::error::VotingError::VotingPeriodOutOfRange {})
+        }
+    }
+}
+
+/// Validates that an earliest execution time has the same units as
+/// the max voting period. Passes the argument through the function.
+pub fn validate_execute_after(
+    execute_after: Option<Expiration>,
+    max_voting_period: &Duration,
+) -> Result<Option<Expiration>, crate::error::VotingError> {
+    match (execute_after, max_voting_period) {
+        (None, _)
+        | (Some(Expiration::AtHeight(_)), Duration::Height(_))
+        | (Some(Expiration::AtTime(_)), Duration::Time(_)) => Ok(execute_after),
+        _ => Err(crate::error::VotingError::ExecuteAfterUnitsConflict {}),
+    }
+}
+
+/// Validates that a `NoWithVeto` threshold is strictly between zero
+/// and one. The share of vetoes must exceed the threshold, so a
+/// threshold of one could never be reached.
+pub fn validate_no_with_veto_threshold(
+    threshold: Option<Decimal>,
+) -> Result<(), crate::error::VotingError> {
+    match threshold {
+        Some(threshold) if threshold.is_zero() || threshold >= Decimal::one() => {
+            Err(crate::error::VotingError::InvalidNoWithVetoThreshold {})
+        }
+        _ => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -263,6 +365,29 @@ mod test {
         assert_eq!(votes.yes, Uint128::new(35));
         assert_eq!(votes.no, Uint128::new(10));
         assert_eq!(votes.abstain, Uint128::new(40));
+
+        votes.add_vote(Vote::NoWithVeto, Uint128::new(15));
+        assert_eq!(votes.total(), Uint128::new(5 + 10 + 30 + 40 + 15));
+        assert_eq!(votes.no, Uint128::new(25));
+        assert_eq!(votes.no_with_veto, Uint128::new(15));
+
+        votes.remove_vote(Vote::NoWithVeto, Uint128::new(15));
+        assert_eq!(votes.no, Uint128::new(10));
+        assert_eq!(votes.no_with_veto, Uint128::zero());
+    }
+
+    #[test]
+    fn no_with_veto_threshold_validation() {
+        assert!(validate_no_with_veto_threshold(None).is_ok());
+        assert!(validate_no_with_veto_threshold(Some(Decimal::percent(33))).is_ok());
+        assert_eq!(
+            validate_no_with_veto_threshold(Some(Decimal::zero())),
+            Err(crate::error::VotingError::InvalidNoWithVetoThreshold {})
+        );
+        assert_eq!(
+            validate_no_with_veto_threshold(Some(Decimal::one())),
+            Err(crate::error::VotingError::InvalidNoWithVetoThreshold {})
+        );
     }
 
     #[test]
@@ -461,4 +586,80 @@ mod test {
             Decimal::percent(0)
         ))
     }
+
+    #[test]
+    fn test_validate_execute_after() {
+        use cosmwasm_std::Timestamp;
+
+        assert_eq!(
+            validate_execute_after(None, &Duration::Height(10)),
+            Ok(None)
+        );
+        assert_eq!(
+            validate_execute_after(Some(Expiration::AtHeight(100)), &Duration::Height(10)),
+            Ok(Some(Expiration::AtHeight(100)))
+        );
+        assert_eq!(
+            validate_execute_after(
+                Some(Expiration::AtTime(Timestamp::from_seconds(100))),
+                &Duration::Time(10)
+            ),
+            Ok(Some(Expiration::AtTime(Timestamp::from_seconds(100))))
+        );
+        assert_eq!(
+            validate_execute_after(Some(Expiration::AtHeight(100)), &Duration::Time(10)),
+            Err(crate::error::VotingError::ExecuteAfterUnitsConflict {})
+        );
+        assert_eq!(
+            validate_execute_after(Some(Expiration::Never {}), &Duration::Height(10)),
+            Err(crate::error::VotingError::ExecuteAfterUnitsConflict {})
+        );
+    }
+
+    #[test]
+    fn test_voting_period_range() {
+        let range = VotingPeriodRange {
+            min: Duration::Time(10),
+            max: Duration::Time(100),
+        };
+        range.validate(None, Duration::Time(100)).unwrap();
+        range
+            .validate(Some(Duration::Time(10)), Duration::Time(200))
+            .unwrap();
+        assert_eq!(
+            range.validate(None, Duration::Time(50)),
+            Err(crate::error::VotingError::VotingPeriodRangeTooLong {})
+        );
+        assert_eq!(
+            range.validate(Some(Duration::Time(11)), Duration::Time(100)),
+            Err(crate::error::VotingError::InvalidMinVotingPeriod {})
+        );
+        assert_eq!(
+            range.validate(None, Duration::Height(100)),
+            Err(crate::error::VotingError::DurationUnitsConflict {})
+        );
+        assert_eq!(
+            VotingPeriodRange {
+                min: Duration::Time(100),
+                max: Duration::Time(10),
+            }
+            .validate(None, Duration::Time(100)),
+            Err(crate::error::VotingError::InvalidMinVotingPeriod {})
+        );
+
+        assert_eq!(range.check(Duration::Time(10)), Ok(Duration::Time(10)));
+        assert_eq!(range.check(Duration::Time(100)), Ok(Duration::Time(100)));
+        assert_eq!(
+            range.check(Duration::Time(9)),
+            Err(crate::error::VotingError::VotingPeriodOutOfRange {})
+        );
+        assert_eq!(
+            range.check(Duration::Time(101)),
+            Err(crate::error::VotingError::VotingPeriodOutOfRange {})
+        );
+        assert_eq!(
+            range.check(Duration::Height(50)),
+            Err(crate::error::VotingError::DurationUnitsConflict {})
+        );
+    }
 }
@@ -153,6 +153,10 @@ cd contracts/proposal/dao-proposal-condorcet
 cargo hack publish --no-dev-deps --allow-dirty
 cd "$START_DIR"
 
+cd contracts/pre-propose/dao-pre-propose-condorcet
+cargo hack publish --no-dev-deps --allow-dirty
+cd "$START_DIR"
+
 cd contracts/staking/cw20-stake
 cargo hack publish --no-dev-deps --allow-dirty
 cd "$START_DIR"